@@ -0,0 +1,257 @@
+//! Throughput benchmarks of the executor.
+//!
+//! Run them with `cargo bench --bench executor`. Each benchmark prints the average time
+//! of one iteration.
+//!
+//! The results depend on the machine, so no baseline is committed. To compare with your own one,
+//! save it with `ORENGINE_BENCH_SAVE_BASELINE=<path>` and pass it later with
+//! `ORENGINE_BENCH_BASELINE=<path>`. If some benchmark is slower than its baseline
+//! by more than [`TOLERANCE`], the process exits with a non-zero code.
+use orengine::io::{
+    buffer, full_buffer, AsyncAccept, AsyncBind, AsyncConnectStream, AsyncRecv, AsyncSend,
+};
+use orengine::net::{Socket, TcpListener, TcpStream};
+use orengine::runtime::{run_on_new_thread, Config};
+use orengine::sync::{AsyncMutex, LocalMutex};
+use orengine::{local_executor, yield_now, Executor};
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::hint::black_box;
+use std::path::Path;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// The allowed slowdown relative to the baseline.
+const TOLERANCE: f64 = 0.2;
+
+/// The environment variable with the path of the baseline to compare the results with.
+const BASELINE_VAR: &str = "ORENGINE_BENCH_BASELINE";
+
+/// The environment variable with the path to save the results to.
+const SAVE_BASELINE_VAR: &str = "ORENGINE_BENCH_SAVE_BASELINE";
+
+/// Results of the benchmarks: the name and the average time of one iteration in nanoseconds.
+type Results = BTreeMap<&'static str, f64>;
+
+/// Records the average time of one of `iterations` since `start`.
+#[allow(clippy::cast_precision_loss, reason = "It is a benchmark.")]
+fn record(results: &mut Results, name: &'static str, start: Instant, iterations: usize) {
+    let ns_per_iter = start.elapsed().as_nanos() as f64 / iterations as f64;
+    println!("{name:<24} {ns_per_iter:>12.1} ns/iter");
+
+    results.insert(name, ns_per_iter);
+}
+
+/// Measures spawning and completion of `local` tasks.
+async fn bench_local_spawn(results: &mut Results) {
+    const ITERATIONS: usize = 100_000;
+
+    let executed = Rc::new(Cell::new(0));
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        let executed = executed.clone();
+        local_executor().spawn_local(async move {
+            executed.set(executed.get() + 1);
+        });
+    }
+
+    while executed.get() < ITERATIONS {
+        yield_now().await;
+    }
+
+    record(results, "local_spawn", start, ITERATIONS);
+}
+
+/// Measures spawning and completion of `shared` tasks that can be executed
+/// by another executor.
+async fn bench_shared_spawn(results: &mut Results) {
+    const ITERATIONS: usize = 100_000;
+
+    let executed = Arc::new(AtomicUsize::new(0));
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        let executed = executed.clone();
+        local_executor().spawn_shared(async move {
+            executed.fetch_add(1, Ordering::Relaxed);
+        });
+    }
+
+    while executed.load(Ordering::Relaxed) < ITERATIONS {
+        yield_now().await;
+    }
+
+    record(results, "shared_spawn", start, ITERATIONS);
+}
+
+/// Measures a `send` + `recv` round-trip of 64 bytes over a loopback `TCP` connection.
+async fn bench_tcp_round_trip(results: &mut Results) {
+    const ITERATIONS: usize = 20_000;
+    const MESSAGE: [u8; 64] = [42; 64];
+
+    let mut listener = TcpListener::bind("127.0.0.1:0").await.expect("bind failed");
+    let addr = listener.local_addr().expect("local_addr failed");
+
+    local_executor().spawn_local(async move {
+        let (mut stream, _) = listener.accept().await.expect("accept failed");
+        let mut buf = full_buffer();
+        loop {
+            let n = stream.recv(&mut buf).await.expect("recv failed");
+            if n == 0 {
+                break;
+            }
+
+            stream.send_all(&buf.slice(..n)).await.expect("send failed");
+        }
+    });
+
+    let mut stream = TcpStream::connect(addr).await.expect("connect failed");
+    let mut buf = vec![0; MESSAGE.len()];
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        stream.send_all_bytes(&MESSAGE).await.expect("send failed");
+        stream
+            .recv_bytes_exact(&mut buf)
+            .await
+            .expect("recv failed");
+    }
+
+    record(results, "tcp_round_trip", start, ITERATIONS);
+}
+
+/// Measures getting a [`Buffer`](orengine::io::Buffer) from the pool and returning it back.
+fn bench_buf_pool(results: &mut Results) {
+    const ITERATIONS: usize = 1_000_000;
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        drop(black_box(buffer()));
+    }
+
+    record(results, "buf_pool_get_release", start, ITERATIONS);
+}
+
+/// Measures [`LocalMutex`] under contention of `TASKS` tasks that hold the lock
+/// across a yield.
+async fn bench_local_mutex(results: &mut Results) {
+    const TASKS: usize = 16;
+    const LOCKS_PER_TASK: usize = 5_000;
+
+    let mutex = Rc::new(LocalMutex::new(0));
+    let finished = Rc::new(Cell::new(0));
+    let start = Instant::now();
+    for _ in 0..TASKS {
+        let mutex = mutex.clone();
+        let finished = finished.clone();
+        local_executor().spawn_local(async move {
+            for _ in 0..LOCKS_PER_TASK {
+                let mut guard = mutex.lock().await;
+                *guard += 1;
+                yield_now().await;
+            }
+
+            finished.set(finished.get() + 1);
+        });
+    }
+
+    while finished.get() < TASKS {
+        yield_now().await;
+    }
+
+    assert_eq!(*mutex.lock().await, TASKS * LOCKS_PER_TASK);
+    record(
+        results,
+        "local_mutex_contention",
+        start,
+        TASKS * LOCKS_PER_TASK,
+    );
+}
+
+/// Parses the baseline written by [`write_baseline`].
+fn read_baseline(path: &Path) -> Option<BTreeMap<String, f64>> {
+    let content = std::fs::read_to_string(path).ok()?;
+
+    Some(
+        content
+            .lines()
+            .filter_map(|line| {
+                let (name, value) = line.trim().trim_end_matches(',').split_once(':')?;
+
+                Some((
+                    name.trim().trim_matches('"').to_string(),
+                    value.trim().parse().ok()?,
+                ))
+            })
+            .collect(),
+    )
+}
+
+/// Writes `results` as a flat `JSON` object.
+fn write_baseline(path: &Path, results: &Results) {
+    let entries: Vec<String> = results
+        .iter()
+        .map(|(name, value)| format!("  \"{name}\": {value:.1}"))
+        .collect();
+
+    std::fs::write(path, format!("{{\n{}\n}}\n", entries.join(",\n")))
+        .expect("failed to write the baseline");
+}
+
+/// Compares `results` with the baseline. Returns the names of the regressed benchmarks.
+fn find_regressions(baseline: &BTreeMap<String, f64>, results: &Results) -> Vec<&'static str> {
+    results
+        .iter()
+        .filter(|(name, value)| {
+            baseline
+                .get(**name)
+                .is_some_and(|baseline| **value > baseline * (1.0 + TOLERANCE))
+        })
+        .map(|(name, _)| *name)
+        .collect()
+}
+
+fn main() {
+    // The second executor takes `shared` tasks of the first one.
+    drop(run_on_new_thread(
+        Config::default(),
+        std::future::pending::<()>(),
+    ));
+
+    let mut results = Results::new();
+    Executor::init()
+        .run_and_block_on_local(async {
+            bench_local_spawn(&mut results).await;
+            bench_shared_spawn(&mut results).await;
+            bench_tcp_round_trip(&mut results).await;
+            bench_buf_pool(&mut results);
+            bench_local_mutex(&mut results).await;
+        })
+        .expect("the benchmarks have been interrupted");
+
+    if let Some(path) = std::env::var_os(SAVE_BASELINE_VAR) {
+        write_baseline(Path::new(&path), &results);
+        println!(
+            "the baseline has been written to {}",
+            path.to_string_lossy()
+        );
+    }
+
+    if let Some(path) = std::env::var_os(BASELINE_VAR) {
+        let path = Path::new(&path);
+        let Some(baseline) = read_baseline(path) else {
+            eprintln!("failed to read the baseline from {}", path.display());
+            std::process::exit(1);
+        };
+
+        let regressions = find_regressions(&baseline, &results);
+        if !regressions.is_empty() {
+            eprintln!(
+                "regressions of more than {:.0}% against {}: {regressions:?}",
+                TOLERANCE * 100.0,
+                path.display()
+            );
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! Benchmarks of work sharing between executors.
+//!
+//! Run them with `cargo bench --bench work_sharing`. Each benchmark prints the average time
+//! of one shared task.
+//!
+//! `shared_list_*` benchmarks compare the lock-free [`SegQueue`] used by the shared task lists
+//! of executors with the previous list (a `Vec` behind a lock that is never waited for).
+//! They repeat the access pattern of executors: the owner flushes a half of its queue
+//! to the list (`spawn_shared_task`), and other executors take at most `work_sharing_level`
+//! tasks at once (`take_work_if_needed`). The `SegQueue` moves tasks one at a time,
+//! the locked `Vec` moves the whole batch under one lock.
+use crossbeam::queue::SegQueue;
+use orengine::runtime::{run_on_new_thread, Config};
+use orengine::utils::SpinLock;
+use orengine::{local_executor, yield_now, Executor};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// The number of tasks that are shared in each benchmark.
+const NUMBER_OF_TASKS: usize = 1_000_000;
+
+/// The number of executors that take the shared tasks.
+const NUMBER_OF_STEALERS: usize = 3;
+
+/// The default `work_sharing_level` of [`Config`].
+const WORK_SHARING_LEVEL: usize = 7;
+
+/// A stand-in for a task: a fat pointer has the same size.
+type FakeTask = [usize; 2];
+
+/// Prints the average time of one of `iterations` since `start`.
+#[allow(clippy::cast_precision_loss, reason = "It is a benchmark.")]
+fn record(name: &'static str, start: Instant, iterations: usize) {
+    let ns_per_iter = start.elapsed().as_nanos() as f64 / iterations as f64;
+    println!("{name:<32} {ns_per_iter:>12.1} ns/task");
+}
+
+/// A list of shared tasks of one executor.
+trait SharedList: Sync {
+    /// Moves the tasks of `batch` to the list. The tasks that are not moved stay in `batch`.
+    fn flush(&self, batch: &mut Vec<FakeTask>);
+
+    /// Moves at most `limit` tasks from the list to `taken`.
+    fn take_batch(&self, taken: &mut Vec<FakeTask>, limit: usize);
+}
+
+impl SharedList for SegQueue<FakeTask> {
+    fn flush(&self, batch: &mut Vec<FakeTask>) {
+        for task in batch.drain(..) {
+            self.push(task);
+        }
+    }
+
+    fn take_batch(&self, taken: &mut Vec<FakeTask>, limit: usize) {
+        for _ in 0..limit {
+            match self.pop() {
+                Some(task) => taken.push(task),
+                None => break,
+            }
+        }
+    }
+}
+
+impl SharedList for SpinLock<Vec<FakeTask>> {
+    fn flush(&self, batch: &mut Vec<FakeTask>) {
+        if let Some(mut list) = self.try_lock() {
+            list.append(batch);
+        }
+    }
+
+    fn take_batch(&self, taken: &mut Vec<FakeTask>, limit: usize) {
+        if let Some(mut list) = self.try_lock() {
+            let new_len = list.len().saturating_sub(limit);
+            taken.extend(list.drain(new_len..));
+        }
+    }
+}
+
+/// Measures moving [`NUMBER_OF_TASKS`] tasks from one thread to [`NUMBER_OF_STEALERS`]
+/// threads through `list`.
+fn bench_shared_list(name: &'static str, list: &impl SharedList) {
+    let taken = AtomicUsize::new(0);
+    let start = Instant::now();
+
+    std::thread::scope(|scope| {
+        for _ in 0..NUMBER_OF_STEALERS {
+            scope.spawn(|| {
+                let mut batch = Vec::with_capacity(WORK_SHARING_LEVEL);
+                while taken.load(Ordering::Relaxed) < NUMBER_OF_TASKS {
+                    list.take_batch(&mut batch, WORK_SHARING_LEVEL);
+                    taken.fetch_add(batch.len(), Ordering::Relaxed);
+                    black_box(&batch);
+                    batch.clear();
+                }
+            });
+        }
+
+        // The owner flushes `WORK_SHARING_LEVEL / 2 + 1` tasks at once like `spawn_shared_task`.
+        let mut batch = Vec::with_capacity(WORK_SHARING_LEVEL);
+        for i in 0..NUMBER_OF_TASKS {
+            batch.push([i, i]);
+            if batch.len() > WORK_SHARING_LEVEL / 2 {
+                list.flush(&mut batch);
+            }
+        }
+
+        while !batch.is_empty() {
+            list.flush(&mut batch);
+        }
+    });
+
+    record(name, start, NUMBER_OF_TASKS);
+}
+
+/// Measures `shared` tasks that are spawned by one executor and mostly executed
+/// by [`NUMBER_OF_STEALERS`] other executors.
+async fn bench_executors() {
+    const WORK_PER_TASK: usize = 64;
+
+    let executed = Arc::new(AtomicUsize::new(0));
+    let start = Instant::now();
+    for i in 0..NUMBER_OF_TASKS {
+        let executed = executed.clone();
+        local_executor().spawn_shared(async move {
+            let mut sum = i;
+            for j in 0..WORK_PER_TASK {
+                sum = black_box(sum.wrapping_mul(31).wrapping_add(j));
+            }
+            black_box(sum);
+
+            executed.fetch_add(1, Ordering::Relaxed);
+        });
+
+        if i % 1024 == 0 {
+            yield_now().await;
+        }
+    }
+
+    while executed.load(Ordering::Relaxed) < NUMBER_OF_TASKS {
+        yield_now().await;
+    }
+
+    record("executors_work_sharing", start, NUMBER_OF_TASKS);
+}
+
+fn main() {
+    bench_shared_list("shared_list_seg_queue", &SegQueue::new());
+    bench_shared_list("shared_list_never_wait_lock", &SpinLock::new(Vec::new()));
+
+    for _ in 0..NUMBER_OF_STEALERS {
+        drop(run_on_new_thread(
+            Config::default(),
+            std::future::pending::<()>(),
+        ));
+    }
+
+    Executor::init()
+        .run_and_block_on_local(bench_executors())
+        .expect("the benchmark has been interrupted");
+}
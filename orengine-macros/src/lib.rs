@@ -27,12 +27,15 @@ use syn::parse_macro_input;
 ///
 /// * `do_request` - the request code
 /// * `ret_statement` - the return statement which can use `ret`(`usize`) variable.
+/// * `err_statement` - optional statement which is executed before returning an error
+///   and can use `err` ([`io::Error`](std::io::Error)) variable.
 #[proc_macro]
 pub fn poll_for_io_request(input: TokenStream) -> TokenStream {
     let input_elems = parse_macro_input!(input as syn::ExprTuple).elems;
 
     let do_request = &input_elems[0];
     let ret_statement = &input_elems[1];
+    let err_statement = input_elems.iter().nth(2);
 
     let expanded = quote! {
         if let Some(mut io_request_data) = this.io_request_data.take() {
@@ -43,6 +46,8 @@ pub fn poll_for_io_request(input: TokenStream) -> TokenStream {
                     return Poll::Ready(Ok(#ret_statement));
                 }
                 Err(err) => {
+                    #err_statement;
+
                     return Poll::Ready(Err(err));
                 }
             }
@@ -50,6 +55,7 @@ pub fn poll_for_io_request(input: TokenStream) -> TokenStream {
 
         let task = unsafe { orengine::get_task_from_context!(cx) };
         this.io_request_data = Some(IoRequestData::new(task));
+        orengine::local_executor().metrics_mut().inc_io_ops();
 
         #do_request;
 
@@ -81,12 +87,15 @@ pub fn poll_for_io_request(input: TokenStream) -> TokenStream {
 ///
 /// * `do_request` - the request code
 /// * `ret_statement` - the return statement which can use `ret`(`usize`) variable.
+/// * `err_statement` - optional statement which is executed before returning an error
+///   and can use `err` ([`io::Error`](std::io::Error)) variable.
 #[proc_macro]
 pub fn poll_for_time_bounded_io_request(input: TokenStream) -> TokenStream {
     let input_elems = parse_macro_input!(input as syn::ExprTuple).elems;
 
     let do_request = &input_elems[0];
     let ret_statement = &input_elems[1];
+    let err_statement = input_elems.iter().nth(2);
 
     let expanded = quote! {
         if let Some(mut io_request_data) = this.io_request_data.take() {
@@ -102,6 +111,8 @@ pub fn poll_for_time_bounded_io_request(input: TokenStream) -> TokenStream {
                         worker.deregister_time_bounded_io_task(&this.deadline);
                     }
 
+                    #err_statement;
+
                     return Poll::Ready(Err(err));
                 }
             }
@@ -109,6 +120,7 @@ pub fn poll_for_time_bounded_io_request(input: TokenStream) -> TokenStream {
 
         let task = unsafe { orengine::get_task_from_context!(cx) };
         this.io_request_data = Some(IoRequestData::new(task));
+        orengine::local_executor().metrics_mut().inc_io_ops();
 
         #do_request;
 
@@ -231,3 +243,79 @@ pub fn test_local(_: TokenStream, input: TokenStream) -> TokenStream {
 pub fn test_shared(_: TokenStream, input: TokenStream) -> TokenStream {
     generate_test(input, false)
 }
+
+/// Inserts a check of the ambient deadline before every `.await` of the function.
+///
+/// `.await` points of nested closures, `async` blocks and items are not changed,
+/// because they belong to other functions and futures.
+struct AwaitDeadlineChecker;
+
+impl syn::visit_mut::VisitMut for AwaitDeadlineChecker {
+    fn visit_expr_mut(&mut self, expr: &mut syn::Expr) {
+        match expr {
+            syn::Expr::Closure(_) | syn::Expr::Async(_) => {}
+            syn::Expr::Await(await_expr) => {
+                self.visit_expr_mut(&mut await_expr.base);
+                let await_expr = &*await_expr;
+                *expr = syn::parse_quote! {
+                    {
+                        orengine::deadline::check_ambient_deadline()?;
+                        #await_expr
+                    }
+                };
+            }
+            _ => syn::visit_mut::visit_expr_mut(self, expr),
+        }
+    }
+
+    fn visit_item_mut(&mut self, _: &mut syn::Item) {}
+}
+
+/// Checks the ambient deadline of the current task before every `.await` of an `async fn`.
+///
+/// If the deadline has passed, the function returns `orengine::deadline::Elapsed`
+/// converted with `?`, so the error type of the function must implement
+/// `From<orengine::deadline::Elapsed>` (for example, `std::io::Error`).
+///
+/// # Example
+///
+/// ```ignore
+/// #[orengine::deadline::propagate_deadline]
+/// async fn handle(stream: &mut TcpStream) -> std::io::Result<()> {
+///     let request = read_request(stream).await?;
+///     write_response(stream, request).await
+/// }
+/// ```
+///
+/// # Note
+///
+/// Code above is equal to:
+///
+/// ```ignore
+/// async fn handle(stream: &mut TcpStream) -> std::io::Result<()> {
+///     let request = {
+///         orengine::deadline::check_ambient_deadline()?;
+///         read_request(stream).await
+///     }?;
+///     {
+///         orengine::deadline::check_ambient_deadline()?;
+///         write_response(stream, request).await
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn propagate_deadline(_: TokenStream, input: TokenStream) -> TokenStream {
+    let mut input = parse_macro_input!(input as syn::ItemFn);
+    if input.sig.asyncness.is_none() {
+        return syn::Error::new_spanned(
+            input.sig.fn_token,
+            "`propagate_deadline` can be applied only to `async fn`",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    syn::visit_mut::VisitMut::visit_block_mut(&mut AwaitDeadlineChecker, &mut input.block);
+
+    TokenStream::from(quote! { #input })
+}
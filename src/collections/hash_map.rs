@@ -0,0 +1,249 @@
+//! This module contains [`LocalAsyncHashMap`].
+use crate::sync::{AsyncMutex, LocalMutex, LocalMutexGuard};
+use ahash::RandomState;
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Deref;
+
+/// The default number of shards of the [`LocalAsyncHashMap`].
+const DEFAULT_NUMBER_OF_SHARDS: usize = 16;
+
+/// A shared reference to the value of the [`LocalAsyncHashMap`].
+///
+/// The shard that contains the value is locked while the `Ref` is alive.
+pub struct Ref<'map, K, V> {
+    #[allow(dead_code, reason = "It keeps the shard locked")]
+    guard: LocalMutexGuard<'map, HashMap<K, V, RandomState>>,
+    value: *const V,
+}
+
+impl<K, V> Deref for Ref<'_, K, V> {
+    type Target = V;
+
+    fn deref(&self) -> &V {
+        // The value can't be moved or removed while the shard is locked.
+        unsafe { &*self.value }
+    }
+}
+
+/// `LocalAsyncHashMap` is a hash map that can be used concurrently by many `local` tasks
+/// on the same executor.
+///
+/// The entries are sharded across a power of two number of
+/// [`LocalMutex`]-protected [`HashMaps`](HashMap), so tasks that work with different shards
+/// don't wait for each other even if they hold the lock across `.await`.
+///
+/// It is useful for per-executor caches, session stores and routing tables.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::collections::LocalAsyncHashMap;
+///
+/// # async fn foo() {
+/// let sessions = LocalAsyncHashMap::new();
+///
+/// sessions.insert(1, "alice").await;
+/// assert_eq!(sessions.get(&1).await.as_deref(), Some(&"alice"));
+/// # }
+/// ```
+pub struct LocalAsyncHashMap<K, V> {
+    shards: Box<[LocalMutex<HashMap<K, V, RandomState>>]>,
+    hasher: RandomState,
+}
+
+impl<K: Hash + Eq, V> LocalAsyncHashMap<K, V> {
+    /// Creates a new `LocalAsyncHashMap` with 16 shards.
+    pub fn new() -> Self {
+        Self::with_shards(DEFAULT_NUMBER_OF_SHARDS)
+    }
+
+    /// Creates a new `LocalAsyncHashMap` with the provided number of shards.
+    ///
+    /// # Panics
+    ///
+    /// If `number_of_shards` is not a power of two.
+    pub fn with_shards(number_of_shards: usize) -> Self {
+        assert!(
+            number_of_shards.is_power_of_two(),
+            "The number of shards must be a power of two."
+        );
+
+        let hasher = RandomState::new();
+
+        Self {
+            shards: (0..number_of_shards)
+                .map(|_| LocalMutex::new(HashMap::with_hasher(hasher.clone())))
+                .collect(),
+            hasher,
+        }
+    }
+
+    /// Returns the number of shards.
+    pub fn number_of_shards(&self) -> usize {
+        self.shards.len()
+    }
+
+    /// Returns the shard that contains the key.
+    #[inline]
+    #[allow(
+        clippy::cast_possible_truncation,
+        reason = "Only the low bits are used"
+    )]
+    fn shard<Q: Hash + ?Sized>(&self, key: &Q) -> &LocalMutex<HashMap<K, V, RandomState>> {
+        let hash = self.hasher.hash_one(key) as usize;
+
+        &self.shards[hash & (self.shards.len() - 1)]
+    }
+
+    /// Returns a [`Ref`] to the value corresponding to the key.
+    ///
+    /// The shard is locked while the returned [`Ref`] is alive.
+    #[allow(
+        clippy::future_not_send,
+        reason = "LocalAsyncHashMap is not `Send`, it is fine"
+    )]
+    pub async fn get<Q>(&self, key: &Q) -> Option<Ref<'_, K, V>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let guard = self.shard(key).lock().await;
+        let value = std::ptr::from_ref(guard.get(key)?);
+
+        Some(Ref { guard, value })
+    }
+
+    /// Returns `true` if the map contains the key.
+    #[allow(
+        clippy::future_not_send,
+        reason = "LocalAsyncHashMap is not `Send`, it is fine"
+    )]
+    pub async fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.shard(key).lock().await.contains_key(key)
+    }
+
+    /// Inserts the key-value pair into the map.
+    ///
+    /// Returns the previous value if the map contained the key.
+    #[allow(
+        clippy::future_not_send,
+        reason = "LocalAsyncHashMap is not `Send`, it is fine"
+    )]
+    pub async fn insert(&self, key: K, value: V) -> Option<V> {
+        self.shard(&key).lock().await.insert(key, value)
+    }
+
+    /// Removes the key from the map.
+    ///
+    /// Returns the value if the map contained the key.
+    #[allow(
+        clippy::future_not_send,
+        reason = "LocalAsyncHashMap is not `Send`, it is fine"
+    )]
+    pub async fn remove<Q>(&self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.shard(key).lock().await.remove(key)
+    }
+
+    /// Returns the number of entries in the map.
+    ///
+    /// It locks all shards one by one.
+    #[allow(
+        clippy::future_not_send,
+        reason = "LocalAsyncHashMap is not `Send`, it is fine"
+    )]
+    pub async fn len(&self) -> usize {
+        let mut len = 0;
+        for shard in &self.shards {
+            len += shard.lock().await.len();
+        }
+
+        len
+    }
+
+    /// Returns `true` if the map contains no entries.
+    ///
+    /// It locks all shards one by one.
+    #[allow(
+        clippy::future_not_send,
+        reason = "LocalAsyncHashMap is not `Send`, it is fine"
+    )]
+    pub async fn is_empty(&self) -> bool {
+        for shard in &self.shards {
+            if !shard.lock().await.is_empty() {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+impl<K: Hash + Eq, V> Default for LocalAsyncHashMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::{local_executor, yield_now};
+    use std::rc::Rc;
+
+    #[orengine::test::test_local]
+    fn test_local_async_hash_map() {
+        let map = LocalAsyncHashMap::with_shards(4);
+        assert!(map.is_empty().await);
+
+        for i in 0..100 {
+            assert_eq!(map.insert(i, i * 2).await, None);
+        }
+        assert_eq!(map.len().await, 100);
+        assert_eq!(map.insert(5, 0).await, Some(10));
+
+        assert_eq!(*map.get(&7).await.unwrap(), 14);
+        assert!(map.get(&100).await.is_none());
+        assert!(map.contains_key(&99).await);
+
+        assert_eq!(map.remove(&99).await, Some(198));
+        assert!(!map.contains_key(&99).await);
+        assert_eq!(map.len().await, 99);
+    }
+
+    #[orengine::test::test_local]
+    fn test_local_async_hash_map_shard_lock() {
+        let map = Rc::new(LocalAsyncHashMap::with_shards(1));
+        map.insert(1, 1).await;
+
+        let value = map.get(&1).await.unwrap();
+        let map_clone = map.clone();
+        local_executor().spawn_local(async move {
+            map_clone.insert(1, 2).await;
+        });
+        yield_now().await;
+
+        // The insert waits for the shard.
+        assert_eq!(*value, 1);
+        drop(value);
+        yield_now().await;
+
+        assert_eq!(*map.get(&1).await.unwrap(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "The number of shards must be a power of two.")]
+    fn test_local_async_hash_map_not_power_of_two() {
+        let _ = LocalAsyncHashMap::<usize, usize>::with_shards(3);
+    }
+}
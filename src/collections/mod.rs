@@ -0,0 +1,8 @@
+//! The `collections` module provides asynchronous collections, such as [`LocalAsyncHashMap`]
+//! and [`AsyncPriorityQueue`].
+
+pub mod hash_map;
+pub mod priority_queue;
+
+pub use hash_map::LocalAsyncHashMap;
+pub use priority_queue::AsyncPriorityQueue;
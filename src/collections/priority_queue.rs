@@ -0,0 +1,236 @@
+//! This module contains [`AsyncPriorityQueue`].
+use crate::runtime::call::Call;
+use crate::runtime::{local_executor, Task};
+use crate::utils::{SpinLock, SpinLockGuard};
+use crate::{get_task_from_context, panic_if_local_in_future};
+use std::collections::{BinaryHeap, VecDeque};
+use std::future::Future;
+use std::ops::Deref;
+use std::panic::{RefUnwindSafe, UnwindSafe};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// The state of the [`AsyncPriorityQueue`].
+struct State<T> {
+    heap: BinaryHeap<T>,
+    waiters: VecDeque<Task>,
+}
+
+/// A shared reference to the greatest item of the [`AsyncPriorityQueue`].
+///
+/// It blocks the queue while it is alive, so don't hold it for a long time.
+pub struct PeekRef<'queue, T> {
+    guard: SpinLockGuard<'queue, State<T>>,
+}
+
+impl<T: Ord> Deref for PeekRef<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // `peek` returns `PeekRef` only if the heap is not empty
+        // and the heap can't be changed while the queue is locked.
+        unsafe { self.guard.heap.peek().unwrap_unchecked() }
+    }
+}
+
+/// A [`Future`] that pops the greatest item from the [`AsyncPriorityQueue`].
+///
+/// It is returned by [`AsyncPriorityQueue::pop`].
+pub struct WaitPop<'queue, T> {
+    queue: &'queue AsyncPriorityQueue<T>,
+}
+
+impl<T: Ord> Future for WaitPop<'_, T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        panic_if_local_in_future!(cx, "AsyncPriorityQueue");
+
+        let mut state = self.queue.state.lock();
+        if let Some(item) = state.heap.pop() {
+            return Poll::Ready(item);
+        }
+
+        unsafe {
+            state.waiters.push_back(get_task_from_context!(cx));
+            local_executor().invoke_call(Call::ReleaseAtomicBool(state.leak_to_atomic()));
+        }
+
+        Poll::Pending
+    }
+}
+
+unsafe impl<T: Send> Send for WaitPop<'_, T> {}
+impl<T: RefUnwindSafe> UnwindSafe for WaitPop<'_, T> {}
+impl<T: RefUnwindSafe> RefUnwindSafe for WaitPop<'_, T> {}
+
+/// `AsyncPriorityQueue` is a max-priority queue that can be shared between threads.
+///
+/// [`pop`](Self::pop) returns the greatest item and waits if the queue is empty,
+/// so it is useful for schedulers and job queues where urgent work must be processed first.
+///
+/// # The `AsyncPriorityQueue` and `shared` tasks
+///
+/// The `AsyncPriorityQueue` can be shared between threads, so [`pop`](Self::pop)
+/// can be awaited only by `shared` tasks.
+///
+/// Read [`Executor`](crate::Executor) for more details.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::collections::AsyncPriorityQueue;
+///
+/// # async fn foo() {
+/// let jobs = AsyncPriorityQueue::new();
+///
+/// jobs.push(1);
+/// jobs.push(10);
+/// jobs.push(5);
+///
+/// assert_eq!(jobs.peek().as_deref(), Some(&10));
+/// assert_eq!(jobs.pop().await, 10);
+/// assert_eq!(jobs.pop().await, 5);
+/// # }
+/// ```
+pub struct AsyncPriorityQueue<T> {
+    state: SpinLock<State<T>>,
+}
+
+impl<T: Ord> AsyncPriorityQueue<T> {
+    /// Creates a new empty `AsyncPriorityQueue`.
+    pub const fn new() -> Self {
+        Self {
+            state: SpinLock::new(State {
+                heap: BinaryHeap::new(),
+                waiters: VecDeque::new(),
+            }),
+        }
+    }
+
+    /// Pushes the item into the queue and wakes one task that waits in [`pop`](Self::pop).
+    ///
+    /// It never waits, because the queue is unbounded.
+    pub fn push(&self, item: T) {
+        let waiter = {
+            let mut state = self.state.lock();
+            state.heap.push(item);
+            state.waiters.pop_front()
+        };
+
+        if let Some(task) = waiter {
+            local_executor().spawn_shared_task(task);
+        }
+    }
+
+    /// Returns a future that pops the greatest item from the queue.
+    ///
+    /// If the queue is empty, the current task is parked until an item is
+    /// [`pushed`](Self::push).
+    ///
+    /// # Panics
+    ///
+    /// If it is polled by a `local` task with `debug_assertions`.
+    pub fn pop(&self) -> WaitPop<'_, T> {
+        WaitPop { queue: self }
+    }
+
+    /// Pops the greatest item from the queue if it is not empty.
+    pub fn try_pop(&self) -> Option<T> {
+        self.state.lock().heap.pop()
+    }
+
+    /// Returns a [`PeekRef`] to the greatest item without removing it,
+    /// or [`None`] if the queue is empty.
+    pub fn peek(&self) -> Option<PeekRef<'_, T>> {
+        let guard = self.state.lock();
+        if guard.heap.is_empty() {
+            return None;
+        }
+
+        Some(PeekRef { guard })
+    }
+
+    /// Returns the number of items in the queue.
+    pub fn len(&self) -> usize {
+        self.state.lock().heap.len()
+    }
+
+    /// Returns `true` if the queue contains no items.
+    pub fn is_empty(&self) -> bool {
+        self.state.lock().heap.is_empty()
+    }
+}
+
+impl<T: Ord> Default for AsyncPriorityQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl<T: Send> Sync for AsyncPriorityQueue<T> {}
+#[allow(
+    clippy::non_send_fields_in_send_ty,
+    reason = "We guarantee that `AsyncPriorityQueue<T>` is `Send`"
+)]
+unsafe impl<T: Send> Send for AsyncPriorityQueue<T> {}
+impl<T: UnwindSafe> UnwindSafe for AsyncPriorityQueue<T> {}
+impl<T: UnwindSafe> RefUnwindSafe for AsyncPriorityQueue<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::sync::{AsyncWaitGroup, WaitGroup};
+    use crate::test::sched_future_to_another_thread;
+    use crate::yield_now;
+    use std::sync::Arc;
+
+    const PAR: usize = 10;
+
+    #[orengine::test::test_shared]
+    fn test_priority_queue_order() {
+        let queue = AsyncPriorityQueue::new();
+        assert!(queue.is_empty());
+        assert!(queue.peek().is_none());
+
+        for i in [3, 7, 1, 9, 5] {
+            queue.push(i);
+        }
+        assert_eq!(queue.len(), 5);
+        assert_eq!(queue.peek().as_deref(), Some(&9));
+        assert_eq!(queue.len(), 5);
+
+        let mut res = Vec::new();
+        while !queue.is_empty() {
+            res.push(queue.pop().await);
+        }
+        assert_eq!(res, vec![9, 7, 5, 3, 1]);
+        assert_eq!(queue.try_pop(), None);
+    }
+
+    #[orengine::test::test_shared]
+    fn test_priority_queue_wait_pop() {
+        let queue = Arc::new(AsyncPriorityQueue::new());
+        let wg = Arc::new(WaitGroup::new());
+
+        for _ in 0..PAR {
+            let queue = queue.clone();
+            let wg = wg.clone();
+            wg.inc();
+
+            sched_future_to_another_thread(async move {
+                assert!(queue.pop().await < PAR);
+                wg.done();
+            });
+        }
+
+        yield_now().await;
+
+        for i in 0..PAR {
+            queue.push(i);
+        }
+        wg.wait().await;
+        assert!(queue.is_empty());
+    }
+}
@@ -0,0 +1,225 @@
+//! This module contains [`FuturesIoCompat`].
+use crate::compat::io_state::{IoState, RawIo};
+use ::futures_io::{AsyncBufRead, AsyncRead, AsyncWrite};
+use std::io::Result;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+/// The size of the inner buffer that is filled by
+/// [`poll_fill_buf`](AsyncBufRead::poll_fill_buf).
+const BUF_READ_SIZE: usize = 8192;
+
+/// `FuturesIoCompat` implements [`AsyncRead`], [`AsyncBufRead`] and [`AsyncWrite`]
+/// from `futures-io` for orengine files and sockets.
+///
+/// It can wrap a [`File`](crate::fs::File), a [`TcpStream`](crate::net::TcpStream),
+/// a [`UnixStream`](crate::net::UnixStream),
+/// a [`UdpConnectedSocket`](crate::net::UdpConnectedSocket) or
+/// a [`UnixConnectedDatagram`](crate::net::UnixConnectedDatagram).
+///
+/// It allows using crates built on `futures::io` traits with orengine.
+/// It must be polled only by the [`Executor`](crate::Executor).
+///
+/// # Writing
+///
+/// If [`poll_write`](AsyncWrite::poll_write) returns [`Poll::Pending`],
+/// the provided data is already copied and will be written,
+/// so the next call must be made with data that starts with the same bytes
+/// and returns the result of the previous write.
+/// Otherwise, it returns an error of the kind [`InvalidInput`](std::io::ErrorKind::InvalidInput)
+/// after the previous write is completed.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::compat::FuturesIoCompat;
+/// use orengine::fs::{File, OpenOptions};
+///
+/// # async fn foo() -> std::io::Result<()> {
+/// let file = File::open("example.txt", &OpenOptions::new().read(true)).await?;
+/// let mut compat = FuturesIoCompat::new(file);
+///
+/// // pass `compat` to any code that works with `futures::io` traits
+/// # Ok(())
+/// # }
+/// ```
+pub struct FuturesIoCompat<S> {
+    inner: S,
+    state: Box<IoState>,
+}
+
+impl<S> FuturesIoCompat<S> {
+    /// Wraps the provided file or socket.
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            state: Box::default(),
+        }
+    }
+
+    /// Returns a shared reference to the wrapped file or socket.
+    pub fn get_ref(&self) -> &S {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the wrapped file or socket.
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.inner
+    }
+
+    /// Returns the wrapped file or socket.
+    ///
+    /// The data that has been read, but has not been consumed yet, is lost.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: RawIo + Unpin> AsyncRead for FuturesIoCompat<S> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<Result<usize>> {
+        let this = self.get_mut();
+
+        this.state.read.poll_read(&this.inner, buf, cx)
+    }
+}
+
+impl<S: RawIo + Unpin> AsyncBufRead for FuturesIoCompat<S> {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<&[u8]>> {
+        let this = self.get_mut();
+        ready!(this.state.read.poll_fill(&this.inner, BUF_READ_SIZE, cx))?;
+
+        Poll::Ready(Ok(this.state.read.buffered()))
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        self.get_mut().state.read.consume(amt);
+    }
+}
+
+impl<S: RawIo + Unpin> AsyncWrite for FuturesIoCompat<S> {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<Result<usize>> {
+        let this = self.get_mut();
+
+        this.state.write.poll_write(&this.inner, buf, cx)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        let this = self.get_mut();
+
+        this.state.write.poll_flush(&this.inner, cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        // The file or socket is closed on drop.
+        self.poll_flush(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::fs::test_helper::{create_test_dir_if_not_exist, TEST_DIR_PATH};
+    use crate::fs::{File, OpenOptions};
+    use std::future::poll_fn;
+    use std::io::ErrorKind;
+
+    #[orengine::test::test_local]
+    fn test_futures_io_compat_file() {
+        create_test_dir_if_not_exist();
+
+        let path = format!("{TEST_DIR_PATH}/test_futures_io_compat_file.txt");
+        let options = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true);
+        let file = File::open(&path, &options).await.expect("open failed");
+        let mut compat = FuturesIoCompat::new(file);
+
+        let data = b"Hello, futures-io!";
+        let mut written = 0;
+        while written < data.len() {
+            written +=
+                poll_fn(|cx| AsyncWrite::poll_write(Pin::new(&mut compat), cx, &data[written..]))
+                    .await
+                    .expect("poll_write failed");
+        }
+        poll_fn(|cx| AsyncWrite::poll_flush(Pin::new(&mut compat), cx))
+            .await
+            .expect("poll_flush failed");
+        drop(compat);
+
+        let file = File::open(&path, &OpenOptions::new().read(true))
+            .await
+            .expect("open failed");
+        let mut compat = FuturesIoCompat::new(file);
+        let mut read = Vec::new();
+        let mut buf = [0u8; 4];
+        loop {
+            let n = poll_fn(|cx| {
+                let res = AsyncRead::poll_read(Pin::new(&mut compat), cx, &mut buf);
+                if res.is_pending() {
+                    // A poll before the completion must not be taken for the completion.
+                    assert!(AsyncRead::poll_read(Pin::new(&mut compat), cx, &mut buf).is_pending());
+                }
+
+                res
+            })
+            .await
+            .expect("poll_read failed");
+            if n == 0 {
+                break;
+            }
+            read.extend_from_slice(&buf[..n]);
+        }
+        assert_eq!(read, data);
+
+        drop(compat);
+        File::remove(&path).await.expect("remove failed");
+    }
+
+    #[orengine::test::test_local]
+    fn test_futures_io_compat_write_other_data_while_pending() {
+        create_test_dir_if_not_exist();
+
+        let path = format!("{TEST_DIR_PATH}/test_futures_io_compat_write_other_data.txt");
+        let options = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true);
+        let file = File::open(&path, &options).await.expect("open failed");
+        let mut compat = FuturesIoCompat::new(file);
+
+        let mut is_first_poll = true;
+        let res = poll_fn(|cx| {
+            if is_first_poll {
+                is_first_poll = false;
+                assert!(AsyncWrite::poll_write(Pin::new(&mut compat), cx, b"ping").is_pending());
+
+                return Poll::Pending;
+            }
+
+            AsyncWrite::poll_write(Pin::new(&mut compat), cx, b"pong")
+        })
+        .await;
+        assert_eq!(
+            res.expect_err("the result of the previous write was returned for other data")
+                .kind(),
+            ErrorKind::InvalidInput
+        );
+
+        let written = poll_fn(|cx| AsyncWrite::poll_write(Pin::new(&mut compat), cx, b"pong"))
+            .await
+            .expect("poll_write failed");
+        assert_eq!(written, 4);
+
+        drop(compat);
+        File::remove(&path).await.expect("remove failed");
+    }
+}
@@ -0,0 +1,301 @@
+use crate as orengine;
+use crate::fs::File;
+use crate::io::io_request_data::{IoRequestData, IoRequestDataPtr};
+use crate::io::sys::{AsRawFile, AsRawSocket};
+use crate::io::worker::{local_worker, IoWorker};
+use crate::net::{Socket, TcpStream, UdpConnectedSocket};
+#[cfg(unix)]
+use crate::net::{UnixConnectedDatagram, UnixStream};
+use orengine_macros::poll_for_io_request;
+use std::io::{Error, ErrorKind, Result};
+use std::task::{ready, Context, Poll};
+
+/// `RawIo` registers `read` and `write` io operations for compatibility layers,
+/// which can't store borrowing futures between polls.
+pub(crate) trait RawIo {
+    /// Registers an io operation that reads at most `len` bytes into `ptr`.
+    fn register_read(&self, ptr: *mut u8, len: u32, request_ptr: IoRequestDataPtr);
+
+    /// Registers an io operation that writes `len` bytes from `ptr`.
+    fn register_write(&self, ptr: *const u8, len: u32, request_ptr: IoRequestDataPtr);
+
+    /// Is called after the `read` operation is completed.
+    fn after_read(&self, _ret: &Result<usize>) {}
+
+    /// Is called after the `write` operation is completed.
+    fn after_write(&self, _ret: &Result<usize>) {}
+}
+
+impl RawIo for File {
+    #[inline]
+    fn register_read(&self, ptr: *mut u8, len: u32, request_ptr: IoRequestDataPtr) {
+        local_worker().read(AsRawFile::as_raw_file(self), ptr, len, request_ptr);
+    }
+
+    #[inline]
+    fn register_write(&self, ptr: *const u8, len: u32, request_ptr: IoRequestDataPtr) {
+        local_worker().write(AsRawFile::as_raw_file(self), ptr, len, request_ptr);
+    }
+}
+
+/// Implements [`RawIo`] for sockets that can `recv` and `send` without an address.
+macro_rules! impl_raw_io_for_socket {
+    ($ty:ty) => {
+        impl RawIo for $ty {
+            #[inline]
+            fn register_read(&self, ptr: *mut u8, len: u32, request_ptr: IoRequestDataPtr) {
+                local_worker().recv(AsRawSocket::as_raw_socket(self), ptr, len, request_ptr);
+            }
+
+            #[inline]
+            fn register_write(&self, ptr: *const u8, len: u32, request_ptr: IoRequestDataPtr) {
+                local_worker().send(AsRawSocket::as_raw_socket(self), ptr, len, request_ptr);
+            }
+
+            #[inline]
+            fn after_read(&self, ret: &Result<usize>) {
+                let Some(stats) = self.stats() else {
+                    return;
+                };
+
+                match ret {
+                    Ok(n) => stats.add_bytes_recv(*n),
+                    Err(_) => stats.inc_recv_errors(),
+                }
+            }
+
+            #[inline]
+            fn after_write(&self, ret: &Result<usize>) {
+                let Some(stats) = self.stats() else {
+                    return;
+                };
+
+                match ret {
+                    Ok(n) => stats.add_bytes_sent(*n),
+                    Err(_) => stats.inc_send_errors(),
+                }
+            }
+        }
+    };
+}
+
+impl_raw_io_for_socket!(TcpStream);
+impl_raw_io_for_socket!(UdpConnectedSocket);
+#[cfg(unix)]
+impl_raw_io_for_socket!(UnixStream);
+#[cfg(unix)]
+impl_raw_io_for_socket!(UnixConnectedDatagram);
+
+/// `PendingIo` registers an io operation and returns its result after the completion.
+#[derive(Default)]
+struct PendingIo {
+    io_request_data: Option<IoRequestData>,
+}
+
+impl PendingIo {
+    /// Returns `true` if the io operation is registered, but its result is not returned yet.
+    #[inline]
+    fn is_registered(&self) -> bool {
+        self.io_request_data.is_some()
+    }
+
+    /// Registers the io operation via `register` on the first call and returns its result
+    /// after the completion.
+    ///
+    /// The caller of `poll_read` or `poll_write` can poll again before the completion
+    /// (for example, when it is woken by another future), so it returns [`Poll::Pending`]
+    /// until the result is set.
+    fn poll(
+        &mut self,
+        cx: &mut Context,
+        register: impl FnOnce(IoRequestDataPtr),
+    ) -> Poll<Result<usize>> {
+        if self
+            .io_request_data
+            .as_ref()
+            .is_some_and(|data| !data.is_completed())
+        {
+            return Poll::Pending;
+        }
+
+        let this = self;
+        let ret;
+
+        poll_for_io_request!((
+            register(unsafe {
+                IoRequestDataPtr::new(this.io_request_data.as_mut().unwrap_unchecked())
+            }),
+            ret
+        ));
+    }
+}
+
+/// State of the `read` operation of a compatibility layer.
+///
+/// The data is read into the inner buffer, because the buffer provided to `poll_read`
+/// can be different in the next call.
+#[derive(Default)]
+pub(crate) struct ReadState {
+    buf: Vec<u8>,
+    /// The position of the first not yet consumed byte in `buf`.
+    pos: usize,
+    /// The number of read bytes in `buf`.
+    filled: usize,
+    pending: PendingIo,
+}
+
+impl ReadState {
+    /// Returns the read but not yet consumed bytes.
+    #[inline]
+    pub(crate) fn buffered(&self) -> &[u8] {
+        &self.buf[self.pos..self.filled]
+    }
+
+    /// Marks `amt` bytes of the [`buffered`](Self::buffered) data as consumed.
+    #[inline]
+    pub(crate) fn consume(&mut self, amt: usize) {
+        self.pos = (self.pos + amt).min(self.filled);
+    }
+
+    /// Reads at most `len` bytes into the inner buffer if all buffered data is consumed.
+    #[allow(
+        clippy::cast_possible_truncation,
+        reason = "The length is clamped to u32::MAX"
+    )]
+    pub(crate) fn poll_fill<IO: RawIo>(
+        &mut self,
+        io: &IO,
+        len: usize,
+        cx: &mut Context,
+    ) -> Poll<Result<()>> {
+        if self.pos < self.filled {
+            return Poll::Ready(Ok(()));
+        }
+
+        if !self.pending.is_registered() {
+            self.buf.resize(len.min(u32::MAX as usize), 0);
+        }
+
+        let ptr = self.buf.as_mut_ptr();
+        let len = self.buf.len() as u32;
+        let ret =
+            ready!(self
+                .pending
+                .poll(cx, |request_ptr| io.register_read(ptr, len, request_ptr)));
+        io.after_read(&ret);
+
+        self.pos = 0;
+        self.filled = ret?;
+
+        Poll::Ready(Ok(()))
+    }
+
+    /// Reads into `buf` from the inner buffer, filling it if all buffered data is consumed.
+    pub(crate) fn poll_read<IO: RawIo>(
+        &mut self,
+        io: &IO,
+        buf: &mut [u8],
+        cx: &mut Context,
+    ) -> Poll<Result<usize>> {
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+
+        ready!(self.poll_fill(io, buf.len(), cx))?;
+
+        let buffered = self.buffered();
+        let n = buf.len().min(buffered.len());
+        buf[..n].copy_from_slice(&buffered[..n]);
+        self.consume(n);
+
+        Poll::Ready(Ok(n))
+    }
+}
+
+/// State of the `write` operation of a compatibility layer.
+///
+/// The data is copied into the inner buffer, because the buffer provided to `poll_write`
+/// can be dropped before the operation is completed.
+#[derive(Default)]
+pub(crate) struct WriteState {
+    buf: Vec<u8>,
+    pending: PendingIo,
+}
+
+impl WriteState {
+    /// Writes `buf` or, if the previous write is not completed yet,
+    /// returns the result of the previous write.
+    ///
+    /// The data of the previous write is already copied and can't be replaced,
+    /// so, if `buf` doesn't start with it, an error of the kind
+    /// [`InvalidInput`](ErrorKind::InvalidInput) is returned after the previous write
+    /// is completed. Otherwise, the caller could take the result of the previous write
+    /// for the result of writing other data.
+    #[allow(
+        clippy::cast_possible_truncation,
+        reason = "The length is clamped to u32::MAX"
+    )]
+    pub(crate) fn poll_write<IO: RawIo>(
+        &mut self,
+        io: &IO,
+        buf: &[u8],
+        cx: &mut Context,
+    ) -> Poll<Result<usize>> {
+        if self.pending.is_registered() {
+            let ret = ready!(self.poll_previous(io, cx));
+            if !buf.starts_with(&self.buf) {
+                ret?;
+
+                return Poll::Ready(Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "poll_write was called with other data while the previous write was pending",
+                )));
+            }
+
+            return Poll::Ready(ret);
+        }
+
+        self.buf.clear();
+        self.buf
+            .extend_from_slice(&buf[..buf.len().min(u32::MAX as usize)]);
+
+        let ptr = self.buf.as_ptr();
+        let len = self.buf.len() as u32;
+        let ret =
+            ready!(self
+                .pending
+                .poll(cx, |request_ptr| io.register_write(ptr, len, request_ptr)));
+        io.after_write(&ret);
+
+        Poll::Ready(ret)
+    }
+
+    /// Waits for the completion of the registered write and returns its result.
+    fn poll_previous<IO: RawIo>(&mut self, io: &IO, cx: &mut Context) -> Poll<Result<usize>> {
+        let ret = ready!(self.pending.poll(cx, |_| {
+            unreachable!("the write must be registered before")
+        }));
+        io.after_write(&ret);
+
+        Poll::Ready(ret)
+    }
+
+    /// Waits for the completion of the previous write if it exists.
+    pub(crate) fn poll_flush<IO: RawIo>(&mut self, io: &IO, cx: &mut Context) -> Poll<Result<()>> {
+        if !self.pending.is_registered() {
+            return Poll::Ready(Ok(()));
+        }
+
+        self.poll_previous(io, cx).map_ok(|_| ())
+    }
+}
+
+/// State of the `read` and `write` operations of a compatibility layer.
+///
+/// It must be boxed, because the [`IoRequestData`] must not be moved
+/// while the operation is in progress.
+#[derive(Default)]
+pub(crate) struct IoState {
+    pub(crate) read: ReadState,
+    pub(crate) write: WriteState,
+}
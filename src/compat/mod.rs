@@ -0,0 +1,19 @@
+//! The `compat` module provides compatibility layers that allow using orengine files and
+//! sockets with crates built on other asynchronous ecosystems.
+//!
+//! - [`TokioCompat`](tokio::TokioCompat) (with the `tokio-compat` feature) implements
+//!   `tokio::io` traits.
+//!
+//! - [`FuturesIoCompat`](futures_io::FuturesIoCompat) (with the `futures-io` feature)
+//!   implements `futures_io` traits.
+
+#[cfg(feature = "futures-io")]
+pub mod futures_io;
+pub(crate) mod io_state;
+#[cfg(feature = "tokio-compat")]
+pub mod tokio;
+
+#[cfg(feature = "futures-io")]
+pub use self::futures_io::FuturesIoCompat;
+#[cfg(feature = "tokio-compat")]
+pub use self::tokio::TokioCompat;
@@ -0,0 +1,244 @@
+//! This module contains [`TokioCompat`].
+use crate::compat::io_state::{IoState, RawIo};
+use ::tokio::io::{AsyncBufRead, AsyncRead, AsyncWrite, ReadBuf};
+use std::io::Result;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+/// The size of the inner buffer that is filled by
+/// [`poll_fill_buf`](AsyncBufRead::poll_fill_buf).
+const BUF_READ_SIZE: usize = 8192;
+
+/// `TokioCompat` implements [`AsyncRead`], [`AsyncBufRead`] and [`AsyncWrite`] from `tokio`
+/// for orengine files and sockets.
+///
+/// It can wrap a [`File`](crate::fs::File), a [`TcpStream`](crate::net::TcpStream),
+/// a [`UnixStream`](crate::net::UnixStream),
+/// a [`UdpConnectedSocket`](crate::net::UdpConnectedSocket) or
+/// a [`UnixConnectedDatagram`](crate::net::UnixConnectedDatagram).
+///
+/// It allows using crates built on `tokio::io` traits (codecs, protocol implementations)
+/// with orengine. It must be polled only by the [`Executor`](crate::Executor).
+///
+/// # Writing
+///
+/// If [`poll_write`](AsyncWrite::poll_write) returns [`Poll::Pending`],
+/// the provided data is already copied and will be written,
+/// so the next call must be made with data that starts with the same bytes
+/// and returns the result of the previous write.
+/// Otherwise, it returns an error of the kind [`InvalidInput`](std::io::ErrorKind::InvalidInput)
+/// after the previous write is completed.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::compat::TokioCompat;
+/// use orengine::io::AsyncConnectStream;
+/// use orengine::net::TcpStream;
+///
+/// # async fn foo() -> std::io::Result<()> {
+/// let stream = TcpStream::connect("127.0.0.1:8080").await?;
+/// let mut compat = TokioCompat::new(stream);
+///
+/// // pass `compat` to any code that works with `tokio::io` traits
+/// # Ok(())
+/// # }
+/// ```
+pub struct TokioCompat<S> {
+    inner: S,
+    state: Box<IoState>,
+}
+
+impl<S> TokioCompat<S> {
+    /// Wraps the provided file or socket.
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            state: Box::default(),
+        }
+    }
+
+    /// Returns a shared reference to the wrapped file or socket.
+    pub fn get_ref(&self) -> &S {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the wrapped file or socket.
+    pub fn get_mut(&mut self) -> &mut S {
+        &mut self.inner
+    }
+
+    /// Returns the wrapped file or socket.
+    ///
+    /// The data that has been read, but has not been consumed yet, is lost.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: RawIo + Unpin> AsyncRead for TokioCompat<S> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<Result<()>> {
+        let this = self.get_mut();
+        let n = ready!(this
+            .state
+            .read
+            .poll_read(&this.inner, buf.initialize_unfilled(), cx))?;
+        buf.advance(n);
+
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<S: RawIo + Unpin> AsyncBufRead for TokioCompat<S> {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<&[u8]>> {
+        let this = self.get_mut();
+        ready!(this.state.read.poll_fill(&this.inner, BUF_READ_SIZE, cx))?;
+
+        Poll::Ready(Ok(this.state.read.buffered()))
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        self.get_mut().state.read.consume(amt);
+    }
+}
+
+impl<S: RawIo + Unpin> AsyncWrite for TokioCompat<S> {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<Result<usize>> {
+        let this = self.get_mut();
+
+        this.state.write.poll_write(&this.inner, buf, cx)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        let this = self.get_mut();
+
+        this.state.write.poll_flush(&this.inner, cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        // The file or socket is closed on drop.
+        self.poll_flush(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::io::{AsyncAccept, AsyncBind, AsyncConnectStream, AsyncRecv, AsyncSend};
+    use crate::net::{Socket, TcpListener, TcpStream};
+    use crate::sync::{AsyncWaitGroup, LocalWaitGroup};
+    use crate::{local_executor, yield_now};
+    use std::future::poll_fn;
+    use std::rc::Rc;
+
+    #[orengine::test::test_local]
+    fn test_tokio_compat_tcp() {
+        const ADDR: &str = "127.0.0.1:6098";
+
+        let mut listener = TcpListener::bind(ADDR).await.expect("bind failed");
+        let wg = Rc::new(LocalWaitGroup::new());
+        wg.inc();
+        let wg_clone = wg.clone();
+
+        local_executor().spawn_local(async move {
+            let mut stream = listener.accept().await.expect("accept failed").0;
+            stream
+                .send_all_bytes(b"first line\nsecond line\n")
+                .await
+                .expect("send failed");
+
+            let mut buf = [0u8; 4];
+            stream
+                .recv_bytes_exact(&mut buf)
+                .await
+                .expect("recv failed");
+            assert_eq!(&buf, b"pong");
+
+            wg_clone.done();
+        });
+        yield_now().await;
+
+        let stream = TcpStream::connect(ADDR).await.expect("connect failed");
+        let mut compat = TokioCompat::new(stream);
+
+        let mut lines = Vec::new();
+        let mut line = Vec::new();
+        while lines.len() < 2 {
+            let (chunk, is_line_end) = poll_fn(|cx| {
+                let buffered = ready!(AsyncBufRead::poll_fill_buf(Pin::new(&mut compat), cx))?;
+                let (consumed, is_line_end) = buffered
+                    .iter()
+                    .position(|&b| b == b'\n')
+                    .map_or((buffered.len(), false), |i| (i + 1, true));
+
+                Poll::Ready(Ok::<_, std::io::Error>((
+                    buffered[..consumed].to_vec(),
+                    is_line_end,
+                )))
+            })
+            .await
+            .expect("poll_fill_buf failed");
+            AsyncBufRead::consume(Pin::new(&mut compat), chunk.len());
+            line.extend_from_slice(&chunk);
+            if is_line_end {
+                lines.push(std::mem::take(&mut line));
+            }
+        }
+        assert_eq!(lines, [b"first line\n".to_vec(), b"second line\n".to_vec()]);
+
+        let written = poll_fn(|cx| AsyncWrite::poll_write(Pin::new(&mut compat), cx, b"pong"))
+            .await
+            .expect("poll_write failed");
+        assert_eq!(written, 4);
+        assert_eq!(compat.get_ref().stats().unwrap().bytes_sent(), 4);
+
+        wg.wait().await;
+    }
+
+    #[orengine::test::test_local]
+    fn test_tokio_compat_spurious_poll() {
+        const ADDR: &str = "127.0.0.1:6103";
+
+        let mut listener = TcpListener::bind(ADDR).await.expect("bind failed");
+        let wg = Rc::new(LocalWaitGroup::new());
+        wg.inc();
+        let wg_clone = wg.clone();
+
+        local_executor().spawn_local(async move {
+            let mut stream = listener.accept().await.expect("accept failed").0;
+            // Let the reader poll before the data is sent.
+            yield_now().await;
+            stream.send_all_bytes(b"ping").await.expect("send failed");
+
+            wg_clone.done();
+        });
+        yield_now().await;
+
+        let stream = TcpStream::connect(ADDR).await.expect("connect failed");
+        let mut compat = TokioCompat::new(stream);
+
+        let mut buf = [0u8; 16];
+        let mut read_buf = ReadBuf::new(&mut buf);
+        poll_fn(|cx| {
+            let res = AsyncRead::poll_read(Pin::new(&mut compat), cx, &mut read_buf);
+            if res.is_pending() {
+                // A poll before the completion must not be taken for the completion.
+                assert!(
+                    AsyncRead::poll_read(Pin::new(&mut compat), cx, &mut read_buf).is_pending()
+                );
+            }
+
+            res
+        })
+        .await
+        .expect("poll_read failed");
+        assert_eq!(read_buf.filled(), b"ping");
+
+        wg.wait().await;
+    }
+}
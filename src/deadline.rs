@@ -0,0 +1,289 @@
+//! This module provides the ambient deadline of a task.
+//!
+//! The ambient deadline allows setting a deadline once with [`timeout_at`] instead of
+//! threading a `deadline` parameter through the whole call chain.
+//!
+//! - [`timeout_at`] sets the ambient deadline for the provided future;
+//!
+//! - [`ambient_deadline`] returns the ambient deadline of the current task.
+//!   Operations such as
+//!   [`recv_with_ambient_deadline`](crate::io::AsyncRecv::recv_with_ambient_deadline)
+//!   use it;
+//!
+//! - [`check_ambient_deadline`] returns [`Elapsed`] if the ambient deadline has passed;
+//!
+//! - [`propagate_deadline`] is an attribute macro for `async fn` that calls
+//!   [`check_ambient_deadline`] before every `.await` and returns the error with `?`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use orengine::deadline::propagate_deadline;
+//! use orengine::io::{AsyncRecv, AsyncSend};
+//! use orengine::net::TcpStream;
+//! use orengine::timeout_at;
+//! use std::time::{Duration, Instant};
+//!
+//! #[propagate_deadline]
+//! async fn ping(stream: &mut TcpStream) -> std::io::Result<()> {
+//!     stream.send_all_bytes(b"ping").await?;
+//!
+//!     let mut buf = [0u8; 4];
+//!     stream.recv_bytes_with_ambient_deadline(&mut buf).await?;
+//!
+//!     Ok(())
+//! }
+//!
+//! async fn foo(stream: &mut TcpStream) {
+//!     let deadline = Instant::now() + Duration::from_secs(1);
+//!
+//!     match timeout_at(deadline, ping(stream)).await {
+//!         Ok(Ok(())) => println!("pong"),
+//!         Ok(Err(err)) => eprintln!("ping failed: {err}"),
+//!         Err(_) => eprintln!("ping timed out"),
+//!     }
+//! }
+//! ```
+use crate::local_executor;
+use std::fmt::{Display, Formatter};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Instant;
+
+pub use orengine_macros::propagate_deadline;
+
+/// `Elapsed` is returned when the ambient deadline has passed.
+///
+/// It can be converted into [`std::io::Error`] with kind
+/// [`ErrorKind::TimedOut`](std::io::ErrorKind::TimedOut).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elapsed;
+
+impl Display for Elapsed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("deadline has elapsed")
+    }
+}
+
+impl std::error::Error for Elapsed {}
+
+impl From<Elapsed> for std::io::Error {
+    fn from(elapsed: Elapsed) -> Self {
+        Self::new(std::io::ErrorKind::TimedOut, elapsed)
+    }
+}
+
+/// Returns the ambient deadline of the current task.
+///
+/// It is `None` if the current task is not polled inside [`timeout_at`].
+///
+/// # Panics
+///
+/// If the local executor is not initialized with `debug_assertions`.
+#[inline]
+pub fn ambient_deadline() -> Option<Instant> {
+    local_executor().ambient_deadline()
+}
+
+/// Returns [`Elapsed`] if the ambient deadline of the current task has passed.
+///
+/// The deadline is compared with
+/// [`start_round_time`](crate::Executor::start_round_time), so it is cheap to call
+/// it before every `.await`.
+///
+/// # Panics
+///
+/// If the local executor is not initialized with `debug_assertions`.
+#[inline]
+pub fn check_ambient_deadline() -> Result<(), Elapsed> {
+    let executor = local_executor();
+    match executor.ambient_deadline() {
+        Some(deadline) if deadline <= executor.start_round_time() => Err(Elapsed),
+        _ => Ok(()),
+    }
+}
+
+/// `TimeoutAt` sets the ambient deadline while its future is polled.
+///
+/// Read [`timeout_at`] for more details.
+pub struct TimeoutAt<Fut: Future> {
+    deadline: Instant,
+    was_polled: bool,
+    future: Fut,
+}
+
+impl<Fut: Future> Future for TimeoutAt<Fut> {
+    type Output = Result<Fut::Output, Elapsed>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = unsafe { self.get_unchecked_mut() };
+        let executor = local_executor();
+        if !this.was_polled {
+            if this.deadline <= executor.start_round_time() {
+                return Poll::Ready(Err(Elapsed));
+            }
+
+            this.was_polled = true;
+        }
+
+        // A nested deadline cannot extend the outer one.
+        let deadline = match executor.ambient_deadline() {
+            Some(outer_deadline) => outer_deadline.min(this.deadline),
+            None => this.deadline,
+        };
+        let previous_deadline = executor.replace_ambient_deadline(Some(deadline));
+        let poll_res = unsafe { Pin::new_unchecked(&mut this.future) }.poll(cx);
+        local_executor().replace_ambient_deadline(previous_deadline);
+
+        poll_res.map(Ok)
+    }
+}
+
+/// Sets the ambient deadline of the current task to `deadline` while `future` is polled.
+///
+/// The deadline belongs only to the current task. Tasks spawned or executed
+/// (for example, with [`exec_local_future`](crate::Executor::exec_local_future))
+/// inside `future` do not inherit it.
+///
+/// If `deadline` has passed before the first poll, it returns [`Elapsed`] without polling
+/// `future`. After that it never interrupts `future`, because `future` can wait for an
+/// in-flight operation that must not be dropped. Instead, operations inside `future` such as
+/// [`recv_with_ambient_deadline`](crate::io::AsyncRecv::recv_with_ambient_deadline)
+/// fail when the deadline is exceeded, and functions marked with [`propagate_deadline`]
+/// stop at the next `.await`.
+///
+/// If the current task already has an ambient deadline, the earlier one is used.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::{sleep, timeout_at};
+/// use std::time::{Duration, Instant};
+///
+/// async fn foo() {
+///     let deadline = Instant::now() + Duration::from_secs(1);
+///     let res = timeout_at(deadline, async {
+///         sleep(Duration::from_millis(10)).await;
+///
+///         42
+///     })
+///     .await;
+///
+///     assert_eq!(res, Ok(42));
+/// }
+/// ```
+pub fn timeout_at<Fut: Future>(deadline: Instant, future: Fut) -> TimeoutAt<Fut> {
+    TimeoutAt {
+        deadline,
+        was_polled: false,
+        future,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use std::time::Duration;
+
+    #[propagate_deadline]
+    async fn sleep_many_times(number_of_sleeps: usize) -> std::io::Result<usize> {
+        for _ in 0..number_of_sleeps {
+            crate::sleep(Duration::from_millis(1)).await;
+        }
+
+        Ok(number_of_sleeps)
+    }
+
+    #[orengine::test::test_local]
+    fn test_timeout_at_sets_ambient_deadline() {
+        assert_eq!(ambient_deadline(), None);
+
+        let deadline = Instant::now() + Duration::from_secs(10);
+        let res = timeout_at(deadline, async {
+            assert_eq!(ambient_deadline(), Some(deadline));
+
+            let nested = timeout_at(deadline + Duration::from_secs(10), async {
+                assert_eq!(ambient_deadline(), Some(deadline));
+            })
+            .await;
+            assert_eq!(nested, Ok(()));
+
+            crate::yield_now().await;
+            assert_eq!(ambient_deadline(), Some(deadline));
+
+            check_ambient_deadline()
+        })
+        .await;
+
+        assert_eq!(res, Ok(Ok(())));
+        assert_eq!(ambient_deadline(), None);
+    }
+
+    #[orengine::test::test_local]
+    fn test_executed_tasks_do_not_inherit_ambient_deadline() {
+        let deadline = Instant::now() + Duration::from_secs(10);
+        let res = timeout_at(deadline, async {
+            let inner_deadlines = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+
+            let inner_deadlines_clone = inner_deadlines.clone();
+            local_executor().exec_local_future(async move {
+                inner_deadlines_clone.borrow_mut().push(ambient_deadline());
+                crate::yield_now().await;
+                inner_deadlines_clone.borrow_mut().push(ambient_deadline());
+            });
+            assert_eq!(ambient_deadline(), Some(deadline));
+
+            local_executor().exec_shared_future(async {
+                assert_eq!(ambient_deadline(), None);
+            });
+            assert_eq!(ambient_deadline(), Some(deadline));
+
+            crate::yield_now().await;
+            crate::yield_now().await;
+            assert_eq!(*inner_deadlines.borrow(), vec![None, None]);
+        })
+        .await;
+
+        assert_eq!(res, Ok(()));
+    }
+
+    #[orengine::test::test_local]
+    fn test_timeout_at_elapsed() {
+        let past = local_executor().start_round_time();
+        assert_eq!(timeout_at(past, async {}).await, Err(Elapsed));
+
+        let deadline = Instant::now() + Duration::from_millis(5);
+        let res = timeout_at(deadline, sleep_many_times(1000)).await;
+        assert!(!matches!(res, Ok(Ok(_))), "the deadline must be exceeded");
+
+        let deadline = Instant::now() + Duration::from_secs(10);
+        let res = timeout_at(deadline, sleep_many_times(3)).await;
+        assert!(matches!(res, Ok(Ok(3))));
+    }
+
+    #[orengine::test::test_local]
+    fn test_recv_with_ambient_deadline() {
+        use crate::io::{AsyncBind, AsyncConnectStream, AsyncRecv};
+        use crate::net::{Socket, TcpListener, TcpStream};
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind failed");
+        let mut stream = TcpStream::connect(listener.local_addr().unwrap())
+            .await
+            .expect("connect failed");
+
+        let deadline = Instant::now() + Duration::from_millis(10);
+        let res = timeout_at(deadline, async {
+            let mut buf = [0u8; 16];
+            stream.recv_bytes_with_ambient_deadline(&mut buf).await
+        })
+        .await;
+
+        match res {
+            Ok(Err(err)) => assert_eq!(err.kind(), std::io::ErrorKind::TimedOut),
+            Ok(Ok(_)) => panic!("recv must fail, because nothing was sent"),
+            Err(Elapsed) => panic!("recv must fail by itself with the ambient deadline"),
+        }
+    }
+}
@@ -142,7 +142,15 @@ impl DirBuilder {
         let mut path_stack = SmallVec::<usize, 4>::new();
 
         loop {
-            match CreateDir::new(get_os_path(tmp_path)?, tmp_mode).await {
+            let res = match CreateDir::new(get_os_path(tmp_path)?, tmp_mode).await {
+                // The directory can be created concurrently by another task or process.
+                Err(ref e) if e.kind() == io::ErrorKind::AlreadyExists && tmp_path.is_dir() => {
+                    Ok(())
+                }
+                res => res,
+            };
+
+            match res {
                 Ok(()) => {
                     if path_stack.is_empty() {
                         return Ok(());
@@ -176,11 +184,6 @@ impl DirBuilder {
                         }
                     }
                 }
-                Err(_) if path.is_dir() => {
-                    if path_stack.is_empty() {
-                        return Ok(());
-                    }
-                }
                 Err(err) => {
                     return Err(err);
                 }
@@ -1,9 +1,11 @@
 use crate::fs::OpenOptions;
 use crate::io::close::AsyncFileClose;
+use crate::io::fadvise::AsyncFadvise;
 use crate::io::fallocate::AsyncFallocate;
 use crate::io::open::Open;
 use crate::io::remove::Remove;
 use crate::io::rename::Rename;
+use crate::io::set_len::AsyncSetLen;
 use crate::io::sync_all::AsyncSyncAll;
 use crate::io::sync_data::AsyncSyncData;
 use crate::io::sys::get_os_path;
@@ -24,6 +26,14 @@ use std::{io, mem};
 /// Errors detected on closing are ignored by the implementation of Drop.
 /// Use the method [`sync_all`](File::sync_all) if these errors must be manually handled.
 ///
+/// # Ownership of the file descriptor
+///
+/// `File` owns its file descriptor and closes it with an asynchronous `close` operation
+/// when it is dropped. It can be converted from and into [`OwnedFd`](std::os::fd::OwnedFd)
+/// (`OwnedHandle` on `windows`) to interoperate with crates that manage file descriptors,
+/// such as `nix` or `rustix`. After the conversion into [`OwnedFd`](std::os::fd::OwnedFd)
+/// the file is closed synchronously by the [`OwnedFd`](std::os::fd::OwnedFd).
+///
 /// # Examples
 ///
 /// ```rust
@@ -145,6 +155,43 @@ impl File {
         Remove::new(path).await
     }
 
+    /// Changes the permissions of the file to `mode`.
+    ///
+    /// `io_uring` has no `fchmod` operation, so the call is executed in the thread pool
+    /// of the current [`Executor`](crate::Executor).
+    ///
+    /// # Errors
+    ///
+    /// This function will return an `Err` if the user lacks the permission to change
+    /// the attributes of the file.
+    ///
+    /// # Panics
+    ///
+    /// If the thread pool of the current [`Executor`](crate::Executor) is disabled
+    /// with `debug_assertions`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use orengine::fs::{File, OpenOptions};
+    ///
+    /// # async fn foo() -> std::io::Result<()> {
+    /// let file = File::open("upload.bin", &OpenOptions::new().write(true).create(true)).await?;
+    /// file.set_permissions(0o600).await?;
+    /// // validate the upload
+    /// file.set_permissions(0o644).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(unix)]
+    #[allow(
+        clippy::future_not_send,
+        reason = "It is not `Send` because of the result cell, it is fine"
+    )]
+    pub async fn set_permissions(&self, mode: u32) -> Result<()> {
+        crate::io::chmod::fchmod(self.raw_file, mode).await
+    }
+
     /// Executes a closure with a shared reference to the underlying `std::fs::File` object.
     ///
     /// It allows to call sync methods on the file from standard library.
@@ -254,12 +301,52 @@ impl std::os::windows::io::FromRawHandle for File {
 
 impl FromRawFile for File {}
 
+#[cfg(unix)]
+impl From<std::os::fd::OwnedFd> for File {
+    fn from(fd: std::os::fd::OwnedFd) -> Self {
+        unsafe { std::os::fd::FromRawFd::from_raw_fd(std::os::fd::IntoRawFd::into_raw_fd(fd)) }
+    }
+}
+
+#[cfg(unix)]
+impl From<File> for std::os::fd::OwnedFd {
+    fn from(file: File) -> Self {
+        unsafe { std::os::fd::FromRawFd::from_raw_fd(std::os::fd::IntoRawFd::into_raw_fd(file)) }
+    }
+}
+
+#[cfg(windows)]
+impl From<std::os::windows::io::OwnedHandle> for File {
+    fn from(handle: std::os::windows::io::OwnedHandle) -> Self {
+        unsafe {
+            std::os::windows::io::FromRawHandle::from_raw_handle(
+                std::os::windows::io::IntoRawHandle::into_raw_handle(handle),
+            )
+        }
+    }
+}
+
+#[cfg(windows)]
+impl From<File> for std::os::windows::io::OwnedHandle {
+    fn from(file: File) -> Self {
+        unsafe {
+            std::os::windows::io::FromRawHandle::from_raw_handle(
+                std::os::windows::io::IntoRawHandle::into_raw_handle(file),
+            )
+        }
+    }
+}
+
+impl AsyncFadvise for File {}
+
 impl AsyncFallocate for File {}
 
 impl AsyncSyncAll for File {}
 
 impl AsyncSyncData for File {}
 
+impl AsyncSetLen for File {}
+
 impl AsyncRead for File {}
 
 impl AsyncWrite for File {}
@@ -282,7 +369,9 @@ mod tests {
     use super::*;
     use crate as orengine;
     use crate::fs::test_helper::{create_test_dir_if_not_exist, is_exists, TEST_DIR_PATH};
-    use crate::io::{full_buffer, get_fixed_buffer, get_full_fixed_buffer, FixedBuffer};
+    use crate::io::{
+        full_buffer, get_fixed_buffer, get_full_fixed_buffer, DirectBuffer, FixedBuffer,
+    };
     use std::fs::{create_dir, create_dir_all};
     use std::io::{Seek, SeekFrom};
     use std::path::PathBuf;
@@ -541,4 +630,94 @@ mod tests {
 
         assert_eq!(large_big_buff.as_ref(), write_buf.as_ref());
     }
+
+    #[orengine::test::test_local]
+    fn test_file_direct_io() {
+        let test_file_dir_path: &str = &(TEST_DIR_PATH.to_string() + "/direct_file/");
+
+        create_test_dir_if_not_exist();
+
+        let file_path = {
+            let mut file_path_ = PathBuf::from(test_file_dir_path);
+            let _ = create_dir_all(test_file_dir_path);
+            file_path_.push("test.txt");
+            file_path_
+        };
+        let options = OpenOptions::new()
+            .write(true)
+            .read(true)
+            .truncate(true)
+            .create(true)
+            .direct(true);
+        let mut file = match File::open(&file_path, &options).await {
+            Ok(file) => file,
+            Err(err) => panic!("Can't open (create) file with O_DIRECT: {err}"),
+        };
+
+        let mut write_buf = DirectBuffer::new(8192);
+        for (i, byte) in write_buf.iter_mut().enumerate() {
+            *byte = u8::try_from(i % 256).unwrap();
+        }
+        file.pwrite_all(&write_buf, 0).await.unwrap();
+
+        let mut read_buf = DirectBuffer::new(4096);
+        file.pread_exact(&mut read_buf, 4096).await.unwrap();
+        assert_eq!(read_buf.as_ref(), &write_buf[4096..]);
+
+        let unaligned = [0u8; 100];
+        file.pwrite_bytes(&unaligned, 1)
+            .await
+            .expect_err("unaligned direct write must fail");
+    }
+
+    #[orengine::test::test_local]
+    fn test_file_tmpfile() {
+        let test_file_dir_path: &str = &(TEST_DIR_PATH.to_string() + "/tmpfile/");
+
+        create_test_dir_if_not_exist();
+        let _ = create_dir_all(test_file_dir_path);
+
+        let mut file = OpenOptions::new()
+            .read(true)
+            .mode(0o600)
+            .tmpfile(test_file_dir_path)
+            .await
+            .expect("Can't create tmpfile");
+        assert_eq!(std::fs::read_dir(test_file_dir_path).unwrap().count(), 0);
+
+        file.pwrite_all_bytes(b"Hello, tmpfile!", 0).await.unwrap();
+        let mut buf = [0u8; 15];
+        file.pread_bytes_exact(&mut buf, 0).await.unwrap();
+        assert_eq!(&buf, b"Hello, tmpfile!");
+
+        assert!(OpenOptions::new()
+            .tmpfile(TEST_DIR_PATH.to_string() + "/tmpfile_not_exists/")
+            .await
+            .is_err());
+    }
+
+    #[orengine::test::test_local]
+    fn test_file_into_and_from_owned_fd() {
+        use std::os::fd::{AsRawFd, OwnedFd};
+
+        create_test_dir_if_not_exist();
+        let file_path = PathBuf::from(TEST_DIR_PATH).join("owned_fd.txt");
+        let options = OpenOptions::new()
+            .write(true)
+            .read(true)
+            .truncate(true)
+            .create(true);
+        let file = File::open(&file_path, &options).await.unwrap();
+        let raw_fd = file.as_raw_fd();
+
+        let owned_fd = OwnedFd::from(file);
+        assert_eq!(owned_fd.as_raw_fd(), raw_fd);
+
+        let mut file = File::from(owned_fd);
+        assert_eq!(file.as_raw_fd(), raw_fd);
+        file.pwrite_all_bytes(b"owned", 0).await.unwrap();
+        drop(file);
+
+        assert_eq!(std::fs::read(&file_path).unwrap(), b"owned");
+    }
 }
@@ -1,6 +1,8 @@
+use crate::fs::File;
 use crate::io::sys::OsOpenOptions;
 use std::fmt::Debug;
 use std::io;
+use std::path::Path;
 
 /// Options and flags which can be used to configure how a file is opened.
 #[derive(Copy, Clone)]
@@ -36,6 +38,10 @@ pub struct OpenOptions {
     ///
     /// For more information, see [`OpenOptions::create_new`](OpenOptions::create_new).
     create_new: bool,
+    /// This option, when true, will indicate that the file should be opened with `O_DIRECT`.
+    ///
+    /// For more information, see [`OpenOptions::direct`](OpenOptions::direct).
+    direct: bool,
     /// Pass custom flags to the flags argument of open.
     ///
     /// For more information, see [`OpenOptions::custom_flags`](OpenOptions::custom_flags).
@@ -65,6 +71,7 @@ impl OpenOptions {
             truncate: false,
             create: false,
             create_new: false,
+            direct: false,
             custom_flags: 0,
             #[cfg(unix)]
             mode: 0o666,
@@ -152,6 +159,30 @@ impl OpenOptions {
         self
     }
 
+    /// Sets the option to bypass the page cache (`O_DIRECT`).
+    ///
+    /// Reads and writes go directly between the buffer and the disk,
+    /// which is useful for database WALs and storage engines that cache data themselves.
+    ///
+    /// # Alignment
+    ///
+    /// The kernel rejects direct I/O with [`InvalidInput`](io::ErrorKind::InvalidInput)
+    /// if the buffer address, its length or the file offset is not aligned to the logical
+    /// block size of the device (usually 512 or 4096 bytes).
+    /// Use [`DirectBuffer`](crate::io::DirectBuffer) to get aligned buffers.
+    ///
+    /// Some file systems (for example, `tmpfs`) don't support `O_DIRECT`,
+    /// in this case opening the file fails.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// It has an effect only on Linux. In other platforms, it does nothing.
+    #[must_use]
+    pub fn direct(mut self, direct: bool) -> Self {
+        self.direct = direct;
+        self
+    }
+
     /// Pass custom flags to the flags argument of open.
     ///
     /// The bits that define the access mode are masked out with `O_ACCMODE`,
@@ -262,6 +293,64 @@ impl OpenOptions {
         self
     }
 
+    /// Creates an unnamed temporary file in the directory `dir` using `O_TMPFILE`.
+    ///
+    /// The file has no name and is removed when it is closed, unless it is linked
+    /// into the file system with [`link_file`](crate::io::link_file). Unlike creating a named temporary file,
+    /// no other process can see or open the file before it is fully written,
+    /// so there is no `TOCTOU` window.
+    ///
+    /// The file is always opened with write access, [`create`](Self::create),
+    /// [`create_new`](Self::create_new) and [`truncate`](Self::truncate) are ignored.
+    /// [`mode`](Self::mode) is applied to the file when it is linked.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an `Err` if:
+    /// - The provided path is empty or is not a directory;
+    /// - The file system of `dir` doesn't support `O_TMPFILE`;
+    /// - The platform is not Linux ([`Unsupported`](io::ErrorKind::Unsupported)).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::fs::OpenOptions;
+    /// use orengine::io::{link_file, AsyncSyncData, AsyncWrite};
+    ///
+    /// # async fn foo() -> std::io::Result<()> {
+    /// let mut file = OpenOptions::new().mode(0o644).tmpfile("data").await?;
+    /// file.write_all_bytes(b"fully written content").await?;
+    /// file.sync_data().await?;
+    ///
+    /// // Atomically publish the file. It fails if `data/report.txt` already exists.
+    /// link_file(&file, "data/report.txt").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn tmpfile<P: AsRef<Path> + Send>(&self, dir: P) -> io::Result<File> {
+        #[cfg(target_os = "linux")]
+        {
+            let options = self
+                .write(true)
+                .create(false)
+                .create_new(false)
+                .truncate(false)
+                .custom_flags(self.custom_flags | libc::O_TMPFILE);
+
+            File::open(dir, &options).await
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            let _ = dir;
+
+            Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "O_TMPFILE is supported only on Linux",
+            ))
+        }
+    }
+
     #[cfg(target_os = "linux")]
     /// Converts the `OpenOptions` into the argument to `open()` provided by the os.
     pub(crate) fn into_os_options(mut self) -> io::Result<OsOpenOptions> {
@@ -276,13 +365,19 @@ impl OpenOptions {
             (false, false, false) => return Err(io::Error::from_raw_os_error(libc::EINVAL)),
         };
 
+        // O_TMPFILE creates a file, so it needs the mode.
+        let is_tmpfile = self.custom_flags & libc::O_TMPFILE == libc::O_TMPFILE;
         let creation_flags = match (self.create, self.truncate, self.create_new) {
             (false, false, false) => {
-                self.mode = 0;
+                if !is_tmpfile {
+                    self.mode = 0;
+                }
                 0
             }
             (false, true, false) => {
-                self.mode = 0;
+                if !is_tmpfile {
+                    self.mode = 0;
+                }
                 libc::O_TRUNC
             }
             (true, false, false) => libc::O_CREAT,
@@ -290,12 +385,15 @@ impl OpenOptions {
             (_, _, true) => libc::O_CREAT | libc::O_EXCL,
         };
 
+        let direct_flag = if self.direct { libc::O_DIRECT } else { 0 };
+
         #[allow(clippy::cast_sign_loss, reason = "Flags don't have signs.")]
         Ok(OsOpenOptions::new()
             .flags(
                 (libc::O_CLOEXEC
                     | access_mode
                     | creation_flags
+                    | direct_flag
                     | (self.custom_flags & !libc::O_ACCMODE)) as u64,
             )
             .mode(self.mode.into()))
@@ -365,6 +463,7 @@ impl Debug for OpenOptions {
             .field("truncate", &self.truncate)
             .field("create", &self.create)
             .field("create_new", &self.create_new)
+            .field("direct", &self.direct)
             .field("custom_flags", &self.custom_flags);
 
         #[cfg(unix)]
@@ -84,6 +84,31 @@ pub async fn create_dir_all<P: AsRef<Path> + Send>(path: P) -> Result<()> {
     DirBuilder::new().recursive(true).create(path).await
 }
 
+/// Creates a new directory at the specified path with the provided `mode`.
+///
+/// It works like [`create_dir`], but allows to specify the permissions of the directory
+/// (before the `umask` is applied).
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::fs::create_dir_with_mode;
+///
+/// # async fn foo() -> std::io::Result<()> {
+/// create_dir_with_mode("private_directory", 0o700).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// This function will return an `Err` if the directory cannot be created due to I/O errors
+/// (e.g., permission denied, path does not exist).
+#[inline]
+pub async fn create_dir_with_mode<P: AsRef<Path> + Send>(path: P, mode: u32) -> Result<()> {
+    DirBuilder::new().mode(mode).create(path).await
+}
+
 /// Removes the directory at the specified path.
 ///
 /// This function asynchronously deletes a directory. The directory must be empty,
@@ -172,8 +197,9 @@ where
 }
 
 #[cfg(test)]
-/// we need to check only [`remove_dir`], because all others functions was already tested in
-/// [`file`](crate::fs::file) or [`dir_builder`](crate::fs::dir_builder).
+/// we need to check only [`remove_dir`] and [`create_dir_with_mode`], because all others
+/// functions was already tested in [`file`](crate::fs::file)
+/// or [`dir_builder`](crate::fs::dir_builder).
 mod tests {
     use super::*;
     use crate as orengine;
@@ -196,4 +222,27 @@ mod tests {
             Err(err) => panic!("Can't remove dir: {err}"),
         }
     }
+
+    #[cfg(unix)]
+    #[orengine::test::test_local]
+    fn test_create_dir_with_mode() {
+        use std::os::unix::fs::PermissionsExt;
+
+        create_test_dir_if_not_exist();
+
+        let mut path = PathBuf::from(TEST_DIR_PATH);
+        path.push("create_dir_with_mode");
+        if is_exists(&path) {
+            std::fs::remove_dir(&path).unwrap();
+        }
+
+        create_dir_with_mode(&path, 0o700).await.unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o700);
+
+        // An already existing tree is not an error.
+        create_dir_all(&path).await.unwrap();
+
+        std::fs::remove_dir(path).unwrap();
+    }
 }
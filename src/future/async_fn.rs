@@ -0,0 +1,76 @@
+use std::future::Future;
+
+/// `AsyncFnMut` is a function that can be called by a mutable reference and returns
+/// a [`Future`].
+///
+/// It is used as a bound in the public API (for example,
+/// [`TcpListener::for_each_connection`](crate::net::TcpListener::for_each_connection))
+/// until the `async_fn_traits` are available on the supported Rust version.
+///
+/// It is implemented for all closures that return a [`Future`]:
+/// for `|arg| async move { ... }` as well as for closures that return
+/// `Pin<Box<dyn Future<Output = ...>>>`.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::future::AsyncFnMut;
+///
+/// async fn call_twice(mut f: impl AsyncFnMut<usize, Output = usize>) -> usize {
+///     f.call_mut(1).await + f.call_mut(2).await
+/// }
+///
+/// # async fn foo() {
+/// let mut calls = 0;
+/// let res = call_twice(|n| {
+///     calls += 1;
+///     async move { n * 10 }
+/// }).await;
+///
+/// assert_eq!(res, 30);
+/// assert_eq!(calls, 2);
+/// # }
+/// ```
+pub trait AsyncFnMut<Arg> {
+    /// The output of the returned [`Future`].
+    type Output;
+    /// The returned [`Future`].
+    type Future: Future<Output = Self::Output>;
+
+    /// Calls the function.
+    fn call_mut(&mut self, arg: Arg) -> Self::Future;
+}
+
+impl<Arg, F, Fut> AsyncFnMut<Arg> for F
+where
+    F: FnMut(Arg) -> Fut,
+    Fut: Future,
+{
+    type Output = Fut::Output;
+    type Future = Fut;
+
+    #[inline]
+    fn call_mut(&mut self, arg: Arg) -> Fut {
+        self(arg)
+    }
+}
+
+/// `AsyncFn` is a function that can be called by a shared reference and returns
+/// a [`Future`].
+///
+/// Read [`AsyncFnMut`] for more details.
+pub trait AsyncFn<Arg>: AsyncFnMut<Arg> {
+    /// Calls the function.
+    fn call(&self, arg: Arg) -> Self::Future;
+}
+
+impl<Arg, F, Fut> AsyncFn<Arg> for F
+where
+    F: Fn(Arg) -> Fut,
+    Fut: Future,
+{
+    #[inline]
+    fn call(&self, arg: Arg) -> Fut {
+        self(arg)
+    }
+}
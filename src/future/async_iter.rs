@@ -0,0 +1,295 @@
+/// `AsyncIterator` is an asynchronous version of [`Iterator`].
+///
+/// Until the `async for` syntax is stabilized, it is used via
+/// `while let Some(item) = iter.next().await`.
+///
+/// It is implemented for [`receivers`](crate::sync::AsyncReceiver) of channels.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::future::AsyncIterator;
+/// use orengine::sync::{AsyncChannel, AsyncReceiver, AsyncSender, LocalChannel};
+///
+/// # async fn foo() {
+/// let channel = LocalChannel::bounded(4);
+/// let (sender, mut receiver) = channel.split();
+///
+/// for i in 0..4 {
+///     sender.send(i).await.unwrap();
+/// }
+///
+/// let mut evens = receiver.by_ref().take(4).filter(|i| i % 2 == 0).map(|i| i * 10);
+/// while let Some(i) = evens.next().await {
+///     println!("{i}"); // 0, 20
+/// }
+/// # }
+/// ```
+pub trait AsyncIterator {
+    /// The type of the elements being iterated over.
+    type Item;
+
+    /// Advances the iterator and returns the next value.
+    ///
+    /// Returns [`None`] when the iteration is finished.
+    async fn next(&mut self) -> Option<Self::Item>;
+
+    /// Creates an iterator that calls the closure on each element.
+    fn map<B, F>(self, f: F) -> Map<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> B,
+    {
+        Map { iter: self, f }
+    }
+
+    /// Creates an iterator that yields only the elements for which the predicate
+    /// returns `true`.
+    fn filter<P>(self, predicate: P) -> Filter<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        Filter {
+            iter: self,
+            predicate,
+        }
+    }
+
+    /// Creates an iterator that yields at most `n` elements.
+    fn take(self, n: usize) -> Take<Self>
+    where
+        Self: Sized,
+    {
+        Take { iter: self, n }
+    }
+
+    /// Creates an iterator that maps each element to an [`AsyncIterator`]
+    /// and yields the elements of these iterators.
+    fn flat_map<U, F>(self, f: F) -> FlatMap<Self, U, F>
+    where
+        Self: Sized,
+        U: AsyncIterator,
+        F: FnMut(Self::Item) -> U,
+    {
+        FlatMap {
+            iter: self,
+            f,
+            current: None,
+        }
+    }
+
+    /// Calls the closure on each element until the iteration is finished.
+    async fn for_each<F>(mut self, mut f: F)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item),
+    {
+        while let Some(item) = self.next().await {
+            f(item);
+        }
+    }
+
+    /// Borrows the iterator, rather than consuming it.
+    ///
+    /// It allows applying adapters and continuing to use the original iterator.
+    fn by_ref(&mut self) -> &mut Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+}
+
+impl<I: AsyncIterator> AsyncIterator for &mut I {
+    type Item = I::Item;
+
+    #[allow(
+        clippy::future_not_send,
+        reason = "It is not `Send` only when I is not `Send`, it is fine"
+    )]
+    async fn next(&mut self) -> Option<Self::Item> {
+        (**self).next().await
+    }
+}
+
+/// An [`AsyncIterator`] that maps the elements with a closure.
+///
+/// Created by [`AsyncIterator::map`].
+pub struct Map<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<B, I: AsyncIterator, F: FnMut(I::Item) -> B> AsyncIterator for Map<I, F> {
+    type Item = B;
+
+    #[allow(
+        clippy::future_not_send,
+        reason = "It is not `Send` only when I or F is not `Send`, it is fine"
+    )]
+    async fn next(&mut self) -> Option<B> {
+        self.iter.next().await.map(&mut self.f)
+    }
+}
+
+/// An [`AsyncIterator`] that filters the elements with a predicate.
+///
+/// Created by [`AsyncIterator::filter`].
+pub struct Filter<I, P> {
+    iter: I,
+    predicate: P,
+}
+
+impl<I: AsyncIterator, P: FnMut(&I::Item) -> bool> AsyncIterator for Filter<I, P> {
+    type Item = I::Item;
+
+    #[allow(
+        clippy::future_not_send,
+        reason = "It is not `Send` only when I or P is not `Send`, it is fine"
+    )]
+    async fn next(&mut self) -> Option<I::Item> {
+        while let Some(item) = self.iter.next().await {
+            if (self.predicate)(&item) {
+                return Some(item);
+            }
+        }
+
+        None
+    }
+}
+
+/// An [`AsyncIterator`] that yields at most `n` elements.
+///
+/// Created by [`AsyncIterator::take`].
+pub struct Take<I> {
+    iter: I,
+    n: usize,
+}
+
+impl<I: AsyncIterator> AsyncIterator for Take<I> {
+    type Item = I::Item;
+
+    #[allow(
+        clippy::future_not_send,
+        reason = "It is not `Send` only when I is not `Send`, it is fine"
+    )]
+    async fn next(&mut self) -> Option<I::Item> {
+        if self.n == 0 {
+            return None;
+        }
+
+        self.n -= 1;
+        self.iter.next().await
+    }
+}
+
+/// An [`AsyncIterator`] that maps each element to an [`AsyncIterator`]
+/// and yields the elements of these iterators.
+///
+/// Created by [`AsyncIterator::flat_map`].
+pub struct FlatMap<I, U, F> {
+    iter: I,
+    f: F,
+    current: Option<U>,
+}
+
+impl<I, U, F> AsyncIterator for FlatMap<I, U, F>
+where
+    I: AsyncIterator,
+    U: AsyncIterator,
+    F: FnMut(I::Item) -> U,
+{
+    type Item = U::Item;
+
+    #[allow(
+        clippy::future_not_send,
+        reason = "It is not `Send` only when I, U or F is not `Send`, it is fine"
+    )]
+    async fn next(&mut self) -> Option<U::Item> {
+        loop {
+            if let Some(current) = &mut self.current {
+                if let Some(item) = current.next().await {
+                    return Some(item);
+                }
+            }
+
+            self.current = Some((self.f)(self.iter.next().await?));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::sync::{AsyncChannel, AsyncSender, LocalChannel};
+
+    /// Yields numbers from `0` to `n`.
+    struct Range {
+        current: usize,
+        n: usize,
+    }
+
+    impl AsyncIterator for Range {
+        type Item = usize;
+
+        async fn next(&mut self) -> Option<usize> {
+            if self.current == self.n {
+                return None;
+            }
+
+            self.current += 1;
+            Some(self.current - 1)
+        }
+    }
+
+    const fn range(n: usize) -> Range {
+        Range { current: 0, n }
+    }
+
+    #[orengine::test::test_local]
+    fn test_async_iter_combinators() {
+        let mut res = Vec::new();
+        range(10)
+            .filter(|i| i % 3 == 0)
+            .map(|i| i * 2)
+            .take(3)
+            .for_each(|i| res.push(i))
+            .await;
+        assert_eq!(res, [0, 6, 12]);
+
+        let mut res = Vec::new();
+        range(4).flat_map(range).for_each(|i| res.push(i)).await;
+        assert_eq!(res, [0, 0, 1, 0, 1, 2]);
+
+        let mut iter = range(5);
+        let first_two: Vec<usize> = {
+            let mut taken = iter.by_ref().take(2);
+            let mut v = Vec::new();
+            while let Some(i) = taken.next().await {
+                v.push(i);
+            }
+            v
+        };
+        assert_eq!(first_two, [0, 1]);
+        assert_eq!(iter.next().await, Some(2));
+    }
+
+    #[orengine::test::test_local]
+    fn test_async_iter_for_receiver() {
+        let channel = LocalChannel::bounded(5);
+        let (sender, mut receiver) = channel.split();
+
+        for i in 0..5 {
+            sender.send(i).await.unwrap();
+        }
+
+        let mut res = Vec::new();
+        receiver.by_ref().take(4).for_each(|i| res.push(i)).await;
+        assert_eq!(res, [0, 1, 2, 3]);
+
+        sender.sender_close().await;
+        assert_eq!(AsyncIterator::next(&mut receiver).await, None);
+    }
+}
@@ -0,0 +1,280 @@
+use crate::local_executor;
+use crate::utils::SpinLock;
+use std::fmt::{Debug, Display, Formatter};
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+/// `CircuitBreakerState` is the state of the [`CircuitBreaker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitBreakerState {
+    /// Calls are executed normally.
+    Closed,
+    /// Calls fail immediately with [`CircuitBreakerError::Open`].
+    Open,
+    /// One probe call is executed. Other calls fail immediately.
+    HalfOpen,
+}
+
+/// `CircuitBreakerError` is returned by [`CircuitBreaker::call`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitBreakerError<E> {
+    /// The circuit breaker is open, and the call has not been executed.
+    Open,
+    /// The call has been executed and returned the error.
+    Inner(E),
+}
+
+impl<E: Display> Display for CircuitBreakerError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Open => f.write_str("circuit breaker is open"),
+            Self::Inner(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+impl<E: Debug + Display> std::error::Error for CircuitBreakerError<E> {}
+
+/// Internal state of the [`CircuitBreaker`].
+enum State {
+    Closed { consecutive_failures: usize },
+    Open { since: Instant },
+    HalfOpen,
+}
+
+/// `ProbeGuard` opens the [`CircuitBreaker`] again if the probe call is dropped
+/// before it completes.
+struct ProbeGuard<'breaker> {
+    breaker: &'breaker CircuitBreaker,
+    is_completed: bool,
+}
+
+impl Drop for ProbeGuard<'_> {
+    fn drop(&mut self) {
+        if !self.is_completed {
+            *self.breaker.state.lock() = State::Open {
+                since: local_executor().start_round_time(),
+            };
+        }
+    }
+}
+
+/// `CircuitBreaker` protects a downstream service from calls that are likely to fail.
+///
+/// - In the [`Closed`](CircuitBreakerState::Closed) state all calls are executed.
+///   After `failure_threshold` consecutive failures it becomes
+///   [`Open`](CircuitBreakerState::Open);
+///
+/// - In the [`Open`](CircuitBreakerState::Open) state all calls fail immediately
+///   with [`CircuitBreakerError::Open`]. After `reset_timeout` it becomes
+///   [`HalfOpen`](CircuitBreakerState::HalfOpen);
+///
+/// - In the [`HalfOpen`](CircuitBreakerState::HalfOpen) state only one probe call
+///   is executed. If it succeeds, the circuit breaker is closed,
+///   otherwise it is opened again.
+///
+/// The time is read from [`start_round_time`](crate::Executor::start_round_time),
+/// so the configured [`Clock`](crate::runtime::Clock) is used.
+///
+/// It can be shared between `local` and `shared` tasks.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::future::{CircuitBreaker, CircuitBreakerError};
+/// use std::time::Duration;
+///
+/// # async fn request() -> std::io::Result<String> { Ok(String::new()) }
+/// # async fn foo() {
+/// let breaker = CircuitBreaker::new(5, Duration::from_secs(10));
+///
+/// match breaker.call(|| request()).await {
+///     Ok(response) => println!("{response}"),
+///     Err(CircuitBreakerError::Open) => println!("the service is unavailable"),
+///     Err(CircuitBreakerError::Inner(err)) => println!("request failed: {err}"),
+/// }
+/// # }
+/// ```
+pub struct CircuitBreaker {
+    state: SpinLock<State>,
+    failure_threshold: usize,
+    reset_timeout: Duration,
+}
+
+impl CircuitBreaker {
+    /// Creates a new closed `CircuitBreaker`.
+    ///
+    /// # Panics
+    ///
+    /// If `failure_threshold` is zero.
+    pub const fn new(failure_threshold: usize, reset_timeout: Duration) -> Self {
+        assert!(
+            failure_threshold > 0,
+            "failure_threshold must be greater than 0"
+        );
+
+        Self {
+            state: SpinLock::new(State::Closed {
+                consecutive_failures: 0,
+            }),
+            failure_threshold,
+            reset_timeout,
+        }
+    }
+
+    /// Returns the number of consecutive failures after which the circuit breaker is opened.
+    pub const fn failure_threshold(&self) -> usize {
+        self.failure_threshold
+    }
+
+    /// Returns how long the circuit breaker stays open before it allows a probe call.
+    pub const fn reset_timeout(&self) -> Duration {
+        self.reset_timeout
+    }
+
+    /// Returns the current [`state`](CircuitBreakerState) of the circuit breaker.
+    ///
+    /// An open circuit breaker whose `reset_timeout` has passed is still reported as
+    /// [`Open`](CircuitBreakerState::Open) until the next call.
+    pub fn state(&self) -> CircuitBreakerState {
+        match *self.state.lock() {
+            State::Closed { .. } => CircuitBreakerState::Closed,
+            State::Open { .. } => CircuitBreakerState::Open,
+            State::HalfOpen => CircuitBreakerState::HalfOpen,
+        }
+    }
+
+    /// Returns whether the call can be executed, and whether it is the probe call.
+    fn acquire_call(&self) -> Option<bool> {
+        let mut state = self.state.lock();
+        match *state {
+            State::Closed { .. } => Some(false),
+            State::Open { since }
+                if local_executor().start_round_time() >= since + self.reset_timeout =>
+            {
+                *state = State::HalfOpen;
+                Some(true)
+            }
+            State::Open { .. } | State::HalfOpen => None,
+        }
+    }
+
+    /// Records the result of the executed call.
+    fn record(&self, is_success: bool) {
+        let mut state = self.state.lock();
+        if is_success {
+            *state = State::Closed {
+                consecutive_failures: 0,
+            };
+
+            return;
+        }
+
+        let should_open = match &mut *state {
+            State::Closed {
+                consecutive_failures,
+            } => {
+                *consecutive_failures += 1;
+                *consecutive_failures >= self.failure_threshold
+            }
+            State::HalfOpen => true,
+            // Another call has already opened the circuit breaker.
+            State::Open { .. } => false,
+        };
+
+        if should_open {
+            *state = State::Open {
+                since: local_executor().start_round_time(),
+            };
+        }
+    }
+
+    /// Executes the future returned by `f` if the circuit breaker allows it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CircuitBreakerError::Open`] without calling `f`
+    /// if the circuit breaker is open or a probe call is in progress,
+    /// and [`CircuitBreakerError::Inner`] if the future returned an error.
+    pub async fn call<T, E, F, Fut>(&self, f: F) -> Result<T, CircuitBreakerError<E>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let Some(is_probe) = self.acquire_call() else {
+            return Err(CircuitBreakerError::Open);
+        };
+
+        let mut probe_guard = is_probe.then(|| ProbeGuard {
+            breaker: self,
+            is_completed: false,
+        });
+        let res = f().await;
+        if let Some(probe_guard) = probe_guard.as_mut() {
+            probe_guard.is_completed = true;
+        }
+
+        self.record(res.is_ok());
+
+        res.map_err(CircuitBreakerError::Inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::Config;
+    use crate::test::FakeClock;
+    use crate::Executor;
+
+    #[allow(clippy::future_not_send, reason = "Because it is `local`")]
+    async fn fail(breaker: &CircuitBreaker) -> Result<(), CircuitBreakerError<()>> {
+        breaker.call(|| async { Err(()) }).await
+    }
+
+    #[allow(clippy::future_not_send, reason = "Because it is `local`")]
+    async fn succeed(breaker: &CircuitBreaker) -> Result<(), CircuitBreakerError<()>> {
+        breaker.call(|| async { Ok(()) }).await
+    }
+
+    #[test]
+    fn test_circuit_breaker() {
+        static CLOCK: FakeClock = FakeClock::new();
+
+        let ex = Executor::init_with_config(Config::default().set_clock(&CLOCK));
+        let res = ex.run_and_block_on_local(async {
+            let breaker = CircuitBreaker::new(3, Duration::from_secs(10));
+
+            for _ in 0..2 {
+                assert_eq!(fail(&breaker).await, Err(CircuitBreakerError::Inner(())));
+            }
+            assert_eq!(succeed(&breaker).await, Ok(()));
+            assert_eq!(breaker.state(), CircuitBreakerState::Closed);
+
+            for _ in 0..3 {
+                assert_eq!(fail(&breaker).await, Err(CircuitBreakerError::Inner(())));
+            }
+            assert_eq!(breaker.state(), CircuitBreakerState::Open);
+            assert_eq!(succeed(&breaker).await, Err(CircuitBreakerError::Open));
+
+            CLOCK.advance(Duration::from_secs(11));
+            crate::yield_now().await;
+            assert_eq!(fail(&breaker).await, Err(CircuitBreakerError::Inner(())));
+            assert_eq!(breaker.state(), CircuitBreakerState::Open);
+            assert_eq!(succeed(&breaker).await, Err(CircuitBreakerError::Open));
+
+            CLOCK.advance(Duration::from_secs(11));
+            crate::yield_now().await;
+            let probe = breaker.call(|| async {
+                assert_eq!(breaker.state(), CircuitBreakerState::HalfOpen);
+                assert_eq!(succeed(&breaker).await, Err(CircuitBreakerError::Open));
+
+                Ok::<_, ()>(())
+            });
+            assert_eq!(probe.await, Ok(()));
+            assert_eq!(breaker.state(), CircuitBreakerState::Closed);
+        });
+
+        assert!(res.is_ok(), "{res:?}");
+    }
+}
@@ -0,0 +1,190 @@
+//! This module contains [`Debounce`].
+use crate::{local_executor, sleep};
+use std::cell::{Cell, RefCell};
+use std::future::Future;
+use std::rc::{Rc, Weak};
+use std::time::{Duration, Instant};
+
+/// The state shared between the [`Debounce`] and its timer task.
+struct Shared<F> {
+    deadline: Cell<Option<Instant>>,
+    future_factory: RefCell<F>,
+}
+
+/// `Debounce` collapses bursts of triggers into a single delivery.
+///
+/// Every [`trigger`](Self::trigger) starts or restarts a timer of `wait` duration.
+/// When the timer fires, `future_factory` is called, and the returned future is executed
+/// in a `local` task. So, the future is executed once after the triggers stop for `wait`.
+///
+/// It is useful for coalescing events of a [`FileWatcher`](crate::io::FileWatcher),
+/// input validation and rate-limiting expensive recomputations.
+///
+/// Dropping the `Debounce` cancels the delivery that has not fired yet.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::future::Debounce;
+/// use std::time::Duration;
+///
+/// # async fn rebuild_index() {}
+/// # async fn foo() {
+/// let mut debounce = Debounce::new(Duration::from_millis(100), rebuild_index);
+///
+/// for _ in 0..10 {
+///     debounce.trigger();
+/// }
+/// // `rebuild_index` will be called once 100 millis after the last trigger.
+/// # }
+/// ```
+pub struct Debounce<F> {
+    wait: Duration,
+    shared: Rc<Shared<F>>,
+}
+
+impl<Fut, F> Debounce<F>
+where
+    Fut: Future<Output = ()> + 'static,
+    F: FnMut() -> Fut + 'static,
+{
+    /// Creates a new `Debounce` that calls `future_factory` after `wait`
+    /// since the last [`trigger`](Self::trigger).
+    pub fn new(wait: Duration, future_factory: F) -> Self {
+        Self {
+            wait,
+            shared: Rc::new(Shared {
+                deadline: Cell::new(None),
+                future_factory: RefCell::new(future_factory),
+            }),
+        }
+    }
+
+    /// Returns the duration the triggers must stop for before the delivery.
+    pub const fn wait(&self) -> Duration {
+        self.wait
+    }
+
+    /// Returns whether the timer is started and has not fired yet.
+    pub fn is_pending(&self) -> bool {
+        self.shared.deadline.get().is_some()
+    }
+
+    /// Starts the timer or restarts it if it is already started.
+    pub fn trigger(&mut self) {
+        let deadline = local_executor().start_round_time_for_deadlines() + self.wait;
+        let is_started = self.shared.deadline.replace(Some(deadline)).is_some();
+        if !is_started {
+            local_executor().spawn_local(Self::run_timer(Rc::downgrade(&self.shared)));
+        }
+    }
+
+    /// Stops the timer without the delivery.
+    pub fn cancel(&mut self) {
+        self.shared.deadline.set(None);
+    }
+
+    /// Waits until the deadline that can be moved by [`trigger`](Self::trigger)
+    /// and executes the future returned by the factory.
+    ///
+    /// It returns without the delivery if the timer is cancelled or the `Debounce` is dropped.
+    #[allow(clippy::future_not_send, reason = "Because it is `local`")]
+    async fn run_timer(shared: Weak<Shared<F>>) {
+        let future = loop {
+            let Some(shared) = shared.upgrade() else {
+                return;
+            };
+            let Some(deadline) = shared.deadline.get() else {
+                return;
+            };
+
+            let now = local_executor().start_round_time_for_deadlines();
+            if now >= deadline {
+                shared.deadline.set(None);
+
+                break (shared.future_factory.borrow_mut())();
+            }
+
+            drop(shared);
+            sleep(deadline - now).await;
+        };
+
+        future.await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::yield_now;
+
+    #[orengine::test::test_local]
+    fn test_debounce_collapses_burst() {
+        let delivered = Rc::new(Cell::new(0));
+        let delivered_clone = delivered.clone();
+        let mut debounce = Debounce::new(Duration::from_millis(10), move || {
+            let delivered = delivered_clone.clone();
+            async move {
+                delivered.set(delivered.get() + 1);
+            }
+        });
+
+        for _ in 0..10 {
+            debounce.trigger();
+            yield_now().await;
+        }
+        assert!(debounce.is_pending());
+
+        sleep(Duration::from_millis(40)).await;
+        assert_eq!(delivered.get(), 1);
+        assert!(!debounce.is_pending());
+
+        debounce.trigger();
+        sleep(Duration::from_millis(40)).await;
+        assert_eq!(delivered.get(), 2);
+    }
+
+    #[orengine::test::test_local]
+    fn test_debounce_restarts_timer() {
+        let delivered = Rc::new(Cell::new(0));
+        let delivered_clone = delivered.clone();
+        let mut debounce = Debounce::new(Duration::from_millis(60), move || {
+            let delivered = delivered_clone.clone();
+            async move {
+                delivered.set(delivered.get() + 1);
+            }
+        });
+
+        debounce.trigger();
+        sleep(Duration::from_millis(30)).await;
+        debounce.trigger();
+        sleep(Duration::from_millis(45)).await;
+        assert_eq!(delivered.get(), 0);
+
+        sleep(Duration::from_millis(60)).await;
+        assert_eq!(delivered.get(), 1);
+    }
+
+    #[orengine::test::test_local]
+    fn test_debounce_cancel_and_drop() {
+        let delivered = Rc::new(Cell::new(0));
+        let delivered_clone = delivered.clone();
+        let mut debounce = Debounce::new(Duration::from_millis(10), move || {
+            let delivered = delivered_clone.clone();
+            async move {
+                delivered.set(delivered.get() + 1);
+            }
+        });
+
+        debounce.trigger();
+        debounce.cancel();
+        assert!(!debounce.is_pending());
+
+        debounce.trigger();
+        drop(debounce);
+
+        sleep(Duration::from_millis(40)).await;
+        assert_eq!(delivered.get(), 0);
+    }
+}
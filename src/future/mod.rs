@@ -0,0 +1,13 @@
+//! The `future` module provides asynchronous abstractions over futures.
+//!
+//! It contains [`AsyncIterator`], [`AsyncFnMut`], [`CircuitBreaker`] and [`Debounce`].
+
+pub mod async_fn;
+pub mod async_iter;
+pub mod circuit_breaker;
+pub mod debounce;
+
+pub use async_fn::{AsyncFn, AsyncFnMut};
+pub use async_iter::*;
+pub use circuit_breaker::*;
+pub use debounce::Debounce;
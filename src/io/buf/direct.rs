@@ -0,0 +1,188 @@
+use crate::io::{FixedBuffer, FixedBufferMut};
+use crate::utils::Sealed;
+use std::alloc::{alloc_zeroed, dealloc, handle_alloc_error, Layout};
+use std::fmt::{Debug, Formatter};
+use std::ops::{Deref, DerefMut};
+use std::ptr::NonNull;
+
+/// The default alignment of the [`DirectBuffer`].
+///
+/// It is a multiple of the logical block size of almost all devices (512 or 4096 bytes).
+pub const DEFAULT_DIRECT_IO_ALIGNMENT: usize = 4096;
+
+/// `DirectBuffer` is a zeroed buffer whose address and length are aligned
+/// for direct I/O ([`OpenOptions::direct`](crate::fs::OpenOptions::direct)).
+///
+/// It implements [`FixedBuffer`] and [`FixedBufferMut`], so it can be passed to
+/// [`read`](crate::io::AsyncRead::read), [`pread`](crate::io::AsyncRead::pread),
+/// [`write`](crate::io::AsyncWrite::write) and [`pwrite`](crate::io::AsyncWrite::pwrite)
+/// of a file opened with `O_DIRECT`. File offsets must be aligned too.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::fs::{File, OpenOptions};
+/// use orengine::io::{AsyncRead, AsyncWrite, DirectBuffer};
+///
+/// # async fn foo() -> std::io::Result<()> {
+/// let options = OpenOptions::new().read(true).write(true).create(true).direct(true);
+/// let mut wal = File::open("wal.log", &options).await?;
+///
+/// let mut buf = DirectBuffer::new(4096);
+/// buf[..5].copy_from_slice(b"entry");
+/// wal.pwrite_all(&buf, 0).await?;
+///
+/// wal.pread_exact(&mut buf, 0).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct DirectBuffer {
+    ptr: NonNull<u8>,
+    layout: Layout,
+}
+
+impl DirectBuffer {
+    /// Creates a new zeroed `DirectBuffer` with the given length aligned to
+    /// [`DEFAULT_DIRECT_IO_ALIGNMENT`].
+    ///
+    /// # Panics
+    ///
+    /// If `len` is zero or is not a multiple of [`DEFAULT_DIRECT_IO_ALIGNMENT`].
+    pub fn new(len: u32) -> Self {
+        Self::with_alignment(len, DEFAULT_DIRECT_IO_ALIGNMENT)
+    }
+
+    /// Creates a new zeroed `DirectBuffer` with the given length and alignment.
+    ///
+    /// Use it if the logical block size of the device is known,
+    /// for example, `512`.
+    ///
+    /// # Panics
+    ///
+    /// If `len` is zero, `alignment` is not a power of two
+    /// or `len` is not a multiple of `alignment`.
+    pub fn with_alignment(len: u32, alignment: usize) -> Self {
+        assert_ne!(len, 0, "len of the DirectBuffer must be greater than 0");
+        let layout = Layout::from_size_align(len as usize, alignment)
+            .expect("alignment of the DirectBuffer must be a power of two");
+        assert!(
+            len as usize % alignment == 0,
+            "len of the DirectBuffer must be a multiple of the alignment"
+        );
+        let ptr = NonNull::new(unsafe { alloc_zeroed(layout) })
+            .unwrap_or_else(|| handle_alloc_error(layout));
+
+        Self { ptr, layout }
+    }
+
+    /// Returns the alignment of the buffer.
+    #[inline]
+    pub const fn alignment(&self) -> usize {
+        self.layout.align()
+    }
+}
+
+impl Sealed for DirectBuffer {}
+
+impl FixedBuffer for DirectBuffer {
+    #[inline]
+    fn as_ptr(&self) -> *const u8 {
+        self.ptr.as_ptr()
+    }
+
+    #[inline]
+    #[allow(
+        clippy::cast_possible_truncation,
+        reason = "The length is created from u32"
+    )]
+    fn len_u32(&self) -> u32 {
+        self.layout.size() as u32
+    }
+
+    #[inline]
+    fn fixed_index(&self) -> u16 {
+        u16::MAX
+    }
+
+    #[inline]
+    fn is_fixed(&self) -> bool {
+        false
+    }
+}
+
+impl FixedBufferMut for DirectBuffer {
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.ptr.as_ptr()
+    }
+}
+
+impl Deref for DirectBuffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_bytes()
+    }
+}
+
+impl DerefMut for DirectBuffer {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_bytes_mut()
+    }
+}
+
+impl AsRef<[u8]> for DirectBuffer {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl AsMut<[u8]> for DirectBuffer {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self
+    }
+}
+
+impl Debug for DirectBuffer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DirectBuffer")
+            .field("len", &self.layout.size())
+            .field("alignment", &self.layout.align())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for DirectBuffer {
+    fn drop(&mut self) {
+        unsafe { dealloc(self.ptr.as_ptr(), self.layout) };
+    }
+}
+
+unsafe impl Send for DirectBuffer {}
+unsafe impl Sync for DirectBuffer {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direct_buffer() {
+        let mut buf = DirectBuffer::with_alignment(1024, 512);
+        assert_eq!(buf.len(), 1024);
+        assert_eq!(buf.alignment(), 512);
+        assert_eq!(buf.as_ptr() as usize % 512, 0);
+        assert!(buf.iter().all(|byte| *byte == 0));
+
+        buf[..5].copy_from_slice(b"hello");
+        assert_eq!(&buf[..5], b"hello");
+
+        let buf = DirectBuffer::new(8192);
+        assert_eq!(buf.as_ptr() as usize % DEFAULT_DIRECT_IO_ALIGNMENT, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "multiple of the alignment")]
+    fn test_direct_buffer_unaligned_len() {
+        let _ = DirectBuffer::new(100);
+    }
+}
@@ -1,7 +1,8 @@
-//! This module contains [`Buffer`] and [`BufPool`].
+//! This module contains [`Buffer`], [`BufPool`] and [`DirectBuffer`].
 //! Read [`Buffer`] and [`BufPool`] for more information.
 pub use buf_pool::*;
 pub use buffer::*;
+pub use direct::*;
 pub use fixed_io_buffer::*;
 pub use sendable_buffer::*;
 pub use slice::*;
@@ -9,6 +10,7 @@ pub use with_buffer::*;
 
 pub mod buf_pool;
 pub mod buffer;
+pub mod direct;
 pub mod fixed_io_buffer;
 pub(crate) mod linux;
 pub mod sendable_buffer;
@@ -146,3 +146,114 @@ mod buf_pool_tests {
         assert_eq!(pool.len(), start_len - 1);
     }
 }
+
+#[cfg(test)]
+mod buf_pool_random_ops_tests {
+    use crate as orengine;
+    use crate::io::{buf_pool, Buffer, FixedBuffer};
+
+    /// A buffer taken from the pool and the data that must be in it.
+    struct Held {
+        buffer: Buffer,
+        expected: Vec<u8>,
+    }
+
+    /// Applies a random sequence of `get`, `get_full`, `resize` and release operations
+    /// to the pool and checks after each operation that:
+    ///
+    /// - the number of buffers in the pool matches the model;
+    ///
+    /// - no buffer is handed out twice;
+    ///
+    /// - the data of the buffers is not corrupted.
+    fn check_random_ops(seed: u64, number_of_ops: usize) {
+        let mut rng = fastrand::Rng::with_seed(seed);
+        let pool = buf_pool();
+        let default_cap = pool.default_buffer_capacity();
+        let mut expected_pool_len = pool.len();
+        let mut held: Vec<Held> = Vec::new();
+
+        for op in 0..number_of_ops {
+            match rng.u8(0..4) {
+                0 | 1 => {
+                    let is_full = rng.bool();
+                    let mut buffer = if is_full { pool.get_full() } else { pool.get() };
+                    expected_pool_len = expected_pool_len.saturating_sub(1);
+
+                    assert_eq!(buffer.capacity(), default_cap, "seed: {seed}, op: {op}");
+                    if is_full {
+                        assert_eq!(buffer.len_u32(), default_cap, "seed: {seed}, op: {op}");
+                        buffer.clear();
+                    } else {
+                        assert!(buffer.is_empty(), "seed: {seed}, op: {op}");
+                    }
+
+                    let mut expected = vec![0; rng.usize(0..=default_cap as usize)];
+                    rng.fill(&mut expected);
+                    buffer.append(&expected);
+
+                    held.push(Held { buffer, expected });
+                }
+                2 if !held.is_empty() => {
+                    let held = held.swap_remove(rng.usize(..held.len()));
+                    assert_eq!(
+                        held.buffer.as_ref(),
+                        held.expected,
+                        "seed: {seed}, op: {op}"
+                    );
+
+                    if held.buffer.capacity() == default_cap {
+                        expected_pool_len += 1;
+                    }
+                }
+                3 if !held.is_empty() => {
+                    let index = rng.usize(..held.len());
+                    let held = &mut held[index];
+                    let new_size = match rng.u8(0..3) {
+                        0 => default_cap,
+                        1 => default_cap / 2,
+                        _ => rng.u32(1..default_cap * 3),
+                    };
+                    let old_cap = held.buffer.capacity();
+
+                    held.buffer.resize(new_size);
+                    if (new_size as usize) < held.expected.len() {
+                        held.expected.truncate(new_size as usize);
+                        assert_eq!(held.buffer.capacity(), old_cap, "seed: {seed}, op: {op}");
+                    } else {
+                        assert_eq!(held.buffer.capacity(), new_size, "seed: {seed}, op: {op}");
+                        if new_size == default_cap {
+                            expected_pool_len = expected_pool_len.saturating_sub(1);
+                        }
+                        if old_cap == default_cap {
+                            expected_pool_len += 1;
+                        }
+                    }
+                }
+                _ => {}
+            }
+
+            assert_eq!(pool.len(), expected_pool_len, "seed: {seed}, op: {op}");
+
+            let mut pointers: Vec<_> = held.iter().map(|held| held.buffer.as_ptr()).collect();
+            pointers.sort_unstable();
+            pointers.dedup();
+            assert_eq!(pointers.len(), held.len(), "seed: {seed}, op: {op}");
+
+            for held in &held {
+                assert_eq!(
+                    held.buffer.as_ref(),
+                    held.expected,
+                    "seed: {seed}, op: {op}"
+                );
+            }
+        }
+    }
+
+    #[orengine::test::test_local]
+    fn test_buf_pool_random_ops() {
+        for seed in 0..32 {
+            check_random_ops(seed, 256);
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use crate::io::{buffer, AsyncRead, Buffer};
+use std::io::{Error, ErrorKind, Result};
+use std::mem;
+
+/// The `AsyncBufRead` trait provides asynchronous methods for readers with an internal buffer.
+///
+/// It allows reading the data by parts (for example, line by line)
+/// without allocating a new buffer on every call.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::fs::{File, OpenOptions};
+/// use orengine::io::{AsyncBufRead, BufReader};
+///
+/// # async fn foo() -> std::io::Result<()> {
+/// let file = File::open("example.txt", &OpenOptions::new().read(true)).await?;
+/// let mut reader = BufReader::new(file);
+/// let mut line = String::new();
+///
+/// while reader.read_line(&mut line).await? != 0 {
+///     println!("{line}");
+///     line.clear();
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub trait AsyncBufRead {
+    /// Returns the contents of the internal buffer, filling it with more data
+    /// from the inner reader if it is empty.
+    ///
+    /// An empty slice is returned only if the inner reader has reached its end.
+    ///
+    /// Call [`consume`](Self::consume) with the number of the bytes that have been processed.
+    async fn fill_buf(&mut self) -> Result<&[u8]>;
+
+    /// Marks `amt` bytes of the internal buffer as consumed,
+    /// so they will no longer be returned by [`fill_buf`](Self::fill_buf).
+    fn consume(&mut self, amt: usize);
+
+    /// Reads all bytes into `buf` until the `byte` or the end of the reader is reached.
+    ///
+    /// The `byte` (if found) is appended to `buf`. Returns the number of read bytes.
+    async fn read_until(&mut self, byte: u8, buf: &mut Vec<u8>) -> Result<usize> {
+        let mut read = 0;
+
+        loop {
+            let (is_done, used) = {
+                let available = self.fill_buf().await?;
+                if let Some(i) = available.iter().position(|&b| b == byte) {
+                    buf.extend_from_slice(&available[..=i]);
+                    (true, i + 1)
+                } else {
+                    buf.extend_from_slice(available);
+                    (available.is_empty(), available.len())
+                }
+            };
+
+            self.consume(used);
+            read += used;
+
+            if is_done {
+                return Ok(read);
+            }
+        }
+    }
+
+    /// Reads all bytes until a newline (the `0xA` byte) or the end of the reader is reached,
+    /// and appends them to `buf`.
+    ///
+    /// The newline (if found) is appended to `buf`. Returns the number of read bytes.
+    ///
+    /// # Errors
+    ///
+    /// If the read data is not valid UTF-8, an error with kind
+    /// [`ErrorKind::InvalidData`] is returned and `buf` is left unchanged.
+    async fn read_line(&mut self, buf: &mut String) -> Result<usize> {
+        let mut bytes = mem::take(buf).into_bytes();
+        let start = bytes.len();
+        let ret = self.read_until(b'\n', &mut bytes).await;
+
+        match String::from_utf8(bytes) {
+            Ok(string) => {
+                *buf = string;
+
+                ret
+            }
+            Err(err) => {
+                let mut bytes = err.into_bytes();
+                bytes.truncate(start);
+                *buf = unsafe { String::from_utf8_unchecked(bytes) };
+
+                ret?;
+                Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "stream did not contain valid UTF-8",
+                ))
+            }
+        }
+    }
+}
+
+/// `BufReader` adds buffering to any [`reader`](AsyncRead) and implements [`AsyncBufRead`].
+///
+/// The internal [`Buffer`] is taken from the local [`BufPool`](crate::io::BufPool)
+/// and is returned to it after drop. It can be replaced with
+/// [`with_buffer`](BufReader::with_buffer).
+///
+/// Read [`AsyncBufRead`] for an example.
+pub struct BufReader<R> {
+    inner: R,
+    buf: Buffer,
+    /// The position of the first not yet consumed byte in `buf`.
+    pos: usize,
+}
+
+impl<R> BufReader<R> {
+    /// Creates a new `BufReader` with a [`Buffer`] from the local
+    /// [`BufPool`](crate::io::BufPool).
+    pub fn new(inner: R) -> Self {
+        Self::with_buffer(inner, buffer())
+    }
+
+    /// Creates a new `BufReader` with the provided [`Buffer`].
+    ///
+    /// The buffer is filled up to its [`capacity`](Buffer::capacity),
+    /// its current data is discarded.
+    pub fn with_buffer(inner: R, mut buf: Buffer) -> Self {
+        buf.clear();
+
+        Self { inner, buf, pos: 0 }
+    }
+
+    /// Returns a shared reference to the inner reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner reader.
+    ///
+    /// Reading directly from the inner reader can cause data loss.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the buffered but not yet consumed data.
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf[self.pos..]
+    }
+
+    /// Returns the inner reader. The buffered data is lost.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: AsyncRead> AsyncBufRead for BufReader<R> {
+    #[allow(
+        clippy::future_not_send,
+        reason = "It is not `Send` only when R is not `Send`, it is fine"
+    )]
+    async fn fill_buf(&mut self) -> Result<&[u8]> {
+        if self.pos >= self.buf.len() {
+            self.pos = 0;
+            self.buf.set_len_to_capacity();
+
+            match self.inner.read(&mut self.buf).await {
+                Ok(read) => unsafe { self.buf.set_len_unchecked(read) },
+                Err(err) => {
+                    self.buf.clear();
+
+                    return Err(err);
+                }
+            }
+        }
+
+        Ok(self.buffer())
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos = (self.pos + amt).min(self.buf.len());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::fs::test_helper::{create_test_dir_if_not_exist, TEST_DIR_PATH};
+    use crate::fs::{File, OpenOptions};
+    use crate::io::AsyncWrite;
+
+    #[orengine::test::test_local]
+    fn test_buf_reader() {
+        create_test_dir_if_not_exist();
+
+        let path = format!("{TEST_DIR_PATH}/test_buf_reader.txt");
+        let options = OpenOptions::new().write(true).create(true).truncate(true);
+        let mut file = File::open(&path, &options).await.expect("open failed");
+        file.write_all_bytes(b"first\nsecond\n\nlast")
+            .await
+            .expect("write failed");
+        drop(file);
+
+        let file = File::open(&path, &OpenOptions::new().read(true))
+            .await
+            .expect("open failed");
+        let mut small_buffer = buffer();
+        small_buffer.resize(4);
+        let mut reader = BufReader::with_buffer(file, small_buffer);
+
+        let mut lines = Vec::new();
+        let mut line = String::new();
+        while reader.read_line(&mut line).await.expect("read_line failed") != 0 {
+            lines.push(mem::take(&mut line));
+        }
+        assert_eq!(lines, ["first\n", "second\n", "\n", "last"]);
+
+        File::remove(&path).await.expect("remove failed");
+    }
+}
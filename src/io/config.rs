@@ -25,6 +25,55 @@ impl IoWorkerConfig {
         }
     }
 
+    /// Sets the number of entries in the submission queue of `io_uring`.
+    ///
+    /// The optimal size depends on the parallelism level of the application: a connection
+    /// proxy needs a larger ring than a simple echo server.
+    /// It must be a power of two and at least 8.
+    #[must_use]
+    pub const fn sq_entries(mut self, number_of_entries: u32) -> Self {
+        self.io_uring.number_of_entries = number_of_entries;
+
+        self
+    }
+
+    /// Sets the number of entries in the completion queue of `io_uring`.
+    ///
+    /// By default, the kernel uses twice the number of submission queue entries.
+    /// It must be a power of two and at least the number of submission queue entries.
+    #[must_use]
+    pub const fn cq_entries(mut self, number_of_cq_entries: u32) -> Self {
+        self.io_uring.number_of_cq_entries = Some(number_of_cq_entries);
+
+        self
+    }
+
+    /// Sets the number of accumulated `io_uring` submission queue entries after which
+    /// they are submitted immediately.
+    ///
+    /// By default, entries are submitted once per round of the executor, which minimizes
+    /// the number of system calls. Smaller values reduce the latency at the cost
+    /// of more system calls. It must be greater than 0.
+    ///
+    /// Use it with [`Config::set_io_worker_config`](crate::runtime::Config::set_io_worker_config).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::io::IoWorkerConfig;
+    /// use orengine::runtime::Config;
+    ///
+    /// let config = Config::default()
+    ///     .set_io_worker_config(Some(IoWorkerConfig::default().submit_batch_size(32)))
+    ///     .unwrap();
+    /// ```
+    #[must_use]
+    pub const fn submit_batch_size(mut self, submit_batch_size: usize) -> Self {
+        self.io_uring.submit_batch_size = submit_batch_size;
+
+        self
+    }
+
     /// Checks if [`IoWorkerConfig`] is valid.
     pub const fn validate(&self) -> Result<(), &'static str> {
         if let Err(err) = self.io_uring.validate() {
@@ -0,0 +1,132 @@
+use crate::fs::{File, OpenOptions};
+use crate::io::{AsyncSyncData, AsyncWrite};
+use std::ffi::OsString;
+use std::io::{Error, ErrorKind, Result};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Returns a unique path of a temporary file in the same directory as `path`.
+fn temp_path_for(path: &Path) -> Result<PathBuf> {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let Some(file_name) = path.file_name() else {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "path for write_atomic must point to a file",
+        ));
+    };
+
+    let mut temp_name = OsString::from(".");
+    temp_name.push(file_name);
+    temp_name.push(format!(
+        ".tmp.{}.{}",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    Ok(path.with_file_name(temp_name))
+}
+
+/// Writes `data` to a temporary file, syncs it and renames it to `path`.
+async fn write_and_rename(temp_path: &Path, path: &Path, data: &[u8]) -> Result<()> {
+    let options = OpenOptions::new().write(true).create_new(true);
+    let mut file = File::open(temp_path, &options).await?;
+
+    file.write_all_bytes(data).await?;
+    file.sync_data().await?;
+    drop(file);
+
+    File::rename(temp_path, path).await
+}
+
+/// Atomically replaces the content of the file at `path` with `data`.
+///
+/// It writes `data` to a temporary file in the same directory, syncs it to disk
+/// and renames it to `path`. So, readers see either the old or the new content of the file,
+/// but never a partially written one, even if the power is lost.
+///
+/// On `Unix` it also syncs the parent directory after the rename to make the rename durable.
+///
+/// # Errors
+///
+/// This function will return an `Err` if `path` doesn't point to a file or if any of the
+/// operations fails. In this case the temporary file is removed and the file at `path`
+/// is left untouched.
+///
+/// # Example
+///
+/// ```no_run
+/// use orengine::io::write_atomic;
+///
+/// # async fn foo() -> std::io::Result<()> {
+/// write_atomic("config.json", br#"{"workers": 4}"#).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn write_atomic<P: AsRef<Path> + Send>(path: P, data: &[u8]) -> Result<()> {
+    let path = path.as_ref();
+    let temp_path = temp_path_for(path)?;
+
+    if let Err(err) = write_and_rename(&temp_path, path, data).await {
+        let _ = File::remove(&temp_path).await;
+
+        return Err(err);
+    }
+
+    #[cfg(unix)]
+    {
+        let parent = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+        let dir = File::open(parent, &OpenOptions::new().read(true)).await?;
+        dir.sync_data().await?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::fs::test_helper::{create_test_dir_if_not_exist, TEST_DIR_PATH};
+
+    #[orengine::test::test_local]
+    fn test_write_atomic() {
+        create_test_dir_if_not_exist();
+        let path = PathBuf::from(TEST_DIR_PATH).join("write_atomic.txt");
+
+        write_atomic(&path, b"first")
+            .await
+            .expect("write_atomic failed");
+        assert_eq!(std::fs::read(&path).unwrap(), b"first");
+
+        write_atomic(&path, b"second version")
+            .await
+            .expect("write_atomic failed");
+        assert_eq!(std::fs::read(&path).unwrap(), b"second version");
+
+        let leftovers = std::fs::read_dir(TEST_DIR_PATH)
+            .unwrap()
+            .filter(|entry| {
+                entry
+                    .as_ref()
+                    .unwrap()
+                    .file_name()
+                    .to_string_lossy()
+                    .starts_with(".write_atomic.txt.tmp")
+            })
+            .count();
+        assert_eq!(leftovers, 0);
+
+        write_atomic(
+            PathBuf::from(TEST_DIR_PATH).join("no_such_dir/file.txt"),
+            b"data",
+        )
+        .await
+        .expect_err("write_atomic must fail if the directory does not exist");
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,121 @@
+use crate::io::sys::RawFile;
+use crate::BUG_MESSAGE;
+use std::cell::Cell;
+use std::io::{Error, Result};
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+
+/// Changes the permissions of the file at `path` to `mode` using `chmod(2)`.
+///
+/// Symbolic links are followed.
+///
+/// `io_uring` has no `chmod` operation, so the call is executed in the thread pool
+/// of the current [`Executor`](crate::Executor).
+///
+/// # Errors
+///
+/// This function will return an `Err` if `path` does not exist or the user lacks
+/// the permission to change the attributes of the file.
+///
+/// # Panics
+///
+/// If the thread pool of the current [`Executor`](crate::Executor) is disabled
+/// with `debug_assertions`.
+///
+/// # Example
+///
+/// ```no_run
+/// use orengine::io::chmod;
+///
+/// # async fn foo() -> std::io::Result<()> {
+/// chmod("upload.bin", 0o644).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[allow(
+    clippy::future_not_send,
+    reason = "It is not `Send` because of the result cell, it is fine"
+)]
+pub async fn chmod<P: AsRef<Path> + Send>(path: P, mode: u32) -> Result<()> {
+    let path = path.as_ref();
+    let result = Cell::new(None);
+
+    crate::asyncify!(|| {
+        result.set(Some(std::fs::set_permissions(
+            path,
+            std::fs::Permissions::from_mode(mode),
+        )));
+    })
+    .await;
+
+    result.take().expect(BUG_MESSAGE)
+}
+
+/// Changes the permissions of the file associated with `raw_file` to `mode` using `fchmod(2)`.
+///
+/// `io_uring` has no `fchmod` operation, so the call is executed in the thread pool
+/// of the current [`Executor`](crate::Executor).
+///
+/// # Panics
+///
+/// If the thread pool of the current [`Executor`](crate::Executor) is disabled
+/// with `debug_assertions`.
+#[allow(
+    clippy::future_not_send,
+    reason = "It is not `Send` because of the result cell, it is fine"
+)]
+pub(crate) async fn fchmod(raw_file: RawFile, mode: u32) -> Result<()> {
+    let result = Cell::new(None);
+
+    crate::asyncify!(|| {
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::useless_conversion,
+            reason = "mode_t is u16 on some platforms and all permission bits fit in it"
+        )]
+        let ret = unsafe { libc::fchmod(raw_file, mode as libc::mode_t) };
+
+        result.set(Some(if ret == -1 {
+            Err(Error::last_os_error())
+        } else {
+            Ok(())
+        }));
+    })
+    .await;
+
+    result.take().expect(BUG_MESSAGE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::fs::test_helper::{create_test_dir_if_not_exist, TEST_DIR_PATH};
+    use crate::fs::{File, OpenOptions};
+
+    fn mode_of(path: &str) -> u32 {
+        std::fs::metadata(path).unwrap().permissions().mode() & 0o777
+    }
+
+    #[orengine::test::test_local]
+    fn test_chmod_and_set_permissions() {
+        create_test_dir_if_not_exist();
+        let path = TEST_DIR_PATH.to_string() + "/chmod.txt";
+        let options = OpenOptions::new().write(true).create(true).truncate(true);
+        let file = File::open(&path, &options).await.expect("open failed");
+
+        file.set_permissions(0o600)
+            .await
+            .expect("set_permissions failed");
+        assert_eq!(mode_of(&path), 0o600);
+
+        chmod(&path, 0o644).await.expect("chmod failed");
+        assert_eq!(mode_of(&path), 0o644);
+
+        drop(file);
+        std::fs::remove_file(&path).unwrap();
+        chmod(&path, 0o644)
+            .await
+            .expect_err("chmod must fail for a removed file");
+    }
+}
@@ -0,0 +1,130 @@
+use crate::fs::File;
+use crate::io::sys::AsRawFile;
+use crate::BUG_MESSAGE;
+use std::cell::Cell;
+use std::io::{Error, Result};
+
+/// Copies up to `len` bytes from `src` starting at `src_offset` to `dst` starting at `dst_offset`
+/// using `copy_file_range(2)` and returns the number of copied bytes.
+///
+/// The data is copied by the kernel without reading it into user space, so copy-on-write
+/// file systems (like `Btrfs` or `XFS`) can share the extents (reflink) instead of copying them.
+///
+/// It can copy fewer bytes than requested, for example, if `src` is shorter than
+/// `src_offset + len`. It returns `Ok(0)` if `src_offset` is at or past the end of `src`.
+///
+/// `io_uring` has no `copy_file_range` operation, so the call is executed in the thread pool
+/// of the current [`Executor`](crate::Executor).
+///
+/// # Errors
+///
+/// Returns the error of `copy_file_range(2)`.
+///
+/// # Panics
+///
+/// If the thread pool of the current [`Executor`](crate::Executor) is disabled
+/// with `debug_assertions`.
+///
+/// # Example
+///
+/// ```no_run
+/// use orengine::fs::{File, OpenOptions};
+/// use orengine::io::copy_file_range;
+///
+/// # async fn foo() -> std::io::Result<()> {
+/// let src = File::open("foo.txt", &OpenOptions::new().read(true)).await?;
+/// let dst = File::open("bar.txt", &OpenOptions::new().write(true).create(true)).await?;
+///
+/// let copied = copy_file_range(&src, 0, &dst, 0, 4096).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[allow(
+    clippy::future_not_send,
+    reason = "It is not `Send` because of the result cell, it is fine"
+)]
+pub async fn copy_file_range(
+    src: &File,
+    src_offset: u64,
+    dst: &File,
+    dst_offset: u64,
+    len: u64,
+) -> Result<u64> {
+    let src = src.as_raw_file();
+    let dst = dst.as_raw_file();
+    let result = Cell::new(None);
+
+    crate::asyncify!(|| {
+        #[allow(clippy::cast_possible_wrap, reason = "Offsets are less than i64::MAX")]
+        let (mut src_offset, mut dst_offset) = (src_offset as i64, dst_offset as i64);
+        #[allow(
+            clippy::cast_possible_truncation,
+            reason = "usize is u64 on 64-bit targets"
+        )]
+        let ret = unsafe {
+            libc::copy_file_range(
+                src,
+                &raw mut src_offset,
+                dst,
+                &raw mut dst_offset,
+                len as usize,
+                0,
+            )
+        };
+
+        #[allow(clippy::cast_sign_loss, reason = "The sign was checked")]
+        result.set(Some(if ret < 0 {
+            Err(Error::last_os_error())
+        } else {
+            Ok(ret as u64)
+        }));
+    })
+    .await;
+
+    result.take().expect(BUG_MESSAGE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::fs::test_helper::{create_test_dir_if_not_exist, TEST_DIR_PATH};
+    use crate::fs::OpenOptions;
+    use crate::io::{AsyncRead, AsyncWrite};
+
+    #[orengine::test::test_local]
+    fn test_copy_file_range() {
+        create_test_dir_if_not_exist();
+        let src_path = TEST_DIR_PATH.to_string() + "/copy_file_range_src.txt";
+        let dst_path = TEST_DIR_PATH.to_string() + "/copy_file_range_dst.txt";
+        let options = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true);
+
+        let mut src = File::open(&src_path, &options).await.expect("open failed");
+        let mut dst = File::open(&dst_path, &options).await.expect("open failed");
+        src.pwrite_all_bytes(b"Hello, world!", 0)
+            .await
+            .expect("pwrite failed");
+
+        let copied = copy_file_range(&src, 7, &dst, 0, 100)
+            .await
+            .expect("copy_file_range failed");
+        assert_eq!(copied, 6);
+
+        let mut buf = [0u8; 6];
+        dst.pread_bytes_exact(&mut buf, 0)
+            .await
+            .expect("pread failed");
+        assert_eq!(&buf, b"world!");
+
+        assert_eq!(copy_file_range(&src, 100, &dst, 0, 100).await.unwrap(), 0);
+
+        drop(src);
+        drop(dst);
+        std::fs::remove_file(src_path).unwrap();
+        std::fs::remove_file(dst_path).unwrap();
+    }
+}
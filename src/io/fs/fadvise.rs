@@ -0,0 +1,162 @@
+use orengine_macros::poll_for_io_request;
+use std::future::Future;
+use std::io::Result;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate as orengine;
+use crate::io::io_request_data::{IoRequestData, IoRequestDataPtr};
+use crate::io::sys::{AsRawFile, RawFile};
+use crate::io::worker::{local_worker, IoWorker};
+
+/// `FAdvice` is an access pattern passed to [`AsyncFadvise::advise`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FAdvice {
+    /// No special treatment (`POSIX_FADV_NORMAL`). It resets previous advice.
+    Normal,
+    /// The data will be accessed sequentially (`POSIX_FADV_SEQUENTIAL`).
+    /// The kernel reads ahead more aggressively.
+    Sequential,
+    /// The data will be accessed in random order (`POSIX_FADV_RANDOM`).
+    /// The kernel disables read ahead.
+    Random,
+    /// The data will be accessed soon (`POSIX_FADV_WILLNEED`).
+    /// The kernel starts reading it into the page cache.
+    WillNeed,
+    /// The data will not be accessed soon (`POSIX_FADV_DONTNEED`).
+    /// The kernel can evict it from the page cache.
+    DontNeed,
+}
+
+impl FAdvice {
+    /// Returns the `advice` argument of `posix_fadvise(2)`.
+    #[cfg(target_os = "linux")]
+    pub const fn as_raw(self) -> i32 {
+        match self {
+            Self::Normal => libc::POSIX_FADV_NORMAL,
+            Self::Sequential => libc::POSIX_FADV_SEQUENTIAL,
+            Self::Random => libc::POSIX_FADV_RANDOM,
+            Self::WillNeed => libc::POSIX_FADV_WILLNEED,
+            Self::DontNeed => libc::POSIX_FADV_DONTNEED,
+        }
+    }
+
+    /// Returns the `advice` argument of `posix_fadvise(2)`.
+    ///
+    /// Other platforms ignore the advice, so it returns the Linux values.
+    #[cfg(not(target_os = "linux"))]
+    pub const fn as_raw(self) -> i32 {
+        match self {
+            Self::Normal => 0,
+            Self::Random => 1,
+            Self::Sequential => 2,
+            Self::WillNeed => 3,
+            Self::DontNeed => 4,
+        }
+    }
+}
+
+/// `fadvise` io operation which announces an intention to access file data
+/// in a specific pattern.
+#[repr(C)]
+pub struct Fadvise {
+    raw_file: RawFile,
+    offset: u64,
+    len: u64,
+    advice: i32,
+    io_request_data: Option<IoRequestData>,
+}
+
+impl Fadvise {
+    /// Creates a new `fadvise` io operation.
+    pub fn new(raw_file: RawFile, offset: u64, len: u64, advice: FAdvice) -> Self {
+        Self {
+            raw_file,
+            offset,
+            len,
+            advice: advice.as_raw(),
+            io_request_data: None,
+        }
+    }
+}
+
+impl Future for Fadvise {
+    type Output = Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = &mut *self;
+        #[allow(unused, reason = "Cannot write proc_macro else to make it readable.")]
+        let ret;
+
+        poll_for_io_request!((
+            local_worker().fadvise(this.raw_file, this.offset, this.len, this.advice, unsafe {
+                IoRequestDataPtr::new(this.io_request_data.as_mut().unwrap_unchecked())
+            }),
+            ()
+        ));
+    }
+}
+
+unsafe impl Send for Fadvise {}
+
+/// This trait allows to create a `fadvise` io operation
+/// which announces an intention to access file data in a specific pattern.
+///
+/// Call [`advise`](AsyncFadvise::advise) to prefetch or evict file data.
+pub trait AsyncFadvise: AsRawFile {
+    /// Announces an intention to access the file data in a specific pattern.
+    ///
+    /// The range starts at the `offset` and continues for `len` bytes.
+    /// If `len` is `0`, the range extends to the end of the file.
+    ///
+    /// It is only a hint: the data is neither read nor written.
+    /// On platforms without `posix_fadvise` it does nothing and returns `Ok(())`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use orengine::fs::{File, OpenOptions};
+    /// use orengine::io::{AsyncFadvise, FAdvice};
+    ///
+    /// # async fn foo() -> std::io::Result<()> {
+    /// let file = File::open("video.mp4", &OpenOptions::new().read(true)).await?;
+    ///
+    /// // Start reading the first 4 MiB into the page cache before sending it to a client.
+    /// file.advise(0, 4 << 20, FAdvice::WillNeed).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn advise(&self, offset: u64, len: u64, advice: FAdvice) -> impl Future<Output = Result<()>> {
+        Fadvise::new(self.as_raw_file(), offset, len, advice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::fs::test_helper::{create_test_dir_if_not_exist, TEST_DIR_PATH};
+    use crate::fs::{File, OpenOptions};
+    use std::path::PathBuf;
+
+    #[orengine::test::test_local]
+    fn test_fadvise() {
+        create_test_dir_if_not_exist();
+        let path = PathBuf::from(TEST_DIR_PATH).join("fadvise.txt");
+        std::fs::write(&path, vec![7u8; 8192]).unwrap();
+
+        let file = File::open(&path, &OpenOptions::new().read(true))
+            .await
+            .unwrap();
+        for advice in [
+            FAdvice::Sequential,
+            FAdvice::Random,
+            FAdvice::WillNeed,
+            FAdvice::DontNeed,
+            FAdvice::Normal,
+        ] {
+            file.advise(0, 0, advice).await.expect("fadvise failed");
+        }
+    }
+}
@@ -9,12 +9,22 @@ use crate::io::io_request_data::{IoRequestData, IoRequestDataPtr};
 use crate::io::sys::{AsRawFile, RawFile};
 use crate::io::worker::{local_worker, IoWorker};
 
+/// `fallocate(2)` mode that zeroes the range and allocates space for it.
+#[cfg(target_os = "linux")]
+const FALLOC_FL_ZERO_RANGE: i32 = libc::FALLOC_FL_ZERO_RANGE;
+
+/// `fallocate(2)` mode that zeroes the range and allocates space for it.
+///
+/// Other platforms ignore the mode, so it is the Linux value.
+#[cfg(not(target_os = "linux"))]
+const FALLOC_FL_ZERO_RANGE: i32 = 0x10;
+
 /// `fallocate` io operation which allows to allocate space in a file from a given offset.
 #[repr(C)]
 pub struct Fallocate {
     raw_file: RawFile,
-    offset: usize,
-    len: usize,
+    offset: u64,
+    len: u64,
     flags: i32,
     io_request_data: Option<IoRequestData>,
 }
@@ -22,10 +32,15 @@ pub struct Fallocate {
 impl Fallocate {
     /// Creates a new `fallocate` io operation.
     pub fn new(raw_file: RawFile, offset: usize, len: usize, flags: i32) -> Self {
+        Self::with_u64_range(raw_file, offset as u64, len as u64, flags)
+    }
+
+    /// Creates a new `fallocate` io operation with a range that can exceed `usize`.
+    const fn with_u64_range(raw_file: RawFile, offset: u64, len: u64, flags: i32) -> Self {
         Self {
             raw_file,
-            len,
             offset,
+            len,
             flags,
             io_request_data: None,
         }
@@ -41,13 +56,9 @@ impl Future for Fallocate {
         let ret;
 
         poll_for_io_request!((
-            local_worker().fallocate(
-                this.raw_file,
-                this.offset as u64,
-                this.len as u64,
-                this.flags,
-                unsafe { IoRequestDataPtr::new(this.io_request_data.as_mut().unwrap_unchecked()) }
-            ),
+            local_worker().fallocate(this.raw_file, this.offset, this.len, this.flags, unsafe {
+                IoRequestDataPtr::new(this.io_request_data.as_mut().unwrap_unchecked())
+            }),
             ()
         ));
     }
@@ -58,7 +69,9 @@ unsafe impl Send for Fallocate {}
 /// This trait allows to create a `fallocate` io operation
 /// which allows to allocate space in a file from a given offset.
 ///
-/// Call [`fallocate`](AsyncFallocate::fallocate) to allocate len bytes on the disk.
+/// Call [`allocate`](AsyncFallocate::allocate) to preallocate len bytes on the disk,
+/// [`allocate_and_zero`](AsyncFallocate::allocate_and_zero) to zero them
+/// or [`fallocate`](AsyncFallocate::fallocate) to use any other mode.
 pub trait AsyncFallocate: AsRawFile {
     /// Allocate space in a file from a given offset.
     ///
@@ -88,4 +101,101 @@ pub trait AsyncFallocate: AsRawFile {
     fn fallocate(&self, offset: usize, len: usize, flags: i32) -> impl Future<Output = Result<()>> {
         Fallocate::new(self.as_raw_file(), offset, len, flags)
     }
+
+    /// Allocates disk space for the range that starts at the `offset`
+    /// and continues for `len` bytes.
+    ///
+    /// It is `fallocate(2)` with the default mode (`0`): the file size is extended
+    /// if the range ends beyond the end of the file, and the new bytes read as zeros.
+    /// Preallocating the space before sequential writes prevents fragmentation
+    /// and guarantees that the writes don't fail with `ENOSPC`.
+    ///
+    /// Not all OS and not all file systems support this operation. If the operation is not supported,
+    /// it does nothing and returns `Ok(())`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use orengine::fs::{File, OpenOptions};
+    /// use orengine::io::{AsyncFallocate, AsyncWrite};
+    ///
+    /// # async fn foo() -> std::io::Result<()> {
+    /// let mut file = File::open("log.bin", &OpenOptions::new().write(true).create(true)).await?;
+    ///
+    /// // Reserve 64 MiB for the log before appending to it.
+    /// file.allocate(0, 64 << 20).await?;
+    /// file.write_all_bytes(b"first record").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn allocate(&self, offset: u64, len: u64) -> impl Future<Output = Result<()>> {
+        Fallocate::with_u64_range(self.as_raw_file(), offset, len, 0)
+    }
+
+    /// Zeroes the range that starts at the `offset` and continues for `len` bytes
+    /// and allocates disk space for it (`FALLOC_FL_ZERO_RANGE`).
+    ///
+    /// The file size is extended if the range ends beyond the end of the file.
+    /// Unlike writing zeros, most file systems only mark the range as unwritten,
+    /// so it is fast even for large ranges.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EOPNOTSUPP` if the file system doesn't support zeroing ranges
+    /// (for example, `tmpfs`).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use orengine::fs::{File, OpenOptions};
+    /// use orengine::io::AsyncFallocate;
+    ///
+    /// # async fn foo() -> std::io::Result<()> {
+    /// let file = File::open("db.bin", &OpenOptions::new().write(true)).await?;
+    ///
+    /// // Erase the second page.
+    /// file.allocate_and_zero(4096, 4096).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn allocate_and_zero(&self, offset: u64, len: u64) -> impl Future<Output = Result<()>> {
+        Fallocate::with_u64_range(self.as_raw_file(), offset, len, FALLOC_FL_ZERO_RANGE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::fs::test_helper::{create_test_dir_if_not_exist, TEST_DIR_PATH};
+    use crate::fs::{File, OpenOptions};
+    use std::path::PathBuf;
+
+    #[orengine::test::test_local]
+    fn test_allocate() {
+        create_test_dir_if_not_exist();
+        let path = PathBuf::from(TEST_DIR_PATH).join("allocate.txt");
+        std::fs::write(&path, vec![7u8; 8192]).unwrap();
+
+        let file = File::open(&path, &OpenOptions::new().write(true))
+            .await
+            .unwrap();
+        file.allocate(0, 16384).await.expect("allocate failed");
+        assert_eq!(std::fs::metadata(&path).unwrap().len(), 16384);
+
+        match file.allocate_and_zero(4096, 2048).await {
+            Ok(()) => {
+                let data = std::fs::read(&path).unwrap();
+                assert_eq!(data.len(), 16384);
+                assert!(data[..4096].iter().all(|b| *b == 7));
+                assert!(data[4096..6144].iter().all(|b| *b == 0));
+                assert!(data[6144..8192].iter().all(|b| *b == 7));
+            }
+            Err(err) => assert_eq!(err.raw_os_error(), Some(libc::EOPNOTSUPP)),
+        }
+
+        std::fs::remove_file(&path).unwrap();
+    }
 }
@@ -0,0 +1,198 @@
+use crate as orengine;
+use crate::io::io_request_data::{IoRequestData, IoRequestDataPtr};
+#[cfg(target_os = "linux")]
+use crate::io::sys::AsRawFile;
+use crate::io::sys::{get_os_path, get_os_path_ptr, OsPath, RawFile};
+use crate::io::worker::{local_worker, IoWorker};
+use orengine_macros::poll_for_io_request;
+use std::future::Future;
+use std::io::Result;
+use std::path::Path;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// `linkat` io operation which creates a new hard link.
+#[repr(C)]
+pub struct Link {
+    old_dir: RawFile,
+    old_path: OsPath,
+    new_dir: RawFile,
+    new_path: OsPath,
+    flags: i32,
+    io_request_data: Option<IoRequestData>,
+}
+
+impl Link {
+    /// Creates a new `linkat` io operation.
+    ///
+    /// Relative paths are resolved relative to `old_dir` and `new_dir`,
+    /// use `libc::AT_FDCWD` to resolve them relative to the current working directory.
+    /// `flags` are passed to `linkat(2)` as is.
+    pub fn new(
+        old_dir: RawFile,
+        old_path: OsPath,
+        new_dir: RawFile,
+        new_path: OsPath,
+        flags: i32,
+    ) -> Self {
+        Self {
+            old_dir,
+            old_path,
+            new_dir,
+            new_path,
+            flags,
+            io_request_data: None,
+        }
+    }
+}
+
+impl Future for Link {
+    type Output = Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = &mut *self;
+        #[allow(unused, reason = "Cannot write proc_macro else to make it readable.")]
+        let ret;
+
+        poll_for_io_request!((
+            local_worker().linkat(
+                this.old_dir,
+                get_os_path_ptr(&this.old_path),
+                this.new_dir,
+                get_os_path_ptr(&this.new_path),
+                this.flags,
+                unsafe { IoRequestDataPtr::new(this.io_request_data.as_mut().unwrap_unchecked()) }
+            ),
+            ()
+        ));
+    }
+}
+
+unsafe impl Send for Link {}
+
+/// Creates a new hard link `dst` to the file at `src`.
+///
+/// # Errors
+///
+/// This function will return an `Err` if:
+/// - Either `src` or `dst` cannot be converted into an OS path;
+/// - `src` does not exist or `dst` already exists;
+/// - The operation fails due to other I/O issues such as permission errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use orengine::io::link;
+///
+/// # async fn foo() -> std::io::Result<()> {
+/// link("snapshots/current.db", "backups/2024-01-01.db").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn link<Src, Dst>(src: Src, dst: Dst) -> Result<()>
+where
+    Src: AsRef<Path> + Send,
+    Dst: AsRef<Path> + Send,
+{
+    Link::new(
+        libc::AT_FDCWD,
+        get_os_path(src.as_ref())?,
+        libc::AT_FDCWD,
+        get_os_path(dst.as_ref())?,
+        0,
+    )
+    .await
+}
+
+/// Links the open `file` into the file system at `dst`.
+///
+/// It is used to publish a file created by [`OpenOptions::tmpfile`](crate::fs::OpenOptions::tmpfile):
+/// the file becomes visible at `dst` only after it is fully written,
+/// and the directory is touched once.
+///
+/// # Errors
+///
+/// This function will return an `Err` if `dst` already exists,
+/// `dst` is on another file system or the file can't be linked
+/// (for example, it was opened with `O_EXCL`).
+///
+/// # Example
+///
+/// ```no_run
+/// use orengine::fs::OpenOptions;
+/// use orengine::io::{link_file, AsyncSyncData, AsyncWrite};
+///
+/// # async fn foo() -> std::io::Result<()> {
+/// let mut file = OpenOptions::new().mode(0o644).tmpfile("data").await?;
+/// file.write_all_bytes(b"fully written content").await?;
+/// file.sync_data().await?;
+///
+/// link_file(&file, "data/report.txt").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(target_os = "linux")]
+pub async fn link_file<F, Dst>(file: &F, dst: Dst) -> Result<()>
+where
+    F: AsRawFile + Sync,
+    Dst: AsRef<Path> + Send,
+{
+    let fd_path = format!("/proc/self/fd/{}", AsRawFile::as_raw_file(file));
+
+    Link::new(
+        libc::AT_FDCWD,
+        get_os_path(Path::new(&fd_path))?,
+        libc::AT_FDCWD,
+        get_os_path(dst.as_ref())?,
+        libc::AT_SYMLINK_FOLLOW,
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::fs::test_helper::{create_test_dir_if_not_exist, TEST_DIR_PATH};
+    use crate::fs::OpenOptions;
+    use crate::io::AsyncWrite;
+    use std::path::PathBuf;
+
+    #[orengine::test::test_local]
+    fn test_link() {
+        create_test_dir_if_not_exist();
+        let src = PathBuf::from(TEST_DIR_PATH).join("link_src.txt");
+        let dst = PathBuf::from(TEST_DIR_PATH).join("link_dst.txt");
+        std::fs::write(&src, b"Hello, world!").unwrap();
+        let _ = std::fs::remove_file(&dst);
+
+        link(&src, &dst).await.expect("link failed");
+        assert_eq!(std::fs::read(&dst).unwrap(), b"Hello, world!");
+
+        link(&src, &dst)
+            .await
+            .expect_err("link to an existing path must fail");
+
+        std::fs::remove_file(&src).unwrap();
+        std::fs::remove_file(&dst).unwrap();
+    }
+
+    #[orengine::test::test_local]
+    fn test_link_file() {
+        create_test_dir_if_not_exist();
+        let dst = PathBuf::from(TEST_DIR_PATH).join("link_tmpfile.txt");
+        let _ = std::fs::remove_file(&dst);
+
+        let mut file = OpenOptions::new()
+            .mode(0o644)
+            .tmpfile(TEST_DIR_PATH)
+            .await
+            .expect("tmpfile failed");
+        file.write_all_bytes(b"published").await.unwrap();
+
+        link_file(&file, &dst).await.expect("link_file failed");
+        assert_eq!(std::fs::read(&dst).unwrap(), b"published");
+
+        std::fs::remove_file(&dst).unwrap();
+    }
+}
@@ -3,9 +3,24 @@
 //! It is focused on low-level interactions with the file system such as file and directory
 //! creation, removal, reading, writing, and syncing data to disk.
 
+/// Contains tools for atomically replacing files.
+pub mod atomic_write;
+
+/// Contains tools for changing permissions of files.
+#[cfg(unix)]
+pub mod chmod;
+
+/// Contains tools for copying data between files.
+#[cfg(target_os = "linux")]
+pub mod copy;
+
 /// Contains tools for creating directories.
 pub mod create_dir;
 
+/// Contains tools for creating hard links.
+#[cfg(unix)]
+pub mod link;
+
 /// Contains tools for opening files.
 pub mod open;
 
@@ -27,19 +42,46 @@ pub mod write;
 /// Contains tools for file allocation operations.
 pub mod fallocate;
 
+/// Contains tools for announcing access patterns of file data.
+pub mod fadvise;
+
 /// Contains tools for syncing all file metadata to disk.
 pub mod sync_all;
 
 /// Contains tools for syncing file data to disk.
 pub mod sync_data;
 
+/// Contains tools for changing the size of files.
+pub mod set_len;
+
+/// Contains tools for creating and reading symbolic links.
+pub mod symlink;
+
+/// Contains tools for watching files for changes.
+#[cfg(target_os = "linux")]
+pub mod watcher;
+
+pub use atomic_write::write_atomic;
+#[cfg(unix)]
+pub use chmod::chmod;
+#[cfg(target_os = "linux")]
+pub use copy::copy_file_range;
 pub use create_dir::CreateDir;
+pub use fadvise::{AsyncFadvise, FAdvice, Fadvise};
 pub use fallocate::{AsyncFallocate, Fallocate};
+#[cfg(target_os = "linux")]
+pub use link::link_file;
+#[cfg(unix)]
+pub use link::{link, Link};
 pub use open::Open;
 pub use read::AsyncRead;
 pub use remove::Remove;
 pub use remove_dir::RemoveDir;
 pub use rename::Rename;
+pub use set_len::{AsyncSetLen, SetLen};
+pub use symlink::{read_link, symlink, Symlink};
 pub use sync_all::{AsyncSyncAll, SyncAll};
 pub use sync_data::{AsyncSyncData, SyncData};
+#[cfg(target_os = "linux")]
+pub use watcher::{FileEvent, FileWatcher};
 pub use write::AsyncWrite;
@@ -0,0 +1,109 @@
+use orengine_macros::poll_for_io_request;
+use std::future::Future;
+use std::io::Result;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate as orengine;
+use crate::io::io_request_data::{IoRequestData, IoRequestDataPtr};
+use crate::io::sys::{AsRawFile, RawFile};
+use crate::io::worker::{local_worker, IoWorker};
+
+/// `truncate` io operation which truncates or extends a file to a given length.
+#[repr(C)]
+pub struct SetLen {
+    raw_file: RawFile,
+    len: u64,
+    io_request_data: Option<IoRequestData>,
+}
+
+impl SetLen {
+    /// Creates a new `truncate` io operation.
+    pub fn new(raw_file: RawFile, len: u64) -> Self {
+        Self {
+            raw_file,
+            len,
+            io_request_data: None,
+        }
+    }
+}
+
+impl Future for SetLen {
+    type Output = Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = &mut *self;
+        #[allow(unused, reason = "Cannot write proc_macro else to make it readable.")]
+        let ret;
+
+        poll_for_io_request!((
+            local_worker().truncate(this.raw_file, this.len, unsafe {
+                IoRequestDataPtr::new(this.io_request_data.as_mut().unwrap_unchecked())
+            }),
+            ()
+        ));
+    }
+}
+
+unsafe impl Send for SetLen {}
+
+/// The [`AsyncSetLen`] trait provides a [`set_len`](AsyncSetLen::set_len) method
+/// to truncate or extend a file.
+///
+/// For more details, see [`set_len`](AsyncSetLen::set_len).
+pub trait AsyncSetLen: AsRawFile {
+    /// Truncates or extends the underlying file, updating the size of this file to become `size`.
+    ///
+    /// If the `size` is less than the current file's size, then the file will be shrunk.
+    /// If it is greater than the current file's size, then the file will be extended to `size`
+    /// and have all the intermediate data filled in with 0s.
+    ///
+    /// The file's cursor isn't changed.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the file is not opened for writing.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use orengine::fs::{File, OpenOptions};
+    /// use orengine::io::AsyncSetLen;
+    ///
+    /// async fn foo() -> std::io::Result<()> {
+    /// let file = File::open("foo.txt", &OpenOptions::new().write(true).create(true)).await?;
+    /// file.set_len(10).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn set_len(&self, size: u64) -> impl Future<Output = Result<()>> {
+        SetLen::new(self.as_raw_file(), size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::fs::test_helper::{create_test_dir_if_not_exist, TEST_DIR_PATH};
+    use crate::fs::{File, OpenOptions};
+
+    #[orengine::test::test_local]
+    fn test_set_len() {
+        create_test_dir_if_not_exist();
+        let path = TEST_DIR_PATH.to_string() + "/set_len.txt";
+        let options = OpenOptions::new().write(true).create(true).truncate(true);
+
+        let file = File::open(&path, &options).await.expect("open failed");
+
+        file.set_len(100).await.expect("set_len failed");
+        assert_eq!(std::fs::metadata(&path).unwrap().len(), 100);
+
+        file.set_len(10).await.expect("set_len failed");
+        assert_eq!(std::fs::metadata(&path).unwrap().len(), 10);
+
+        drop(file);
+        std::fs::remove_file(path).unwrap();
+    }
+}
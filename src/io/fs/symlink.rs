@@ -0,0 +1,154 @@
+use crate as orengine;
+use crate::io::io_request_data::{IoRequestData, IoRequestDataPtr};
+use crate::io::sys::{get_os_path, get_os_path_ptr, OsPath};
+use crate::io::worker::{local_worker, IoWorker};
+use crate::BUG_MESSAGE;
+use orengine_macros::poll_for_io_request;
+use std::cell::Cell;
+use std::future::Future;
+use std::io::Result;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// `symlink` io operation which creates a symbolic link.
+#[repr(C)]
+pub struct Symlink {
+    target: OsPath,
+    link_path: OsPath,
+    io_request_data: Option<IoRequestData>,
+}
+
+impl Symlink {
+    /// Creates a new `symlink` io operation.
+    pub fn new(target: OsPath, link_path: OsPath) -> Self {
+        Self {
+            target,
+            link_path,
+            io_request_data: None,
+        }
+    }
+}
+
+impl Future for Symlink {
+    type Output = Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = &mut *self;
+        #[allow(unused, reason = "Cannot write proc_macro else to make it readable.")]
+        let ret;
+
+        poll_for_io_request!((
+            local_worker().symlink(
+                get_os_path_ptr(&this.target),
+                get_os_path_ptr(&this.link_path),
+                unsafe { IoRequestDataPtr::new(this.io_request_data.as_mut().unwrap_unchecked()) }
+            ),
+            ()
+        ));
+    }
+}
+
+unsafe impl Send for Symlink {}
+
+/// Creates a new symbolic link at `link` which points to `target`.
+///
+/// On `Windows` it creates a file symbolic link.
+///
+/// # Errors
+///
+/// This function will return an `Err` if:
+/// - Either `target` or `link` cannot be converted into an OS path;
+/// - `link` already exists or its parent directory does not exist;
+/// - The operation fails due to other I/O issues such as permission errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use orengine::io::symlink;
+///
+/// # async fn foo() -> std::io::Result<()> {
+/// symlink("assets/v2/index.html", "assets/index.html").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn symlink<Target, Link>(target: Target, link: Link) -> Result<()>
+where
+    Target: AsRef<Path> + Send,
+    Link: AsRef<Path> + Send,
+{
+    Symlink::new(get_os_path(target.as_ref())?, get_os_path(link.as_ref())?).await
+}
+
+/// Reads the target of the symbolic link at `path`.
+///
+/// `io_uring` has no `readlink` operation, so the call is executed in the thread pool
+/// of the current [`Executor`](crate::Executor).
+///
+/// # Errors
+///
+/// This function will return an `Err` if `path` does not exist or is not a symbolic link.
+///
+/// # Panics
+///
+/// If the thread pool of the current [`Executor`](crate::Executor) is disabled
+/// with `debug_assertions`.
+///
+/// # Example
+///
+/// ```no_run
+/// use orengine::io::read_link;
+///
+/// # async fn foo() -> std::io::Result<()> {
+/// let target = read_link("assets/index.html").await?;
+/// println!("assets/index.html points to {}", target.display());
+/// # Ok(())
+/// # }
+/// ```
+#[allow(
+    clippy::future_not_send,
+    reason = "It is not `Send` because of the result cell, it is fine"
+)]
+pub async fn read_link<P: AsRef<Path> + Send>(path: P) -> Result<PathBuf> {
+    let path = path.as_ref();
+    let result = Cell::new(None);
+
+    crate::asyncify!(|| {
+        result.set(Some(std::fs::read_link(path)));
+    })
+    .await;
+
+    result.take().expect(BUG_MESSAGE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::fs::test_helper::{create_test_dir_if_not_exist, TEST_DIR_PATH};
+
+    #[orengine::test::test_local]
+    fn test_symlink_and_read_link() {
+        create_test_dir_if_not_exist();
+        // A relative target is resolved relative to the directory of the link.
+        let target = PathBuf::from("symlink_target.txt");
+        let target_path = PathBuf::from(TEST_DIR_PATH).join(&target);
+        let link = PathBuf::from(TEST_DIR_PATH).join("symlink_link.txt");
+        std::fs::write(&target_path, b"Hello, world!").unwrap();
+        let _ = std::fs::remove_file(&link);
+
+        symlink(&target, &link).await.expect("symlink failed");
+        assert_eq!(read_link(&link).await.expect("read_link failed"), target);
+        assert_eq!(std::fs::read(&link).unwrap(), b"Hello, world!");
+
+        symlink(&target, &link)
+            .await
+            .expect_err("symlink must fail if the link already exists");
+        read_link(&target_path)
+            .await
+            .expect_err("read_link must fail for a regular file");
+
+        std::fs::remove_file(link).unwrap();
+        std::fs::remove_file(target_path).unwrap();
+    }
+}
@@ -0,0 +1,267 @@
+use crate::future::AsyncIterator;
+use crate::io::net::poll_fd::PollRecv;
+use crate::sleep;
+use std::ffi::{CString, OsStr, OsString};
+use std::io::{Error, ErrorKind, Result};
+use std::mem::size_of;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+use std::os::unix::ffi::{OsStrExt, OsStringExt};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// The default debounce window of the [`FileWatcher`].
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(50);
+
+/// Events of the watched directory that can change the watched file.
+const WATCH_MASK: u32 = libc::IN_CREATE
+    | libc::IN_MOVED_TO
+    | libc::IN_DELETE
+    | libc::IN_MOVED_FROM
+    | libc::IN_MODIFY
+    | libc::IN_CLOSE_WRITE;
+
+/// `FileEvent` is a change of the file watched by the [`FileWatcher`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileEvent {
+    /// The file has been created or moved to the watched path.
+    Created,
+    /// The content of the file has been modified.
+    Modified,
+    /// The file has been deleted or moved from the watched path.
+    Deleted,
+}
+
+/// `FileWatcher` watches a file for changes using `inotify(7)`.
+///
+/// It watches the parent directory of the file, so it keeps watching the path after the file
+/// is deleted and recreated (for example, when an editor saves the file by renaming
+/// a temporary file over it).
+///
+/// Events received within the debounce window (see [`with_debounce`](Self::with_debounce))
+/// are coalesced into one [`FileEvent`]:
+///
+/// - [`Deleted`](FileEvent::Deleted) if the file does not exist at the end of the window;
+///
+/// - [`Created`](FileEvent::Created) if the file has been (re)created in the window;
+///
+/// - [`Modified`](FileEvent::Modified) otherwise.
+///
+/// The iteration ends when the watched directory is deleted.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::future::AsyncIterator;
+/// use orengine::io::{FileEvent, FileWatcher};
+///
+/// # async fn reload_config() {}
+/// # async fn foo() -> std::io::Result<()> {
+/// let mut watcher = FileWatcher::watch_file("config.toml")?;
+///
+/// while let Some(event) = watcher.next().await {
+///     if event? != FileEvent::Deleted {
+///         reload_config().await;
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct FileWatcher {
+    inotify: OwnedFd,
+    file_name: OsString,
+    debounce: Duration,
+    is_finished: bool,
+}
+
+impl FileWatcher {
+    /// Starts watching the file at `path`. The file may not exist yet,
+    /// but its parent directory must.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `path` doesn't point to a file, the parent directory
+    /// doesn't exist or `inotify` can't be initialized.
+    pub fn watch_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let Some(file_name) = path.file_name() else {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "path for watch_file must point to a file",
+            ));
+        };
+        let dir = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+
+        let raw_fd = unsafe { libc::inotify_init1(libc::IN_NONBLOCK | libc::IN_CLOEXEC) };
+        if raw_fd == -1 {
+            return Err(Error::last_os_error());
+        }
+        let inotify = unsafe { OwnedFd::from_raw_fd(raw_fd) };
+
+        let dir = CString::new(dir.into_os_string().into_vec())
+            .map_err(|err| Error::new(ErrorKind::InvalidInput, err))?;
+        if unsafe { libc::inotify_add_watch(raw_fd, dir.as_ptr(), WATCH_MASK) } == -1 {
+            return Err(Error::last_os_error());
+        }
+
+        Ok(Self {
+            inotify,
+            file_name: file_name.to_os_string(),
+            debounce: DEFAULT_DEBOUNCE,
+            is_finished: false,
+        })
+    }
+
+    /// Sets the debounce window. Default is [`DEFAULT_DEBOUNCE`].
+    ///
+    /// `Duration::ZERO` disables coalescing of events that are not read at once.
+    #[must_use]
+    pub const fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Returns the debounce window.
+    pub const fn debounce(&self) -> Duration {
+        self.debounce
+    }
+
+    /// Reads all available `inotify` events and coalesces the ones for the watched file
+    /// into `event`.
+    fn read_available_events(&mut self, event: &mut Option<FileEvent>) -> Result<()> {
+        #[repr(C, align(4))]
+        struct EventsBuffer([u8; 4096]);
+
+        let mut buf = EventsBuffer([0; 4096]);
+        loop {
+            let n = unsafe {
+                libc::read(
+                    self.inotify.as_raw_fd(),
+                    buf.0.as_mut_ptr().cast(),
+                    buf.0.len(),
+                )
+            };
+            if n == -1 {
+                let err = Error::last_os_error();
+                return match err.kind() {
+                    ErrorKind::WouldBlock => Ok(()),
+                    ErrorKind::Interrupted => continue,
+                    _ => Err(err),
+                };
+            }
+
+            #[allow(clippy::cast_sign_loss, reason = "n is not negative here")]
+            let n = n as usize;
+            let mut offset = 0;
+            while offset + size_of::<libc::inotify_event>() <= n {
+                let raw_event = unsafe {
+                    buf.0
+                        .as_ptr()
+                        .add(offset)
+                        .cast::<libc::inotify_event>()
+                        .read_unaligned()
+                };
+                let name_start = offset + size_of::<libc::inotify_event>();
+                let name_end = name_start + raw_event.len as usize;
+                offset = name_end;
+
+                if raw_event.mask & (libc::IN_IGNORED | libc::IN_DELETE_SELF) != 0 {
+                    self.is_finished = true;
+                    continue;
+                }
+
+                let name = &buf.0[name_start..name_end];
+                let name = &name[..name.iter().position(|b| *b == 0).unwrap_or(name.len())];
+                if OsStr::from_bytes(name) != self.file_name {
+                    continue;
+                }
+
+                *event = Some(
+                    if raw_event.mask & (libc::IN_DELETE | libc::IN_MOVED_FROM) != 0 {
+                        FileEvent::Deleted
+                    } else if raw_event.mask & (libc::IN_CREATE | libc::IN_MOVED_TO) != 0 {
+                        FileEvent::Created
+                    } else {
+                        match *event {
+                            Some(FileEvent::Created) => FileEvent::Created,
+                            _ => FileEvent::Modified,
+                        }
+                    },
+                );
+            }
+        }
+    }
+}
+
+impl AsyncIterator for FileWatcher {
+    type Item = Result<FileEvent>;
+
+    #[allow(clippy::future_not_send, reason = "Because it is `local`")]
+    async fn next(&mut self) -> Option<Self::Item> {
+        let mut event = None;
+        while !self.is_finished {
+            if let Err(err) = PollRecv::new(self.inotify.as_raw_fd()).await {
+                return Some(Err(err));
+            }
+            if let Err(err) = self.read_available_events(&mut event) {
+                return Some(Err(err));
+            }
+
+            if event.is_some() {
+                if !self.debounce.is_zero() {
+                    sleep(self.debounce).await;
+                    if let Err(err) = self.read_available_events(&mut event) {
+                        return Some(Err(err));
+                    }
+                }
+
+                return event.map(Ok);
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::fs::test_helper::{create_test_dir_if_not_exist, TEST_DIR_PATH};
+    use std::fs;
+
+    #[orengine::test::test_local]
+    fn test_file_watcher() {
+        create_test_dir_if_not_exist();
+        let dir = PathBuf::from(TEST_DIR_PATH).join("file_watcher");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+
+        let mut watcher = FileWatcher::watch_file(&path)
+            .unwrap()
+            .with_debounce(Duration::from_millis(20));
+        fs::write(dir.join("other.toml"), b"ignored").unwrap();
+
+        fs::write(&path, b"a = 1").unwrap();
+        fs::write(&path, b"a = 2").unwrap();
+        assert_eq!(watcher.next().await.unwrap().unwrap(), FileEvent::Created);
+
+        fs::write(&path, b"a = 3").unwrap();
+        assert_eq!(watcher.next().await.unwrap().unwrap(), FileEvent::Modified);
+
+        fs::remove_file(&path).unwrap();
+        assert_eq!(watcher.next().await.unwrap().unwrap(), FileEvent::Deleted);
+
+        let temp_path = dir.join("config.toml.tmp");
+        fs::write(&temp_path, b"a = 4").unwrap();
+        fs::rename(&temp_path, &path).unwrap();
+        assert_eq!(watcher.next().await.unwrap().unwrap(), FileEvent::Created);
+
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(watcher.next().await.unwrap().unwrap(), FileEvent::Deleted);
+        assert!(watcher.next().await.is_none());
+    }
+}
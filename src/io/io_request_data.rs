@@ -1,18 +1,33 @@
 use crate::runtime::task::Task;
+use std::fmt::{Debug, Formatter};
 use std::io::Result;
 use std::ptr;
 
+/// The value of [`UNINIT_RESULT`].
+const UNINIT_RESULT_VALUE: usize = (1 << 32) - 1;
+
 /// Default value of [`IoRequestData::ret`].
-pub(crate) const UNINIT_RESULT: Result<usize> = Ok((1 << 32) - 1);
+pub(crate) const UNINIT_RESULT: Result<usize> = Ok(UNINIT_RESULT_VALUE);
 
 /// Data of io request. It contains a result and a task.
 /// After the task is done, the result will be set and the task will be executed.
+///
+/// It is stored inline in the future of the io operation (`Option<IoRequestData>`),
+/// and the future is pinned until the operation is completed.
+/// So, it is never allocated on the heap and doesn't need a pool.
 #[repr(C)]
 pub(crate) struct IoRequestData {
     ret: Result<usize>,
+    /// Whether the result has been set. Unlike [`UNINIT_RESULT`], it is not reset by
+    /// [`ret`](Self::ret), so a spurious poll can't take it for the completion.
+    is_completed: bool,
     task: Task,
     #[cfg(debug_assertions)]
     was_executed: bool,
+    /// The name of the completion of the traced operation and the time of its dispatch.
+    /// It is `None` if the operation is not traced.
+    #[cfg(feature = "tracing")]
+    trace: Option<(&'static str, std::time::Instant)>,
 }
 
 impl IoRequestData {
@@ -21,12 +36,25 @@ impl IoRequestData {
     pub(crate) fn new(task: Task) -> Self {
         Self {
             ret: UNINIT_RESULT,
+            is_completed: false,
             task,
             #[cfg(debug_assertions)]
             was_executed: false,
+            #[cfg(feature = "tracing")]
+            trace: None,
         }
     }
 
+    /// Starts tracing of the operation. After the result is set, an event
+    /// with the name `completion_op`, the result and the duration of the operation is emitted.
+    ///
+    /// Use [`trace_io_dispatch`] instead of calling it directly.
+    #[cfg(feature = "tracing")]
+    #[inline]
+    pub(crate) fn start_trace(&mut self, completion_op: &'static str) {
+        self.trace = Some((completion_op, std::time::Instant::now()));
+    }
+
     /// Checks whether an associated task has been read to execute. If yes, it panics.
     #[inline]
     fn check_if_executed_in_debug(&mut self) {
@@ -41,7 +69,26 @@ impl IoRequestData {
     /// Sets the result.
     #[inline(always)]
     pub(crate) fn set_ret(&mut self, ret: Result<usize>) {
+        #[cfg(feature = "tracing")]
+        if let Some((op, start)) = self.trace.take() {
+            #[allow(
+                clippy::cast_possible_truncation,
+                reason = "an io operation can't take 584,554 years"
+            )]
+            let duration_us = start.elapsed().as_micros() as u64;
+
+            tracing::trace!(op, ret = ?ret, duration_us);
+        }
+
         self.ret = ret;
+        self.is_completed = true;
+    }
+
+    /// Returns whether the result has been set by [`set_ret`](Self::set_ret).
+    #[cfg(any(test, feature = "futures-io", feature = "tokio-compat"))]
+    #[inline]
+    pub(crate) fn is_completed(&self) -> bool {
+        self.is_completed
     }
 
     /// Returns the result.
@@ -71,6 +118,28 @@ impl IoRequestData {
     }
 }
 
+/// Emits a `TRACE` event with the name of the dispatched io operation and the provided fields
+/// and starts tracing of the [`IoRequestData`], so its completion is traced too.
+///
+/// It does nothing if the `tracing` feature is disabled or the `TRACE` level is not enabled.
+///
+/// # Example
+///
+/// ```ignore
+/// trace_io_dispatch!(request_ptr, "recv", socket = raw_socket, len = len);
+/// ```
+macro_rules! trace_io_dispatch {
+    ($request_ptr:expr, $op:literal $(, $field:ident = $value:expr)* $(,)?) => {
+        #[cfg(feature = "tracing")]
+        if tracing::enabled!(tracing::Level::TRACE) {
+            tracing::trace!(op = $op $(, $field = $value)*);
+            $request_ptr.get_mut().start_trace(concat!($op, "_complete"));
+        }
+    };
+}
+
+pub(crate) use trace_io_dispatch;
+
 /// `IoRequestDataPtr` is a mutable pointer to [`IoRequestData`] that implements [`Send`].
 #[derive(Copy, Clone)]
 pub(crate) struct IoRequestDataPtr(*mut IoRequestData);
@@ -101,4 +170,67 @@ impl IoRequestDataPtr {
     }
 }
 
+impl Debug for IoRequestData {
+    /// Prints the result if the request has been completed, whether it has been completed
+    /// and the locality of the task.
+    /// With `debug_assertions` it also prints whether the task has been taken to be executed,
+    /// so a request completed twice can be found.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut debug_struct = f.debug_struct("IoRequestData");
+        match &self.ret {
+            Ok(UNINIT_RESULT_VALUE) => debug_struct.field("ret", &"not completed"),
+            ret => debug_struct.field("ret", ret),
+        };
+        debug_struct.field("is_completed", &self.is_completed);
+        debug_struct.field("is_task_local", &self.task.is_local());
+
+        #[cfg(debug_assertions)]
+        debug_struct.field("was_executed", &self.was_executed);
+
+        #[cfg(feature = "tracing")]
+        debug_struct.field("trace", &self.trace);
+
+        debug_struct.finish()
+    }
+}
+
+impl Debug for IoRequestDataPtr {
+    /// Prints only the address, because the [`IoRequestData`] can be already dropped.
+    /// Use [`get_mut`](Self::get_mut) to print the data of a pending request.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("IoRequestDataPtr").field(&self.0).finish()
+    }
+}
+
 unsafe impl Send for IoRequestDataPtr {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::local_executor;
+    use crate::runtime::Locality;
+
+    #[orengine::test::test_local]
+    fn test_io_request_data_debug() {
+        let task = unsafe { Task::from_future(async {}, Locality::local()) };
+        let mut data = IoRequestData::new(task);
+        let ptr = IoRequestDataPtr::new(&mut data);
+
+        assert!(format!("{:?}", ptr.get_mut()).contains("ret: \"not completed\""));
+        assert!(format!("{ptr:?}").starts_with("IoRequestDataPtr(0x"));
+        assert!(!data.is_completed());
+
+        data.set_ret(Ok(5));
+        assert!(data.is_completed());
+        let debug = format!("{data:?}");
+        assert!(debug.contains("ret: Ok(5)"));
+        assert!(debug.contains("is_task_local: true"));
+
+        let task = unsafe { data.task() };
+        #[cfg(debug_assertions)]
+        assert!(format!("{data:?}").contains("was_executed: true"));
+
+        unsafe { task.release(local_executor()) };
+    }
+}
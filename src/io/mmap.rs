@@ -0,0 +1,288 @@
+//! This module contains [`MmapFile`] that maps a file into memory and [`madvise`]
+//! that announces an intention to access a memory region in a specific pattern.
+use crate::fs::File;
+use crate::io::sys::AsRawFile;
+use crate::BUG_MESSAGE;
+use std::cell::Cell;
+use std::fmt::{Debug, Formatter};
+use std::io::{Error, Result};
+use std::mem::MaybeUninit;
+use std::ops::Deref;
+use std::ptr::NonNull;
+
+/// Announces an intention to access the memory region of `len` bytes starting at `addr`
+/// in the `advice` pattern using `madvise(2)`.
+///
+/// `io_uring` has no `madvise` operation that works on all supported kernels,
+/// so the call is executed in the thread pool of the current [`Executor`](crate::Executor).
+///
+/// # Safety
+///
+/// The region must be mapped by the caller and `addr` must be aligned to the page size.
+/// Some advices (like `MADV_DONTNEED` or `MADV_FREE` for anonymous memory) change
+/// the content of the region, so the caller must ensure that no one relies on it.
+///
+/// # Errors
+///
+/// Returns the error of `madvise(2)`.
+///
+/// # Panics
+///
+/// If the thread pool of the current [`Executor`](crate::Executor) is disabled
+/// with `debug_assertions`.
+#[allow(
+    clippy::future_not_send,
+    reason = "It is not `Send` because of the result cell, it is fine"
+)]
+pub async unsafe fn madvise(
+    addr: *mut libc::c_void,
+    len: usize,
+    advice: libc::c_int,
+) -> Result<()> {
+    let result = Cell::new(None);
+
+    crate::asyncify!(|| {
+        let ret = unsafe { libc::madvise(addr, len, advice) };
+
+        result.set(Some(if ret == -1 {
+            Err(Error::last_os_error())
+        } else {
+            Ok(())
+        }));
+    })
+    .await;
+
+    result.take().expect(BUG_MESSAGE)
+}
+
+/// `MmapFile` is a read-only shared memory mapping of a whole [`File`].
+///
+/// After [`MmapFile::new`] completes, the file data can be accessed as `&[u8]`
+/// without copying it into user space buffers, for example, to serve static files.
+///
+/// The region is unmapped only when the `MmapFile` is dropped. All io operations
+/// ([`send_all_bytes`](crate::io::AsyncSend::send_all_bytes),
+/// [`write_all_bytes`](crate::io::AsyncWrite::write_all_bytes) and others)
+/// borrow the slice, so the region can't be unmapped while an operation references it.
+///
+/// The mapping doesn't keep the [`File`] open, but it reflects changes of the file
+/// made after the mapping, even by other processes. So, creating an `MmapFile` is unsafe:
+/// read more in the `# Safety` section of [`MmapFile::new`].
+///
+/// # Example
+///
+/// ```no_run
+/// use orengine::fs::{File, OpenOptions};
+/// use orengine::io::{AsyncSend, MmapFile};
+/// use orengine::net::TcpStream;
+///
+/// # async fn foo(mut stream: TcpStream) -> std::io::Result<()> {
+/// let file = File::open("index.html", &OpenOptions::new().read(true)).await?;
+/// // Safety: `index.html` is not modified or truncated while it is served.
+/// let page = unsafe { MmapFile::new(&file).await? };
+/// page.advise(libc::MADV_SEQUENTIAL).await?;
+///
+/// stream.send_all_bytes(&page).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct MmapFile {
+    ptr: NonNull<u8>,
+    len: usize,
+}
+
+impl MmapFile {
+    /// Maps the whole `file` into memory for reading.
+    ///
+    /// The file must be opened for reading. An empty file is mapped to an empty slice.
+    ///
+    /// `io_uring` has no `mmap` operation and `mmap(2)` can block on the file system,
+    /// so the call is executed in the thread pool of the current [`Executor`](crate::Executor).
+    ///
+    /// # Safety
+    ///
+    /// The mapping is shared, so the returned `MmapFile` gives `&[u8]` over memory
+    /// that reflects any later change of the file. The caller must ensure that the file
+    /// is not modified (by this or any other process) while the `MmapFile` is alive,
+    /// because the data behind a shared reference would change, which is undefined behavior.
+    /// The file also must not be truncated, because access to the truncated pages
+    /// leads to `SIGBUS`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `fstat(2)` or `mmap(2)`.
+    ///
+    /// # Panics
+    ///
+    /// If the thread pool of the current [`Executor`](crate::Executor) is disabled
+    /// with `debug_assertions`.
+    #[allow(
+        clippy::future_not_send,
+        reason = "It is not `Send` because of the result cell, it is fine"
+    )]
+    pub async unsafe fn new(file: &File) -> Result<Self> {
+        let raw_file = file.as_raw_file();
+        let result = Cell::new(None);
+
+        crate::asyncify!(|| {
+            result.set(Some(Self::map(raw_file)));
+        })
+        .await;
+
+        result.take().expect(BUG_MESSAGE)
+    }
+
+    /// Maps the file associated with `raw_file` into memory.
+    fn map(raw_file: libc::c_int) -> Result<Self> {
+        let mut stat = MaybeUninit::<libc::stat>::uninit();
+        if unsafe { libc::fstat(raw_file, stat.as_mut_ptr()) } == -1 {
+            return Err(Error::last_os_error());
+        }
+
+        let size = unsafe { stat.assume_init() }.st_size;
+        let len = usize::try_from(size).map_err(Error::other)?;
+        if len == 0 {
+            return Ok(Self {
+                ptr: NonNull::dangling(),
+                len: 0,
+            });
+        }
+
+        let ptr = unsafe {
+            libc::mmap(
+                std::ptr::null_mut(),
+                len,
+                libc::PROT_READ,
+                libc::MAP_SHARED,
+                raw_file,
+                0,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err(Error::last_os_error());
+        }
+
+        Ok(Self {
+            ptr: NonNull::new(ptr.cast()).expect(BUG_MESSAGE),
+            len,
+        })
+    }
+
+    /// Returns the mapped data.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    /// Returns the length of the mapping, which is the length of the file at the time
+    /// of the mapping.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the mapping is empty.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Announces an intention to access the whole mapping in the `advice` pattern
+    /// (for example, `libc::MADV_SEQUENTIAL` or `libc::MADV_WILLNEED`).
+    /// Read more in [`madvise`].
+    ///
+    /// The mapping is read-only and shared, so no advice can change the mapped data.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `madvise(2)`.
+    ///
+    /// # Panics
+    ///
+    /// If the thread pool of the current [`Executor`](crate::Executor) is disabled
+    /// with `debug_assertions`.
+    #[allow(
+        clippy::future_not_send,
+        reason = "It is not `Send` because of the result cell, it is fine"
+    )]
+    pub async fn advise(&self, advice: libc::c_int) -> Result<()> {
+        if self.is_empty() {
+            return Ok(());
+        }
+
+        unsafe { madvise(self.ptr.as_ptr().cast(), self.len, advice).await }
+    }
+}
+
+impl Deref for MmapFile {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_bytes()
+    }
+}
+
+impl AsRef<[u8]> for MmapFile {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl Debug for MmapFile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MmapFile")
+            .field("len", &self.len)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for MmapFile {
+    fn drop(&mut self) {
+        if self.len != 0 {
+            unsafe { libc::munmap(self.ptr.as_ptr().cast(), self.len) };
+        }
+    }
+}
+
+unsafe impl Send for MmapFile {}
+unsafe impl Sync for MmapFile {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::fs::test_helper::{create_test_dir_if_not_exist, TEST_DIR_PATH};
+    use crate::fs::OpenOptions;
+    use std::path::PathBuf;
+
+    #[orengine::test::test_local]
+    fn test_mmap_file() {
+        create_test_dir_if_not_exist();
+        let path = PathBuf::from(TEST_DIR_PATH).join("mmap.txt");
+        let data: Vec<u8> = (0..10_000u32).map(|i| (i % 251) as u8).collect();
+        std::fs::write(&path, &data).unwrap();
+
+        let file = File::open(&path, &OpenOptions::new().read(true))
+            .await
+            .unwrap();
+        let mmap = unsafe { MmapFile::new(&file).await }.expect("mmap failed");
+        drop(file);
+
+        assert_eq!(mmap.len(), data.len());
+        mmap.advise(libc::MADV_SEQUENTIAL)
+            .await
+            .expect("madvise failed");
+        assert_eq!(&*mmap, data.as_slice());
+        drop(mmap);
+
+        std::fs::write(&path, b"").unwrap();
+        let file = File::open(&path, &OpenOptions::new().read(true))
+            .await
+            .unwrap();
+        let empty = unsafe { MmapFile::new(&file).await }.expect("mmap of empty file failed");
+        assert!(empty.is_empty());
+        empty.advise(libc::MADV_WILLNEED).await.unwrap();
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -1,18 +1,26 @@
 //! This module contains async io operations, utils for working with them and structs
 //! for working with them.
 pub mod buf;
+pub mod buf_reader;
 pub(crate) mod close;
 pub mod config;
 pub mod fs;
 pub(crate) mod io_request_data;
+#[cfg(unix)]
+pub mod mmap;
 pub mod net;
+pub mod stdio;
 pub mod sys;
 pub(crate) mod time_bounded_io_task;
 pub(crate) mod worker;
 
 pub use buf::*;
+pub use buf_reader::{AsyncBufRead, BufReader};
 pub use close::AsyncSocketClose;
 pub use config::IoWorkerConfig;
 pub use fs::*;
+#[cfg(unix)]
+pub use mmap::{madvise, MmapFile};
 pub use net::*;
+pub use stdio::{stderr, stdin, stdout, AsyncStderr, AsyncStdin, AsyncStdout};
 pub use sys::IOUringConfig;
@@ -5,6 +5,48 @@ use crate::utils::each_addr::each_addr;
 use socket2::SockRef;
 use std::io::Result;
 
+/// Makes the socket transparent. Read [`BindConfig::transparent`] for more details.
+fn set_transparent(socket_ref: &SockRef) -> Result<()> {
+    #[cfg(target_os = "linux")]
+    {
+        use std::os::fd::AsRawFd;
+
+        if socket_ref.domain()? != socket2::Domain::IPV6 {
+            return socket_ref.set_ip_transparent(true);
+        }
+
+        let value: libc::c_int = 1;
+        #[allow(
+            clippy::cast_possible_truncation,
+            reason = "size of c_int is less than u32::MAX"
+        )]
+        let res = unsafe {
+            libc::setsockopt(
+                socket_ref.as_raw_fd(),
+                libc::IPPROTO_IPV6,
+                libc::IPV6_TRANSPARENT,
+                std::ptr::from_ref(&value).cast(),
+                size_of::<libc::c_int>() as libc::socklen_t,
+            )
+        };
+        if res == -1 {
+            return Err(std::io::Error::last_os_error());
+        }
+
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = socket_ref;
+
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "transparent sockets are supported only on Linux",
+        ))
+    }
+}
+
 /// The `AsyncBind` trait provides asynchronous methods for creating, binding, and configuring
 /// sockets.
 ///
@@ -30,7 +72,11 @@ use std::io::Result;
 ///     reuse_address: true,
 ///     reuse_port: orengine::net::ReusePort::Default,
 ///     backlog_size: 1024,
-///     only_v6: false
+///     only_v6: false,
+///     recv_buffer_size: Some(1 << 20),
+///     send_buffer_size: None,
+///     mptcp: false,
+///     transparent: false,
 /// };
 /// let listener = TcpListener::bind_with_config("127.0.0.1:8080", &config).await?;
 ///
@@ -62,6 +108,18 @@ pub trait AsyncBind: Sized + Socket {
     /// ```
     async fn new_socket(addr: &Self::Addr) -> Result<RawSocket>;
 
+    /// Creates a new socket that can be bound to the specified address
+    /// with the provided [`configuration`](BindConfig).
+    ///
+    /// It is used by [`bind_with_config`](Self::bind_with_config) and calls
+    /// [`new_socket`](Self::new_socket) by default. Override it if the configuration affects
+    /// the creation of the socket (like [`BindConfig::mptcp`]).
+    async fn new_socket_with_config(addr: &Self::Addr, config: &BindConfig) -> Result<RawSocket> {
+        let _ = config;
+
+        Self::new_socket(addr).await
+    }
+
     /// Binds the socket and listens on the provided address if needed, applying the provided
     /// configuration from [`BindConfig`].
     ///
@@ -122,7 +180,7 @@ pub trait AsyncBind: Sized + Socket {
         config: &BindConfig,
     ) -> Result<Self> {
         each_addr(addrs, move |addr| async move {
-            let raw_fd = Self::new_socket(&addr).await?;
+            let raw_fd = Self::new_socket_with_config(&addr, config).await?;
             let borrowed_raw_socket = unsafe { BorrowedSocket::borrow_raw(raw_fd) };
             let socket_ref = socket2::SockRef::from(&borrowed_raw_socket);
 
@@ -134,6 +192,18 @@ pub trait AsyncBind: Sized + Socket {
                 socket_ref.set_reuse_address(true)?;
             }
 
+            if let Some(recv_buffer_size) = config.recv_buffer_size {
+                socket_ref.set_recv_buffer_size(recv_buffer_size as usize)?;
+            }
+
+            if let Some(send_buffer_size) = config.send_buffer_size {
+                socket_ref.set_send_buffer_size(send_buffer_size as usize)?;
+            }
+
+            if config.transparent {
+                set_transparent(&socket_ref)?;
+            }
+
             match config.reuse_port {
                 ReusePort::Disabled => {
                     Self::bind_and_listen_if_needed(socket_ref, addr, config)?;
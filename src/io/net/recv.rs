@@ -13,12 +13,14 @@ use crate::io::sys::{AsRawSocket, RawSocket};
 use crate::io::worker::{local_worker, IoWorker};
 use crate::io::FixedBufferMut;
 use crate::local_executor;
-use crate::net::Socket;
+use crate::net::{Socket, SocketStats};
+use crate::utils::RateMeter;
 
 /// `recv` io operation.
 #[repr(C)]
 pub struct RecvBytes<'buf> {
     raw_socket: RawSocket,
+    stats: Option<&'buf SocketStats>,
     buf: &'buf mut [u8],
     io_request_data: Option<IoRequestData>,
 }
@@ -28,10 +30,19 @@ impl<'buf> RecvBytes<'buf> {
     pub fn new(raw_socket: RawSocket, buf: &'buf mut [u8]) -> Self {
         Self {
             raw_socket,
+            stats: None,
             buf,
             io_request_data: None,
         }
     }
+
+    /// Sets the [`SocketStats`] that are updated after the operation is completed.
+    #[must_use]
+    pub fn with_stats(mut self, stats: Option<&'buf SocketStats>) -> Self {
+        self.stats = stats;
+
+        self
+    }
 }
 
 impl Future for RecvBytes<'_> {
@@ -52,7 +63,15 @@ impl Future for RecvBytes<'_> {
                 this.buf.len() as u32,
                 unsafe { IoRequestDataPtr::new(this.io_request_data.as_mut().unwrap_unchecked()) }
             ),
-            ret
+            {
+                if let Some(stats) = this.stats {
+                    stats.add_bytes_recv(ret);
+                }
+                ret
+            },
+            if let Some(stats) = this.stats {
+                stats.inc_recv_errors();
+            }
         ));
     }
 }
@@ -63,6 +82,7 @@ unsafe impl Send for RecvBytes<'_> {}
 #[repr(C)]
 pub struct RecvFixed<'buf> {
     raw_socket: RawSocket,
+    stats: Option<&'buf SocketStats>,
     ptr: *mut u8,
     len: u32,
     fixed_index: u16,
@@ -70,11 +90,12 @@ pub struct RecvFixed<'buf> {
     phantom_data: PhantomData<&'buf [u8]>,
 }
 
-impl RecvFixed<'_> {
+impl<'buf> RecvFixed<'buf> {
     /// Creates a new `recv` io operation with __fixed__ [`Buffer`](crate::io::Buffer).
     pub fn new(raw_socket: RawSocket, ptr: *mut u8, len: u32, fixed_index: u16) -> Self {
         Self {
             raw_socket,
+            stats: None,
             ptr,
             len,
             fixed_index,
@@ -82,6 +103,14 @@ impl RecvFixed<'_> {
             phantom_data: PhantomData,
         }
     }
+
+    /// Sets the [`SocketStats`] that are updated after the operation is completed.
+    #[must_use]
+    pub fn with_stats(mut self, stats: Option<&'buf SocketStats>) -> Self {
+        self.stats = stats;
+
+        self
+    }
 }
 
 impl Future for RecvFixed<'_> {
@@ -103,7 +132,15 @@ impl Future for RecvFixed<'_> {
                 this.fixed_index,
                 unsafe { IoRequestDataPtr::new(this.io_request_data.as_mut().unwrap_unchecked()) }
             ),
-            ret as u32
+            {
+                if let Some(stats) = this.stats {
+                    stats.add_bytes_recv(ret);
+                }
+                ret as u32
+            },
+            if let Some(stats) = this.stats {
+                stats.inc_recv_errors();
+            }
         ));
     }
 }
@@ -114,6 +151,7 @@ unsafe impl Send for RecvFixed<'_> {}
 #[repr(C)]
 pub struct RecvBytesWithDeadline<'buf> {
     raw_socket: RawSocket,
+    stats: Option<&'buf SocketStats>,
     buf: &'buf mut [u8],
     io_request_data: Option<IoRequestData>,
     deadline: Instant,
@@ -124,11 +162,20 @@ impl<'buf> RecvBytesWithDeadline<'buf> {
     pub fn new(raw_socket: RawSocket, buf: &'buf mut [u8], deadline: Instant) -> Self {
         Self {
             raw_socket,
+            stats: None,
             buf,
             io_request_data: None,
             deadline,
         }
     }
+
+    /// Sets the [`SocketStats`] that are updated after the operation is completed.
+    #[must_use]
+    pub fn with_stats(mut self, stats: Option<&'buf SocketStats>) -> Self {
+        self.stats = stats;
+
+        self
+    }
 }
 
 impl Future for RecvBytesWithDeadline<'_> {
@@ -151,7 +198,15 @@ impl Future for RecvBytesWithDeadline<'_> {
                 unsafe { IoRequestDataPtr::new(this.io_request_data.as_mut().unwrap_unchecked()) },
                 &mut this.deadline
             ),
-            ret
+            {
+                if let Some(stats) = this.stats {
+                    stats.add_bytes_recv(ret);
+                }
+                ret
+            },
+            if let Some(stats) = this.stats {
+                stats.inc_recv_errors();
+            }
         ));
     }
 }
@@ -162,6 +217,7 @@ unsafe impl Send for RecvBytesWithDeadline<'_> {}
 #[repr(C)]
 pub struct RecvFixedWithDeadline<'buf> {
     raw_socket: RawSocket,
+    stats: Option<&'buf SocketStats>,
     ptr: *mut u8,
     len: u32,
     fixed_index: u16,
@@ -170,7 +226,7 @@ pub struct RecvFixedWithDeadline<'buf> {
     phantom_data: PhantomData<&'buf [u8]>,
 }
 
-impl RecvFixedWithDeadline<'_> {
+impl<'buf> RecvFixedWithDeadline<'buf> {
     /// Creates a new `recv` io operation with deadline and __fixed__ [`Buffer`](crate::io::Buffer).
     pub fn new(
         raw_socket: RawSocket,
@@ -181,6 +237,7 @@ impl RecvFixedWithDeadline<'_> {
     ) -> Self {
         Self {
             raw_socket,
+            stats: None,
             ptr,
             len,
             fixed_index,
@@ -189,6 +246,14 @@ impl RecvFixedWithDeadline<'_> {
             phantom_data: PhantomData,
         }
     }
+
+    /// Sets the [`SocketStats`] that are updated after the operation is completed.
+    #[must_use]
+    pub fn with_stats(mut self, stats: Option<&'buf SocketStats>) -> Self {
+        self.stats = stats;
+
+        self
+    }
 }
 
 impl Future for RecvFixedWithDeadline<'_> {
@@ -212,7 +277,15 @@ impl Future for RecvFixedWithDeadline<'_> {
                 unsafe { IoRequestDataPtr::new(this.io_request_data.as_mut().unwrap_unchecked()) },
                 &mut this.deadline
             ),
-            ret as u32
+            {
+                if let Some(stats) = this.stats {
+                    stats.add_bytes_recv(ret);
+                }
+                ret as u32
+            },
+            if let Some(stats) = this.stats {
+                stats.inc_recv_errors();
+            }
         ));
     }
 }
@@ -268,8 +341,11 @@ pub trait AsyncRecv: Socket {
     /// # }
     /// ```
     #[inline]
-    fn recv_bytes(&mut self, buf: &mut [u8]) -> impl Future<Output = Result<usize>> {
-        RecvBytes::new(AsRawSocket::as_raw_socket(self), buf)
+    fn recv_bytes<'fut>(
+        &'fut mut self,
+        buf: &'fut mut [u8],
+    ) -> impl Future<Output = Result<usize>> + 'fut {
+        RecvBytes::new(AsRawSocket::as_raw_socket(self), buf).with_stats(self.stats())
     }
 
     /// Asynchronously receives into the provided byte slice the incoming data with consuming it,
@@ -305,6 +381,7 @@ pub trait AsyncRecv: Socket {
                 buf.len_u32(),
                 buf.fixed_index(),
             )
+            .with_stats(self.stats())
             .await
         } else {
             #[allow(
@@ -312,6 +389,7 @@ pub trait AsyncRecv: Socket {
                 reason = "It never receive more than u32::MAX bytes"
             )]
             RecvBytes::new(AsRawSocket::as_raw_socket(self), buf.as_bytes_mut())
+                .with_stats(self.stats())
                 .await
                 .map(|r| r as u32)
         }
@@ -347,12 +425,13 @@ pub trait AsyncRecv: Socket {
     /// # }
     /// ```
     #[inline]
-    fn recv_bytes_with_deadline(
-        &mut self,
-        buf: &mut [u8],
+    fn recv_bytes_with_deadline<'fut>(
+        &'fut mut self,
+        buf: &'fut mut [u8],
         deadline: Instant,
-    ) -> impl Future<Output = Result<usize>> {
+    ) -> impl Future<Output = Result<usize>> + 'fut {
         RecvBytesWithDeadline::new(AsRawSocket::as_raw_socket(self), buf, deadline)
+            .with_stats(self.stats())
     }
 
     /// Asynchronously receives into the provided byte slice the incoming data with consuming it,
@@ -398,6 +477,7 @@ pub trait AsyncRecv: Socket {
                 buf.fixed_index(),
                 deadline,
             )
+            .with_stats(self.stats())
             .await
         } else {
             #[allow(
@@ -409,6 +489,7 @@ pub trait AsyncRecv: Socket {
                 buf.as_bytes_mut(),
                 deadline,
             )
+            .with_stats(self.stats())
             .await
             .map(|r| r as u32)
         }
@@ -444,11 +525,11 @@ pub trait AsyncRecv: Socket {
     /// # }
     /// ```
     #[inline]
-    fn recv_bytes_with_timeout(
-        &mut self,
-        buf: &mut [u8],
+    fn recv_bytes_with_timeout<'fut>(
+        &'fut mut self,
+        buf: &'fut mut [u8],
         timeout: Duration,
-    ) -> impl Future<Output = Result<usize>> {
+    ) -> impl Future<Output = Result<usize>> + 'fut {
         self.recv_bytes_with_deadline(
             buf,
             local_executor().start_round_time_for_deadlines() + timeout,
@@ -496,6 +577,91 @@ pub trait AsyncRecv: Socket {
         )
     }
 
+    /// Asynchronously receives into the provided byte slice the incoming data with consuming it,
+    /// with the [`ambient deadline`](crate::deadline) of the current task.
+    /// Returns the number of bytes received.
+    ///
+    /// If the current task has no ambient deadline, it works as
+    /// [`recv_bytes`](Self::recv_bytes).
+    ///
+    /// If the deadline is exceeded, the method will return an error with
+    /// kind [`ErrorKind::TimedOut`](std::io::ErrorKind::TimedOut).
+    ///
+    /// # Difference between `recv_with_ambient_deadline` and `recv_bytes_with_ambient_deadline`
+    ///
+    /// Use [`recv_with_ambient_deadline`](Self::recv_with_ambient_deadline) if it is possible,
+    /// because [`Buffer`](crate::io::Buffer) can be __fixed__.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::net::TcpStream;
+    /// use orengine::io::{AsyncConnectStream, AsyncRecv};
+    /// use orengine::timeout_at;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// async fn foo() -> std::io::Result<()> {
+    /// let deadline = Instant::now() + Duration::from_secs(5);
+    /// timeout_at(deadline, async {
+    ///     let mut stream = TcpStream::connect("127.0.0.1:8080").await?;
+    ///     let mut vec = vec![0u8; 1024];
+    ///
+    ///     stream.recv_bytes_with_ambient_deadline(&mut vec).await
+    /// })
+    /// .await??;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    async fn recv_bytes_with_ambient_deadline(&mut self, buf: &mut [u8]) -> Result<usize> {
+        match local_executor().ambient_deadline() {
+            Some(deadline) => self.recv_bytes_with_deadline(buf, deadline).await,
+            None => self.recv_bytes(buf).await,
+        }
+    }
+
+    /// Asynchronously receives into the provided byte slice the incoming data with consuming it,
+    /// with the [`ambient deadline`](crate::deadline) of the current task.
+    /// Returns the number of bytes received.
+    ///
+    /// If the current task has no ambient deadline, it works as [`recv`](Self::recv).
+    ///
+    /// If the deadline is exceeded, the method will return an error with
+    /// kind [`ErrorKind::TimedOut`](std::io::ErrorKind::TimedOut).
+    ///
+    /// # Difference between `recv_with_ambient_deadline` and `recv_bytes_with_ambient_deadline`
+    ///
+    /// Use [`recv_with_ambient_deadline`](Self::recv_with_ambient_deadline) if it is possible,
+    /// because [`Buffer`](crate::io::Buffer) can be __fixed__.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::net::TcpStream;
+    /// use orengine::io::{full_buffer, AsyncConnectStream, AsyncRecv};
+    /// use orengine::timeout_at;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// async fn foo() -> std::io::Result<()> {
+    /// let deadline = Instant::now() + Duration::from_secs(5);
+    /// timeout_at(deadline, async {
+    ///     let mut stream = TcpStream::connect("127.0.0.1:8080").await?;
+    ///     let mut buffer = full_buffer();
+    ///
+    ///     stream.recv_with_ambient_deadline(&mut buffer).await
+    /// })
+    /// .await??;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    async fn recv_with_ambient_deadline(&mut self, buf: &mut impl FixedBufferMut) -> Result<u32> {
+        match local_executor().ambient_deadline() {
+            Some(deadline) => self.recv_with_deadline(buf, deadline).await,
+            None => self.recv(buf).await,
+        }
+    }
+
     /// Asynchronously receives into the provided byte slice the incoming data with consuming it,
     /// until the buffer is completely filled with exactly the requested number of bytes.
     ///
@@ -568,6 +734,7 @@ pub trait AsyncRecv: Socket {
                     buf.len_u32() - received,
                     buf.fixed_index(),
                 )
+                .with_stats(self.stats())
                 .await?;
             }
         } else {
@@ -679,6 +846,7 @@ pub trait AsyncRecv: Socket {
                     buf.fixed_index(),
                     deadline,
                 )
+                .with_stats(self.stats())
                 .await?;
             }
         } else {
@@ -779,3 +947,121 @@ pub trait AsyncRecv: Socket {
         )
     }
 }
+
+/// `RatedRecv` wraps an [`AsyncRecv`] socket and measures its receiving throughput
+/// with a [`RateMeter`].
+///
+/// Every completed operation is recorded with the number of received bytes.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::net::TcpStream;
+/// use orengine::io::{full_buffer, AsyncConnectStream, RatedRecv};
+/// use std::time::Duration;
+///
+/// # async fn foo() -> std::io::Result<()> {
+/// let stream = TcpStream::connect("127.0.0.1:8080").await?;
+/// let mut stream = RatedRecv::new(stream, Duration::from_secs(5));
+/// let mut buf = full_buffer();
+///
+/// while stream.recv(&mut buf).await? > 0 {
+///     println!("{:.0} B/s", stream.meter().rate_bytes_per_sec());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct RatedRecv<S: AsyncRecv> {
+    socket: S,
+    meter: RateMeter,
+}
+
+impl<S: AsyncRecv> RatedRecv<S> {
+    /// Wraps the `socket` and creates a [`RateMeter`] with the given averaging `window`.
+    ///
+    /// # Panics
+    ///
+    /// If `window` is zero.
+    pub fn new(socket: S, window: Duration) -> Self {
+        Self {
+            socket,
+            meter: RateMeter::new(window),
+        }
+    }
+
+    /// Returns the [`RateMeter`] of the receiving throughput.
+    pub const fn meter(&self) -> &RateMeter {
+        &self.meter
+    }
+
+    /// Returns a reference to the wrapped socket.
+    pub const fn get_ref(&self) -> &S {
+        &self.socket
+    }
+
+    /// Returns a mutable reference to the wrapped socket.
+    ///
+    /// Operations executed via it are not recorded.
+    pub const fn get_mut(&mut self) -> &mut S {
+        &mut self.socket
+    }
+
+    /// Returns the wrapped socket.
+    pub fn into_inner(self) -> S {
+        self.socket
+    }
+
+    /// Receives into the provided byte slice like [`AsyncRecv::recv_bytes`] and records it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [`AsyncRecv::recv_bytes`].
+    #[allow(clippy::future_not_send, reason = "Because it is `local`")]
+    pub async fn recv_bytes(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let received = self.socket.recv_bytes(buf).await?;
+        self.meter.record(received);
+
+        Ok(received)
+    }
+
+    /// Receives into the provided [`Buffer`](crate::io::Buffer) like [`AsyncRecv::recv`]
+    /// and records it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [`AsyncRecv::recv`].
+    #[allow(clippy::future_not_send, reason = "Because it is `local`")]
+    pub async fn recv(&mut self, buf: &mut impl FixedBufferMut) -> Result<u32> {
+        let received = self.socket.recv(buf).await?;
+        self.meter.record(received as usize);
+
+        Ok(received)
+    }
+
+    /// Fills the provided byte slice like [`AsyncRecv::recv_bytes_exact`] and records it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [`AsyncRecv::recv_bytes_exact`].
+    #[allow(clippy::future_not_send, reason = "Because it is `local`")]
+    pub async fn recv_bytes_exact(&mut self, buf: &mut [u8]) -> Result<()> {
+        self.socket.recv_bytes_exact(buf).await?;
+        self.meter.record(buf.len());
+
+        Ok(())
+    }
+
+    /// Fills the provided [`Buffer`](crate::io::Buffer) like [`AsyncRecv::recv_exact`]
+    /// and records it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [`AsyncRecv::recv_exact`].
+    #[allow(clippy::future_not_send, reason = "Because it is `local`")]
+    pub async fn recv_exact(&mut self, buf: &mut impl FixedBufferMut) -> Result<()> {
+        self.socket.recv_exact(buf).await?;
+        self.meter.record(buf.len_u32() as usize);
+
+        Ok(())
+    }
+}
@@ -1,6 +1,10 @@
 use std::future::Future;
+#[cfg(target_os = "linux")]
+use std::io::{Error, ErrorKind};
 use std::io::{IoSliceMut, Result};
 use std::mem;
+#[cfg(target_os = "linux")]
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
 use std::pin::Pin;
 use std::task::{Context, Poll};
 use std::time::{Duration, Instant};
@@ -14,13 +18,14 @@ use crate::io::sys::{AsRawSocket, MessageRecvHeader, RawSocket};
 use crate::io::worker::{local_worker, IoWorker};
 use crate::io::FixedBufferMut;
 use crate::net::addr::FromSockAddr;
-use crate::net::Socket;
+use crate::net::{Socket, SocketStats};
 use crate::{local_executor, BUG_MESSAGE};
 
 /// `recv_from` io operation.
 #[repr(C)]
 pub struct RecvFrom<'fut> {
     raw_socket: RawSocket,
+    stats: Option<&'fut SocketStats>,
     sock_addr: &'fut mut SockAddr,
     msg_header: MessageRecvHeader,
     io_request_data: Option<IoRequestData>,
@@ -35,11 +40,20 @@ impl<'fut> RecvFrom<'fut> {
     ) -> Self {
         Self {
             raw_socket,
+            stats: None,
             msg_header: MessageRecvHeader::new(addr, buf_ptr),
             sock_addr: addr,
             io_request_data: None,
         }
     }
+
+    /// Sets the [`SocketStats`] that are updated after the operation is completed.
+    #[must_use]
+    pub fn with_stats(mut self, stats: Option<&'fut SocketStats>) -> Self {
+        self.stats = stats;
+
+        self
+    }
 }
 
 impl Future for RecvFrom<'_> {
@@ -54,8 +68,14 @@ impl Future for RecvFrom<'_> {
                 IoRequestDataPtr::new(this.io_request_data.as_mut().unwrap_unchecked())
             }),
             {
+                if let Some(stats) = this.stats {
+                    stats.add_bytes_recv(ret);
+                }
                 unsafe { this.sock_addr.set_length(this.msg_header.get_addr_len()) };
                 ret
+            },
+            if let Some(stats) = this.stats {
+                stats.inc_recv_errors();
             }
         ));
     }
@@ -71,6 +91,7 @@ unsafe impl Send for RecvFrom<'_> {}
 #[repr(C)]
 pub struct RecvFromWithDeadline<'fut> {
     raw_socket: RawSocket,
+    stats: Option<&'fut SocketStats>,
     sock_addr: &'fut mut SockAddr,
     msg_header: MessageRecvHeader,
     deadline: Instant,
@@ -87,12 +108,21 @@ impl<'fut> RecvFromWithDeadline<'fut> {
     ) -> Self {
         Self {
             raw_socket,
+            stats: None,
             msg_header: MessageRecvHeader::new(addr, buf_ptr),
             sock_addr: addr,
             deadline,
             io_request_data: None,
         }
     }
+
+    /// Sets the [`SocketStats`] that are updated after the operation is completed.
+    #[must_use]
+    pub fn with_stats(mut self, stats: Option<&'fut SocketStats>) -> Self {
+        self.stats = stats;
+
+        self
+    }
 }
 
 impl Future for RecvFromWithDeadline<'_> {
@@ -111,8 +141,14 @@ impl Future for RecvFromWithDeadline<'_> {
                 &mut this.deadline
             ),
             {
+                if let Some(stats) = this.stats {
+                    stats.add_bytes_recv(ret);
+                }
                 unsafe { this.sock_addr.set_length(this.msg_header.get_addr_len()) };
                 ret
+            },
+            if let Some(stats) = this.stats {
+                stats.inc_recv_errors();
             }
         ));
     }
@@ -124,6 +160,170 @@ impl Future for RecvFromWithDeadline<'_> {
 )]
 unsafe impl Send for RecvFromWithDeadline<'_> {}
 
+/// `PktInfo` is the packet information of a received datagram: the local address
+/// the datagram was sent to and the index of the interface it arrived on.
+///
+/// It is returned by [`AsyncRecvFromWithPktInfo::recv_from_with_pktinfo`].
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PktInfo {
+    local_addr: IpAddr,
+    interface_index: u32,
+}
+
+#[cfg(target_os = "linux")]
+impl PktInfo {
+    /// Returns the local (destination) address of the datagram.
+    ///
+    /// Send the reply from this address to answer from the same address
+    /// the request arrived on.
+    #[inline]
+    pub const fn local_addr(&self) -> IpAddr {
+        self.local_addr
+    }
+
+    /// Returns the index of the interface the datagram arrived on.
+    #[inline]
+    pub const fn interface_index(&self) -> u32 {
+        self.interface_index
+    }
+
+    /// Finds `IP_PKTINFO` or `IPV6_PKTINFO` control message in the received message header.
+    fn from_msg_header(header: &libc::msghdr) -> Option<Self> {
+        let mut cmsg = unsafe { libc::CMSG_FIRSTHDR(header) };
+
+        while !cmsg.is_null() {
+            let (level, ty) = unsafe { ((*cmsg).cmsg_level, (*cmsg).cmsg_type) };
+            let data = unsafe { libc::CMSG_DATA(cmsg) };
+
+            #[allow(clippy::cast_sign_loss, reason = "Interface indexes are positive")]
+            match (level, ty) {
+                (libc::IPPROTO_IP, libc::IP_PKTINFO) => {
+                    let info = unsafe { data.cast::<libc::in_pktinfo>().read_unaligned() };
+
+                    return Some(Self {
+                        local_addr: IpAddr::V4(Ipv4Addr::from(u32::from_be(info.ipi_addr.s_addr))),
+                        interface_index: info.ipi_ifindex as u32,
+                    });
+                }
+                (libc::IPPROTO_IPV6, libc::IPV6_PKTINFO) => {
+                    let info = unsafe { data.cast::<libc::in6_pktinfo>().read_unaligned() };
+
+                    return Some(Self {
+                        local_addr: IpAddr::V6(Ipv6Addr::from(info.ipi6_addr.s6_addr)),
+                        interface_index: info.ipi6_ifindex,
+                    });
+                }
+                _ => {}
+            }
+
+            cmsg = unsafe { libc::CMSG_NXTHDR(header, cmsg) };
+        }
+
+        None
+    }
+}
+
+/// The buffer for control messages of [`RecvFromWithPktInfo`].
+///
+/// It is enough for both `IP_PKTINFO` and `IPV6_PKTINFO` control messages
+/// and is aligned as `cmsghdr`.
+#[cfg(target_os = "linux")]
+#[repr(C, align(8))]
+pub struct PktInfoControlBuffer([u8; 128]);
+
+#[cfg(target_os = "linux")]
+impl PktInfoControlBuffer {
+    /// Creates a new zeroed `PktInfoControlBuffer`.
+    pub const fn new() -> Self {
+        Self([0; 128])
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Default for PktInfoControlBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `recv_from` io operation that receives `IP_PKTINFO` or `IPV6_PKTINFO` control message.
+#[cfg(target_os = "linux")]
+#[repr(C)]
+pub struct RecvFromWithPktInfo<'fut> {
+    raw_socket: RawSocket,
+    stats: Option<&'fut SocketStats>,
+    sock_addr: &'fut mut SockAddr,
+    msg_header: MessageRecvHeader,
+    io_request_data: Option<IoRequestData>,
+}
+
+#[cfg(target_os = "linux")]
+impl<'fut> RecvFromWithPktInfo<'fut> {
+    /// Creates a new `recv_from` io operation that receives packet information.
+    pub fn new(
+        raw_socket: RawSocket,
+        buf_ptr: *mut [IoSliceMut],
+        addr: &'fut mut SockAddr,
+        control: &'fut mut PktInfoControlBuffer,
+    ) -> Self {
+        let mut msg_header = MessageRecvHeader::new(addr, buf_ptr);
+        msg_header.set_control(&raw mut control.0);
+
+        Self {
+            raw_socket,
+            stats: None,
+            msg_header,
+            sock_addr: addr,
+            io_request_data: None,
+        }
+    }
+
+    /// Sets the [`SocketStats`] that are updated after the operation is completed.
+    #[must_use]
+    pub fn with_stats(mut self, stats: Option<&'fut SocketStats>) -> Self {
+        self.stats = stats;
+
+        self
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Future for RecvFromWithPktInfo<'_> {
+    type Output = Result<(usize, Option<PktInfo>)>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let ret;
+
+        poll_for_io_request!((
+            local_worker().recv_from(this.raw_socket, &mut this.msg_header, unsafe {
+                IoRequestDataPtr::new(this.io_request_data.as_mut().unwrap_unchecked())
+            }),
+            {
+                if let Some(stats) = this.stats {
+                    stats.add_bytes_recv(ret);
+                }
+                unsafe { this.sock_addr.set_length(this.msg_header.get_addr_len()) };
+                (
+                    ret,
+                    PktInfo::from_msg_header(this.msg_header.get_os_message_header()),
+                )
+            },
+            if let Some(stats) = this.stats {
+                stats.inc_recv_errors();
+            }
+        ));
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[allow(
+    clippy::non_send_fields_in_send_ty,
+    reason = "We guarantee that `RecvFromWithPktInfo` is `Send`."
+)]
+unsafe impl Send for RecvFromWithPktInfo<'_> {}
+
 /// The `AsyncRecvFrom` trait provides asynchronous methods for receiving at the incoming data
 /// with consuming it from the socket (datagram).
 ///
@@ -178,7 +378,9 @@ pub trait AsyncRecvFrom: Socket {
         let mut sock_addr = unsafe { mem::zeroed() };
         let buf_ptr = &mut [IoSliceMut::new(buf)];
 
-        let n = RecvFrom::new(AsRawSocket::as_raw_socket(self), buf_ptr, &mut sock_addr).await?;
+        let n = RecvFrom::new(AsRawSocket::as_raw_socket(self), buf_ptr, &mut sock_addr)
+            .with_stats(self.stats())
+            .await?;
 
         Ok((n, Self::Addr::from_sock_addr(sock_addr).expect(BUG_MESSAGE)))
     }
@@ -256,6 +458,7 @@ pub trait AsyncRecvFrom: Socket {
             &mut sock_addr,
             deadline,
         )
+        .with_stats(self.stats())
         .await?;
 
         Ok((n, Self::Addr::from_sock_addr(sock_addr).expect(BUG_MESSAGE)))
@@ -383,3 +586,128 @@ pub trait AsyncRecvFrom: Socket {
             .await
     }
 }
+
+/// The `AsyncRecvFromWithPktInfo` trait provides asynchronous methods for receiving
+/// the incoming datagram with its [`PktInfo`].
+///
+/// [`PktInfo`] contains the local address the datagram was sent to
+/// and the index of the interface it arrived on.
+///
+/// It is needed for UDP servers that are bound to multiple addresses (or to the unspecified
+/// address) and must send the reply from the same address the request arrived on,
+/// for example, DHCP or authoritative DNS servers.
+///
+/// Call [`set_recv_pktinfo`](Self::set_recv_pktinfo) before receiving datagrams.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::io::{full_buffer, AsyncBind, AsyncRecvFromWithPktInfo};
+/// use orengine::net::UdpSocket;
+///
+/// # async fn foo() -> std::io::Result<()> {
+/// let mut socket = UdpSocket::bind("0.0.0.0:5353").await?;
+/// socket.set_recv_pktinfo(true)?;
+/// let mut buf = full_buffer();
+///
+/// let (n, peer, pkt_info) = socket.recv_from_with_pktinfo(&mut buf).await?;
+/// println!("{n} bytes from {peer} to {}", pkt_info.local_addr());
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(target_os = "linux")]
+pub trait AsyncRecvFromWithPktInfo: Socket<Addr = SocketAddr> {
+    /// Enables or disables receiving of `IP_PKTINFO` (or `IPV6_PKTINFO`
+    /// for IPv6 sockets) control messages.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `getsockname(2)` or `setsockopt(2)`.
+    fn set_recv_pktinfo(&self, enabled: bool) -> Result<()> {
+        let (level, name) = if self.local_addr()?.is_ipv4() {
+            (libc::IPPROTO_IP, libc::IP_PKTINFO)
+        } else {
+            (libc::IPPROTO_IPV6, libc::IPV6_RECVPKTINFO)
+        };
+        let value = libc::c_int::from(enabled);
+
+        #[allow(
+            clippy::cast_possible_truncation,
+            reason = "size of c_int fits in socklen_t"
+        )]
+        let ret = unsafe {
+            libc::setsockopt(
+                AsRawSocket::as_raw_socket(self),
+                level,
+                name,
+                (&raw const value).cast(),
+                mem::size_of::<libc::c_int>() as libc::socklen_t,
+            )
+        };
+        if ret == -1 {
+            return Err(Error::last_os_error());
+        }
+
+        Ok(())
+    }
+
+    /// Asynchronously receives into the incoming datagram with consuming it, filling the buffer
+    /// with available data and returning the number of bytes received, the sender's address
+    /// and the [`PktInfo`] of the datagram.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the receiving fails or if the datagram has no packet information
+    /// because it is not enabled with [`set_recv_pktinfo`](Self::set_recv_pktinfo).
+    /// The datagram is consumed in the latter case.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::io::{full_buffer, AsyncBind, AsyncRecvFromWithPktInfo, AsyncSendTo};
+    /// use orengine::net::UdpSocket;
+    ///
+    /// # async fn foo() -> std::io::Result<()> {
+    /// let mut socket = UdpSocket::bind("0.0.0.0:67").await?;
+    /// socket.set_recv_pktinfo(true)?;
+    /// let mut buf = full_buffer();
+    ///
+    /// let (n, peer, pkt_info) = socket.recv_from_with_pktinfo(&mut buf).await?;
+    /// if pkt_info.local_addr().is_loopback() {
+    ///     socket.send_bytes_to(&buf[..n], peer).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    async fn recv_from_with_pktinfo(
+        &mut self,
+        buf: &mut [u8],
+    ) -> Result<(usize, SocketAddr, PktInfo)> {
+        let mut sock_addr = unsafe { mem::zeroed() };
+        let mut control = PktInfoControlBuffer::new();
+        let buf_ptr = &mut [IoSliceMut::new(buf)];
+
+        let (n, pkt_info) = RecvFromWithPktInfo::new(
+            AsRawSocket::as_raw_socket(self),
+            buf_ptr,
+            &mut sock_addr,
+            &mut control,
+        )
+        .with_stats(self.stats())
+        .await?;
+
+        let pkt_info = pkt_info.ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                "the datagram has no packet information, enable it with `set_recv_pktinfo`",
+            )
+        })?;
+
+        Ok((
+            n,
+            SocketAddr::from_sock_addr(sock_addr).expect(BUG_MESSAGE),
+            pkt_info,
+        ))
+    }
+}
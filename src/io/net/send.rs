@@ -13,12 +13,14 @@ use crate::io::sys::{AsRawSocket, RawSocket};
 use crate::io::worker::{local_worker, IoWorker};
 use crate::io::{Buffer, FixedBuffer};
 use crate::local_executor;
-use crate::net::Socket;
+use crate::net::{Socket, SocketStats};
+use crate::utils::RateMeter;
 
 /// `send` io operation.
 #[repr(C)]
 pub struct SendBytes<'buf> {
     raw_socket: RawSocket,
+    stats: Option<&'buf SocketStats>,
     buf: &'buf [u8],
     io_request_data: Option<IoRequestData>,
 }
@@ -28,10 +30,19 @@ impl<'buf> SendBytes<'buf> {
     pub fn new(raw_socket: RawSocket, buf: &'buf [u8]) -> Self {
         Self {
             raw_socket,
+            stats: None,
             buf,
             io_request_data: None,
         }
     }
+
+    /// Sets the [`SocketStats`] that are updated after the operation is completed.
+    #[must_use]
+    pub fn with_stats(mut self, stats: Option<&'buf SocketStats>) -> Self {
+        self.stats = stats;
+
+        self
+    }
 }
 
 impl Future for SendBytes<'_> {
@@ -52,7 +63,15 @@ impl Future for SendBytes<'_> {
                 this.buf.len() as u32,
                 unsafe { IoRequestDataPtr::new(this.io_request_data.as_mut().unwrap_unchecked()) }
             ),
-            ret
+            {
+                if let Some(stats) = this.stats {
+                    stats.add_bytes_sent(ret);
+                }
+                ret
+            },
+            if let Some(stats) = this.stats {
+                stats.inc_send_errors();
+            }
         ));
     }
 }
@@ -63,6 +82,7 @@ unsafe impl Send for SendBytes<'_> {}
 #[repr(C)]
 pub struct SendFixed<'buf> {
     raw_socket: RawSocket,
+    stats: Option<&'buf SocketStats>,
     ptr: *const u8,
     len: u32,
     fixed_index: u16,
@@ -70,11 +90,12 @@ pub struct SendFixed<'buf> {
     phantom_data: PhantomData<&'buf Buffer>,
 }
 
-impl SendFixed<'_> {
+impl<'buf> SendFixed<'buf> {
     /// Creates new `send` io operation.
     pub fn new(raw_socket: RawSocket, ptr: *const u8, len: u32, fixed_index: u16) -> Self {
         Self {
             raw_socket,
+            stats: None,
             ptr,
             len,
             fixed_index,
@@ -82,6 +103,14 @@ impl SendFixed<'_> {
             phantom_data: PhantomData,
         }
     }
+
+    /// Sets the [`SocketStats`] that are updated after the operation is completed.
+    #[must_use]
+    pub fn with_stats(mut self, stats: Option<&'buf SocketStats>) -> Self {
+        self.stats = stats;
+
+        self
+    }
 }
 
 impl Future for SendFixed<'_> {
@@ -103,7 +132,15 @@ impl Future for SendFixed<'_> {
                 this.fixed_index,
                 unsafe { IoRequestDataPtr::new(this.io_request_data.as_mut().unwrap_unchecked()) }
             ),
-            ret as u32
+            {
+                if let Some(stats) = this.stats {
+                    stats.add_bytes_sent(ret);
+                }
+                ret as u32
+            },
+            if let Some(stats) = this.stats {
+                stats.inc_send_errors();
+            }
         ));
     }
 }
@@ -114,6 +151,7 @@ unsafe impl Send for SendFixed<'_> {}
 #[repr(C)]
 pub struct SendBytesWithDeadline<'buf> {
     raw_socket: RawSocket,
+    stats: Option<&'buf SocketStats>,
     buf: &'buf [u8],
     io_request_data: Option<IoRequestData>,
     deadline: Instant,
@@ -124,11 +162,20 @@ impl<'buf> SendBytesWithDeadline<'buf> {
     pub fn new(raw_socket: RawSocket, buf: &'buf [u8], deadline: Instant) -> Self {
         Self {
             raw_socket,
+            stats: None,
             buf,
             io_request_data: None,
             deadline,
         }
     }
+
+    /// Sets the [`SocketStats`] that are updated after the operation is completed.
+    #[must_use]
+    pub fn with_stats(mut self, stats: Option<&'buf SocketStats>) -> Self {
+        self.stats = stats;
+
+        self
+    }
 }
 
 impl Future for SendBytesWithDeadline<'_> {
@@ -151,7 +198,15 @@ impl Future for SendBytesWithDeadline<'_> {
                 unsafe { IoRequestDataPtr::new(this.io_request_data.as_mut().unwrap_unchecked()) },
                 &mut this.deadline
             ),
-            ret
+            {
+                if let Some(stats) = this.stats {
+                    stats.add_bytes_sent(ret);
+                }
+                ret
+            },
+            if let Some(stats) = this.stats {
+                stats.inc_send_errors();
+            }
         ));
     }
 }
@@ -162,6 +217,7 @@ unsafe impl Send for SendBytesWithDeadline<'_> {}
 #[repr(C)]
 pub struct SendFixedWithDeadline<'buf> {
     raw_socket: RawSocket,
+    stats: Option<&'buf SocketStats>,
     ptr: *const u8,
     len: u32,
     fixed_index: u16,
@@ -170,7 +226,7 @@ pub struct SendFixedWithDeadline<'buf> {
     phantom_data: PhantomData<&'buf Buffer>,
 }
 
-impl SendFixedWithDeadline<'_> {
+impl<'buf> SendFixedWithDeadline<'buf> {
     /// Creates new `send` io operation with deadline.
     pub fn new(
         raw_socket: RawSocket,
@@ -181,6 +237,7 @@ impl SendFixedWithDeadline<'_> {
     ) -> Self {
         Self {
             raw_socket,
+            stats: None,
             ptr,
             len,
             fixed_index,
@@ -189,6 +246,14 @@ impl SendFixedWithDeadline<'_> {
             phantom_data: PhantomData,
         }
     }
+
+    /// Sets the [`SocketStats`] that are updated after the operation is completed.
+    #[must_use]
+    pub fn with_stats(mut self, stats: Option<&'buf SocketStats>) -> Self {
+        self.stats = stats;
+
+        self
+    }
 }
 
 impl Future for SendFixedWithDeadline<'_> {
@@ -212,7 +277,15 @@ impl Future for SendFixedWithDeadline<'_> {
                 unsafe { IoRequestDataPtr::new(this.io_request_data.as_mut().unwrap_unchecked()) },
                 &mut this.deadline
             ),
-            ret as u32
+            {
+                if let Some(stats) = this.stats {
+                    stats.add_bytes_sent(ret);
+                }
+                ret as u32
+            },
+            if let Some(stats) = this.stats {
+                stats.inc_send_errors();
+            }
         ));
     }
 }
@@ -265,8 +338,11 @@ pub trait AsyncSend: Socket {
     /// # }
     /// ```
     #[inline]
-    fn send_bytes(&mut self, buf: &[u8]) -> impl Future<Output = Result<usize>> {
-        SendBytes::new(AsRawSocket::as_raw_socket(self), buf)
+    fn send_bytes<'fut>(
+        &'fut mut self,
+        buf: &'fut [u8],
+    ) -> impl Future<Output = Result<usize>> + 'fut {
+        SendBytes::new(AsRawSocket::as_raw_socket(self), buf).with_stats(self.stats())
     }
 
     /// Asynchronously sends the provided [`Buffer`]. Returns the number of bytes sent.
@@ -302,6 +378,7 @@ pub trait AsyncSend: Socket {
                 buf.len_u32(),
                 buf.fixed_index(),
             )
+            .with_stats(self.stats())
             .await
         } else {
             #[allow(
@@ -309,6 +386,7 @@ pub trait AsyncSend: Socket {
                 reason = "It never send more than u32::MAX bytes"
             )]
             SendBytes::new(AsRawSocket::as_raw_socket(self), buf.as_bytes())
+                .with_stats(self.stats())
                 .await
                 .map(|r| r as u32)
         }
@@ -341,12 +419,13 @@ pub trait AsyncSend: Socket {
     /// # }
     /// ```
     #[inline]
-    fn send_bytes_with_deadline(
-        &mut self,
-        buf: &[u8],
+    fn send_bytes_with_deadline<'fut>(
+        &'fut mut self,
+        buf: &'fut [u8],
         deadline: Instant,
-    ) -> impl Future<Output = Result<usize>> {
+    ) -> impl Future<Output = Result<usize>> + 'fut {
         SendBytesWithDeadline::new(AsRawSocket::as_raw_socket(self), buf, deadline)
+            .with_stats(self.stats())
     }
 
     /// Asynchronously sends the provided [`Buffer`] with a specified deadline.
@@ -394,6 +473,7 @@ pub trait AsyncSend: Socket {
                 buf.fixed_index(),
                 deadline,
             )
+            .with_stats(self.stats())
             .await
         } else {
             #[allow(
@@ -401,6 +481,7 @@ pub trait AsyncSend: Socket {
                 reason = "It never send more than u32::MAX bytes"
             )]
             SendBytesWithDeadline::new(AsRawSocket::as_raw_socket(self), buf.as_bytes(), deadline)
+                .with_stats(self.stats())
                 .await
                 .map(|r| r as u32)
         }
@@ -433,16 +514,17 @@ pub trait AsyncSend: Socket {
     /// # }
     /// ```
     #[inline]
-    fn send_bytes_with_timeout(
-        &mut self,
-        buf: &[u8],
+    fn send_bytes_with_timeout<'fut>(
+        &'fut mut self,
+        buf: &'fut [u8],
         timeout: Duration,
-    ) -> impl Future<Output = Result<usize>> {
+    ) -> impl Future<Output = Result<usize>> + 'fut {
         SendBytesWithDeadline::new(
             AsRawSocket::as_raw_socket(self),
             buf,
             local_executor().start_round_time_for_deadlines() + timeout,
         )
+        .with_stats(self.stats())
     }
 
     /// Asynchronously sends the provided [`Buffer`] with a specified timeout.
@@ -559,6 +641,7 @@ pub trait AsyncSend: Socket {
                     buf.len_u32() - sent,
                     buf.fixed_index(),
                 )
+                .with_stats(self.stats())
                 .await?;
             }
         } else {
@@ -662,6 +745,7 @@ pub trait AsyncSend: Socket {
                     buf.fixed_index(),
                     deadline,
                 )
+                .with_stats(self.stats())
                 .await?;
             }
         } else {
@@ -759,3 +843,117 @@ pub trait AsyncSend: Socket {
         )
     }
 }
+
+/// `RatedSend` wraps an [`AsyncSend`] socket and measures its sending throughput
+/// with a [`RateMeter`].
+///
+/// Every completed operation is recorded with the number of sent bytes.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::net::TcpStream;
+/// use orengine::io::{AsyncConnectStream, RatedSend};
+/// use std::time::Duration;
+///
+/// # async fn foo() -> std::io::Result<()> {
+/// let stream = TcpStream::connect("127.0.0.1:8080").await?;
+/// let mut stream = RatedSend::new(stream, Duration::from_secs(5));
+///
+/// stream.send_all_bytes(b"Hello, World!").await?;
+/// println!("{:.0} B/s", stream.meter().rate_bytes_per_sec());
+/// # Ok(())
+/// # }
+/// ```
+pub struct RatedSend<S: AsyncSend> {
+    socket: S,
+    meter: RateMeter,
+}
+
+impl<S: AsyncSend> RatedSend<S> {
+    /// Wraps the `socket` and creates a [`RateMeter`] with the given averaging `window`.
+    ///
+    /// # Panics
+    ///
+    /// If `window` is zero.
+    pub fn new(socket: S, window: Duration) -> Self {
+        Self {
+            socket,
+            meter: RateMeter::new(window),
+        }
+    }
+
+    /// Returns the [`RateMeter`] of the sending throughput.
+    pub const fn meter(&self) -> &RateMeter {
+        &self.meter
+    }
+
+    /// Returns a reference to the wrapped socket.
+    pub const fn get_ref(&self) -> &S {
+        &self.socket
+    }
+
+    /// Returns a mutable reference to the wrapped socket.
+    ///
+    /// Operations executed via it are not recorded.
+    pub const fn get_mut(&mut self) -> &mut S {
+        &mut self.socket
+    }
+
+    /// Returns the wrapped socket.
+    pub fn into_inner(self) -> S {
+        self.socket
+    }
+
+    /// Sends the provided byte slice like [`AsyncSend::send_bytes`] and records it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [`AsyncSend::send_bytes`].
+    #[allow(clippy::future_not_send, reason = "Because it is `local`")]
+    pub async fn send_bytes(&mut self, buf: &[u8]) -> Result<usize> {
+        let sent = self.socket.send_bytes(buf).await?;
+        self.meter.record(sent);
+
+        Ok(sent)
+    }
+
+    /// Sends the provided [`Buffer`] like [`AsyncSend::send`] and records it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [`AsyncSend::send`].
+    #[allow(clippy::future_not_send, reason = "Because it is `local`")]
+    pub async fn send(&mut self, buf: &impl FixedBuffer) -> Result<u32> {
+        let sent = self.socket.send(buf).await?;
+        self.meter.record(sent as usize);
+
+        Ok(sent)
+    }
+
+    /// Sends the entire provided byte slice like [`AsyncSend::send_all_bytes`] and records it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [`AsyncSend::send_all_bytes`].
+    #[allow(clippy::future_not_send, reason = "Because it is `local`")]
+    pub async fn send_all_bytes(&mut self, buf: &[u8]) -> Result<()> {
+        self.socket.send_all_bytes(buf).await?;
+        self.meter.record(buf.len());
+
+        Ok(())
+    }
+
+    /// Sends the entire provided [`Buffer`] like [`AsyncSend::send_all`] and records it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [`AsyncSend::send_all`].
+    #[allow(clippy::future_not_send, reason = "Because it is `local`")]
+    pub async fn send_all(&mut self, buf: &impl FixedBuffer) -> Result<()> {
+        self.socket.send_all(buf).await?;
+        self.meter.record(buf.len_u32() as usize);
+
+        Ok(())
+    }
+}
@@ -5,7 +5,7 @@ use crate::io::worker::{local_worker, IoWorker};
 use crate::io::FixedBuffer;
 use crate::local_executor;
 use crate::net::addr::{FromSockAddr, IntoSockAddr, ToSockAddrs};
-use crate::net::Socket;
+use crate::net::{Socket, SocketStats};
 use orengine_macros::{poll_for_io_request, poll_for_time_bounded_io_request};
 use socket2::SockAddr;
 use std::future::Future;
@@ -20,6 +20,7 @@ use std::time::{Duration, Instant};
 #[repr(C)]
 pub struct SendTo<'fut> {
     raw_socket: RawSocket,
+    stats: Option<&'fut SocketStats>,
     message_header: MessageSendHeader,
     bufs: &'fut [IoSlice<'fut>],
     addr: &'fut SockAddr,
@@ -31,12 +32,21 @@ impl<'fut> SendTo<'fut> {
     pub fn new(raw_socket: RawSocket, bufs: &'fut [IoSlice<'fut>], addr: &'fut SockAddr) -> Self {
         Self {
             raw_socket,
+            stats: None,
             message_header: MessageSendHeader::new(),
             bufs,
             addr,
             io_request_data: None,
         }
     }
+
+    /// Sets the [`SocketStats`] that are updated after the operation is completed.
+    #[must_use]
+    pub fn with_stats(mut self, stats: Option<&'fut SocketStats>) -> Self {
+        self.stats = stats;
+
+        self
+    }
 }
 
 impl Future for SendTo<'_> {
@@ -54,7 +64,15 @@ impl Future for SendTo<'_> {
             local_worker().send_to(this.raw_socket, os_message_header_ptr, unsafe {
                 IoRequestDataPtr::new(this.io_request_data.as_mut().unwrap_unchecked())
             }),
-            ret
+            {
+                if let Some(stats) = this.stats {
+                    stats.add_bytes_sent(ret);
+                }
+                ret
+            },
+            if let Some(stats) = this.stats {
+                stats.inc_send_errors();
+            }
         ));
     }
 }
@@ -69,6 +87,7 @@ unsafe impl Send for SendTo<'_> {}
 #[repr(C)]
 pub struct SendToWithDeadline<'fut> {
     raw_socket: RawSocket,
+    stats: Option<&'fut SocketStats>,
     message_header: MessageSendHeader,
     bufs: &'fut [IoSlice<'fut>],
     addr: &'fut SockAddr,
@@ -87,6 +106,7 @@ impl<'fut> SendToWithDeadline<'fut> {
     ) -> Self {
         Self {
             raw_socket,
+            stats: None,
             message_header: MessageSendHeader::new(),
             bufs,
             addr,
@@ -95,6 +115,14 @@ impl<'fut> SendToWithDeadline<'fut> {
             phantom_data: PhantomData,
         }
     }
+
+    /// Sets the [`SocketStats`] that are updated after the operation is completed.
+    #[must_use]
+    pub fn with_stats(mut self, stats: Option<&'fut SocketStats>) -> Self {
+        self.stats = stats;
+
+        self
+    }
 }
 
 impl Future for SendToWithDeadline<'_> {
@@ -117,7 +145,15 @@ impl Future for SendToWithDeadline<'_> {
                 unsafe { IoRequestDataPtr::new(this.io_request_data.as_mut().unwrap_unchecked()) },
                 &mut this.deadline
             ),
-            ret
+            {
+                if let Some(stats) = this.stats {
+                    stats.add_bytes_sent(ret);
+                }
+                ret
+            },
+            if let Some(stats) = this.stats {
+                stats.inc_send_errors();
+            }
         ));
     }
 }
@@ -209,6 +245,7 @@ pub trait AsyncSendTo: Socket {
             bufs_ptr,
             &sock_addr_from_to_socket_addr(&addr)?,
         )
+        .with_stats(self.stats())
         .await
     }
 
@@ -288,6 +325,7 @@ pub trait AsyncSendTo: Socket {
             &sock_addr_from_to_socket_addr(&addr)?,
             deadline,
         )
+        .with_stats(self.stats())
         .await
     }
 
@@ -375,6 +413,7 @@ pub trait AsyncSendTo: Socket {
             &sock_addr_from_to_socket_addr(&addr)?,
             local_executor().start_round_time_for_deadlines() + timeout,
         )
+        .with_stats(self.stats())
         .await
     }
 
@@ -455,7 +494,9 @@ pub trait AsyncSendTo: Socket {
         while sent < buf.len() {
             let bufs_ptr = &[IoSlice::new(&buf[sent..])];
 
-            sent += SendTo::new(AsRawSocket::as_raw_socket(self), bufs_ptr, &addr).await?;
+            sent += SendTo::new(AsRawSocket::as_raw_socket(self), bufs_ptr, &addr)
+                .with_stats(self.stats())
+                .await?;
         }
 
         Ok(sent)
@@ -544,6 +585,7 @@ pub trait AsyncSendTo: Socket {
                 &addr,
                 deadline,
             )
+            .with_stats(self.stats())
             .await?;
         }
 
@@ -96,4 +96,44 @@ pub trait AsyncShutdown: Socket {
     fn shutdown(&mut self, how: ShutdownHow) -> impl Future<Output = Result<()>> {
         Shutdown::new(AsRawSocket::as_raw_socket(self), how)
     }
+
+    /// Shuts down the writing half of the connection (half-close).
+    ///
+    /// The peer receives `EOF` after all sent data, and the connection can still be used
+    /// for reading, for example, to receive a response. Sending after it fails with
+    /// [`BrokenPipe`](std::io::ErrorKind::BrokenPipe).
+    ///
+    /// It is the same as `shutdown(Shutdown::Write)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::net::TcpStream;
+    /// use orengine::io::{AsyncConnectStream, AsyncRecv, AsyncSend, AsyncShutdown};
+    ///
+    /// # async fn foo() -> std::io::Result<()> {
+    /// let mut stream = TcpStream::connect("127.0.0.1:8080").await?;
+    ///
+    /// stream.send_all_bytes(b"request").await?;
+    /// stream.shutdown_write().await?; // signal the end of the request
+    ///
+    /// let mut response = [0u8; 1024];
+    /// let n = stream.recv_bytes(&mut response).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn shutdown_write(&mut self) -> impl Future<Output = Result<()>> {
+        self.shutdown(ShutdownHow::Write)
+    }
+
+    /// Shuts down the reading half of the connection.
+    ///
+    /// Receiving after it returns `0` bytes. The connection can still be used for writing.
+    ///
+    /// It is the same as `shutdown(Shutdown::Read)`.
+    #[inline]
+    fn shutdown_read(&mut self) -> impl Future<Output = Result<()>> {
+        self.shutdown(ShutdownHow::Read)
+    }
 }
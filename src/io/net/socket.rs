@@ -46,3 +46,84 @@ impl Future for Socket {
 }
 
 unsafe impl Send for Socket {}
+
+/// Creates an unnamed pair of connected sockets with the provided `domain` and `sock_type`
+/// and returns their [`raw sockets`](RawSocket).
+///
+/// It is the standard mechanism for in-process bidirectional communication.
+/// Convert the raw sockets into [`UnixStream`](crate::net::UnixStream)
+/// (or another socket type that matches `sock_type`) to split them across tasks.
+///
+/// It doesn't block, so it is executed synchronously.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::io::{socketpair, AsyncRecv, AsyncSend};
+/// use orengine::net::UnixStream;
+/// use socket2::{Domain, Type};
+/// use std::os::fd::FromRawFd;
+///
+/// # async fn foo() -> std::io::Result<()> {
+/// let (first, second) = socketpair(Domain::UNIX, Type::STREAM)?;
+/// let (mut first, mut second) = unsafe {
+///     (UnixStream::from_raw_fd(first), UnixStream::from_raw_fd(second))
+/// };
+///
+/// first.send_all_bytes(b"ping").await?;
+/// let mut buf = [0u8; 4];
+/// second.recv_bytes_exact(&mut buf).await?;
+/// assert_eq!(&buf, b"ping");
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(unix)]
+pub fn socketpair(domain: Domain, sock_type: Type) -> std::io::Result<(RawSocket, RawSocket)> {
+    use std::os::fd::IntoRawFd;
+
+    let (first, second) = socket2::Socket::pair(domain, sock_type, None)?;
+
+    Ok((first.into_raw_fd(), second.into_raw_fd()))
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::io::{AsyncRecv, AsyncSend};
+    use crate::net::UnixStream;
+    use std::os::fd::FromRawFd;
+
+    #[orengine::test::test_local]
+    fn test_socketpair() {
+        let (first, second) =
+            socketpair(Domain::UNIX, Type::STREAM).expect("socketpair call failed");
+        let (mut first, mut second) = unsafe {
+            (
+                UnixStream::from_raw_fd(first),
+                UnixStream::from_raw_fd(second),
+            )
+        };
+
+        first
+            .send_all_bytes(b"ping")
+            .await
+            .expect("send call failed");
+        let mut buf = [0u8; 4];
+        second
+            .recv_bytes_exact(&mut buf)
+            .await
+            .expect("recv call failed");
+        assert_eq!(&buf, b"ping");
+
+        second
+            .send_all_bytes(b"pong")
+            .await
+            .expect("send call failed");
+        first
+            .recv_bytes_exact(&mut buf)
+            .await
+            .expect("recv call failed");
+        assert_eq!(&buf, b"pong");
+    }
+}
@@ -0,0 +1,134 @@
+//! This module contains [`AsyncStdin`], [`AsyncStdout`] and [`AsyncStderr`] and functions
+//! to get them: [`stdin`], [`stdout`] and [`stderr`].
+//!
+//! Unlike [`std::io::Stdout`], [`AsyncStdout`] is not buffered and not synchronized with
+//! [`println!`], so mixing them can reorder the output.
+use crate::io::sys::AsRawFile;
+use crate::io::{AsyncRead, AsyncWrite};
+
+/// Generates a standard stream struct with `AsRawFile` implementation
+/// for provided `std` stream.
+macro_rules! generate_stdio {
+    ($name:ident, $std_stream:path, $doc:literal) => {
+        #[doc = $doc]
+        #[derive(Copy, Clone, Debug, Default)]
+        pub struct $name {
+            _private: (),
+        }
+
+        #[cfg(unix)]
+        impl std::os::fd::AsRawFd for $name {
+            fn as_raw_fd(&self) -> std::os::fd::RawFd {
+                std::os::fd::AsRawFd::as_raw_fd(&$std_stream())
+            }
+        }
+
+        #[cfg(windows)]
+        impl std::os::windows::io::AsRawHandle for $name {
+            fn as_raw_handle(&self) -> std::os::windows::io::RawHandle {
+                std::os::windows::io::AsRawHandle::as_raw_handle(&$std_stream())
+            }
+        }
+
+        impl AsRawFile for $name {}
+    };
+}
+
+generate_stdio!(
+    AsyncStdin,
+    std::io::stdin,
+    "An asynchronous handle to the standard input of the current process.\n\n\
+    It implements [`AsyncRead`]. Create it with [`stdin`]."
+);
+
+generate_stdio!(
+    AsyncStdout,
+    std::io::stdout,
+    "An asynchronous handle to the standard output of the current process.\n\n\
+    It implements [`AsyncWrite`]. Create it with [`stdout`]."
+);
+
+generate_stdio!(
+    AsyncStderr,
+    std::io::stderr,
+    "An asynchronous handle to the standard error of the current process.\n\n\
+    It implements [`AsyncWrite`]. Create it with [`stderr`]."
+);
+
+impl AsyncRead for AsyncStdin {}
+
+impl AsyncWrite for AsyncStdout {}
+
+impl AsyncWrite for AsyncStderr {}
+
+/// Returns a new [`AsyncStdin`] handle to the standard input of the current process.
+///
+/// # Example
+///
+/// ```no_run
+/// use orengine::io::{stdin, AsyncRead};
+///
+/// # async fn foo() -> std::io::Result<()> {
+/// let mut buf = [0u8; 1024];
+/// let n = stdin().read_bytes(&mut buf).await?;
+/// println!("read {n} bytes from stdin");
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub const fn stdin() -> AsyncStdin {
+    AsyncStdin { _private: () }
+}
+
+/// Returns a new [`AsyncStdout`] handle to the standard output of the current process.
+///
+/// # Example
+///
+/// ```no_run
+/// use orengine::io::{stdout, AsyncWrite};
+///
+/// # async fn foo() -> std::io::Result<()> {
+/// stdout().write_all_bytes(b"Hello, world!\n").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub const fn stdout() -> AsyncStdout {
+    AsyncStdout { _private: () }
+}
+
+/// Returns a new [`AsyncStderr`] handle to the standard error of the current process.
+///
+/// # Example
+///
+/// ```no_run
+/// use orengine::io::{stderr, AsyncWrite};
+///
+/// # async fn foo() -> std::io::Result<()> {
+/// stderr().write_all_bytes(b"Something went wrong\n").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub const fn stderr() -> AsyncStderr {
+    AsyncStderr { _private: () }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+
+    #[cfg(unix)]
+    #[orengine::test::test_local]
+    fn test_stdio_raw_files() {
+        assert_eq!(stdin().as_raw_file(), libc::STDIN_FILENO);
+        assert_eq!(stdout().as_raw_file(), libc::STDOUT_FILENO);
+        assert_eq!(stderr().as_raw_file(), libc::STDERR_FILENO);
+    }
+
+    #[orengine::test::test_local]
+    fn test_stderr_write() {
+        assert_eq!(stderr().write_bytes(b"\n").await.expect("write failed"), 1);
+    }
+}
@@ -46,6 +46,8 @@ pub(crate) enum IoCall {
     #[cfg(feature = "fallback_thread_pool")]
     FDataSync(RawFile),
     #[cfg(feature = "fallback_thread_pool")]
+    FTruncate(RawFile, u64),
+    #[cfg(feature = "fallback_thread_pool")]
     Read(RawFile, *mut u8, u32),
     #[cfg(feature = "fallback_thread_pool")]
     PRead(RawFile, *mut u8, u32, u64),
@@ -65,6 +67,10 @@ pub(crate) enum IoCall {
     RemoveDir(OsPathPtr),
     #[cfg(feature = "fallback_thread_pool")]
     RemoveFile(OsPathPtr),
+    #[cfg(feature = "fallback_thread_pool")]
+    Symlink(OsPathPtr, OsPathPtr),
+    #[cfg(all(unix, feature = "fallback_thread_pool"))]
+    LinkAt(RawFile, OsPathPtr, RawFile, OsPathPtr, i32),
 }
 
 impl IoCall {
@@ -156,6 +162,9 @@ impl IoCall {
             #[cfg(feature = "fallback_thread_pool")]
             Self::FDataSync(file) => operations::fsync_data_op(file),
 
+            #[cfg(feature = "fallback_thread_pool")]
+            Self::FTruncate(file, len) => operations::ftruncate_op(file, len),
+
             #[cfg(feature = "fallback_thread_pool")]
             Self::Read(file, buf_ptr, buf_len) => operations::read_op(file, buf_ptr, buf_len),
 
@@ -197,6 +206,14 @@ impl IoCall {
 
             #[cfg(feature = "fallback_thread_pool")]
             Self::RemoveFile(path_ptr) => operations::unlink_op(path_ptr),
+
+            #[cfg(feature = "fallback_thread_pool")]
+            Self::Symlink(target, link_path) => operations::symlink_op(target, link_path),
+
+            #[cfg(all(unix, feature = "fallback_thread_pool"))]
+            Self::LinkAt(old_dir, old_path, new_dir, new_path, flags) => {
+                operations::linkat_op(old_dir, old_path, new_dir, new_path, flags)
+            }
         }
     }
 
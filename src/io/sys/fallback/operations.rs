@@ -211,6 +211,11 @@ pub(crate) fn fsync_data_op(raw_file: RawFile) -> io::Result<usize> {
     with_file(raw_file, |file| file.sync_data().map(|()| 0))
 }
 
+/// Truncates or extends a file to the provided length.
+pub(crate) fn ftruncate_op(raw_file: RawFile, len: u64) -> io::Result<usize> {
+    with_file(raw_file, |file| file.set_len(len).map(|()| 0))
+}
+
 /// Reads data from a file.
 pub(crate) fn read_op(raw_file: RawFile, buf_ptr: *mut u8, buf_len: u32) -> io::Result<usize> {
     with_file(raw_file, |file| {
@@ -301,3 +306,51 @@ pub(crate) fn unlink_op(path_ptr: OsPathPtr) -> io::Result<usize> {
 
     std::fs::remove_file(path).map(|()| 0)
 }
+
+/// Creates a symbolic link at `link_path_ptr` which points to `target_ptr`.
+pub(crate) fn symlink_op(target_ptr: OsPathPtr, link_path_ptr: OsPathPtr) -> io::Result<usize> {
+    let target = unsafe { &*target_ptr };
+    let link_path = unsafe { &*link_path_ptr };
+
+    #[cfg(unix)]
+    let res = std::os::unix::fs::symlink(target, link_path);
+    #[cfg(windows)]
+    let res = std::os::windows::fs::symlink_file(target, link_path);
+
+    res.map(|()| 0)
+}
+
+/// Creates a new hard link `new_path_ptr` relative to `new_dir` for `old_path_ptr`
+/// relative to `old_dir`.
+#[cfg(unix)]
+pub(crate) fn linkat_op(
+    old_dir: RawFile,
+    old_path_ptr: OsPathPtr,
+    new_dir: RawFile,
+    new_path_ptr: OsPathPtr,
+    flags: i32,
+) -> io::Result<usize> {
+    use std::os::unix::ffi::OsStrExt;
+
+    let to_c_string = |path_ptr: OsPathPtr| {
+        std::ffi::CString::new(unsafe { &*path_ptr }.as_os_str().as_bytes())
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))
+    };
+    let old_path = to_c_string(old_path_ptr)?;
+    let new_path = to_c_string(new_path_ptr)?;
+
+    let ret = unsafe {
+        libc::linkat(
+            old_dir,
+            old_path.as_ptr(),
+            new_dir,
+            new_path.as_ptr(),
+            flags,
+        )
+    };
+    if ret == -1 {
+        return Err(io::Error::last_os_error());
+    }
+
+    Ok(0)
+}
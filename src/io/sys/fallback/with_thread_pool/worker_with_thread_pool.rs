@@ -1,4 +1,4 @@
-use crate::io::io_request_data::IoRequestDataPtr;
+use crate::io::io_request_data::{trace_io_dispatch, IoRequestDataPtr};
 use crate::io::sys::fallback::io_call::IoCall;
 use crate::io::sys::fallback::mio_poller::MioPoller;
 use crate::io::sys::{
@@ -104,7 +104,10 @@ impl ThreadWorker {
 }
 
 /// A fallback implementation of [`IoWorker`] that uses a thread pool.
-pub(crate) struct FallbackWorker {
+///
+/// It is `pub`, but unreachable, because it is the inner worker of the public
+/// `FaultIoWorker` with the `fault_injection` feature.
+pub struct FallbackWorker {
     number_of_active_tasks: usize,
     workers: Box<[crossbeam::channel::Sender<(IoCall, IoRequestDataPtr)>]>,
     completions: Arc<SyncWorkerResultList>,
@@ -428,17 +431,27 @@ impl IoWorker for FallbackWorker {
     }
 
     #[inline]
-    fn must_poll(&mut self, mut timeout_option: Option<Duration>) {
+    fn number_of_active_tasks(&self) -> usize {
+        self.number_of_active_tasks
+    }
+
+    #[inline]
+    fn must_poll(&mut self, timeout_option: Option<Duration>) {
         if self.number_of_active_tasks == 0 {
             return;
         }
 
-        if timeout_option.is_none() {
-            timeout_option = Some(Duration::from_nanos(0));
-        }
-
-        let mut timeout = timeout_option.unwrap();
         let mut diff = Duration::from_micros(16);
+        let Some(mut timeout) = timeout_option else {
+            // Completions of the thread workers don't wake the poller up,
+            // so it polls with growing timeouts until the state of io operations changes.
+            let number_of_active_tasks = self.number_of_active_tasks;
+            while !self.must_poll_(diff) && self.number_of_active_tasks == number_of_active_tasks {
+                diff = (diff * 2).min(Duration::from_millis(10));
+            }
+
+            return;
+        };
 
         if !self.must_poll_(timeout.min(diff)) {
             timeout = timeout.checked_sub(diff).unwrap_or(Duration::from_nanos(0));
@@ -465,6 +478,7 @@ impl IoWorker for FallbackWorker {
         protocol: Protocol,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "socket");
         self.push_to_worker_pool(IoCall::Socket(domain, sock_type, protocol), request_ptr);
     }
 
@@ -476,6 +490,7 @@ impl IoWorker for FallbackWorker {
         addr_len: *mut sys::socklen_t,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "accept", socket = raw_socket);
         self.push_to_worker_pool(IoCall::Accept(raw_socket, addr_ptr, addr_len), request_ptr);
     }
 
@@ -488,6 +503,7 @@ impl IoWorker for FallbackWorker {
         request_ptr: IoRequestDataPtr,
         deadline: &mut Instant,
     ) {
+        trace_io_dispatch!(request_ptr, "accept_with_deadline", socket = raw_socket);
         self.push_to_worker_pool_with_deadline(
             IoCall::AcceptWithDeadline(raw_socket, addr_ptr, addr_len, deadline),
             request_ptr,
@@ -502,6 +518,7 @@ impl IoWorker for FallbackWorker {
         addr_len: sys::socklen_t,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "connect", socket = raw_socket);
         self.push_to_worker_pool(IoCall::Connect(raw_socket, addr_ptr, addr_len), request_ptr);
     }
 
@@ -514,6 +531,7 @@ impl IoWorker for FallbackWorker {
         request_ptr: IoRequestDataPtr,
         deadline: &mut Instant,
     ) {
+        trace_io_dispatch!(request_ptr, "connect_with_deadline", socket = raw_socket);
         self.push_to_worker_pool_with_deadline(
             IoCall::ConnectWithDeadline(raw_socket, addr_ptr, addr_len, deadline),
             request_ptr,
@@ -522,6 +540,7 @@ impl IoWorker for FallbackWorker {
 
     #[inline]
     fn poll_socket_read(&mut self, raw_socket: RawSocket, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "poll_socket_read", socket = raw_socket);
         self.number_of_active_tasks += 1;
         self.poller.register(
             Interest::READABLE,
@@ -536,6 +555,11 @@ impl IoWorker for FallbackWorker {
         request_ptr: IoRequestDataPtr,
         deadline: &mut Instant,
     ) {
+        trace_io_dispatch!(
+            request_ptr,
+            "poll_socket_read_with_deadline",
+            socket = raw_socket
+        );
         check_deadline_and!(self, *deadline, request_ptr, {
             self.number_of_active_tasks += 1;
             let slot_ptr = self.poller.register(
@@ -551,6 +575,7 @@ impl IoWorker for FallbackWorker {
 
     #[inline]
     fn poll_socket_write(&mut self, raw_socket: RawSocket, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "poll_socket_write", socket = raw_socket);
         self.number_of_active_tasks += 1;
         self.poller.register(
             Interest::WRITABLE,
@@ -565,6 +590,11 @@ impl IoWorker for FallbackWorker {
         request_ptr: IoRequestDataPtr,
         deadline: &mut Instant,
     ) {
+        trace_io_dispatch!(
+            request_ptr,
+            "poll_socket_write_with_deadline",
+            socket = raw_socket
+        );
         check_deadline_and!(self, *deadline, request_ptr, {
             self.number_of_active_tasks += 1;
             let slot_ptr = self.poller.register(
@@ -586,6 +616,7 @@ impl IoWorker for FallbackWorker {
         len: u32,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "recv", socket = raw_socket, len = len);
         self.push_to_worker_pool(IoCall::Recv(raw_socket, ptr, len), request_ptr);
     }
 
@@ -598,6 +629,7 @@ impl IoWorker for FallbackWorker {
         _buf_index: u16,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "recv_fixed", socket = raw_socket, len = len);
         self.push_to_worker_pool(IoCall::Recv(raw_socket, ptr, len), request_ptr);
     }
 
@@ -610,6 +642,12 @@ impl IoWorker for FallbackWorker {
         request_ptr: IoRequestDataPtr,
         deadline: &mut Instant,
     ) {
+        trace_io_dispatch!(
+            request_ptr,
+            "recv_with_deadline",
+            socket = raw_socket,
+            len = len
+        );
         self.push_to_worker_pool_with_deadline(
             IoCall::RecvWithDeadline(raw_socket, ptr, len, deadline),
             request_ptr,
@@ -626,6 +664,12 @@ impl IoWorker for FallbackWorker {
         request_ptr: IoRequestDataPtr,
         deadline: &mut Instant,
     ) {
+        trace_io_dispatch!(
+            request_ptr,
+            "recv_fixed_with_deadline",
+            socket = raw_socket,
+            len = len
+        );
         self.push_to_worker_pool_with_deadline(
             IoCall::RecvWithDeadline(raw_socket, ptr, len, deadline),
             request_ptr,
@@ -639,6 +683,7 @@ impl IoWorker for FallbackWorker {
         msg_header: &mut MessageRecvHeader,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "recv_from", socket = raw_socket);
         self.push_to_worker_pool(IoCall::RecvFrom(raw_socket, msg_header), request_ptr);
     }
 
@@ -650,6 +695,7 @@ impl IoWorker for FallbackWorker {
         request_ptr: IoRequestDataPtr,
         deadline: &mut Instant,
     ) {
+        trace_io_dispatch!(request_ptr, "recv_from_with_deadline", socket = raw_socket);
         self.push_to_worker_pool_with_deadline(
             IoCall::RecvFromWithDeadline(raw_socket, msg_header, deadline),
             request_ptr,
@@ -664,6 +710,7 @@ impl IoWorker for FallbackWorker {
         len: u32,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "send", socket = raw_socket, len = len);
         self.push_to_worker_pool(IoCall::Send(raw_socket, ptr, len), request_ptr);
     }
 
@@ -676,6 +723,7 @@ impl IoWorker for FallbackWorker {
         _buf_index: u16,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "send_fixed", socket = raw_socket, len = len);
         self.push_to_worker_pool(IoCall::Send(raw_socket, ptr, len), request_ptr);
     }
 
@@ -688,6 +736,12 @@ impl IoWorker for FallbackWorker {
         request_ptr: IoRequestDataPtr,
         deadline: &mut Instant,
     ) {
+        trace_io_dispatch!(
+            request_ptr,
+            "send_with_deadline",
+            socket = raw_socket,
+            len = len
+        );
         self.push_to_worker_pool_with_deadline(
             IoCall::SendWithDeadline(raw_socket, ptr, len, deadline),
             request_ptr,
@@ -704,6 +758,12 @@ impl IoWorker for FallbackWorker {
         request_ptr: IoRequestDataPtr,
         deadline: &mut Instant,
     ) {
+        trace_io_dispatch!(
+            request_ptr,
+            "send_fixed_with_deadline",
+            socket = raw_socket,
+            len = len
+        );
         self.push_to_worker_pool_with_deadline(
             IoCall::SendWithDeadline(raw_socket, ptr, len, deadline),
             request_ptr,
@@ -717,6 +777,7 @@ impl IoWorker for FallbackWorker {
         msg_header: *const OsMessageHeader,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "send_to", socket = raw_socket);
         self.push_to_worker_pool(IoCall::SendTo(raw_socket, msg_header), request_ptr);
     }
 
@@ -728,6 +789,7 @@ impl IoWorker for FallbackWorker {
         request_ptr: IoRequestDataPtr,
         deadline: &mut Instant,
     ) {
+        trace_io_dispatch!(request_ptr, "send_to_with_deadline", socket = raw_socket);
         self.push_to_worker_pool_with_deadline(
             IoCall::SendToWithDeadline(raw_socket, msg_header, deadline),
             request_ptr,
@@ -742,6 +804,7 @@ impl IoWorker for FallbackWorker {
         len: u32,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "peek", socket = raw_socket, len = len);
         self.push_to_worker_pool(IoCall::Peek(raw_socket, ptr, len), request_ptr);
     }
 
@@ -754,6 +817,7 @@ impl IoWorker for FallbackWorker {
         _buf_index: u16,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "peek_fixed", socket = raw_socket, len = len);
         self.push_to_worker_pool(IoCall::Peek(raw_socket, ptr, len), request_ptr);
     }
 
@@ -766,6 +830,12 @@ impl IoWorker for FallbackWorker {
         request_ptr: IoRequestDataPtr,
         deadline: &mut Instant,
     ) {
+        trace_io_dispatch!(
+            request_ptr,
+            "peek_with_deadline",
+            socket = raw_socket,
+            len = len
+        );
         self.push_to_worker_pool_with_deadline(
             IoCall::PeekWithDeadline(raw_socket, ptr, len, deadline),
             request_ptr,
@@ -782,6 +852,12 @@ impl IoWorker for FallbackWorker {
         request_ptr: IoRequestDataPtr,
         deadline: &mut Instant,
     ) {
+        trace_io_dispatch!(
+            request_ptr,
+            "peek_fixed_with_deadline",
+            socket = raw_socket,
+            len = len
+        );
         self.push_to_worker_pool_with_deadline(
             IoCall::PeekWithDeadline(raw_socket, ptr, len, deadline),
             request_ptr,
@@ -795,6 +871,7 @@ impl IoWorker for FallbackWorker {
         msg: &mut MessageRecvHeader,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "peek_from", socket = raw_socket);
         self.push_to_worker_pool(IoCall::PeekFrom(raw_socket, msg), request_ptr);
     }
 
@@ -806,6 +883,7 @@ impl IoWorker for FallbackWorker {
         request_ptr: IoRequestDataPtr,
         deadline: &mut Instant,
     ) {
+        trace_io_dispatch!(request_ptr, "peek_from_with_deadline", socket = raw_socket);
         self.push_to_worker_pool_with_deadline(
             IoCall::PeekFromWithDeadline(raw_socket, msg, deadline),
             request_ptr,
@@ -814,6 +892,7 @@ impl IoWorker for FallbackWorker {
 
     #[inline]
     fn shutdown(&mut self, raw_socket: RawSocket, how: Shutdown, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "shutdown", socket = raw_socket);
         self.push_to_worker_pool(IoCall::Shutdown(raw_socket, how), request_ptr);
     }
 
@@ -824,6 +903,7 @@ impl IoWorker for FallbackWorker {
         open_how: *const OsOpenOptions,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "open");
         self.push_to_worker_pool(IoCall::Open(path, open_how), request_ptr);
     }
 
@@ -836,21 +916,44 @@ impl IoWorker for FallbackWorker {
         _flags: i32,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "fallocate");
+        self.push_to_worker_pool(IoCall::Fallocate, request_ptr);
+    }
+
+    #[inline]
+    fn fadvise(
+        &mut self,
+        _raw_file: RawFile,
+        _offset: u64,
+        _len: u64,
+        _advice: i32,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        trace_io_dispatch!(request_ptr, "fadvise");
         self.push_to_worker_pool(IoCall::Fallocate, request_ptr);
     }
 
     #[inline]
     fn sync_all(&mut self, raw_file: RawFile, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "sync_all", file = raw_file);
         self.push_to_worker_pool(IoCall::FAllSync(raw_file), request_ptr);
     }
 
     #[inline]
     fn sync_data(&mut self, raw_file: RawFile, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "sync_data", file = raw_file);
         self.push_to_worker_pool(IoCall::FDataSync(raw_file), request_ptr);
     }
 
+    #[inline]
+    fn truncate(&mut self, raw_file: RawFile, len: u64, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "truncate", file = raw_file, len = len);
+        self.push_to_worker_pool(IoCall::FTruncate(raw_file, len), request_ptr);
+    }
+
     #[inline]
     fn read(&mut self, raw_file: RawFile, ptr: *mut u8, len: u32, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "read", file = raw_file, len = len);
         self.push_to_worker_pool(IoCall::Read(raw_file, ptr, len), request_ptr);
     }
 
@@ -863,6 +966,7 @@ impl IoWorker for FallbackWorker {
         _buf_index: u16,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "read_fixed", file = raw_file, len = len);
         self.push_to_worker_pool(IoCall::Read(raw_file, ptr, len), request_ptr);
     }
 
@@ -875,6 +979,7 @@ impl IoWorker for FallbackWorker {
         offset: usize,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "pread", file = raw_file, len = len);
         self.push_to_worker_pool(
             IoCall::PRead(raw_file, ptr, len, offset as u64),
             request_ptr,
@@ -891,6 +996,7 @@ impl IoWorker for FallbackWorker {
         offset: usize,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "pread_fixed", file = raw_file, len = len);
         self.push_to_worker_pool(
             IoCall::PRead(raw_file, ptr, len, offset as u64),
             request_ptr,
@@ -905,6 +1011,7 @@ impl IoWorker for FallbackWorker {
         len: u32,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "write", file = raw_file, len = len);
         self.push_to_worker_pool(IoCall::Write(raw_file, ptr, len), request_ptr);
     }
 
@@ -917,6 +1024,7 @@ impl IoWorker for FallbackWorker {
         _buf_index: u16,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "write_fixed", file = raw_file, len = len);
         self.push_to_worker_pool(IoCall::Write(raw_file, ptr, len), request_ptr);
     }
 
@@ -929,6 +1037,7 @@ impl IoWorker for FallbackWorker {
         offset: usize,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "pwrite", file = raw_file, len = len);
         self.push_to_worker_pool(
             IoCall::PWrite(raw_file, ptr, len, offset as u64),
             request_ptr,
@@ -945,6 +1054,7 @@ impl IoWorker for FallbackWorker {
         offset: usize,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "pwrite_fixed", file = raw_file, len = len);
         self.push_to_worker_pool(
             IoCall::PWrite(raw_file, ptr, len, offset as u64),
             request_ptr,
@@ -953,31 +1063,61 @@ impl IoWorker for FallbackWorker {
 
     #[inline]
     fn close_file(&mut self, raw_file: RawFile, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "close_file", file = raw_file);
         self.push_to_worker_pool(IoCall::CloseFile(raw_file), request_ptr);
     }
 
     #[inline]
     fn close_socket(&mut self, raw_socket: RawSocket, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "close_socket", socket = raw_socket);
         self.push_to_worker_pool(IoCall::CloseSocket(raw_socket), request_ptr);
     }
 
     #[inline]
     fn rename(&mut self, old_path: OsPathPtr, new_path: OsPathPtr, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "rename");
         self.push_to_worker_pool(IoCall::Rename(old_path, new_path), request_ptr);
     }
 
     #[inline]
     fn create_dir(&mut self, path: OsPathPtr, mode: u32, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "create_dir");
         self.push_to_worker_pool(IoCall::CreateDir(path, mode), request_ptr);
     }
 
     #[inline]
     fn remove_file(&mut self, path: OsPathPtr, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "remove_file");
         self.push_to_worker_pool(IoCall::RemoveFile(path), request_ptr);
     }
 
     #[inline]
     fn remove_dir(&mut self, path: OsPathPtr, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "remove_dir");
         self.push_to_worker_pool(IoCall::RemoveDir(path), request_ptr);
     }
+
+    #[inline]
+    fn symlink(&mut self, target: OsPathPtr, link_path: OsPathPtr, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "symlink");
+        self.push_to_worker_pool(IoCall::Symlink(target, link_path), request_ptr);
+    }
+
+    #[inline]
+    #[cfg(unix)]
+    fn linkat(
+        &mut self,
+        old_dir: RawFile,
+        old_path: OsPathPtr,
+        new_dir: RawFile,
+        new_path: OsPathPtr,
+        flags: i32,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        trace_io_dispatch!(request_ptr, "linkat");
+        self.push_to_worker_pool(
+            IoCall::LinkAt(old_dir, old_path, new_dir, new_path, flags),
+            request_ptr,
+        );
+    }
 }
@@ -1,10 +1,13 @@
 use crate::bug_message::BUG_MESSAGE;
-use crate::io::io_request_data::IoRequestDataPtr;
+use crate::io::io_request_data::{trace_io_dispatch, IoRequestDataPtr};
 use crate::io::sys::fallback::io_call::IoCall;
 use crate::io::sys::fallback::mio_poller::MioPoller;
+#[cfg(unix)]
+use crate::io::sys::fallback::operations::linkat_op;
 use crate::io::sys::fallback::operations::{
-    close_file_op, close_socket_op, fsync_data_op, fsync_op, mkdir_op, open_op, read_at_op,
-    read_op, rename_op, rmdir_op, shutdown_op, socket_op, unlink_op, write_at_op, write_op,
+    close_file_op, close_socket_op, fsync_data_op, fsync_op, ftruncate_op, mkdir_op, open_op,
+    read_at_op, read_op, rename_op, rmdir_op, shutdown_op, socket_op, symlink_op, unlink_op,
+    write_at_op, write_op,
 };
 use crate::io::sys::{
     MessageRecvHeader, OsMessageHeader, OsOpenOptions, OsPathPtr, RawFile, RawSocket,
@@ -22,7 +25,10 @@ use std::net::Shutdown;
 use std::time::{Duration, Instant};
 
 /// A fallback implementation of [`IoWorker`] that uses a thread pool.
-pub(crate) struct FallbackWorker {
+///
+/// It is `pub`, but unreachable, because it is the inner worker of the public
+/// `FaultIoWorker` with the `fault_injection` feature.
+pub struct FallbackWorker {
     number_of_active_tasks: usize,
     poller: MioPoller,
     time_bounded_io_task_queue: BTreeSet<TimeBoundedIoTask>,
@@ -140,9 +146,9 @@ impl FallbackWorker {
     }
 
     /// Polls and processes all the polled requests. Returns if this function have done io work.  
-    fn poll_and_process(&mut self, timeout: Duration) {
+    fn poll_and_process(&mut self, timeout_option: Option<Duration>) {
         self.poller
-            .poll(Some(timeout), &mut self.polled_requests)
+            .poll(timeout_option, &mut self.polled_requests)
             .unwrap();
 
         let polled_requests_len = self.polled_requests.len();
@@ -355,8 +361,8 @@ impl FallbackWorker {
     }
 
     /// Returns if this function have done io work.
-    fn must_poll_(&mut self, timeout: Duration) {
-        self.poll_and_process(timeout);
+    fn must_poll_(&mut self, timeout_option: Option<Duration>) {
+        self.poll_and_process(timeout_option);
 
         self.check_deadlines();
     }
@@ -384,18 +390,28 @@ impl IoWorker for FallbackWorker {
     }
 
     #[inline]
-    fn must_poll(&mut self, mut timeout_option: Option<Duration>) {
+    fn number_of_active_tasks(&self) -> usize {
+        self.number_of_active_tasks
+    }
+
+    #[inline]
+    fn must_poll(&mut self, timeout_option: Option<Duration>) {
         if self.number_of_active_tasks == 0 {
             return;
         }
 
-        if timeout_option.is_none() {
-            timeout_option = Some(Duration::from_nanos(0));
-        }
+        // Time-bounded tasks are cancelled by `check_deadlines`, so the nearest deadline
+        // bounds the blocking.
+        let timeout_option = timeout_option.or_else(|| {
+            self.time_bounded_io_task_queue
+                .first()
+                .map(|task| task.deadline().saturating_duration_since(Instant::now()))
+        });
 
-        self.must_poll_(timeout_option.unwrap());
+        self.must_poll_(timeout_option);
     }
 
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn socket(
         &mut self,
         domain: Domain,
@@ -403,10 +419,12 @@ impl IoWorker for FallbackWorker {
         protocol: Protocol,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "socket");
         Self::handle_io_operation(move || socket_op(domain, sock_type, protocol), request_ptr);
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn accept(
         &mut self,
         raw_socket: RawSocket,
@@ -414,10 +432,12 @@ impl IoWorker for FallbackWorker {
         addr_len: *mut sys::socklen_t,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "accept", socket = raw_socket);
         self.handle_io_call(IoCall::Accept(raw_socket, addr_ptr, addr_len), request_ptr);
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn accept_with_deadline(
         &mut self,
         raw_socket: RawSocket,
@@ -426,6 +446,7 @@ impl IoWorker for FallbackWorker {
         request_ptr: IoRequestDataPtr,
         deadline: &mut Instant,
     ) {
+        trace_io_dispatch!(request_ptr, "accept_with_deadline", socket = raw_socket);
         check_deadline_and!(self, *deadline, request_ptr, {
             self.handle_io_call(
                 IoCall::AcceptWithDeadline(raw_socket, addr_ptr, addr_len, deadline),
@@ -435,6 +456,7 @@ impl IoWorker for FallbackWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn connect(
         &mut self,
         raw_socket: RawSocket,
@@ -442,10 +464,12 @@ impl IoWorker for FallbackWorker {
         addr_len: sys::socklen_t,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "connect", socket = raw_socket);
         self.handle_io_call(IoCall::Connect(raw_socket, addr_ptr, addr_len), request_ptr);
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn connect_with_deadline(
         &mut self,
         raw_socket: RawSocket,
@@ -454,6 +478,7 @@ impl IoWorker for FallbackWorker {
         request_ptr: IoRequestDataPtr,
         deadline: &mut Instant,
     ) {
+        trace_io_dispatch!(request_ptr, "connect_with_deadline", socket = raw_socket);
         check_deadline_and!(self, *deadline, request_ptr, {
             self.handle_io_call(
                 IoCall::ConnectWithDeadline(raw_socket, addr_ptr, addr_len, deadline),
@@ -463,7 +488,9 @@ impl IoWorker for FallbackWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn poll_socket_read(&mut self, raw_socket: RawSocket, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "poll_socket_read", socket = raw_socket);
         self.number_of_active_tasks += 1;
         self.poller.register(
             Interest::READABLE,
@@ -472,12 +499,18 @@ impl IoWorker for FallbackWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn poll_socket_read_with_deadline(
         &mut self,
         raw_socket: RawSocket,
         request_ptr: IoRequestDataPtr,
         deadline: &mut Instant,
     ) {
+        trace_io_dispatch!(
+            request_ptr,
+            "poll_socket_read_with_deadline",
+            socket = raw_socket
+        );
         check_deadline_and!(self, *deadline, request_ptr, {
             self.number_of_active_tasks += 1;
             let slot_ptr = self.poller.register(
@@ -492,7 +525,9 @@ impl IoWorker for FallbackWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn poll_socket_write(&mut self, raw_socket: RawSocket, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "poll_socket_write", socket = raw_socket);
         self.number_of_active_tasks += 1;
         self.poller.register(
             Interest::WRITABLE,
@@ -501,12 +536,18 @@ impl IoWorker for FallbackWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn poll_socket_write_with_deadline(
         &mut self,
         raw_socket: RawSocket,
         request_ptr: IoRequestDataPtr,
         deadline: &mut Instant,
     ) {
+        trace_io_dispatch!(
+            request_ptr,
+            "poll_socket_write_with_deadline",
+            socket = raw_socket
+        );
         check_deadline_and!(self, *deadline, request_ptr, {
             self.number_of_active_tasks += 1;
             let slot_ptr = self.poller.register(
@@ -521,6 +562,7 @@ impl IoWorker for FallbackWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn recv(
         &mut self,
         raw_socket: RawSocket,
@@ -528,10 +570,12 @@ impl IoWorker for FallbackWorker {
         len: u32,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "recv", socket = raw_socket, len = len);
         self.handle_io_call(IoCall::Recv(raw_socket, ptr, len), request_ptr);
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn recv_fixed(
         &mut self,
         raw_socket: RawSocket,
@@ -540,10 +584,12 @@ impl IoWorker for FallbackWorker {
         _buf_index: u16,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "recv_fixed", socket = raw_socket, len = len);
         self.handle_io_call(IoCall::Recv(raw_socket, ptr, len), request_ptr);
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn recv_with_deadline(
         &mut self,
         raw_socket: RawSocket,
@@ -552,6 +598,12 @@ impl IoWorker for FallbackWorker {
         request_ptr: IoRequestDataPtr,
         deadline: &mut Instant,
     ) {
+        trace_io_dispatch!(
+            request_ptr,
+            "recv_with_deadline",
+            socket = raw_socket,
+            len = len
+        );
         check_deadline_and!(self, *deadline, request_ptr, {
             self.handle_io_call(
                 IoCall::RecvWithDeadline(raw_socket, ptr, len, deadline),
@@ -561,6 +613,7 @@ impl IoWorker for FallbackWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn recv_fixed_with_deadline(
         &mut self,
         raw_socket: RawSocket,
@@ -570,6 +623,12 @@ impl IoWorker for FallbackWorker {
         request_ptr: IoRequestDataPtr,
         deadline: &mut Instant,
     ) {
+        trace_io_dispatch!(
+            request_ptr,
+            "recv_fixed_with_deadline",
+            socket = raw_socket,
+            len = len
+        );
         check_deadline_and!(self, *deadline, request_ptr, {
             self.handle_io_call(
                 IoCall::RecvWithDeadline(raw_socket, ptr, len, deadline),
@@ -579,16 +638,19 @@ impl IoWorker for FallbackWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn recv_from(
         &mut self,
         raw_socket: RawSocket,
         msg_header: &mut MessageRecvHeader,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "recv_from", socket = raw_socket);
         self.handle_io_call(IoCall::RecvFrom(raw_socket, msg_header), request_ptr);
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn recv_from_with_deadline(
         &mut self,
         raw_socket: RawSocket,
@@ -596,6 +658,7 @@ impl IoWorker for FallbackWorker {
         request_ptr: IoRequestDataPtr,
         deadline: &mut Instant,
     ) {
+        trace_io_dispatch!(request_ptr, "recv_from_with_deadline", socket = raw_socket);
         check_deadline_and!(self, *deadline, request_ptr, {
             self.handle_io_call(
                 IoCall::RecvFromWithDeadline(raw_socket, msg_header, deadline),
@@ -605,6 +668,7 @@ impl IoWorker for FallbackWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn send(
         &mut self,
         raw_socket: RawSocket,
@@ -612,10 +676,12 @@ impl IoWorker for FallbackWorker {
         len: u32,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "send", socket = raw_socket, len = len);
         self.handle_io_call(IoCall::Send(raw_socket, ptr, len), request_ptr);
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn send_fixed(
         &mut self,
         raw_socket: RawSocket,
@@ -624,10 +690,12 @@ impl IoWorker for FallbackWorker {
         _buf_index: u16,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "send_fixed", socket = raw_socket, len = len);
         self.handle_io_call(IoCall::Send(raw_socket, ptr, len), request_ptr);
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn send_with_deadline(
         &mut self,
         raw_socket: RawSocket,
@@ -636,6 +704,12 @@ impl IoWorker for FallbackWorker {
         request_ptr: IoRequestDataPtr,
         deadline: &mut Instant,
     ) {
+        trace_io_dispatch!(
+            request_ptr,
+            "send_with_deadline",
+            socket = raw_socket,
+            len = len
+        );
         check_deadline_and!(self, *deadline, request_ptr, {
             self.handle_io_call(
                 IoCall::SendWithDeadline(raw_socket, ptr, len, deadline),
@@ -645,6 +719,7 @@ impl IoWorker for FallbackWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn send_fixed_with_deadline(
         &mut self,
         raw_socket: RawSocket,
@@ -654,6 +729,12 @@ impl IoWorker for FallbackWorker {
         request_ptr: IoRequestDataPtr,
         deadline: &mut Instant,
     ) {
+        trace_io_dispatch!(
+            request_ptr,
+            "send_fixed_with_deadline",
+            socket = raw_socket,
+            len = len
+        );
         check_deadline_and!(self, *deadline, request_ptr, {
             self.handle_io_call(
                 IoCall::SendWithDeadline(raw_socket, ptr, len, deadline),
@@ -663,16 +744,19 @@ impl IoWorker for FallbackWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn send_to(
         &mut self,
         raw_socket: RawSocket,
         msg_header: *const OsMessageHeader,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "send_to", socket = raw_socket);
         self.handle_io_call(IoCall::SendTo(raw_socket, msg_header), request_ptr);
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn send_to_with_deadline(
         &mut self,
         raw_socket: RawSocket,
@@ -680,6 +764,7 @@ impl IoWorker for FallbackWorker {
         request_ptr: IoRequestDataPtr,
         deadline: &mut Instant,
     ) {
+        trace_io_dispatch!(request_ptr, "send_to_with_deadline", socket = raw_socket);
         check_deadline_and!(self, *deadline, request_ptr, {
             self.handle_io_call(
                 IoCall::SendToWithDeadline(raw_socket, msg_header, deadline),
@@ -689,6 +774,7 @@ impl IoWorker for FallbackWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn peek(
         &mut self,
         raw_socket: RawSocket,
@@ -696,10 +782,12 @@ impl IoWorker for FallbackWorker {
         len: u32,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "peek", socket = raw_socket, len = len);
         self.handle_io_call(IoCall::Peek(raw_socket, ptr, len), request_ptr);
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn peek_fixed(
         &mut self,
         raw_socket: RawSocket,
@@ -708,10 +796,12 @@ impl IoWorker for FallbackWorker {
         _buf_index: u16,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "peek_fixed", socket = raw_socket, len = len);
         self.handle_io_call(IoCall::Peek(raw_socket, ptr, len), request_ptr);
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn peek_with_deadline(
         &mut self,
         raw_socket: RawSocket,
@@ -720,6 +810,12 @@ impl IoWorker for FallbackWorker {
         request_ptr: IoRequestDataPtr,
         deadline: &mut Instant,
     ) {
+        trace_io_dispatch!(
+            request_ptr,
+            "peek_with_deadline",
+            socket = raw_socket,
+            len = len
+        );
         check_deadline_and!(self, *deadline, request_ptr, {
             self.handle_io_call(
                 IoCall::PeekWithDeadline(raw_socket, ptr, len, deadline),
@@ -729,6 +825,7 @@ impl IoWorker for FallbackWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn peek_fixed_with_deadline(
         &mut self,
         raw_socket: RawSocket,
@@ -738,6 +835,12 @@ impl IoWorker for FallbackWorker {
         request_ptr: IoRequestDataPtr,
         deadline: &mut Instant,
     ) {
+        trace_io_dispatch!(
+            request_ptr,
+            "peek_fixed_with_deadline",
+            socket = raw_socket,
+            len = len
+        );
         check_deadline_and!(self, *deadline, request_ptr, {
             self.handle_io_call(
                 IoCall::PeekWithDeadline(raw_socket, ptr, len, deadline),
@@ -747,16 +850,19 @@ impl IoWorker for FallbackWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn peek_from(
         &mut self,
         raw_socket: RawSocket,
         msg: &mut MessageRecvHeader,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "peek_from", socket = raw_socket);
         self.handle_io_call(IoCall::PeekFrom(raw_socket, msg), request_ptr);
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn peek_from_with_deadline(
         &mut self,
         raw_socket: RawSocket,
@@ -764,6 +870,7 @@ impl IoWorker for FallbackWorker {
         request_ptr: IoRequestDataPtr,
         deadline: &mut Instant,
     ) {
+        trace_io_dispatch!(request_ptr, "peek_from_with_deadline", socket = raw_socket);
         check_deadline_and!(self, *deadline, request_ptr, {
             self.handle_io_call(
                 IoCall::PeekFromWithDeadline(raw_socket, msg, deadline),
@@ -773,21 +880,26 @@ impl IoWorker for FallbackWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn shutdown(&mut self, raw_socket: RawSocket, how: Shutdown, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "shutdown", socket = raw_socket);
         Self::handle_io_operation(move || shutdown_op(raw_socket, how), request_ptr);
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn open(
         &mut self,
         path: OsPathPtr,
         open_how: *const OsOpenOptions,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "open");
         Self::handle_io_operation(move || open_op(path, open_how), request_ptr);
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn fallocate(
         &mut self,
         _raw_file: RawFile,
@@ -796,25 +908,54 @@ impl IoWorker for FallbackWorker {
         _flags: i32,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "fallocate");
+        Self::handle_io_operation(move || Ok(0), request_ptr);
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
+    fn fadvise(
+        &mut self,
+        _raw_file: RawFile,
+        _offset: u64,
+        _len: u64,
+        _advice: i32,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        trace_io_dispatch!(request_ptr, "fadvise");
         Self::handle_io_operation(move || Ok(0), request_ptr);
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn sync_all(&mut self, raw_file: RawFile, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "sync_all", file = raw_file);
         Self::handle_io_operation(move || fsync_op(raw_file), request_ptr);
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn sync_data(&mut self, raw_file: RawFile, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "sync_data", file = raw_file);
         Self::handle_io_operation(move || fsync_data_op(raw_file), request_ptr);
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
+    fn truncate(&mut self, raw_file: RawFile, len: u64, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "truncate", file = raw_file, len = len);
+        Self::handle_io_operation(move || ftruncate_op(raw_file, len), request_ptr);
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn read(&mut self, raw_file: RawFile, ptr: *mut u8, len: u32, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "read", file = raw_file, len = len);
         Self::handle_io_operation(move || read_op(raw_file, ptr, len), request_ptr);
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn read_fixed(
         &mut self,
         raw_file: RawFile,
@@ -823,10 +964,12 @@ impl IoWorker for FallbackWorker {
         _buf_index: u16,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "read_fixed", file = raw_file, len = len);
         Self::handle_io_operation(move || read_op(raw_file, ptr, len), request_ptr);
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn pread(
         &mut self,
         raw_file: RawFile,
@@ -835,6 +978,7 @@ impl IoWorker for FallbackWorker {
         offset: usize,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "pread", file = raw_file, len = len);
         Self::handle_io_operation(
             move || read_at_op(raw_file, offset as u64, ptr, len),
             request_ptr,
@@ -842,6 +986,7 @@ impl IoWorker for FallbackWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn pread_fixed(
         &mut self,
         raw_file: RawFile,
@@ -851,6 +996,7 @@ impl IoWorker for FallbackWorker {
         offset: usize,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "pread_fixed", file = raw_file, len = len);
         Self::handle_io_operation(
             move || read_at_op(raw_file, offset as u64, ptr, len),
             request_ptr,
@@ -858,6 +1004,7 @@ impl IoWorker for FallbackWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn write(
         &mut self,
         raw_file: RawFile,
@@ -865,10 +1012,12 @@ impl IoWorker for FallbackWorker {
         len: u32,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "write", file = raw_file, len = len);
         Self::handle_io_operation(move || write_op(raw_file, ptr, len), request_ptr);
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn write_fixed(
         &mut self,
         raw_file: RawFile,
@@ -877,10 +1026,12 @@ impl IoWorker for FallbackWorker {
         _buf_index: u16,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "write_fixed", file = raw_file, len = len);
         Self::handle_io_operation(move || write_op(raw_file, ptr, len), request_ptr);
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn pwrite(
         &mut self,
         raw_file: RawFile,
@@ -889,6 +1040,7 @@ impl IoWorker for FallbackWorker {
         offset: usize,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "pwrite", file = raw_file, len = len);
         Self::handle_io_operation(
             move || write_at_op(raw_file, offset as u64, ptr, len),
             request_ptr,
@@ -896,6 +1048,7 @@ impl IoWorker for FallbackWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn pwrite_fixed(
         &mut self,
         raw_file: RawFile,
@@ -905,6 +1058,7 @@ impl IoWorker for FallbackWorker {
         offset: usize,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "pwrite_fixed", file = raw_file, len = len);
         Self::handle_io_operation(
             move || write_at_op(raw_file, offset as u64, ptr, len),
             request_ptr,
@@ -912,7 +1066,9 @@ impl IoWorker for FallbackWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn close_file(&mut self, raw_file: RawFile, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "close_file", file = raw_file);
         Self::handle_io_operation(
             move || {
                 close_file_op(raw_file);
@@ -923,7 +1079,9 @@ impl IoWorker for FallbackWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn close_socket(&mut self, raw_socket: RawSocket, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "close_socket", socket = raw_socket);
         Self::handle_io_operation(
             move || {
                 close_socket_op(raw_socket);
@@ -934,22 +1092,56 @@ impl IoWorker for FallbackWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn rename(&mut self, old_path: OsPathPtr, new_path: OsPathPtr, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "rename");
         Self::handle_io_operation(move || rename_op(old_path, new_path), request_ptr);
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn create_dir(&mut self, path: OsPathPtr, mode: u32, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "create_dir");
         Self::handle_io_operation(move || mkdir_op(path, mode), request_ptr);
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn remove_file(&mut self, path: OsPathPtr, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "remove_file");
         Self::handle_io_operation(move || unlink_op(path), request_ptr);
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn remove_dir(&mut self, path: OsPathPtr, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "remove_dir");
         Self::handle_io_operation(move || rmdir_op(path), request_ptr);
     }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
+    fn symlink(&mut self, target: OsPathPtr, link_path: OsPathPtr, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "symlink");
+        Self::handle_io_operation(move || symlink_op(target, link_path), request_ptr);
+    }
+
+    #[inline]
+    #[cfg(unix)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
+    fn linkat(
+        &mut self,
+        old_dir: RawFile,
+        old_path: OsPathPtr,
+        new_dir: RawFile,
+        new_path: OsPathPtr,
+        flags: i32,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        trace_io_dispatch!(request_ptr, "linkat");
+        Self::handle_io_operation(
+            move || linkat_op(old_dir, old_path, new_dir, new_path, flags),
+            request_ptr,
+        );
+    }
 }
@@ -1,6 +1,7 @@
 use crate::io::config::IoWorkerConfig;
-use crate::io::io_request_data::IoRequestDataPtr;
+use crate::io::io_request_data::{trace_io_dispatch, IoRequestDataPtr};
 use crate::io::sys;
+use crate::io::sys::IOUringConfig;
 use crate::io::sys::{
     os_sockaddr, MessageRecvHeader, OsMessageHeader, OsPathPtr, RawFile, RawSocket,
 };
@@ -21,8 +22,11 @@ use std::ptr;
 use std::time::{Duration, Instant};
 
 /// [`IOUringWorker`] implements [`IoWorker`] using `io_uring`.
+///
+/// It is `pub`, but unreachable, because it is the inner worker of the public
+/// `FaultIoWorker` with the `fault_injection` feature.
 #[repr(C)]
-pub(crate) struct IOUringWorker {
+pub struct IOUringWorker {
     /// # Why we need some cell?
     ///
     /// We can't rewrite engine ([`Selector`] trait) to use separately `ring` field and other fields in different methods.
@@ -41,6 +45,8 @@ pub(crate) struct IOUringWorker {
     probe: Probe,
     time_bounded_io_task_queue: BTreeSet<TimeBoundedIoTask>,
     number_of_active_tasks: usize,
+    /// Read [`IOUringConfig::submit_batch_size`].
+    submit_batch_size: usize,
 }
 
 /// User data for [`AsyncClose`](opcode::AsyncCancel) operations.
@@ -73,8 +79,15 @@ impl IOUringWorker {
         unsafe {
             if ring.submission().push(&sqe).is_err() {
                 self.backlog.push_back(sqe);
+
+                return;
             }
         }
+
+        if ring.submission().len() >= self.submit_batch_size {
+            // Errors are not lost: they are returned by the next submit in `must_poll`.
+            let _ = ring.submit();
+        }
     }
 
     /// Add a new sqe to the submission queue with setting `user_data`.
@@ -158,20 +171,21 @@ impl IOUringWorker {
             }
         }
 
-        let res = timeout_option.map_or_else(
-            || submitter.submit(),
-            |timeout| {
-                #[allow(clippy::cast_possible_truncation, reason = "u32 is enough for 4 secs")]
-                let timespec = Timespec::new().nsec(timeout.as_nanos() as u32);
+        let res = match timeout_option {
+            Some(timeout) if timeout.is_zero() => submitter.submit(),
+            Some(timeout) => {
+                let timespec = Timespec::from(timeout);
                 let args = SubmitArgs::new().timespec(&timespec);
                 submitter.submit_with_args(1, &args)
-            },
-        );
+            }
+            None => submitter.submit_and_wait(1),
+        };
 
         match res {
             Ok(_) => (),
             Err(ref err) if err.raw_os_error() == Some(libc::ETIME) => (),
             Err(ref err) if err.raw_os_error() == Some(libc::EBUSY) => (),
+            Err(ref err) if err.raw_os_error() == Some(libc::EINTR) => (),
             Err(err) => return Err(err),
         }
 
@@ -179,14 +193,25 @@ impl IOUringWorker {
     }
 }
 
+/// Builds an [`IoUring`] with the sizes of the queues from the provided [`IOUringConfig`].
+fn build_ring(config: IOUringConfig) -> IoUring {
+    let mut builder = IoUring::builder();
+    if let Some(number_of_cq_entries) = config.number_of_cq_entries {
+        builder.setup_cqsize(number_of_cq_entries);
+    }
+
+    builder.build(config.number_of_entries).unwrap()
+}
+
 impl IoWorker for IOUringWorker {
     fn new(config: IoWorkerConfig) -> Self {
         let mut s = Self {
-            ring: UnsafeCell::new(IoUring::new(config.io_uring.number_of_entries).unwrap()),
+            ring: UnsafeCell::new(build_ring(config.io_uring)),
             backlog: VecDeque::new(),
             probe: Probe::new(),
             time_bounded_io_task_queue: BTreeSet::new(),
             number_of_active_tasks: 0,
+            submit_batch_size: config.io_uring.submit_batch_size,
         };
 
         let submitter = s.ring.get_mut().submitter();
@@ -205,9 +230,25 @@ impl IoWorker for IOUringWorker {
         self.number_of_active_tasks > 0
     }
 
+    #[inline]
+    fn number_of_active_tasks(&self) -> usize {
+        self.number_of_active_tasks
+    }
+
     fn must_poll(&mut self, timeout_option: Option<Duration>) {
         let executor = local_executor();
         self.check_deadlines(executor);
+        let timeout_option = match timeout_option {
+            // Nothing can complete, so blocking would never end.
+            None if self.number_of_active_tasks == 0 => Some(Duration::ZERO),
+            // Time-bounded tasks are cancelled by `check_deadlines`, so the nearest deadline
+            // bounds the blocking.
+            None => self.time_bounded_io_task_queue.first().map(|task| {
+                task.deadline()
+                    .saturating_duration_since(executor.start_round_time())
+            }),
+            timeout_option => timeout_option,
+        };
         self.submit_and_poll(timeout_option)
             .expect("IOUringWorker::submit() failed");
 
@@ -244,6 +285,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn socket(
         &mut self,
         domain: socket2::Domain,
@@ -251,6 +293,7 @@ impl IoWorker for IOUringWorker {
         protocol: socket2::Protocol,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "socket");
         if self.is_supported(opcode::Socket::CODE) {
             self.register_entry(
                 opcode::Socket::new(
@@ -277,6 +320,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn accept(
         &mut self,
         raw_socket: RawSocket,
@@ -284,6 +328,7 @@ impl IoWorker for IOUringWorker {
         addr_len: *mut sys::socklen_t,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "accept", socket = raw_socket);
         self.register_entry(
             opcode::Accept::new(types::Fd(raw_socket), addr_ptr, addr_len).build(),
             request_ptr,
@@ -291,6 +336,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn accept_with_deadline(
         &mut self,
         raw_socket: RawSocket,
@@ -304,6 +350,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn connect(
         &mut self,
         raw_socket: RawSocket,
@@ -311,6 +358,7 @@ impl IoWorker for IOUringWorker {
         addr_len: sys::socklen_t,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "connect", socket = raw_socket);
         self.register_entry(
             opcode::Connect::new(types::Fd(raw_socket), addr_ptr, addr_len).build(),
             request_ptr,
@@ -318,6 +366,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn connect_with_deadline(
         &mut self,
         raw_socket: RawSocket,
@@ -331,7 +380,9 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn poll_socket_read(&mut self, raw_socket: RawSocket, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "poll_socket_read", socket = raw_socket);
         self.register_entry(
             opcode::PollAdd::new(types::Fd(raw_socket), libc::POLLIN as _).build(),
             request_ptr,
@@ -339,6 +390,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn poll_socket_read_with_deadline(
         &mut self,
         raw_socket: RawSocket,
@@ -350,7 +402,9 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn poll_socket_write(&mut self, raw_socket: RawSocket, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "poll_socket_write", socket = raw_socket);
         self.register_entry(
             opcode::PollAdd::new(types::Fd(raw_socket), libc::POLLOUT as _).build(),
             request_ptr,
@@ -358,6 +412,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn poll_socket_write_with_deadline(
         &mut self,
         raw_socket: RawSocket,
@@ -369,6 +424,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn recv(
         &mut self,
         raw_socket: RawSocket,
@@ -376,6 +432,7 @@ impl IoWorker for IOUringWorker {
         len: u32,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "recv", socket = raw_socket, len = len);
         self.register_entry(
             opcode::Recv::new(types::Fd(raw_socket), ptr, len).build(),
             request_ptr,
@@ -383,6 +440,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn recv_fixed(
         &mut self,
         raw_socket: RawSocket,
@@ -391,6 +449,7 @@ impl IoWorker for IOUringWorker {
         buf_index: u16,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "recv_fixed", socket = raw_socket, len = len);
         self.register_entry(
             opcode::ReadFixed::new(types::Fd(raw_socket), ptr, len, buf_index).build(),
             request_ptr,
@@ -398,6 +457,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn recv_with_deadline(
         &mut self,
         raw_socket: RawSocket,
@@ -411,6 +471,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn recv_fixed_with_deadline(
         &mut self,
         raw_socket: RawSocket,
@@ -425,12 +486,14 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn recv_from(
         &mut self,
         raw_socket: RawSocket,
         msg_header: &mut MessageRecvHeader,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "recv_from", socket = raw_socket);
         self.register_entry(
             opcode::RecvMsg::new(
                 types::Fd(raw_socket),
@@ -442,6 +505,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn recv_from_with_deadline(
         &mut self,
         raw_socket: RawSocket,
@@ -454,6 +518,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn send(
         &mut self,
         raw_socket: RawSocket,
@@ -461,6 +526,7 @@ impl IoWorker for IOUringWorker {
         len: u32,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "send", socket = raw_socket, len = len);
         self.register_entry(
             opcode::Send::new(types::Fd(raw_socket), ptr, len).build(),
             request_ptr,
@@ -468,6 +534,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn send_fixed(
         &mut self,
         raw_socket: RawSocket,
@@ -476,6 +543,7 @@ impl IoWorker for IOUringWorker {
         buf_index: u16,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "send_fixed", socket = raw_socket, len = len);
         self.register_entry(
             opcode::WriteFixed::new(types::Fd(raw_socket), ptr, len, buf_index).build(),
             request_ptr,
@@ -483,6 +551,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn send_with_deadline(
         &mut self,
         raw_socket: RawSocket,
@@ -496,6 +565,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn send_fixed_with_deadline(
         &mut self,
         raw_socket: RawSocket,
@@ -510,12 +580,14 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn send_to(
         &mut self,
         raw_socket: RawSocket,
         msg_header: *const OsMessageHeader,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "send_to", socket = raw_socket);
         self.register_entry(
             opcode::SendMsg::new(types::Fd(raw_socket), msg_header).build(),
             request_ptr,
@@ -523,6 +595,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn send_to_with_deadline(
         &mut self,
         raw_socket: RawSocket,
@@ -535,6 +608,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn peek(
         &mut self,
         raw_socket: RawSocket,
@@ -542,6 +616,7 @@ impl IoWorker for IOUringWorker {
         len: u32,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "peek", socket = raw_socket, len = len);
         self.register_entry(
             opcode::Recv::new(types::Fd(raw_socket), ptr, len)
                 .flags(libc::MSG_PEEK)
@@ -551,6 +626,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn peek_fixed(
         &mut self,
         raw_socket: RawSocket,
@@ -571,6 +647,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn peek_with_deadline(
         &mut self,
         raw_socket: RawSocket,
@@ -584,6 +661,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn peek_fixed_with_deadline(
         &mut self,
         raw_socket: RawSocket,
@@ -598,12 +676,14 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn peek_from(
         &mut self,
         raw_socket: RawSocket,
         msg_header: &mut MessageRecvHeader,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "peek_from", socket = raw_socket);
         let msg_header = &mut *msg_header;
         self.register_entry(
             opcode::RecvMsg::new(types::Fd(raw_socket), msg_header.get_os_message_header())
@@ -614,6 +694,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn peek_from_with_deadline(
         &mut self,
         raw_socket: RawSocket,
@@ -626,7 +707,9 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn shutdown(&mut self, raw_socket: RawSocket, how: Shutdown, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "shutdown", socket = raw_socket);
         let how = match how {
             Shutdown::Read => libc::SHUT_RD,
             Shutdown::Write => libc::SHUT_WR,
@@ -639,7 +722,9 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn open(&mut self, path: OsPathPtr, open_how: *const OpenHow, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "open");
         self.register_entry(
             opcode::OpenAt2::new(types::Fd(libc::AT_FDCWD), path, open_how).build(),
             request_ptr,
@@ -647,6 +732,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn fallocate(
         &mut self,
         raw_file: RawFile,
@@ -655,6 +741,7 @@ impl IoWorker for IOUringWorker {
         flags: i32,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "fallocate", file = raw_file, len = len);
         self.register_entry(
             opcode::Fallocate::new(types::Fd(raw_file), len)
                 .offset(offset)
@@ -665,12 +752,38 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
+    fn fadvise(
+        &mut self,
+        raw_file: RawFile,
+        offset: u64,
+        len: u64,
+        advice: i32,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        trace_io_dispatch!(request_ptr, "fadvise", file = raw_file, len = len);
+        // IORING_OP_FADVISE takes a 32-bit length, longer ranges are advised to the end of the file
+        let len = u32::try_from(len).unwrap_or(0);
+
+        self.register_entry(
+            opcode::Fadvise::new(types::Fd(raw_file), libc::off_t::from(len), advice)
+                .offset(offset)
+                .build(),
+            request_ptr,
+        );
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn sync_all(&mut self, raw_file: RawFile, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "sync_all", file = raw_file);
         self.register_entry(opcode::Fsync::new(types::Fd(raw_file)).build(), request_ptr);
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn sync_data(&mut self, raw_file: RawFile, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "sync_data", file = raw_file);
         self.register_entry(
             opcode::Fsync::new(types::Fd(raw_file))
                 .flags(types::FsyncFlags::DATASYNC)
@@ -680,7 +793,37 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
+    fn truncate(&mut self, raw_file: RawFile, len: u64, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "truncate", file = raw_file, len = len);
+        if self.is_supported(opcode::Ftruncate::CODE) {
+            self.register_entry(
+                opcode::Ftruncate::new(types::Fd(raw_file), len).build(),
+                request_ptr,
+            );
+
+            return;
+        }
+
+        let request = request_ptr.get_mut();
+        #[allow(
+            clippy::cast_possible_wrap,
+            reason = "the length of a file cannot exceed i64::MAX"
+        )]
+        let ret = unsafe { libc::ftruncate(raw_file, len as libc::off_t) };
+        request.set_ret(if ret == -1 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(0)
+        });
+
+        local_executor().spawn_local_task(unsafe { request.task() });
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn read(&mut self, raw_file: RawFile, ptr: *mut u8, len: u32, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "read", file = raw_file, len = len);
         #[allow(clippy::cast_sign_loss, reason = "we have to cast it")]
         self.register_entry(
             opcode::Read::new(types::Fd(raw_file), ptr, len)
@@ -691,6 +834,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn read_fixed(
         &mut self,
         raw_file: RawFile,
@@ -699,6 +843,7 @@ impl IoWorker for IOUringWorker {
         buf_index: u16,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "read_fixed", file = raw_file, len = len);
         #[allow(clippy::cast_sign_loss, reason = "we have to cast it")]
         self.register_entry(
             opcode::ReadFixed::new(types::Fd(raw_file), ptr, len, buf_index)
@@ -709,6 +854,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn pread(
         &mut self,
         raw_file: RawFile,
@@ -717,6 +863,7 @@ impl IoWorker for IOUringWorker {
         offset: usize,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "pread", file = raw_file, len = len);
         self.register_entry(
             opcode::Read::new(types::Fd(raw_file), ptr, len)
                 .offset(offset as _)
@@ -726,6 +873,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn pread_fixed(
         &mut self,
         raw_file: RawFile,
@@ -735,6 +883,7 @@ impl IoWorker for IOUringWorker {
         offset: usize,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "pread_fixed", file = raw_file, len = len);
         #[allow(clippy::cast_sign_loss, reason = "we have to cast it")]
         self.register_entry(
             opcode::ReadFixed::new(types::Fd(raw_file), ptr, len, buf_index)
@@ -745,6 +894,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn write(
         &mut self,
         raw_file: RawFile,
@@ -752,6 +902,7 @@ impl IoWorker for IOUringWorker {
         len: u32,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "write", file = raw_file, len = len);
         #[allow(clippy::cast_sign_loss, reason = "we have to cast it")]
         self.register_entry(
             opcode::Write::new(types::Fd(raw_file), ptr, len)
@@ -762,6 +913,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn write_fixed(
         &mut self,
         raw_file: RawFile,
@@ -770,6 +922,7 @@ impl IoWorker for IOUringWorker {
         buf_index: u16,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "write_fixed", file = raw_file, len = len);
         #[allow(clippy::cast_sign_loss, reason = "we have to cast it")]
         self.register_entry(
             opcode::WriteFixed::new(types::Fd(raw_file), ptr, len, buf_index)
@@ -780,6 +933,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn pwrite(
         &mut self,
         raw_file: RawFile,
@@ -788,6 +942,7 @@ impl IoWorker for IOUringWorker {
         offset: usize,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "pwrite", file = raw_file, len = len);
         self.register_entry(
             opcode::Write::new(types::Fd(raw_file), ptr, len)
                 .offset(offset as _)
@@ -797,6 +952,7 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn pwrite_fixed(
         &mut self,
         raw_file: RawFile,
@@ -806,6 +962,7 @@ impl IoWorker for IOUringWorker {
         offset: usize,
         request_ptr: IoRequestDataPtr,
     ) {
+        trace_io_dispatch!(request_ptr, "pwrite_fixed", file = raw_file, len = len);
         #[allow(clippy::cast_sign_loss, reason = "we have to cast it")]
         self.register_entry(
             opcode::WriteFixed::new(types::Fd(raw_file), ptr, len, buf_index)
@@ -816,12 +973,16 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn close_file(&mut self, raw_file: RawFile, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "close_file", file = raw_file);
         self.register_entry(opcode::Close::new(types::Fd(raw_file)).build(), request_ptr);
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn close_socket(&mut self, raw_socket: RawSocket, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "close_socket", socket = raw_socket);
         self.register_entry(
             opcode::Close::new(types::Fd(raw_socket)).build(),
             request_ptr,
@@ -829,7 +990,9 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn rename(&mut self, old_path: OsPathPtr, new_path: OsPathPtr, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "rename");
         self.register_entry(
             opcode::RenameAt::new(
                 types::Fd(libc::AT_FDCWD),
@@ -843,7 +1006,9 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn create_dir(&mut self, path: OsPathPtr, mode: u32, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "create_dir");
         self.register_entry(
             opcode::MkDirAt::new(types::Fd(libc::AT_FDCWD), path)
                 .mode(mode)
@@ -853,7 +1018,9 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn remove_file(&mut self, path: OsPathPtr, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "remove_file");
         self.register_entry(
             opcode::UnlinkAt::new(types::Fd(libc::AT_FDCWD), path).build(),
             request_ptr,
@@ -861,7 +1028,9 @@ impl IoWorker for IOUringWorker {
     }
 
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
     fn remove_dir(&mut self, path: OsPathPtr, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "remove_dir");
         self.register_entry(
             opcode::UnlinkAt::new(types::Fd(libc::AT_FDCWD), path)
                 .flags(libc::AT_REMOVEDIR)
@@ -869,4 +1038,73 @@ impl IoWorker for IOUringWorker {
             request_ptr,
         );
     }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
+    fn symlink(&mut self, target: OsPathPtr, link_path: OsPathPtr, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "symlink");
+        self.register_entry(
+            opcode::SymlinkAt::new(types::Fd(libc::AT_FDCWD), target, link_path).build(),
+            request_ptr,
+        );
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
+    fn linkat(
+        &mut self,
+        old_dir: RawFile,
+        old_path: OsPathPtr,
+        new_dir: RawFile,
+        new_path: OsPathPtr,
+        flags: i32,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        trace_io_dispatch!(request_ptr, "linkat");
+        self.register_entry(
+            opcode::LinkAt::new(types::Fd(old_dir), old_path, types::Fd(new_dir), new_path)
+                .flags(flags)
+                .build(),
+            request_ptr,
+        );
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
+    fn timeout(&mut self, timespec: *const Timespec, request_ptr: IoRequestDataPtr) {
+        trace_io_dispatch!(request_ptr, "timeout");
+        self.register_entry(opcode::Timeout::new(timespec).build(), request_ptr);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::io::worker::local_worker;
+    use crate::{sleep_precise, yield_now};
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[orengine::test::test_local]
+    fn test_must_poll_without_timeout() {
+        // Without active io operations it must not block.
+        local_worker().must_poll(None);
+
+        let is_done = Rc::new(Cell::new(false));
+        let is_done_clone = is_done.clone();
+        local_executor().spawn_local(async move {
+            sleep_precise(Duration::from_millis(5)).await;
+            is_done_clone.set(true);
+        });
+        yield_now().await;
+
+        let start = Instant::now();
+        local_worker().must_poll(None);
+        assert!(start.elapsed() >= Duration::from_millis(4));
+        assert!(!local_worker().has_work());
+
+        yield_now().await;
+        assert!(is_done.get());
+    }
 }
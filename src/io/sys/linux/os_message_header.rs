@@ -31,6 +31,13 @@ impl MessageRecvHeader {
         s
     }
 
+    /// Sets the buffer for ancillary data (control messages).
+    #[inline]
+    pub(crate) fn set_control(&mut self, control_ptr: *mut [u8]) {
+        self.os_header.msg_control = control_ptr.cast();
+        self.os_header.msg_controllen = control_ptr.len() as _;
+    }
+
     /// Returns a shared reference to the message header.
     #[inline]
     pub(crate) fn get_os_message_header(&mut self) -> &mut OsMessageHeader {
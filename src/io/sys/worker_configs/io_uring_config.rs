@@ -2,21 +2,48 @@
 ///
 /// # Fields
 ///
-/// - `number_of_entries`: number of entries in `io_uring`. Must be greater than 0.
+/// - `number_of_entries`: number of entries in the submission queue of `io_uring`.
+///   Must be a power of two and at least 8.
 ///   Every entry is 64 bytes, but `io-uring worker` can't process more requests at a one time
 ///   than the number of entries.
+///
+/// - `number_of_cq_entries`: number of entries in the completion queue of `io_uring`.
+///   If it is `None`, the kernel uses twice the number of submission queue entries.
+///   Otherwise, it must be a power of two and at least `number_of_entries`.
+///
+/// - `submit_batch_size`: number of accumulated submission queue entries after which
+///   they are submitted immediately. Must be greater than 0.
+///   By default, entries are submitted once per round of the executor.
 #[derive(Clone, Copy)]
 pub struct IOUringConfig {
-    /// Number of entries in `io_uring`. Must be greater than 0. Every entry is 64 bytes, but
+    /// Number of entries in the submission queue of `io_uring`.
+    /// Must be a power of two and at least 8. Every entry is 64 bytes, but
     /// `io-uring worker` can't process more requests at a one time than the number of entries.
     pub number_of_entries: u32,
+    /// Number of entries in the completion queue of `io_uring`.
+    /// If it is `None`, the kernel uses twice the number of submission queue entries.
+    /// Otherwise, it must be a power of two and at least
+    /// [`number_of_entries`](#structfield.number_of_entries).
+    pub number_of_cq_entries: Option<u32>,
+    /// Number of accumulated submission queue entries after which they are submitted
+    /// immediately with `io_uring_enter`. Must be greater than 0.
+    ///
+    /// By default, it is [`usize::MAX`] and entries are submitted once per round of the executor,
+    /// which minimizes the number of system calls. Smaller values reduce the latency
+    /// at the cost of more system calls.
+    pub submit_batch_size: usize,
 }
 
+/// The minimum number of entries in the queues of `io_uring`.
+const MIN_NUMBER_OF_ENTRIES: u32 = 8;
+
 impl IOUringConfig {
     /// Creates new `IOUringConfig` with default values.
     pub const fn default() -> Self {
         Self {
             number_of_entries: 256,
+            number_of_cq_entries: None,
+            submit_batch_size: usize::MAX,
         }
     }
 
@@ -24,12 +51,60 @@ impl IOUringConfig {
     ///
     /// # Errors
     ///
-    /// - [`IOUringConfig.number_of_entries`](#field.number_of_entries) must be greater than 0.
+    /// - [`IOUringConfig.number_of_entries`](#structfield.number_of_entries) must be
+    ///   a power of two and at least 8;
+    ///
+    /// - [`IOUringConfig.number_of_cq_entries`](#structfield.number_of_cq_entries) must be
+    ///   a power of two and at least `number_of_entries`;
+    ///
+    /// - [`IOUringConfig.submit_batch_size`](#structfield.submit_batch_size) must be
+    ///   greater than 0.
     pub const fn validate(self) -> Result<(), &'static str> {
-        if self.number_of_entries > 0 {
-            Ok(())
-        } else {
-            Err("io_uring: number_of_entries must be greater than 0")
+        if self.number_of_entries < MIN_NUMBER_OF_ENTRIES
+            || !self.number_of_entries.is_power_of_two()
+        {
+            return Err("io_uring: number_of_entries must be a power of two and at least 8");
+        }
+
+        if let Some(number_of_cq_entries) = self.number_of_cq_entries {
+            if number_of_cq_entries < self.number_of_entries
+                || !number_of_cq_entries.is_power_of_two()
+            {
+                return Err("io_uring: number_of_cq_entries must be a power of two \
+                    and at least number_of_entries");
+            }
         }
+
+        if self.submit_batch_size == 0 {
+            return Err("io_uring: submit_batch_size must be greater than 0");
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_io_uring_config_validate() {
+        assert_eq!(Ok(()), IOUringConfig::default().validate());
+
+        let config = |number_of_entries, number_of_cq_entries| IOUringConfig {
+            number_of_entries,
+            number_of_cq_entries,
+            submit_batch_size: usize::MAX,
+        };
+        assert_eq!(Ok(()), config(8, Some(8)).validate());
+        assert_eq!(Ok(()), config(64, Some(1024)).validate());
+        assert!(config(4, None).validate().is_err());
+        assert!(config(100, None).validate().is_err());
+        assert!(config(64, Some(32)).validate().is_err());
+        assert!(config(64, Some(100)).validate().is_err());
+
+        let mut config = IOUringConfig::default();
+        config.submit_batch_size = 0;
+        assert!(config.validate().is_err());
     }
 }
@@ -3,22 +3,30 @@ use crate::io::io_request_data::IoRequestDataPtr;
 use crate::io::sys;
 use crate::io::sys::{
     os_sockaddr, MessageRecvHeader, OsMessageHeader, OsOpenOptions, OsPathPtr, RawFile, RawSocket,
-    WorkerSys,
 };
 use crate::BUG_MESSAGE;
 use std::cell::UnsafeCell;
 use std::net::Shutdown;
 use std::time::{Duration, Instant};
 
+/// The type of the thread-local worker.
+#[cfg(not(any(test, feature = "fault_injection")))]
+pub(crate) type LocalWorker = sys::WorkerSys;
+
+/// The type of the thread-local worker. It is wrapped in
+/// [`FaultIoWorker`](crate::test::FaultIoWorker) to allow injecting faults.
+#[cfg(any(test, feature = "fault_injection"))]
+pub(crate) type LocalWorker = crate::test::FaultIoWorker<sys::WorkerSys>;
+
 thread_local! {
     /// Thread-local worker for async io operations.
-    pub(crate) static LOCAL_WORKER: UnsafeCell<Option<WorkerSys>> = const {
+    pub(crate) static LOCAL_WORKER: UnsafeCell<Option<LocalWorker>> = const {
         UnsafeCell::new(None)
     };
 }
 
 /// Returns the thread-local worker wrapped in an [`Option`].
-pub(crate) fn get_local_worker_ref() -> &'static mut Option<WorkerSys> {
+pub(crate) fn get_local_worker_ref() -> &'static mut Option<LocalWorker> {
     LOCAL_WORKER.with(|local_worker| unsafe { &mut *local_worker.get() })
 }
 
@@ -26,7 +34,7 @@ pub(crate) fn get_local_worker_ref() -> &'static mut Option<WorkerSys> {
 pub(crate) unsafe fn init_local_worker(config: IoWorkerConfig) {
     assert!(!get_local_worker_ref().is_some(), "{BUG_MESSAGE}");
 
-    *get_local_worker_ref() = Some(WorkerSys::new(config));
+    *get_local_worker_ref() = Some(LocalWorker::new(config));
 }
 
 /// Returns the thread-local worker.
@@ -39,7 +47,7 @@ pub(crate) unsafe fn init_local_worker(config: IoWorkerConfig) {
 ///
 /// If the thread-local worker has not been initialized in `release` mode.
 #[inline]
-pub(crate) fn local_worker() -> &'static mut WorkerSys {
+pub(crate) fn local_worker() -> &'static mut LocalWorker {
     #[cfg(debug_assertions)]
     {
         get_local_worker_ref().as_mut().expect(
@@ -66,9 +74,23 @@ pub(crate) trait IoWorker {
 
     /// Returns whether `worker` has work to do.
     fn has_work(&self) -> bool;
+    /// Returns the number of submitted io operations that are not completed yet.
+    fn number_of_active_tasks(&self) -> usize;
     /// Submits an accumulated tasks to the kernel and polls it for completion if needed.
     ///
-    /// It also gets `timeout` for polling. If it is `None`, it will not wait (__busy polling__).
+    /// It also gets `timeout` for polling:
+    ///
+    /// - `Some(Duration::ZERO)` means that it will not wait (__busy polling__);
+    ///
+    /// - `Some(timeout)` means that it waits at most `timeout` for at least one completion;
+    ///
+    /// - `None` means that it blocks until at least one completion. The wait is still bounded
+    ///   by the nearest deadline of time-bounded io operations, and it doesn't wait
+    ///   if there are no active io operations.
+    ///
+    /// The [`Executor`](crate::Executor) never passes `None`. While the thread is blocked
+    /// in the kernel, nothing can wake it up from other threads, so it would miss
+    /// stop signals and tasks sent by other executors until some io operation is completed.
     fn must_poll(&mut self, timeout_option: Option<Duration>);
     /// Registers a new `socket` io operation.
     fn socket(
@@ -347,10 +369,21 @@ pub(crate) trait IoWorker {
         flags: i32,
         request_ptr: IoRequestDataPtr,
     );
+    /// Registers a new `fadvise` io operation if the kernel supports it.
+    fn fadvise(
+        &mut self,
+        raw_file: RawFile,
+        offset: u64,
+        len: u64,
+        advice: i32,
+        request_ptr: IoRequestDataPtr,
+    );
     /// Registers a new `sync_all` io operation.
     fn sync_all(&mut self, raw_file: RawFile, request_ptr: IoRequestDataPtr);
     /// Registers a new `sync_data` io operation.
     fn sync_data(&mut self, raw_file: RawFile, request_ptr: IoRequestDataPtr);
+    /// Registers a new `truncate` io operation.
+    fn truncate(&mut self, raw_file: RawFile, len: u64, request_ptr: IoRequestDataPtr);
 
     // region read
 
@@ -434,4 +467,25 @@ pub(crate) trait IoWorker {
     fn remove_file(&mut self, path: OsPathPtr, request_ptr: IoRequestDataPtr);
     /// Registers a new `rmdir` io operation.
     fn remove_dir(&mut self, path: OsPathPtr, request_ptr: IoRequestDataPtr);
+    /// Registers a new `symlink` io operation.
+    fn symlink(&mut self, target: OsPathPtr, link_path: OsPathPtr, request_ptr: IoRequestDataPtr);
+    /// Registers a new `linkat` io operation.
+    #[cfg(unix)]
+    fn linkat(
+        &mut self,
+        old_dir: RawFile,
+        old_path: OsPathPtr,
+        new_dir: RawFile,
+        new_path: OsPathPtr,
+        flags: i32,
+        request_ptr: IoRequestDataPtr,
+    );
+    /// Registers a new `timeout` io operation. It completes with `ETIME`
+    /// after `timespec` has elapsed.
+    #[cfg(target_os = "linux")]
+    fn timeout(
+        &mut self,
+        timespec: *const io_uring::types::Timespec,
+        request_ptr: IoRequestDataPtr,
+    );
 }
@@ -37,7 +37,12 @@
     reason = "It allows to create more readable docs."
 )]
 pub(crate) mod bug_message;
+pub mod collections;
+#[cfg(any(feature = "futures-io", feature = "tokio-compat"))]
+pub mod compat;
+pub mod deadline;
 pub mod fs;
+pub mod future;
 pub mod io;
 pub mod local;
 pub mod local_pool;
@@ -52,9 +57,10 @@ pub mod utils;
 pub mod yield_now;
 
 pub(crate) use bug_message::BUG_MESSAGE;
+pub use deadline::timeout_at;
 pub use local::Local;
 pub use run::*;
-pub use runtime::{local_executor, stop_all_executors, stop_executor, Executor};
-pub use sleep::sleep;
+pub use runtime::{local_executor, stop_all_executors, stop_executor, BlockOnError, Executor};
+pub use sleep::{sleep, sleep_precise};
 pub use socket2;
 pub use yield_now::yield_now;
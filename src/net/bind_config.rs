@@ -38,13 +38,23 @@ pub enum ReusePort {
 /// The `BindConfig` struct defines the configuration for binding sockets to addresses.
 ///
 /// It allows fine-tuning of several parameters, such as enabling IPv6-only mode,
-/// controlling whether the address can be reused, and configuring the port reuse mechanism.
+/// controlling whether the address can be reused, configuring the port reuse mechanism
+/// and the sizes of the socket buffers.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[allow(clippy::struct_excessive_bools, reason = "False positive")]
 pub struct BindConfig {
     pub backlog_size: isize,
     pub only_v6: bool,
     pub reuse_address: bool,
     pub reuse_port: ReusePort,
+    /// `SO_RCVBUF` of the socket. If `None`, the OS default is used.
+    pub recv_buffer_size: Option<u32>,
+    /// `SO_SNDBUF` of the socket. If `None`, the OS default is used.
+    pub send_buffer_size: Option<u32>,
+    /// Whether `TCP` listeners use `MPTCP` if the kernel supports it.
+    pub mptcp: bool,
+    /// Whether the socket is transparent (`IP_TRANSPARENT` or `IPV6_TRANSPARENT`).
+    pub transparent: bool,
 }
 
 impl BindConfig {
@@ -58,6 +68,10 @@ impl BindConfig {
             reuse_port: ReusePort::Default,
             #[cfg(windows)]
             reuse_port: ReusePort::Disabled,
+            recv_buffer_size: None,
+            send_buffer_size: None,
+            mptcp: false,
+            transparent: false,
         }
     }
 
@@ -91,6 +105,65 @@ impl BindConfig {
         self.reuse_port = reuse_port;
         self
     }
+
+    /// Sets the size of the receive buffer (`SO_RCVBUF`) of the socket.
+    ///
+    /// It is set before `listen`, so for listeners it is inherited by accepted connections
+    /// and affects the receive window advertised during the handshake.
+    /// Setting it after `accept` is too late for that.
+    ///
+    /// The OS can round the size (for example, Linux doubles it and limits it with
+    /// `net.core.rmem_max`).
+    #[must_use]
+    pub fn recv_buffer_size(mut self, recv_buffer_size: u32) -> Self {
+        self.recv_buffer_size = Some(recv_buffer_size);
+        self
+    }
+
+    /// Sets the size of the send buffer (`SO_SNDBUF`) of the socket.
+    ///
+    /// It is set before `listen`, so for listeners it is inherited by accepted connections.
+    ///
+    /// The OS can round the size (for example, Linux doubles it and limits it with
+    /// `net.core.wmem_max`).
+    #[must_use]
+    pub fn send_buffer_size(mut self, send_buffer_size: u32) -> Self {
+        self.send_buffer_size = Some(send_buffer_size);
+        self
+    }
+
+    /// Configures whether a [`TcpListener`](crate::net::TcpListener) uses multipath `TCP`
+    /// (`IPPROTO_MPTCP`).
+    ///
+    /// `MPTCP` allows connections to use multiple network paths simultaneously,
+    /// it looks like a regular `TCP` socket to the application.
+    /// If the kernel doesn't support `MPTCP`, a regular `TCP` socket is used.
+    /// Other sockets ignore this option.
+    ///
+    /// It is disabled by default.
+    #[must_use]
+    pub fn mptcp(mut self, mptcp: bool) -> Self {
+        self.mptcp = mptcp;
+        self
+    }
+
+    /// Configures whether the socket is transparent (`IP_TRANSPARENT` for IPv4
+    /// or `IPV6_TRANSPARENT` for IPv6).
+    ///
+    /// A transparent socket can be bound to a non-local address and accept connections
+    /// redirected by the `TPROXY` target of `iptables`, which is needed to implement
+    /// a transparent proxy. Read the original destination of a redirected connection with
+    /// [`TcpStream::original_dest_addr`](crate::net::TcpStream::original_dest_addr).
+    ///
+    /// It requires the `CAP_NET_ADMIN` capability and is supported only on `Linux`,
+    /// so binding fails otherwise.
+    ///
+    /// It is disabled by default.
+    #[must_use]
+    pub fn transparent(mut self, transparent: bool) -> Self {
+        self.transparent = transparent;
+        self
+    }
 }
 
 impl Default for BindConfig {
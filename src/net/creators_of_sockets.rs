@@ -31,6 +31,63 @@ pub(crate) async fn new_tcp_socket(addr: &SocketAddr) -> std::io::Result<RawSock
     new_socket(addr, Type::STREAM, Protocol::TCP).await
 }
 
+/// Creates a new [`MPTCP`](Protocol::MPTCP) socket based on the provided `SocketAddr`.
+///
+/// If the kernel doesn't support `MPTCP` (or it is not `Linux`), it creates a `TCP` socket.
+/// `MPTCP` sockets look like regular `TCP` sockets to the application.
+#[inline]
+pub(crate) async fn new_mptcp_socket(addr: &SocketAddr) -> std::io::Result<RawSocket> {
+    #[cfg(target_os = "linux")]
+    match new_socket(addr, Type::STREAM, Protocol::MPTCP).await {
+        // ENOPROTOOPT and EPROTONOSUPPORT are returned if MPTCP is not supported,
+        // EINVAL is returned by old kernels that don't know IPPROTO_MPTCP.
+        Err(err)
+            if matches!(
+                err.raw_os_error(),
+                Some(libc::ENOPROTOOPT | libc::EPROTONOSUPPORT | libc::EINVAL)
+            ) => {}
+        res => return res,
+    }
+
+    new_tcp_socket(addr).await
+}
+
+/// Returns whether the socket uses [`MPTCP`](Protocol::MPTCP).
+///
+/// It always returns `false` on platforms other than `Linux`.
+pub(crate) fn is_mptcp_socket(raw_socket: RawSocket) -> std::io::Result<bool> {
+    #[cfg(target_os = "linux")]
+    {
+        let mut protocol: libc::c_int = 0;
+        #[allow(
+            clippy::cast_possible_truncation,
+            reason = "size of c_int is less than u32::MAX"
+        )]
+        let mut len = size_of::<libc::c_int>() as libc::socklen_t;
+        let res = unsafe {
+            libc::getsockopt(
+                raw_socket,
+                libc::SOL_SOCKET,
+                libc::SO_PROTOCOL,
+                std::ptr::from_mut(&mut protocol).cast(),
+                &raw mut len,
+            )
+        };
+        if res == -1 {
+            return Err(std::io::Error::last_os_error());
+        }
+
+        Ok(protocol == libc::IPPROTO_MPTCP)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = raw_socket;
+
+        Ok(false)
+    }
+}
+
 /// Creates a new UDP socket based on the provided `SocketAddr`.
 /// This is a convenience wrapper around [`new_socket`]
 /// that sets the socket type to [`Type::DGRAM`] ([`UDP`](Protocol::UDP)).
@@ -41,6 +98,30 @@ pub(crate) async fn new_udp_socket(addr: &SocketAddr) -> std::io::Result<RawSock
     new_socket(addr, Type::DGRAM, Protocol::UDP).await
 }
 
+/// Creates a new `netlink` socket with [`raw`](Type::RAW) type and the provided
+/// `netlink` protocol.
+#[cfg(target_os = "linux")]
+pub(crate) async fn new_netlink_socket(protocol: i32) -> std::io::Result<RawSocket> {
+    Socket::new(
+        Domain::from(libc::AF_NETLINK),
+        Type::RAW,
+        Protocol::from(protocol),
+    )
+    .await
+}
+
+/// Creates a new packet socket with [`raw`](Type::RAW) type and the provided
+/// `Ethernet` protocol in host byte order.
+#[cfg(target_os = "linux")]
+pub(crate) async fn new_packet_socket(protocol: u16) -> std::io::Result<RawSocket> {
+    Socket::new(
+        Domain::PACKET,
+        Type::RAW,
+        Protocol::from(i32::from(protocol.to_be())),
+    )
+    .await
+}
+
 /// Creates a new UNIX socket with [`stream`](Type::STREAM) type.
 #[cfg(unix)]
 pub async fn new_unix_stream() -> std::io::Result<RawSocket> {
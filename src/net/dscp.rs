@@ -0,0 +1,100 @@
+//! This module contains [`DscpClass`].
+
+/// `DscpClass` is a Differentiated Services Code Point (RFC 2474) that marks
+/// outgoing packets for `QoS` treatment by network equipment.
+///
+/// Pass [`tos`](Self::tos) to [`Socket::set_tos`](crate::net::Socket::set_tos) for IPv4
+/// or to [`Socket::set_traffic_class`](crate::net::Socket::set_traffic_class) for IPv6.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::io::AsyncBind;
+/// use orengine::net::{DscpClass, Socket, UdpSocket};
+///
+/// # async fn foo() -> std::io::Result<()> {
+/// let socket = UdpSocket::bind("127.0.0.1:8080").await?;
+/// socket.set_tos(DscpClass::EF.tos())?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DscpClass {
+    /// Class selector 0, the default best-effort forwarding.
+    CS0,
+    /// Class selector 1, low-priority data (scavenger).
+    CS1,
+    /// Class selector 2, operations and management.
+    CS2,
+    /// Class selector 3, broadcast video and signaling.
+    CS3,
+    /// Class selector 4, real-time interactive traffic.
+    CS4,
+    /// Class selector 5, signaling.
+    CS5,
+    /// Class selector 6, network control.
+    CS6,
+    /// Class selector 7, reserved for network control.
+    CS7,
+    /// Assured forwarding class 1 with low drop probability (high-throughput data).
+    AF11,
+    /// Assured forwarding class 1 with medium drop probability.
+    AF12,
+    /// Assured forwarding class 1 with high drop probability.
+    AF13,
+    /// Assured forwarding class 2 with low drop probability (low-latency data).
+    AF21,
+    /// Assured forwarding class 2 with medium drop probability.
+    AF22,
+    /// Assured forwarding class 2 with high drop probability.
+    AF23,
+    /// Assured forwarding class 3 with low drop probability (multimedia streaming).
+    AF31,
+    /// Assured forwarding class 3 with medium drop probability.
+    AF32,
+    /// Assured forwarding class 3 with high drop probability.
+    AF33,
+    /// Assured forwarding class 4 with low drop probability (multimedia conferencing).
+    AF41,
+    /// Assured forwarding class 4 with medium drop probability.
+    AF42,
+    /// Assured forwarding class 4 with high drop probability.
+    AF43,
+    /// Expedited forwarding, low-loss and low-latency traffic like `VoIP`.
+    EF,
+}
+
+impl DscpClass {
+    /// Returns the 6-bit code point.
+    pub const fn dscp(self) -> u8 {
+        match self {
+            Self::CS0 => 0,
+            Self::CS1 => 8,
+            Self::CS2 => 16,
+            Self::CS3 => 24,
+            Self::CS4 => 32,
+            Self::CS5 => 40,
+            Self::CS6 => 48,
+            Self::CS7 => 56,
+            Self::AF11 => 10,
+            Self::AF12 => 12,
+            Self::AF13 => 14,
+            Self::AF21 => 18,
+            Self::AF22 => 20,
+            Self::AF23 => 22,
+            Self::AF31 => 26,
+            Self::AF32 => 28,
+            Self::AF33 => 30,
+            Self::AF41 => 34,
+            Self::AF42 => 36,
+            Self::AF43 => 38,
+            Self::EF => 46,
+        }
+    }
+
+    /// Returns the value of the `TOS` (IPv4) or `traffic class` (IPv6) byte
+    /// with this code point and without `ECN` bits.
+    pub const fn tos(self) -> u8 {
+        self.dscp() << 2
+    }
+}
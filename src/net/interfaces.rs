@@ -0,0 +1,273 @@
+//! This module contains [`list_interfaces`] that lists network interfaces of the host.
+use crate::BUG_MESSAGE;
+use std::cell::Cell;
+use std::ffi::CStr;
+use std::io::{Error, Result};
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// `InterfaceFlags` are flags of a [`NetworkInterface`] (`IFF_*`).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct InterfaceFlags(u32);
+
+impl InterfaceFlags {
+    /// Creates `InterfaceFlags` from the raw `IFF_*` bits.
+    pub const fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the raw `IFF_*` bits.
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// Returns whether all bits of `flag` (for example, `libc::IFF_UP`) are set.
+    #[allow(clippy::cast_sign_loss, reason = "IFF_* flags are positive")]
+    pub const fn contains(self, flag: libc::c_int) -> bool {
+        self.0 & flag as u32 == flag as u32
+    }
+
+    /// Returns whether the interface is up (`IFF_UP`).
+    pub const fn is_up(self) -> bool {
+        self.contains(libc::IFF_UP)
+    }
+
+    /// Returns whether the interface is running (`IFF_RUNNING`).
+    pub const fn is_running(self) -> bool {
+        self.contains(libc::IFF_RUNNING)
+    }
+
+    /// Returns whether the interface is a loopback interface (`IFF_LOOPBACK`).
+    pub const fn is_loopback(self) -> bool {
+        self.contains(libc::IFF_LOOPBACK)
+    }
+
+    /// Returns whether the interface is a point-to-point link (`IFF_POINTOPOINT`).
+    pub const fn is_point_to_point(self) -> bool {
+        self.contains(libc::IFF_POINTOPOINT)
+    }
+
+    /// Returns whether the interface supports broadcast (`IFF_BROADCAST`).
+    pub const fn is_broadcast(self) -> bool {
+        self.contains(libc::IFF_BROADCAST)
+    }
+
+    /// Returns whether the interface supports multicast (`IFF_MULTICAST`).
+    pub const fn is_multicast(self) -> bool {
+        self.contains(libc::IFF_MULTICAST)
+    }
+}
+
+/// `InterfaceAddr` is an address of a [`NetworkInterface`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InterfaceAddr {
+    /// An `IPv4` address.
+    Inet {
+        /// The address.
+        addr: Ipv4Addr,
+        /// The netmask of the address.
+        netmask: Option<Ipv4Addr>,
+    },
+    /// An `IPv6` address.
+    Inet6 {
+        /// The address.
+        addr: Ipv6Addr,
+        /// The netmask of the address.
+        netmask: Option<Ipv6Addr>,
+        /// The scope id of the address (non-zero for link-local addresses).
+        scope_id: u32,
+    },
+    /// A link-layer address (`AF_PACKET`). It is listed only on `Linux` and `Android`.
+    Link {
+        /// The index of the interface.
+        ifindex: u32,
+        /// The hardware address (for example, `MAC` address).
+        hw_addr: Vec<u8>,
+    },
+}
+
+/// `NetworkInterface` is a network interface returned by [`list_interfaces`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NetworkInterface {
+    /// The name of the interface.
+    pub name: String,
+    /// The addresses of the interface.
+    pub addresses: Vec<InterfaceAddr>,
+    /// The flags of the interface.
+    pub flags: InterfaceFlags,
+}
+
+/// Returns the `IPv4` address from the `sockaddr` if it is not null.
+unsafe fn ipv4_addr(sockaddr: *const libc::sockaddr) -> Option<Ipv4Addr> {
+    if sockaddr.is_null() {
+        return None;
+    }
+
+    let sockaddr_in = unsafe { std::ptr::read_unaligned(sockaddr.cast::<libc::sockaddr_in>()) };
+
+    Some(Ipv4Addr::from(u32::from_be(sockaddr_in.sin_addr.s_addr)))
+}
+
+/// Returns the `IPv6` address and the scope id from the `sockaddr` if it is not null.
+unsafe fn ipv6_addr(sockaddr: *const libc::sockaddr) -> Option<(Ipv6Addr, u32)> {
+    if sockaddr.is_null() {
+        return None;
+    }
+
+    let sockaddr_in6 = unsafe { std::ptr::read_unaligned(sockaddr.cast::<libc::sockaddr_in6>()) };
+
+    Some((
+        Ipv6Addr::from(sockaddr_in6.sin6_addr.s6_addr),
+        sockaddr_in6.sin6_scope_id,
+    ))
+}
+
+/// Converts an entry of `getifaddrs` to [`InterfaceAddr`]
+/// if the entry has an address of a known family.
+unsafe fn interface_addr(ifaddr: &libc::ifaddrs) -> Option<InterfaceAddr> {
+    if ifaddr.ifa_addr.is_null() {
+        return None;
+    }
+
+    match i32::from(unsafe { (*ifaddr.ifa_addr).sa_family }) {
+        libc::AF_INET => Some(InterfaceAddr::Inet {
+            addr: unsafe { ipv4_addr(ifaddr.ifa_addr) }?,
+            netmask: unsafe { ipv4_addr(ifaddr.ifa_netmask) },
+        }),
+        libc::AF_INET6 => {
+            let (addr, scope_id) = unsafe { ipv6_addr(ifaddr.ifa_addr) }?;
+
+            Some(InterfaceAddr::Inet6 {
+                addr,
+                netmask: unsafe { ipv6_addr(ifaddr.ifa_netmask) }.map(|(netmask, _)| netmask),
+                scope_id,
+            })
+        }
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        libc::AF_PACKET => {
+            let sockaddr_ll =
+                unsafe { std::ptr::read_unaligned(ifaddr.ifa_addr.cast::<libc::sockaddr_ll>()) };
+            let hw_addr_len = (sockaddr_ll.sll_halen as usize).min(sockaddr_ll.sll_addr.len());
+
+            #[allow(clippy::cast_sign_loss, reason = "interface indexes are positive")]
+            Some(InterfaceAddr::Link {
+                ifindex: sockaddr_ll.sll_ifindex as u32,
+                hw_addr: sockaddr_ll.sll_addr[..hw_addr_len].to_vec(),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Lists network interfaces of the host synchronously. Interfaces with the same name
+/// are merged.
+fn list_interfaces_sync() -> Result<Vec<NetworkInterface>> {
+    let mut ifaddrs = std::ptr::null_mut();
+    if unsafe { libc::getifaddrs(&raw mut ifaddrs) } == -1 {
+        return Err(Error::last_os_error());
+    }
+
+    let mut interfaces: Vec<NetworkInterface> = Vec::new();
+    let mut current = ifaddrs;
+    while let Some(ifaddr) = unsafe { current.as_ref() } {
+        current = ifaddr.ifa_next;
+
+        let name = unsafe { CStr::from_ptr(ifaddr.ifa_name) }.to_string_lossy();
+        let index = interfaces
+            .iter()
+            .position(|i| i.name == name)
+            .unwrap_or_else(|| {
+                interfaces.push(NetworkInterface {
+                    name: name.into_owned(),
+                    addresses: Vec::new(),
+                    flags: InterfaceFlags::from_bits(ifaddr.ifa_flags),
+                });
+
+                interfaces.len() - 1
+            });
+
+        if let Some(addr) = unsafe { interface_addr(ifaddr) } {
+            interfaces[index].addresses.push(addr);
+        }
+    }
+
+    unsafe { libc::freeifaddrs(ifaddrs) };
+
+    Ok(interfaces)
+}
+
+/// Lists network interfaces of the host with their addresses and flags using `getifaddrs(3)`.
+///
+/// It allows enumerating local addresses to bind to all of them or to find the name
+/// of an interface to bind a socket to it.
+///
+/// `getifaddrs` is not an `io_uring` operation, so the call is executed in the thread pool
+/// of the current [`Executor`](crate::Executor).
+///
+/// # Errors
+///
+/// Returns an error if `getifaddrs` fails (for example, if there is not enough memory).
+///
+/// # Panics
+///
+/// If the thread pool of the current [`Executor`](crate::Executor) is disabled
+/// with `debug_assertions`.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::net::interfaces::{list_interfaces, InterfaceAddr};
+///
+/// # async fn foo() -> std::io::Result<()> {
+/// for interface in list_interfaces().await? {
+///     if !interface.flags.is_up() {
+///         continue;
+///     }
+///
+///     for addr in &interface.addresses {
+///         if let InterfaceAddr::Inet { addr, .. } = addr {
+///             println!("{}: {addr}", interface.name);
+///         }
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[allow(
+    clippy::future_not_send,
+    reason = "It is not `Send` because of the result cell, it is fine"
+)]
+pub async fn list_interfaces() -> Result<Vec<NetworkInterface>> {
+    let result = Cell::new(None);
+
+    crate::asyncify!(|| {
+        result.set(Some(list_interfaces_sync()));
+    })
+    .await;
+
+    result.take().expect(BUG_MESSAGE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+
+    #[orengine::test::test_local]
+    fn test_list_interfaces() {
+        let interfaces = list_interfaces().await.expect("list_interfaces failed");
+        let loopback = interfaces
+            .iter()
+            .find(|interface| interface.flags.is_loopback())
+            .expect("no loopback interface");
+
+        assert!(loopback.flags.is_up());
+        assert!(loopback.addresses.contains(&InterfaceAddr::Inet {
+            addr: Ipv4Addr::LOCALHOST,
+            netmask: Some(Ipv4Addr::new(255, 0, 0, 0)),
+        }));
+
+        let names: std::collections::HashSet<_> =
+            interfaces.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names.len(), interfaces.len());
+    }
+}
@@ -2,10 +2,17 @@ pub use addr::*;
 pub use bind_config::{BindConfig, ReusePort};
 pub use connected_datagram::ConnectedDatagram;
 pub use datagram::Datagram;
+pub use dscp::DscpClass;
 pub use listener::Listener;
+#[cfg(target_os = "linux")]
+pub use netlink::{NetlinkAddr, NetlinkSocket};
+#[cfg(target_os = "linux")]
+pub use packet::{PacketAddr, PacketSocket};
+pub use pool::{ConnectionPool, ConnectionPoolConfig, PooledConnection};
 pub use socket::Socket;
+pub use socket_stats::SocketStats;
 pub use stream::Stream;
-pub use tcp::{TcpListener, TcpStream};
+pub use tcp::{AcceptLimitedStream, LimitedTcpStream, TcpListener, TcpStream};
 pub use udp::{UdpConnectedSocket, UdpSocket};
 #[cfg(unix)]
 pub use unix::{UnixConnectedDatagram, UnixDatagram, UnixListener, UnixStream};
@@ -16,10 +23,22 @@ pub mod bind_config;
 pub mod connected_datagram;
 pub(crate) mod creators_of_sockets;
 pub mod datagram;
+pub mod dscp;
+#[cfg(unix)]
+pub mod interfaces;
 pub mod listener;
+#[cfg(target_os = "linux")]
+pub mod netlink;
+#[cfg(target_os = "linux")]
+pub mod packet;
+pub mod pool;
+pub mod quic;
 pub mod socket;
+pub mod socket_stats;
 pub mod stream;
 pub mod tcp;
+#[cfg(target_os = "linux")]
+pub mod tun;
 pub mod udp;
 #[cfg(unix)]
 pub mod unix;
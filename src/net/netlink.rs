@@ -0,0 +1,319 @@
+//! This module contains [`NetlinkSocket`] and [`NetlinkAddr`].
+use crate::io::sys::{AsRawSocket, AsSocket, FromRawSocket, IntoRawSocket, RawSocket};
+use crate::io::{AsyncPollSocket, AsyncRecv, AsyncSend, AsyncSocketClose};
+use crate::net::addr::{FromSockAddr, IntoSockAddr, ToSockAddrs};
+use crate::net::creators_of_sockets::new_netlink_socket;
+use crate::net::{Socket, SocketStats};
+use crate::runtime::local_executor;
+use socket2::SockAddr;
+use std::fmt::{Debug, Formatter};
+use std::io::Result;
+use std::mem::ManuallyDrop;
+use std::{io, mem};
+
+/// `NetlinkAddr` is an address of a `netlink` socket (`sockaddr_nl`).
+///
+/// It consists of the port id (`nl_pid`) and the bitmask of multicast groups (`nl_groups`).
+/// The port id `0` means the kernel.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NetlinkAddr {
+    pid: u32,
+    groups: u32,
+}
+
+impl NetlinkAddr {
+    /// Creates a new `NetlinkAddr` with the provided port id and multicast groups.
+    pub const fn new(pid: u32, groups: u32) -> Self {
+        Self { pid, groups }
+    }
+
+    /// Returns the port id of the address.
+    pub const fn pid(&self) -> u32 {
+        self.pid
+    }
+
+    /// Returns the bitmask of multicast groups of the address.
+    pub const fn groups(&self) -> u32 {
+        self.groups
+    }
+}
+
+impl IntoSockAddr for NetlinkAddr {
+    fn into_sock_addr(self) -> SockAddr {
+        // SAFETY: All zeros is a valid representation for `sockaddr_storage`.
+        let mut storage = unsafe { mem::zeroed::<libc::sockaddr_storage>() };
+        let netlink_addr = unsafe { &mut *(&raw mut storage).cast::<libc::sockaddr_nl>() };
+        #[allow(clippy::cast_possible_truncation, reason = "libc::AF_NETLINK is 16")]
+        {
+            netlink_addr.nl_family = libc::AF_NETLINK as libc::sa_family_t;
+        }
+        netlink_addr.nl_pid = self.pid;
+        netlink_addr.nl_groups = self.groups;
+
+        #[allow(
+            clippy::cast_possible_truncation,
+            reason = "size of sockaddr_nl is less than u32::MAX"
+        )]
+        unsafe {
+            SockAddr::new(storage, size_of::<libc::sockaddr_nl>() as libc::socklen_t)
+        }
+    }
+}
+
+impl FromSockAddr for NetlinkAddr {
+    fn from_sock_addr(addr: SockAddr) -> Option<Self> {
+        if i32::from(addr.family()) != libc::AF_NETLINK {
+            return None;
+        }
+
+        let netlink_addr =
+            unsafe { std::ptr::read_unaligned(addr.as_ptr().cast::<libc::sockaddr_nl>()) };
+
+        Some(Self::new(netlink_addr.nl_pid, netlink_addr.nl_groups))
+    }
+}
+
+impl ToSockAddrs<Self> for NetlinkAddr {
+    type Iter = std::iter::Once<Self>;
+
+    fn to_sock_addrs(&self) -> io::Result<Self::Iter> {
+        Ok(std::iter::once(*self))
+    }
+}
+
+/// A `netlink` socket for communication with the kernel.
+///
+/// It is a raw socket (`socket(AF_NETLINK, SOCK_RAW, protocol)`) that sends and receives
+/// `netlink` messages with [`AsyncSend`] and [`AsyncRecv`]. Building and parsing of messages
+/// is left to the caller. Messages are sent to the kernel.
+///
+/// It allows monitoring network interfaces, routing tables and device events
+/// without a dedicated blocking thread.
+///
+/// # OS Support
+///
+/// This structure is only supported on `Linux`.
+///
+/// # Close
+///
+/// [`NetlinkSocket`] is automatically closed after it is dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::io::{full_buffer, AsyncRecv};
+/// use orengine::net::NetlinkSocket;
+///
+/// # async fn foo() -> std::io::Result<()> {
+/// let mut socket = NetlinkSocket::route().await?;
+/// // Subscribe to changes of links (RTMGRP_LINK).
+/// socket.bind_to(0, libc::RTMGRP_LINK as u32)?;
+///
+/// let mut buf = full_buffer();
+/// loop {
+///     let n = socket.recv(&mut buf).await?;
+///     // parse netlink messages in buf[..n]
+/// }
+/// # }
+/// ```
+pub struct NetlinkSocket {
+    raw_socket: RawSocket,
+    stats: SocketStats,
+}
+
+impl NetlinkSocket {
+    /// Creates a new `netlink` socket with the provided `netlink` protocol
+    /// (for example, `libc::NETLINK_ROUTE`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the kernel doesn't support the protocol.
+    pub async fn new(protocol: i32) -> Result<Self> {
+        Ok(Self {
+            raw_socket: new_netlink_socket(protocol).await?,
+            stats: SocketStats::new(),
+        })
+    }
+
+    /// Creates a new `netlink` socket with the `NETLINK_ROUTE` protocol to receive
+    /// and modify routing tables, network interfaces and addresses.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the socket can't be created.
+    pub async fn route() -> Result<Self> {
+        Self::new(libc::NETLINK_ROUTE).await
+    }
+
+    /// Creates a new `netlink` socket with the `NETLINK_KOBJECT_UEVENT` protocol
+    /// to receive device events (`uevent`) of the kernel.
+    ///
+    /// Bind it to the group `1` with [`bind_to`](Self::bind_to) to receive events.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the socket can't be created.
+    pub async fn kobject_uevent() -> Result<Self> {
+        Self::new(libc::NETLINK_KOBJECT_UEVENT).await
+    }
+
+    /// Binds the socket to the port id `pid` and subscribes it to the multicast `groups`.
+    ///
+    /// If `pid` is `0`, the kernel assigns a unique port id.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the port id is already in use or the process has no permission
+    /// to subscribe to the groups.
+    pub fn bind_to(&mut self, pid: u32, groups: u32) -> Result<()> {
+        let borrow_socket = AsSocket::as_socket(self);
+        let socket_ref = socket2::SockRef::from(&borrow_socket);
+
+        socket_ref.bind(&NetlinkAddr::new(pid, groups).into_sock_addr())
+    }
+}
+
+impl std::os::fd::IntoRawFd for NetlinkSocket {
+    fn into_raw_fd(self) -> std::os::fd::RawFd {
+        ManuallyDrop::new(self).raw_socket
+    }
+}
+
+impl IntoRawSocket for NetlinkSocket {}
+
+impl std::os::fd::AsRawFd for NetlinkSocket {
+    fn as_raw_fd(&self) -> std::os::fd::RawFd {
+        self.raw_socket
+    }
+}
+
+impl AsRawSocket for NetlinkSocket {}
+
+impl std::os::fd::AsFd for NetlinkSocket {
+    fn as_fd(&self) -> std::os::fd::BorrowedFd<'_> {
+        unsafe { std::os::fd::BorrowedFd::borrow_raw(self.raw_socket) }
+    }
+}
+
+impl AsSocket for NetlinkSocket {}
+
+impl std::os::fd::FromRawFd for NetlinkSocket {
+    unsafe fn from_raw_fd(raw_fd: std::os::fd::RawFd) -> Self {
+        Self {
+            raw_socket: raw_fd,
+            stats: SocketStats::new(),
+        }
+    }
+}
+
+impl FromRawSocket for NetlinkSocket {}
+
+impl From<std::os::fd::OwnedFd> for NetlinkSocket {
+    fn from(fd: std::os::fd::OwnedFd) -> Self {
+        unsafe { std::os::fd::FromRawFd::from_raw_fd(std::os::fd::IntoRawFd::into_raw_fd(fd)) }
+    }
+}
+
+impl From<NetlinkSocket> for std::os::fd::OwnedFd {
+    fn from(socket: NetlinkSocket) -> Self {
+        unsafe { std::os::fd::FromRawFd::from_raw_fd(std::os::fd::IntoRawFd::into_raw_fd(socket)) }
+    }
+}
+
+impl AsyncPollSocket for NetlinkSocket {}
+
+impl Socket for NetlinkSocket {
+    type Addr = NetlinkAddr;
+
+    #[inline]
+    fn stats(&self) -> Option<&SocketStats> {
+        Some(&self.stats)
+    }
+}
+
+impl AsyncSend for NetlinkSocket {}
+
+impl AsyncRecv for NetlinkSocket {}
+
+impl AsyncSocketClose for NetlinkSocket {}
+
+impl Debug for NetlinkSocket {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut res = f.debug_struct("NetlinkSocket");
+
+        if let Ok(addr) = self.local_addr() {
+            res.field("addr", &addr);
+        }
+
+        res.field("raw_socket", &AsRawSocket::as_raw_socket(self))
+            .finish()
+    }
+}
+
+impl Drop for NetlinkSocket {
+    fn drop(&mut self) {
+        let close_future = self.close();
+        local_executor().exec_local_future(async {
+            close_future.await.expect("Failed to close netlink socket");
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+
+    #[orengine::test::test_local]
+    fn test_netlink_route_dump_links() {
+        const SEQ: u32 = 42;
+
+        let mut socket = NetlinkSocket::route()
+            .await
+            .expect("failed to create netlink socket");
+        socket.bind_to(0, 0).expect("bind_to call failed");
+        let local_addr = socket.local_addr().expect("local_addr call failed");
+        assert_ne!(local_addr.pid(), 0);
+        assert_eq!(local_addr.groups(), 0);
+
+        // nlmsghdr (16 bytes) + rtgenmsg (1 byte) aligned to 4 bytes.
+        let mut request = Vec::with_capacity(20);
+        request.extend_from_slice(&20u32.to_ne_bytes());
+        request.extend_from_slice(&libc::RTM_GETLINK.to_ne_bytes());
+        #[allow(clippy::cast_possible_truncation, reason = "flags fit in u16")]
+        let flags = (libc::NLM_F_REQUEST | libc::NLM_F_DUMP) as u16;
+        request.extend_from_slice(&flags.to_ne_bytes());
+        request.extend_from_slice(&SEQ.to_ne_bytes());
+        request.extend_from_slice(&0u32.to_ne_bytes());
+        #[allow(clippy::cast_possible_truncation, reason = "AF_PACKET fits in u8")]
+        request.extend_from_slice(&[libc::AF_PACKET as u8, 0, 0, 0]);
+
+        socket
+            .send_all_bytes(&request)
+            .await
+            .expect("send call failed");
+
+        let mut buf = vec![0u8; 32 * 1024];
+        let n = socket.recv_bytes(&mut buf).await.expect("recv call failed");
+        assert!(n >= 16);
+
+        let message_type = u16::from_ne_bytes([buf[4], buf[5]]);
+        let seq = u32::from_ne_bytes([buf[8], buf[9], buf[10], buf[11]]);
+        assert_eq!(seq, SEQ);
+        assert!(
+            message_type == libc::RTM_NEWLINK || i32::from(message_type) == libc::NLMSG_DONE,
+            "unexpected message type: {message_type}"
+        );
+        assert_eq!(socket.stats().unwrap().bytes_sent(), request.len() as u64);
+    }
+
+    #[test]
+    fn test_netlink_addr() {
+        let addr = NetlinkAddr::new(123, 0b101);
+        let converted = NetlinkAddr::from_sock_addr(addr.into_sock_addr());
+        assert_eq!(converted, Some(addr));
+
+        let ip_addr = std::net::SocketAddr::from(([127, 0, 0, 1], 80)).into_sock_addr();
+        assert_eq!(NetlinkAddr::from_sock_addr(ip_addr), None);
+    }
+}
@@ -0,0 +1,373 @@
+//! This module contains [`PacketSocket`] and [`PacketAddr`].
+use crate::io::sys::{AsRawSocket, AsSocket, FromRawSocket, IntoRawSocket, RawSocket};
+use crate::io::{AsyncPollSocket, AsyncRecvFrom, AsyncSendTo, AsyncSocketClose};
+use crate::net::addr::{FromSockAddr, IntoSockAddr, ToSockAddrs};
+use crate::net::creators_of_sockets::new_packet_socket;
+use crate::net::{Socket, SocketStats};
+use crate::runtime::local_executor;
+use socket2::SockAddr;
+use std::fmt::{Debug, Formatter};
+use std::io::Result;
+use std::mem::ManuallyDrop;
+use std::{io, mem, ptr};
+
+/// The maximum length of a hardware address in [`PacketAddr`].
+const MAX_HW_ADDR_LEN: usize = 8;
+
+/// `PacketAddr` is a link-layer address of a [`PacketSocket`] (`sockaddr_ll`).
+///
+/// To send a frame, set the index of the interface ([`ifindex`](Self::ifindex)).
+/// Received frames also have the hardware type, the packet type and the hardware address
+/// of the sender.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PacketAddr {
+    ifindex: u32,
+    protocol: u16,
+    hardware_type: u16,
+    packet_type: u8,
+    hw_addr_len: u8,
+    hw_addr: [u8; MAX_HW_ADDR_LEN],
+}
+
+impl PacketAddr {
+    /// Creates a new `PacketAddr` with the provided interface index and `Ethernet` protocol
+    /// (in host byte order, for example, `libc::ETH_P_ARP as u16`).
+    pub const fn new(ifindex: u32, protocol: u16) -> Self {
+        Self {
+            ifindex,
+            protocol,
+            hardware_type: 0,
+            packet_type: 0,
+            hw_addr_len: 0,
+            hw_addr: [0; MAX_HW_ADDR_LEN],
+        }
+    }
+
+    /// Sets the hardware address of the destination.
+    ///
+    /// # Panics
+    ///
+    /// If `hw_addr` is longer than 8 bytes.
+    #[must_use]
+    pub fn with_hw_addr(mut self, hw_addr: &[u8]) -> Self {
+        assert!(
+            hw_addr.len() <= MAX_HW_ADDR_LEN,
+            "hardware address must not be longer than {MAX_HW_ADDR_LEN} bytes"
+        );
+
+        self.hw_addr[..hw_addr.len()].copy_from_slice(hw_addr);
+        #[allow(clippy::cast_possible_truncation, reason = "checked above")]
+        {
+            self.hw_addr_len = hw_addr.len() as u8;
+        }
+
+        self
+    }
+
+    /// Returns the index of the interface.
+    pub const fn ifindex(&self) -> u32 {
+        self.ifindex
+    }
+
+    /// Returns the `Ethernet` protocol in host byte order.
+    pub const fn protocol(&self) -> u16 {
+        self.protocol
+    }
+
+    /// Returns the `ARP` hardware type (`ARPHRD_*`).
+    pub const fn hardware_type(&self) -> u16 {
+        self.hardware_type
+    }
+
+    /// Returns the packet type (`PACKET_HOST`, `PACKET_BROADCAST`, `PACKET_OUTGOING`, etc.).
+    pub const fn packet_type(&self) -> u8 {
+        self.packet_type
+    }
+
+    /// Returns the hardware address.
+    pub fn hw_addr(&self) -> &[u8] {
+        &self.hw_addr[..self.hw_addr_len as usize]
+    }
+}
+
+impl IntoSockAddr for PacketAddr {
+    fn into_sock_addr(self) -> SockAddr {
+        // SAFETY: All zeros is a valid representation for `sockaddr_storage`.
+        let mut storage = unsafe { mem::zeroed::<libc::sockaddr_storage>() };
+        let packet_addr = unsafe { &mut *(&raw mut storage).cast::<libc::sockaddr_ll>() };
+        #[allow(clippy::cast_possible_truncation, reason = "libc::AF_PACKET is 17")]
+        {
+            packet_addr.sll_family = libc::AF_PACKET as libc::c_ushort;
+        }
+        packet_addr.sll_protocol = self.protocol.to_be();
+        #[allow(
+            clippy::cast_possible_wrap,
+            reason = "interface indexes are less than i32::MAX"
+        )]
+        {
+            packet_addr.sll_ifindex = self.ifindex as libc::c_int;
+        }
+        packet_addr.sll_hatype = self.hardware_type;
+        packet_addr.sll_pkttype = self.packet_type;
+        packet_addr.sll_halen = self.hw_addr_len;
+        packet_addr.sll_addr = self.hw_addr;
+
+        #[allow(
+            clippy::cast_possible_truncation,
+            reason = "size of sockaddr_ll is less than u32::MAX"
+        )]
+        unsafe {
+            SockAddr::new(storage, size_of::<libc::sockaddr_ll>() as libc::socklen_t)
+        }
+    }
+}
+
+impl FromSockAddr for PacketAddr {
+    fn from_sock_addr(addr: SockAddr) -> Option<Self> {
+        if i32::from(addr.family()) != libc::AF_PACKET {
+            return None;
+        }
+
+        let packet_addr = unsafe { ptr::read_unaligned(addr.as_ptr().cast::<libc::sockaddr_ll>()) };
+
+        #[allow(clippy::cast_sign_loss, reason = "interface indexes are positive")]
+        Some(Self {
+            ifindex: packet_addr.sll_ifindex as u32,
+            protocol: u16::from_be(packet_addr.sll_protocol),
+            hardware_type: packet_addr.sll_hatype,
+            packet_type: packet_addr.sll_pkttype,
+            #[allow(clippy::cast_possible_truncation, reason = "MAX_HW_ADDR_LEN is 8")]
+            hw_addr_len: packet_addr.sll_halen.min(MAX_HW_ADDR_LEN as u8),
+            hw_addr: packet_addr.sll_addr,
+        })
+    }
+}
+
+impl ToSockAddrs<Self> for PacketAddr {
+    type Iter = std::iter::Once<Self>;
+
+    fn to_sock_addrs(&self) -> io::Result<Self::Iter> {
+        Ok(std::iter::once(*self))
+    }
+}
+
+/// A packet socket for raw access to the link layer.
+///
+/// It is a raw socket (`socket(AF_PACKET, SOCK_RAW, protocol)`) that sends and receives
+/// whole frames including the link-layer header with [`AsyncSendTo`] and [`AsyncRecvFrom`].
+/// It allows writing network probes, packet analysers and `ARP` responders without `libpcap`.
+///
+/// # OS Support
+///
+/// This structure is only supported on `Linux`, and it requires
+/// the `CAP_NET_RAW` capability.
+///
+/// # Close
+///
+/// [`PacketSocket`] is automatically closed after it is dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::io::{full_buffer, AsyncRecvFrom};
+/// use orengine::net::PacketSocket;
+///
+/// # async fn foo(ifindex: u32) -> std::io::Result<()> {
+/// let mut socket = PacketSocket::new().await?;
+/// socket.bind_to_interface(ifindex)?;
+///
+/// let mut buf = full_buffer();
+/// loop {
+///     let (n, addr) = socket.recv_from(&mut buf).await?;
+///     println!("received {n} bytes with protocol {:#06x}", addr.protocol());
+/// }
+/// # }
+/// ```
+pub struct PacketSocket {
+    raw_socket: RawSocket,
+    stats: SocketStats,
+}
+
+impl PacketSocket {
+    /// Creates a new packet socket that receives frames of all protocols (`ETH_P_ALL`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the process has no `CAP_NET_RAW` capability.
+    pub async fn new() -> Result<Self> {
+        #[allow(clippy::cast_possible_truncation, reason = "ETH_P_ALL fits in u16")]
+        Self::with_protocol(libc::ETH_P_ALL as u16).await
+    }
+
+    /// Creates a new packet socket that receives frames of the provided `Ethernet` protocol
+    /// (in host byte order, for example, `libc::ETH_P_ARP as u16`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the process has no `CAP_NET_RAW` capability.
+    pub async fn with_protocol(protocol: u16) -> Result<Self> {
+        Ok(Self {
+            raw_socket: new_packet_socket(protocol).await?,
+            stats: SocketStats::new(),
+        })
+    }
+
+    /// Binds the socket to the interface with the provided index, so it receives frames
+    /// only from this interface.
+    ///
+    /// Get the index with `if_nametoindex`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the interface doesn't exist.
+    pub fn bind_to_interface(&mut self, ifindex: u32) -> Result<()> {
+        let borrow_socket = AsSocket::as_socket(self);
+        let socket_ref = socket2::SockRef::from(&borrow_socket);
+
+        // The zero protocol keeps the protocol of the socket.
+        socket_ref.bind(&PacketAddr::new(ifindex, 0).into_sock_addr())
+    }
+}
+
+impl std::os::fd::IntoRawFd for PacketSocket {
+    fn into_raw_fd(self) -> std::os::fd::RawFd {
+        ManuallyDrop::new(self).raw_socket
+    }
+}
+
+impl IntoRawSocket for PacketSocket {}
+
+impl std::os::fd::AsRawFd for PacketSocket {
+    fn as_raw_fd(&self) -> std::os::fd::RawFd {
+        self.raw_socket
+    }
+}
+
+impl AsRawSocket for PacketSocket {}
+
+impl std::os::fd::AsFd for PacketSocket {
+    fn as_fd(&self) -> std::os::fd::BorrowedFd<'_> {
+        unsafe { std::os::fd::BorrowedFd::borrow_raw(self.raw_socket) }
+    }
+}
+
+impl AsSocket for PacketSocket {}
+
+impl std::os::fd::FromRawFd for PacketSocket {
+    unsafe fn from_raw_fd(raw_fd: std::os::fd::RawFd) -> Self {
+        Self {
+            raw_socket: raw_fd,
+            stats: SocketStats::new(),
+        }
+    }
+}
+
+impl FromRawSocket for PacketSocket {}
+
+impl From<std::os::fd::OwnedFd> for PacketSocket {
+    fn from(fd: std::os::fd::OwnedFd) -> Self {
+        unsafe { std::os::fd::FromRawFd::from_raw_fd(std::os::fd::IntoRawFd::into_raw_fd(fd)) }
+    }
+}
+
+impl From<PacketSocket> for std::os::fd::OwnedFd {
+    fn from(socket: PacketSocket) -> Self {
+        unsafe { std::os::fd::FromRawFd::from_raw_fd(std::os::fd::IntoRawFd::into_raw_fd(socket)) }
+    }
+}
+
+impl AsyncPollSocket for PacketSocket {}
+
+impl Socket for PacketSocket {
+    type Addr = PacketAddr;
+
+    #[inline]
+    fn stats(&self) -> Option<&SocketStats> {
+        Some(&self.stats)
+    }
+}
+
+impl AsyncSendTo for PacketSocket {}
+
+impl AsyncRecvFrom for PacketSocket {}
+
+impl AsyncSocketClose for PacketSocket {}
+
+impl Debug for PacketSocket {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut res = f.debug_struct("PacketSocket");
+
+        if let Ok(addr) = self.local_addr() {
+            res.field("addr", &addr);
+        }
+
+        res.field("raw_socket", &AsRawSocket::as_raw_socket(self))
+            .finish()
+    }
+}
+
+impl Drop for PacketSocket {
+    fn drop(&mut self) {
+        let close_future = self.close();
+        local_executor().exec_local_future(async {
+            close_future.await.expect("Failed to close packet socket");
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+
+    #[orengine::test::test_local]
+    fn test_packet_socket() {
+        // IEEE 802 local experimental ethertype.
+        const PROTOCOL: u16 = 0x88B5;
+
+        let mut socket = match PacketSocket::with_protocol(PROTOCOL).await {
+            Ok(socket) => socket,
+            // It requires the CAP_NET_RAW capability.
+            Err(err) if err.kind() == io::ErrorKind::PermissionDenied => return,
+            Err(err) => panic!("failed to create packet socket: {err}"),
+        };
+        let ifindex = unsafe { libc::if_nametoindex(c"lo".as_ptr()) };
+        assert_ne!(ifindex, 0);
+        socket
+            .bind_to_interface(ifindex)
+            .expect("bind_to_interface call failed");
+        assert_eq!(socket.local_addr().unwrap().ifindex(), ifindex);
+
+        let mut frame = Vec::new();
+        frame.extend_from_slice(&[0; 12]); // destination and source MAC addresses
+        frame.extend_from_slice(&PROTOCOL.to_be_bytes());
+        frame.extend_from_slice(b"ping");
+
+        let addr = PacketAddr::new(ifindex, PROTOCOL).with_hw_addr(&[0; 6]);
+        socket
+            .send_bytes_to(&frame, addr)
+            .await
+            .expect("send_to call failed");
+
+        let mut buf = [0u8; 64];
+        let (n, from) = socket
+            .recv_bytes_from(&mut buf)
+            .await
+            .expect("recv_from call failed");
+        assert_eq!(&buf[..n], frame.as_slice());
+        assert_eq!(from.ifindex(), ifindex);
+        assert_eq!(from.protocol(), PROTOCOL);
+        assert_eq!(from.hw_addr(), &[0; 6]);
+    }
+
+    #[test]
+    fn test_packet_addr() {
+        let addr = PacketAddr::new(3, 0x0806).with_hw_addr(&[1, 2, 3, 4, 5, 6]);
+        let converted = PacketAddr::from_sock_addr(addr.into_sock_addr());
+        assert_eq!(converted, Some(addr));
+        assert_eq!(addr.hw_addr(), &[1, 2, 3, 4, 5, 6]);
+
+        let ip_addr = std::net::SocketAddr::from(([127, 0, 0, 1], 80)).into_sock_addr();
+        assert_eq!(PacketAddr::from_sock_addr(ip_addr), None);
+    }
+}
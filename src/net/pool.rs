@@ -0,0 +1,525 @@
+//! This module contains [`ConnectionPool`] that reuses [`TcpStreams`](TcpStream),
+//! its config [`ConnectionPoolConfig`] and [`PooledConnection`].
+use crate::future::AsyncFn;
+use crate::get_task_from_context;
+use crate::io::AsyncConnectStream;
+use crate::net::TcpStream;
+use crate::runtime::{local_executor, Task};
+use std::cell::{Cell, UnsafeCell};
+use std::collections::VecDeque;
+use std::future::{ready, Future, Ready};
+use std::io::Result;
+use std::marker::PhantomData;
+use std::net::SocketAddr;
+use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+/// `ConnectionPoolConfig` is the config of the [`ConnectionPool`].
+///
+/// # Fields
+///
+/// - `max_size` is the maximum number of open connections (idle and in use).
+///   [`ConnectionPool::get`] waits while this number is reached. Default is `16`;
+///
+/// - `min_idle` is the number of idle connections that
+///   [`ConnectionPool::fill_min_idle`] keeps open. Default is `0`;
+///
+/// - `max_age` is the maximum lifetime of a connection. Older connections are closed instead
+///   of being reused. Default is `None` (no limit).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConnectionPoolConfig {
+    max_size: usize,
+    min_idle: usize,
+    max_age: Option<Duration>,
+}
+
+impl ConnectionPoolConfig {
+    /// Creates a new `ConnectionPoolConfig` with default values.
+    pub const fn new() -> Self {
+        Self {
+            max_size: 16,
+            min_idle: 0,
+            max_age: None,
+        }
+    }
+
+    /// Returns the maximum number of open connections.
+    pub const fn max_size(&self) -> usize {
+        self.max_size
+    }
+
+    /// Sets the maximum number of open connections.
+    #[must_use]
+    pub const fn set_max_size(mut self, max_size: usize) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Returns the number of idle connections that
+    /// [`ConnectionPool::fill_min_idle`] keeps open.
+    pub const fn min_idle(&self) -> usize {
+        self.min_idle
+    }
+
+    /// Sets the number of idle connections that [`ConnectionPool::fill_min_idle`] keeps open.
+    #[must_use]
+    pub const fn set_min_idle(mut self, min_idle: usize) -> Self {
+        self.min_idle = min_idle;
+        self
+    }
+
+    /// Returns the maximum lifetime of a connection.
+    pub const fn max_age(&self) -> Option<Duration> {
+        self.max_age
+    }
+
+    /// Sets the maximum lifetime of a connection.
+    #[must_use]
+    pub const fn set_max_age(mut self, max_age: Option<Duration>) -> Self {
+        self.max_age = max_age;
+        self
+    }
+}
+
+impl Default for ConnectionPoolConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The health check of the [`ConnectionPool`] that accepts all connections.
+pub type NoHealthCheck = fn(TcpStream) -> Ready<Option<TcpStream>>;
+
+/// An idle connection of the [`ConnectionPool`].
+struct IdleConnection {
+    stream: TcpStream,
+    created_at: Instant,
+}
+
+/// `WaitForConnection` is a future that waits until a connection is returned to
+/// the [`ConnectionPool`] or closed.
+struct WaitForConnection<'pool, H> {
+    pool: &'pool ConnectionPool<H>,
+    was_called: bool,
+}
+
+impl<H> Future for WaitForConnection<'_, H> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = unsafe { self.get_unchecked_mut() };
+        if this.was_called {
+            return Poll::Ready(());
+        }
+
+        this.was_called = true;
+        let task = unsafe { get_task_from_context!(cx) };
+        unsafe { &mut *this.pool.waiting_tasks.get() }.push_back(task);
+
+        Poll::Pending
+    }
+}
+
+/// `PooledConnection` is a [`TcpStream`] taken from the [`ConnectionPool`].
+///
+/// It returns the stream to the pool when it is dropped or [`released`](Self::release).
+/// Use [`into_inner`](Self::into_inner) to take the stream out of the pool,
+/// for example, if the connection is broken.
+pub struct PooledConnection<'pool, H> {
+    pool: &'pool ConnectionPool<H>,
+    stream: Option<TcpStream>,
+    created_at: Instant,
+}
+
+impl<H> PooledConnection<'_, H> {
+    /// Returns the stream to the [`ConnectionPool`]. It is the same as dropping it.
+    #[inline]
+    pub fn release(self) {}
+
+    /// Takes the stream out of the [`ConnectionPool`].
+    ///
+    /// The pool opens a new connection instead of it if needed.
+    pub fn into_inner(mut self) -> TcpStream {
+        let stream = unsafe { self.stream.take().unwrap_unchecked() };
+        self.pool.on_connection_closed();
+
+        stream
+    }
+}
+
+impl<H> Deref for PooledConnection<'_, H> {
+    type Target = TcpStream;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { self.stream.as_ref().unwrap_unchecked() }
+    }
+}
+
+impl<H> DerefMut for PooledConnection<'_, H> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { self.stream.as_mut().unwrap_unchecked() }
+    }
+}
+
+impl<H> Drop for PooledConnection<'_, H> {
+    fn drop(&mut self) {
+        if let Some(stream) = self.stream.take() {
+            self.pool.put(stream, self.created_at);
+        }
+    }
+}
+
+/// `ConnectionPool` reuses [`TcpStreams`](TcpStream) to the same address.
+///
+/// [`get`](Self::get) returns an idle connection or opens a new one.
+/// When `max_size` connections are open, it waits until one of them is returned.
+///
+/// Before an idle connection is returned, it is checked:
+///
+/// - connections older than `max_age` are closed;
+///
+/// - the health check (set with [`with_health_check`](Self::with_health_check)) receives
+///   the stream and returns it back if the connection is healthy.
+///
+/// Read [`ConnectionPoolConfig`] for more details.
+///
+/// # The difference between `ConnectionPool` and a shared pool
+///
+/// The `ConnectionPool` works with `local tasks`, so create one pool for each
+/// [`Executor`](crate::Executor).
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::io::{AsyncRecv, AsyncSend};
+/// use orengine::net::pool::{ConnectionPool, ConnectionPoolConfig};
+/// use std::time::Duration;
+///
+/// # async fn foo() -> std::io::Result<()> {
+/// let pool = ConnectionPool::new(
+///     "127.0.0.1:5432".parse().unwrap(),
+///     ConnectionPoolConfig::new()
+///         .set_max_size(8)
+///         .set_max_age(Some(Duration::from_secs(300))),
+/// );
+///
+/// let mut connection = pool.get().await?;
+/// connection.send_all_bytes(b"PING").await?;
+///
+/// let mut buf = [0u8; 4];
+/// connection.recv_bytes_exact(&mut buf).await?;
+///
+/// connection.release(); // or drop it
+/// # Ok(())
+/// # }
+/// ```
+pub struct ConnectionPool<H = NoHealthCheck> {
+    addr: SocketAddr,
+    config: ConnectionPoolConfig,
+    health_check: H,
+    idle: UnsafeCell<VecDeque<IdleConnection>>,
+    number_of_connections: Cell<usize>,
+    waiting_tasks: UnsafeCell<VecDeque<Task>>,
+    // impl !Send
+    no_send_marker: PhantomData<*const ()>,
+}
+
+impl ConnectionPool {
+    /// Creates a new `ConnectionPool` that connects to `addr` without a health check.
+    ///
+    /// It doesn't open connections, use [`fill_min_idle`](Self::fill_min_idle) for it.
+    ///
+    /// # Panics
+    ///
+    /// If `max_size` is zero or `min_idle` is greater than `max_size`.
+    pub fn new(addr: SocketAddr, config: ConnectionPoolConfig) -> Self {
+        assert!(config.max_size > 0, "max_size must be greater than 0");
+        assert!(
+            config.min_idle <= config.max_size,
+            "min_idle must not be greater than max_size"
+        );
+
+        Self {
+            addr,
+            config,
+            health_check: |stream| ready(Some(stream)),
+            idle: UnsafeCell::new(VecDeque::new()),
+            number_of_connections: Cell::new(0),
+            waiting_tasks: UnsafeCell::new(VecDeque::new()),
+            no_send_marker: PhantomData,
+        }
+    }
+}
+
+impl<H> ConnectionPool<H> {
+    /// Sets the health check of idle connections.
+    ///
+    /// It receives an idle stream before it is returned by [`get`](Self::get),
+    /// and returns it back if the connection is healthy or `None` to close it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::io::AsyncSend;
+    /// use orengine::net::pool::{ConnectionPool, ConnectionPoolConfig};
+    /// use orengine::net::TcpStream;
+    ///
+    /// # fn foo() {
+    /// let pool = ConnectionPool::new("127.0.0.1:6379".parse().unwrap(), ConnectionPoolConfig::new())
+    ///     .with_health_check(|mut stream: TcpStream| async move {
+    ///         stream.send_all_bytes(b"PING\r\n").await.ok().map(|()| stream)
+    ///     });
+    /// # }
+    /// ```
+    pub fn with_health_check<NewH>(self, health_check: NewH) -> ConnectionPool<NewH>
+    where
+        NewH: AsyncFn<TcpStream, Output = Option<TcpStream>>,
+    {
+        let this = std::mem::ManuallyDrop::new(self);
+
+        ConnectionPool {
+            addr: this.addr,
+            config: this.config,
+            health_check,
+            idle: UnsafeCell::new(std::mem::take(unsafe { &mut *this.idle.get() })),
+            number_of_connections: Cell::new(this.number_of_connections.get()),
+            waiting_tasks: UnsafeCell::new(std::mem::take(unsafe {
+                &mut *this.waiting_tasks.get()
+            })),
+            no_send_marker: PhantomData,
+        }
+    }
+
+    /// Returns the address of the connections.
+    pub const fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Returns the config of the pool.
+    pub const fn config(&self) -> ConnectionPoolConfig {
+        self.config
+    }
+
+    /// Returns the number of open connections (idle and in use).
+    pub fn number_of_connections(&self) -> usize {
+        self.number_of_connections.get()
+    }
+
+    /// Returns the number of idle connections.
+    pub fn number_of_idle_connections(&self) -> usize {
+        unsafe { &*self.idle.get() }.len()
+    }
+
+    /// Returns whether the connection created at `created_at` is older than `max_age`.
+    fn is_expired(&self, created_at: Instant) -> bool {
+        self.config
+            .max_age
+            .is_some_and(|max_age| local_executor().start_round_time() >= created_at + max_age)
+    }
+
+    /// Wakes up a task that waits for a connection.
+    fn wake_one(&self) {
+        if let Some(task) = unsafe { &mut *self.waiting_tasks.get() }.pop_front() {
+            local_executor().exec_task(task);
+        }
+    }
+
+    /// Decrements the number of connections and wakes up a waiting task.
+    fn on_connection_closed(&self) {
+        self.number_of_connections
+            .set(self.number_of_connections.get() - 1);
+        self.wake_one();
+    }
+
+    /// Returns the stream to the pool or closes it if it is expired.
+    fn put(&self, stream: TcpStream, created_at: Instant) {
+        if self.is_expired(created_at) {
+            drop(stream);
+            self.on_connection_closed();
+
+            return;
+        }
+
+        unsafe { &mut *self.idle.get() }.push_back(IdleConnection { stream, created_at });
+        self.wake_one();
+    }
+
+    /// Opens a new connection. The number of connections must be already incremented.
+    #[allow(clippy::future_not_send, reason = "Because it is `local`")]
+    async fn connect(&self) -> Result<(TcpStream, Instant)> {
+        match TcpStream::connect(self.addr).await {
+            Ok(stream) => Ok((stream, local_executor().start_round_time())),
+            Err(err) => {
+                self.on_connection_closed();
+
+                Err(err)
+            }
+        }
+    }
+
+    /// Opens connections until there are `min_idle` idle connections
+    /// or `max_size` open connections.
+    ///
+    /// Call it after creating the pool and periodically to keep warm connections.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a connection can't be opened.
+    #[allow(clippy::future_not_send, reason = "Because it is `local`")]
+    pub async fn fill_min_idle(&self) -> Result<()> {
+        while self.number_of_idle_connections() < self.config.min_idle
+            && self.number_of_connections.get() < self.config.max_size
+        {
+            self.number_of_connections
+                .set(self.number_of_connections.get() + 1);
+            let (stream, created_at) = self.connect().await?;
+            self.put(stream, created_at);
+        }
+
+        Ok(())
+    }
+}
+
+impl<H> ConnectionPool<H>
+where
+    H: AsyncFn<TcpStream, Output = Option<TcpStream>>,
+{
+    /// Returns an idle healthy connection or opens a new one.
+    ///
+    /// If `max_size` connections are open, it waits until one of them is returned or closed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a new connection can't be opened.
+    #[allow(clippy::future_not_send, reason = "Because it is `local`")]
+    pub async fn get(&self) -> Result<PooledConnection<'_, H>> {
+        loop {
+            while let Some(idle) = unsafe { &mut *self.idle.get() }.pop_front() {
+                if self.is_expired(idle.created_at) {
+                    drop(idle);
+                    self.on_connection_closed();
+
+                    continue;
+                }
+
+                if let Some(stream) = self.health_check.call(idle.stream).await {
+                    return Ok(PooledConnection {
+                        pool: self,
+                        stream: Some(stream),
+                        created_at: idle.created_at,
+                    });
+                }
+
+                self.on_connection_closed();
+            }
+
+            if self.number_of_connections.get() < self.config.max_size {
+                self.number_of_connections
+                    .set(self.number_of_connections.get() + 1);
+                let (stream, created_at) = self.connect().await?;
+
+                return Ok(PooledConnection {
+                    pool: self,
+                    stream: Some(stream),
+                    created_at,
+                });
+            }
+
+            WaitForConnection {
+                pool: self,
+                was_called: false,
+            }
+            .await;
+        }
+    }
+}
+
+unsafe impl<H: Sync> Sync for ConnectionPool<H> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::io::{AsyncAccept, AsyncBind};
+    use crate::net::{Socket, TcpListener};
+    use crate::sync::local_scope;
+    use crate::Local;
+
+    /// Accepts connections and keeps them open. Returns the address of the listener.
+    #[allow(clippy::future_not_send, reason = "Because it is `local`")]
+    async fn run_listener() -> SocketAddr {
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.expect("bind failed");
+        let addr = listener.local_addr().expect("local_addr failed");
+
+        local_executor().spawn_local(async move {
+            #[allow(
+                clippy::collection_is_never_read,
+                reason = "It keeps accepted connections open"
+            )]
+            let mut streams = Vec::new();
+            while let Ok((stream, _)) = listener.accept().await {
+                streams.push(stream);
+            }
+        });
+
+        addr
+    }
+
+    #[orengine::test::test_local]
+    fn test_connection_pool() {
+        let addr = run_listener().await;
+        let pool = ConnectionPool::new(
+            addr,
+            ConnectionPoolConfig::new().set_max_size(2).set_min_idle(1),
+        );
+
+        pool.fill_min_idle().await.expect("fill_min_idle failed");
+        assert_eq!(pool.number_of_idle_connections(), 1);
+
+        let first = pool.get().await.expect("get failed");
+        let first_addr = first.local_addr().expect("local_addr failed");
+        let second = pool.get().await.expect("get failed");
+        assert_eq!(pool.number_of_connections(), 2);
+
+        let was_got = Local::new(false);
+        local_scope(|scope| async {
+            scope.spawn(async {
+                let third = pool.get().await.expect("get failed");
+                assert_eq!(third.local_addr().expect("local_addr failed"), first_addr);
+                *was_got.borrow_mut() = true;
+            });
+
+            assert!(!*was_got.borrow());
+            first.release();
+        })
+        .await;
+
+        assert!(*was_got.borrow());
+        assert_eq!(pool.number_of_connections(), 2);
+
+        drop(second.into_inner());
+        assert_eq!(pool.number_of_connections(), 1);
+    }
+
+    #[orengine::test::test_local]
+    fn test_connection_pool_health_check_and_max_age() {
+        let addr = run_listener().await;
+        let pool = ConnectionPool::new(addr, ConnectionPoolConfig::new())
+            .with_health_check(|_: TcpStream| async { None });
+
+        pool.get().await.expect("get failed").release();
+        assert_eq!(pool.number_of_idle_connections(), 1);
+
+        pool.get().await.expect("get failed").release();
+        assert_eq!(pool.number_of_connections(), 1);
+
+        let pool = ConnectionPool::new(
+            addr,
+            ConnectionPoolConfig::new().set_max_age(Some(Duration::ZERO)),
+        );
+        pool.get().await.expect("get failed").release();
+        assert_eq!(pool.number_of_connections(), 0);
+    }
+}
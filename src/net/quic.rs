@@ -0,0 +1,276 @@
+//! This module contains traits of a pluggable `QUIC` transport:
+//! [`QuicTransport`], [`QuicConnection`], [`QuicSendStream`] and [`QuicRecvStream`].
+//!
+//! `orengine` doesn't implement `QUIC` itself. An application (for example, an `HTTP/3` server)
+//! is written against these traits, and the transport is provided by an implementation
+//! of them that wraps a `QUIC` library. So the transport can be swapped without changing
+//! the application logic.
+use std::io::{Error, ErrorKind, Result};
+use std::net::SocketAddr;
+
+/// `QuicSendStream` is the sending side of a `QUIC` stream.
+pub trait QuicSendStream {
+    /// Writes bytes from `buf` to the stream and returns the number of written bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stream or the connection has been closed.
+    async fn write(&mut self, buf: &[u8]) -> Result<usize>;
+
+    /// Writes all bytes from `buf` to the stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stream or the connection has been closed.
+    async fn write_all(&mut self, mut buf: &[u8]) -> Result<()> {
+        while !buf.is_empty() {
+            let n = self.write(buf).await?;
+            if n == 0 {
+                return Err(Error::from(ErrorKind::WriteZero));
+            }
+
+            buf = &buf[n..];
+        }
+
+        Ok(())
+    }
+
+    /// Gracefully finishes the stream. The peer reads the end of the stream
+    /// after all written bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stream has already been finished or reset.
+    async fn finish(&mut self) -> Result<()>;
+}
+
+/// `QuicRecvStream` is the receiving side of a `QUIC` stream.
+pub trait QuicRecvStream {
+    /// Reads bytes from the stream into `buf` and returns the number of read bytes.
+    /// `0` means that the peer has finished the stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stream has been reset or the connection has been closed.
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize>;
+
+    /// Reads the stream to the end and appends all bytes to `buf`.
+    /// Returns the number of read bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stream has been reset or the connection has been closed.
+    async fn read_to_end(&mut self, buf: &mut Vec<u8>) -> Result<usize> {
+        let mut chunk = [0u8; 4096];
+        let mut total = 0;
+        loop {
+            let n = self.read(&mut chunk).await?;
+            if n == 0 {
+                return Ok(total);
+            }
+
+            buf.extend_from_slice(&chunk[..n]);
+            total += n;
+        }
+    }
+}
+
+/// `QuicConnection` is an established `QUIC` connection that multiplexes streams.
+pub trait QuicConnection {
+    /// The sending side of streams of the connection.
+    type SendStream: QuicSendStream;
+    /// The receiving side of streams of the connection.
+    type RecvStream: QuicRecvStream;
+
+    /// Opens a new bidirectional stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection has been closed.
+    async fn open_bidi(&self) -> Result<(Self::SendStream, Self::RecvStream)>;
+
+    /// Opens a new unidirectional stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection has been closed.
+    async fn open_uni(&self) -> Result<Self::SendStream>;
+
+    /// Waits for the next bidirectional stream opened by the peer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection has been closed.
+    async fn accept_bidi(&self) -> Result<(Self::SendStream, Self::RecvStream)>;
+
+    /// Waits for the next unidirectional stream opened by the peer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection has been closed.
+    async fn accept_uni(&self) -> Result<Self::RecvStream>;
+
+    /// Returns the address of the peer.
+    fn remote_addr(&self) -> SocketAddr;
+
+    /// Closes the connection immediately with the application `error_code` and `reason`.
+    /// All streams of the connection are reset.
+    fn close(&self, error_code: u64, reason: &[u8]);
+}
+
+/// `QuicTransport` creates `QUIC` connections.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::net::quic::{QuicConnection, QuicRecvStream, QuicSendStream, QuicTransport};
+/// use std::net::SocketAddr;
+///
+/// // The application doesn't depend on the QUIC library.
+/// async fn request<T: QuicTransport>(
+///     addr: SocketAddr,
+///     config: &T::Config,
+/// ) -> std::io::Result<Vec<u8>> {
+///     let connection = T::connect(addr, config).await?;
+///     let (mut send, mut recv) = connection.open_bidi().await?;
+///     send.write_all(b"GET /index.html").await?;
+///     send.finish().await?;
+///
+///     let mut response = Vec::new();
+///     recv.read_to_end(&mut response).await?;
+///     connection.close(0, b"done");
+///
+///     Ok(response)
+/// }
+/// ```
+pub trait QuicTransport {
+    /// The configuration of the client (TLS configuration, server name, transport parameters).
+    type Config;
+    /// The connection created by the transport.
+    type Connection: QuicConnection;
+
+    /// Connects to the `QUIC` server at `addr` with the provided `config`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the handshake has failed.
+    async fn connect(addr: SocketAddr, config: &Self::Config) -> Result<Self::Connection>;
+}
+
+#[cfg(test)]
+#[allow(clippy::future_not_send, reason = "Loopback streams are `local`")]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::local::Local;
+    use std::collections::VecDeque;
+
+    /// A stream of [`LoopbackTransport`] that receives the bytes it has sent.
+    #[derive(Clone)]
+    struct LoopbackStream {
+        data: Local<VecDeque<u8>>,
+        is_finished: Local<bool>,
+    }
+
+    impl LoopbackStream {
+        fn new() -> Self {
+            Self {
+                data: Local::new(VecDeque::new()),
+                is_finished: Local::new(false),
+            }
+        }
+    }
+
+    impl QuicSendStream for LoopbackStream {
+        async fn write(&mut self, buf: &[u8]) -> Result<usize> {
+            // Writes at most 3 bytes to check write_all.
+            let n = buf.len().min(3);
+            self.data.borrow_mut().extend(&buf[..n]);
+
+            Ok(n)
+        }
+
+        async fn finish(&mut self) -> Result<()> {
+            *self.is_finished.borrow_mut() = true;
+
+            Ok(())
+        }
+    }
+
+    impl QuicRecvStream for LoopbackStream {
+        async fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            let mut data = self.data.borrow_mut();
+            if data.is_empty() && !*self.is_finished.borrow() {
+                return Err(Error::from(ErrorKind::WouldBlock));
+            }
+
+            let n = buf.len().min(data.len());
+            for (dst, src) in buf.iter_mut().zip(data.drain(..n)) {
+                *dst = src;
+            }
+
+            Ok(n)
+        }
+    }
+
+    struct LoopbackConnection;
+
+    impl QuicConnection for LoopbackConnection {
+        type SendStream = LoopbackStream;
+        type RecvStream = LoopbackStream;
+
+        async fn open_bidi(&self) -> Result<(LoopbackStream, LoopbackStream)> {
+            let stream = LoopbackStream::new();
+
+            Ok((stream.clone(), stream))
+        }
+
+        async fn open_uni(&self) -> Result<LoopbackStream> {
+            Ok(LoopbackStream::new())
+        }
+
+        async fn accept_bidi(&self) -> Result<(LoopbackStream, LoopbackStream)> {
+            Err(Error::from(ErrorKind::ConnectionAborted))
+        }
+
+        async fn accept_uni(&self) -> Result<LoopbackStream> {
+            Err(Error::from(ErrorKind::ConnectionAborted))
+        }
+
+        fn remote_addr(&self) -> SocketAddr {
+            SocketAddr::from(([127, 0, 0, 1], 443))
+        }
+
+        fn close(&self, _error_code: u64, _reason: &[u8]) {}
+    }
+
+    struct LoopbackTransport;
+
+    impl QuicTransport for LoopbackTransport {
+        type Config = ();
+        type Connection = LoopbackConnection;
+
+        async fn connect(_addr: SocketAddr, _config: &()) -> Result<LoopbackConnection> {
+            Ok(LoopbackConnection)
+        }
+    }
+
+    async fn echo<T: QuicTransport>(config: &T::Config) -> Result<Vec<u8>> {
+        let connection = T::connect(SocketAddr::from(([127, 0, 0, 1], 443)), config).await?;
+        let (mut send, mut recv) = connection.open_bidi().await?;
+        send.write_all(b"Hello, QUIC!").await?;
+        send.finish().await?;
+
+        let mut response = Vec::new();
+        recv.read_to_end(&mut response).await?;
+        connection.close(0, b"");
+
+        Ok(response)
+    }
+
+    #[orengine::test::test_local]
+    fn test_quic_transport_traits() {
+        let response = echo::<LoopbackTransport>(&()).await.expect("echo failed");
+        assert_eq!(response, b"Hello, QUIC!");
+    }
+}
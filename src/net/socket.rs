@@ -1,7 +1,7 @@
 use crate::io::sys::{AsRawSocket, AsSocket, FromRawSocket, IntoRawSocket};
 use crate::io::{AsyncPollSocket, AsyncSocketClose};
 use crate::net::addr::{FromSockAddr, IntoSockAddr, ToSockAddrs};
-use crate::net::new_unix_unsupported_error;
+use crate::net::{new_unix_unsupported_error, SocketStats};
 use std::io;
 use std::io::Error;
 
@@ -21,6 +21,14 @@ use std::io::Error;
 /// - [`FromRawSocket`]
 /// - [`AsSocket`]
 /// - [`AsyncPollSocket`]
+///
+/// # Ownership of the socket
+///
+/// All sockets of `orengine` own their file descriptors and close them with an asynchronous
+/// `close` operation when they are dropped. They can be converted from and into
+/// [`OwnedFd`](std::os::fd::OwnedFd) (`OwnedSocket` on `windows`) to interoperate with crates
+/// that manage file descriptors. After the conversion into [`OwnedFd`](std::os::fd::OwnedFd)
+/// the socket is closed synchronously by the [`OwnedFd`](std::os::fd::OwnedFd).
 pub trait Socket:
     IntoRawSocket + AsRawSocket + FromRawSocket + AsSocket + AsyncPollSocket + AsyncSocketClose
 {
@@ -36,6 +44,35 @@ pub trait Socket:
         false
     }
 
+    /// Returns the [`I/O statistics`](SocketStats) of the socket
+    /// or `None` if the socket doesn't track them.
+    ///
+    /// The statistics are updated by `send`, `recv`, `send_to` and `recv_from` operations.
+    /// All sockets of orengine track them. The default implementation returns `None`,
+    /// so sockets implemented outside orengine don't need to store them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::io::{full_buffer, AsyncBind, AsyncRecvFrom};
+    /// use orengine::net::{Socket, UdpSocket};
+    ///
+    /// # async fn foo() -> std::io::Result<()> {
+    /// let mut socket = UdpSocket::bind("127.0.0.1:8080").await?;
+    /// let mut buf = full_buffer();
+    /// socket.recv_from(&mut buf).await?;
+    ///
+    /// if let Some(stats) = socket.stats() {
+    ///     println!("received {} bytes", stats.bytes_recv());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn stats(&self) -> Option<&SocketStats> {
+        None
+    }
+
     /// Returns the local socket address that the listener is bound to.
     ///
     /// This method provides the local address, such as the IP and port, that the listener is
@@ -132,6 +169,166 @@ pub trait Socket:
         socket_ref.ttl()
     }
 
+    /// Sets the value of the `IP_TOS` option for outgoing IPv4 packets of the socket.
+    ///
+    /// The upper 6 bits are the DSCP code point (read [`DscpClass`](crate::net::DscpClass))
+    /// and the lower 2 bits are the `ECN` bits.
+    ///
+    /// # Unix
+    ///
+    /// UNIX sockets do not support `TOS`, therefore this method returns an error for them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::io::AsyncBind;
+    /// use orengine::net::{DscpClass, Socket, UdpSocket};
+    ///
+    /// # async fn foo() -> std::io::Result<()> {
+    /// let socket = UdpSocket::bind("127.0.0.1:8080").await?;
+    /// socket.set_tos(DscpClass::AF41.tos())?;
+    ///
+    /// assert_eq!(socket.tos()?, DscpClass::AF41.tos());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn set_tos(&self, tos: u8) -> io::Result<()> {
+        if self.is_unix() {
+            return Err(new_unix_unsupported_error());
+        }
+
+        let borrow_socket = AsSocket::as_socket(self);
+        let socket_ref = socket2::SockRef::from(&borrow_socket);
+
+        socket_ref.set_tos(u32::from(tos))
+    }
+
+    /// Returns the value of the `IP_TOS` option of the socket.
+    ///
+    /// Read [`set_tos`](Socket::set_tos) for more details.
+    #[inline]
+    fn tos(&self) -> io::Result<u8> {
+        if self.is_unix() {
+            return Err(new_unix_unsupported_error());
+        }
+
+        let borrow_socket = AsSocket::as_socket(self);
+        let socket_ref = socket2::SockRef::from(&borrow_socket);
+
+        #[allow(clippy::cast_possible_truncation, reason = "TOS is a byte")]
+        socket_ref.tos().map(|tos| tos as u8)
+    }
+
+    /// Sets the value of the `IPV6_TCLASS` option (traffic class)
+    /// for outgoing IPv6 packets of the socket.
+    ///
+    /// It is the IPv6 counterpart of [`set_tos`](Socket::set_tos) and has the same format.
+    ///
+    /// # Unix
+    ///
+    /// UNIX sockets do not support traffic classes, therefore this method returns an error
+    /// for them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::io::AsyncBind;
+    /// use orengine::net::{DscpClass, Socket, UdpSocket};
+    ///
+    /// # async fn foo() -> std::io::Result<()> {
+    /// let socket = UdpSocket::bind("[::1]:8080").await?;
+    /// socket.set_traffic_class(DscpClass::EF.tos())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn set_traffic_class(&self, traffic_class: u8) -> io::Result<()> {
+        if self.is_unix() {
+            return Err(new_unix_unsupported_error());
+        }
+
+        #[cfg(unix)]
+        {
+            let value = libc::c_int::from(traffic_class);
+            #[allow(
+                clippy::cast_possible_truncation,
+                reason = "size of c_int is less than u32::MAX"
+            )]
+            let res = unsafe {
+                libc::setsockopt(
+                    AsRawSocket::as_raw_socket(self),
+                    libc::IPPROTO_IPV6,
+                    libc::IPV6_TCLASS,
+                    std::ptr::from_ref(&value).cast(),
+                    size_of::<libc::c_int>() as libc::socklen_t,
+                )
+            };
+            if res == -1 {
+                return Err(Error::last_os_error());
+            }
+
+            Ok(())
+        }
+
+        #[cfg(not(unix))]
+        {
+            let _ = traffic_class;
+
+            Err(Error::new(
+                io::ErrorKind::Unsupported,
+                "IPV6_TCLASS is not supported on this platform",
+            ))
+        }
+    }
+
+    /// Returns the value of the `IPV6_TCLASS` option (traffic class) of the socket.
+    ///
+    /// Read [`set_traffic_class`](Socket::set_traffic_class) for more details.
+    #[inline]
+    fn traffic_class(&self) -> io::Result<u8> {
+        if self.is_unix() {
+            return Err(new_unix_unsupported_error());
+        }
+
+        #[cfg(unix)]
+        {
+            let mut value: libc::c_int = 0;
+            #[allow(
+                clippy::cast_possible_truncation,
+                reason = "size of c_int is less than u32::MAX"
+            )]
+            let mut len = size_of::<libc::c_int>() as libc::socklen_t;
+            let res = unsafe {
+                libc::getsockopt(
+                    AsRawSocket::as_raw_socket(self),
+                    libc::IPPROTO_IPV6,
+                    libc::IPV6_TCLASS,
+                    std::ptr::from_mut(&mut value).cast(),
+                    &raw mut len,
+                )
+            };
+            if res == -1 {
+                return Err(Error::last_os_error());
+            }
+
+            #[allow(
+                clippy::cast_possible_truncation,
+                clippy::cast_sign_loss,
+                reason = "traffic class is a byte"
+            )]
+            Ok(value as u8)
+        }
+
+        #[cfg(not(unix))]
+        {
+            Err(Error::new(
+                io::ErrorKind::Unsupported,
+                "IPV6_TCLASS is not supported on this platform",
+            ))
+        }
+    }
+
     /// Retrieves and clears any pending socket errors on the listener.
     ///
     /// This method allows you to check for any socket errors that occurred during socket operations,
@@ -0,0 +1,84 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// `SocketStats` contains I/O statistics of a [`socket`](crate::net::Socket):
+/// the number of sent and received bytes and the number of failed `send` and `recv` calls.
+///
+/// Peeking is not counted, because it does not consume the data.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::io::{AsyncConnectStream, AsyncSend};
+/// use orengine::net::{Socket, SocketStats, TcpStream};
+///
+/// # async fn foo() -> std::io::Result<()> {
+/// let mut stream = TcpStream::connect("127.0.0.1:8080").await?;
+/// stream.send_all_bytes(b"ping").await?;
+///
+/// assert_eq!(stream.stats().map(SocketStats::bytes_sent), Some(4));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct SocketStats {
+    bytes_sent: AtomicU64,
+    bytes_recv: AtomicU64,
+    send_errors: AtomicU64,
+    recv_errors: AtomicU64,
+}
+
+impl SocketStats {
+    /// Creates a new `SocketStats` with zeroed counters.
+    pub const fn new() -> Self {
+        Self {
+            bytes_sent: AtomicU64::new(0),
+            bytes_recv: AtomicU64::new(0),
+            send_errors: AtomicU64::new(0),
+            recv_errors: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the number of sent bytes.
+    pub fn bytes_sent(&self) -> u64 {
+        self.bytes_sent.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of received bytes.
+    pub fn bytes_recv(&self) -> u64 {
+        self.bytes_recv.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of failed `send` calls.
+    pub fn send_errors(&self) -> u64 {
+        self.send_errors.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of failed `recv` calls.
+    pub fn recv_errors(&self) -> u64 {
+        self.recv_errors.load(Ordering::Relaxed)
+    }
+
+    /// Adds the number of sent bytes.
+    #[inline]
+    pub(crate) fn add_bytes_sent(&self, n: usize) {
+        self.bytes_sent.fetch_add(n as u64, Ordering::Relaxed);
+    }
+
+    /// Adds the number of received bytes.
+    #[inline]
+    pub(crate) fn add_bytes_recv(&self, n: usize) {
+        self.bytes_recv.fetch_add(n as u64, Ordering::Relaxed);
+    }
+
+    /// Increments the number of failed `send` calls.
+    #[inline]
+    pub(crate) fn inc_send_errors(&self) {
+        self.send_errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Increments the number of failed `recv` calls.
+    #[inline]
+    pub(crate) fn inc_recv_errors(&self) {
+        self.recv_errors.fetch_add(1, Ordering::Relaxed);
+    }
+}
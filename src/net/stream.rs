@@ -28,6 +28,15 @@ use std::time::Duration;
 /// - [`AsyncSend`]
 /// - [`AsyncShutdown`]
 ///
+/// # Complete reads and writes
+///
+/// [`recv`](AsyncRecv::recv) and [`send`](AsyncSend::send) can process fewer bytes than
+/// the length of the buffer. Use [`recv_exact`](AsyncRecv::recv_exact) to fill the whole buffer
+/// and [`send_all`](AsyncSend::send_all) to send the whole buffer.
+///
+/// Crates that expect `tokio::io` traits can use the stream through `TokioCompat`
+/// (with the `tokio-compat` feature).
+///
 /// # Example
 ///
 /// ```rust
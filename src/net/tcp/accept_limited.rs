@@ -0,0 +1,271 @@
+//! This module contains [`AcceptLimitedStream`] that accepts connections of the [`TcpListener`]
+//! while the number of alive connections is below the limit.
+use crate::future::AsyncIterator;
+use crate::get_task_from_context;
+use crate::io::AsyncAccept;
+use crate::net::{TcpListener, TcpStream};
+use crate::runtime::local_executor;
+use crate::runtime::task::Task;
+use std::cell::UnsafeCell;
+use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::io::Result;
+use std::net::SocketAddr;
+use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+
+/// Inner structure of the connection limit for internal use via [`UnsafeCell`].
+struct Inner {
+    number_of_connections: usize,
+    max_connections: usize,
+    waited_task: Option<Task>,
+}
+
+/// `ConnectionLimit` counts alive connections accepted by the [`AcceptLimitedStream`].
+struct ConnectionLimit {
+    inner: UnsafeCell<Inner>,
+}
+
+impl ConnectionLimit {
+    /// Returns a mutable reference to the [`Inner`].
+    #[inline]
+    #[allow(clippy::mut_from_ref, reason = "this is local")]
+    fn get_inner(&self) -> &mut Inner {
+        unsafe { &mut *self.inner.get() }
+    }
+}
+
+/// A [`Future`] to wait until the number of alive connections is below the limit.
+/// It takes a place for a new connection.
+struct WaitForFreePlace<'limit> {
+    limit: &'limit ConnectionLimit,
+}
+
+impl Future for WaitForFreePlace<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let inner = self.limit.get_inner();
+        if inner.number_of_connections < inner.max_connections {
+            inner.number_of_connections += 1;
+
+            return Poll::Ready(());
+        }
+
+        inner.waited_task = Some(unsafe { get_task_from_context!(cx) });
+
+        Poll::Pending
+    }
+}
+
+/// `ConnectionPermit` frees a place for a new connection when it is dropped.
+struct ConnectionPermit {
+    limit: Rc<ConnectionLimit>,
+}
+
+impl Drop for ConnectionPermit {
+    fn drop(&mut self) {
+        let inner = self.limit.get_inner();
+        inner.number_of_connections -= 1;
+
+        if let Some(task) = inner.waited_task.take() {
+            local_executor().spawn_local_task(task);
+        }
+    }
+}
+
+/// `LimitedTcpStream` is a [`TcpStream`] accepted by the [`AcceptLimitedStream`].
+///
+/// It dereferences to the [`TcpStream`] and frees a place for a new connection
+/// when it is dropped.
+pub struct LimitedTcpStream {
+    stream: TcpStream,
+    _permit: ConnectionPermit,
+}
+
+impl Deref for LimitedTcpStream {
+    type Target = TcpStream;
+
+    fn deref(&self) -> &Self::Target {
+        &self.stream
+    }
+}
+
+impl DerefMut for LimitedTcpStream {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.stream
+    }
+}
+
+impl Debug for LimitedTcpStream {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("LimitedTcpStream")
+            .field(&self.stream)
+            .finish()
+    }
+}
+
+/// `AcceptLimitedStream` accepts connections of the [`TcpListener`] while the number of alive
+/// [`LimitedTcpStream`]s is below the limit.
+///
+/// When the limit is reached, [`next`](AsyncIterator::next) waits until one of the accepted
+/// streams is dropped. New connections stay in the backlog of the listener meanwhile,
+/// so a spike of connections can't exhaust file descriptors.
+///
+/// Create it with [`TcpListener::accept_with_limit`].
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::future::AsyncIterator;
+/// use orengine::io::{AsyncBind, AsyncSend};
+/// use orengine::local_executor;
+/// use orengine::net::TcpListener;
+///
+/// # async fn foo() -> std::io::Result<()> {
+/// let mut listener = TcpListener::bind("127.0.0.1:8080").await?;
+/// let mut connections = listener.accept_with_limit(10_000);
+///
+/// while let Some(res) = connections.next().await {
+///     let (mut stream, _) = res?;
+///     local_executor().spawn_local(async move {
+///         let _ = stream.send_all_bytes(b"Hello, world!").await;
+///     });
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct AcceptLimitedStream<'listener> {
+    listener: &'listener mut TcpListener,
+    limit: Rc<ConnectionLimit>,
+}
+
+impl<'listener> AcceptLimitedStream<'listener> {
+    /// Creates a new `AcceptLimitedStream`.
+    ///
+    /// # Panics
+    ///
+    /// If `max_connections` is zero.
+    pub(crate) fn new(listener: &'listener mut TcpListener, max_connections: usize) -> Self {
+        assert_ne!(max_connections, 0, "max_connections must be greater than 0");
+
+        Self {
+            listener,
+            limit: Rc::new(ConnectionLimit {
+                inner: UnsafeCell::new(Inner {
+                    number_of_connections: 0,
+                    max_connections,
+                    waited_task: None,
+                }),
+            }),
+        }
+    }
+
+    /// Returns the number of alive connections accepted by this `AcceptLimitedStream`.
+    pub fn number_of_connections(&self) -> usize {
+        self.limit.get_inner().number_of_connections
+    }
+
+    /// Returns the maximum number of alive connections.
+    pub fn max_connections(&self) -> usize {
+        self.limit.get_inner().max_connections
+    }
+}
+
+impl AsyncIterator for AcceptLimitedStream<'_> {
+    type Item = Result<(LimitedTcpStream, SocketAddr)>;
+
+    /// Waits for a free place and accepts a new connection. It never returns `None`.
+    #[allow(clippy::future_not_send, reason = "Because it is `local`")]
+    async fn next(&mut self) -> Option<Self::Item> {
+        WaitForFreePlace { limit: &self.limit }.await;
+        let permit = ConnectionPermit {
+            limit: self.limit.clone(),
+        };
+
+        Some(self.listener.accept().await.map(|(stream, addr)| {
+            (
+                LimitedTcpStream {
+                    stream,
+                    _permit: permit,
+                },
+                addr,
+            )
+        }))
+    }
+}
+
+impl Debug for AcceptLimitedStream<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AcceptLimitedStream")
+            .field("number_of_connections", &self.number_of_connections())
+            .field("max_connections", &self.max_connections())
+            .finish_non_exhaustive()
+    }
+}
+
+impl TcpListener {
+    /// Returns an [`AcceptLimitedStream`] that accepts connections while the number
+    /// of alive connections accepted by it is below `max_connections`.
+    ///
+    /// Read [`AcceptLimitedStream`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// If `max_connections` is zero.
+    pub fn accept_with_limit(&mut self, max_connections: usize) -> AcceptLimitedStream<'_> {
+        AcceptLimitedStream::new(self, max_connections)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::io::{AsyncBind, AsyncConnectStream};
+    use crate::local::Local;
+    use crate::sleep;
+    use std::time::Duration;
+
+    #[orengine::test::test_local]
+    fn test_accept_with_limit() {
+        const ADDR: &str = "127.0.0.1:6101";
+
+        let mut listener = TcpListener::bind(ADDR).await.expect("bind call failed");
+        let accepted = Local::new(Vec::new());
+        let accepted_clone = accepted.clone();
+        local_executor().spawn_local(async move {
+            let mut connections = listener.accept_with_limit(2);
+            while let Some(res) = connections.next().await {
+                let (stream, _) = res.expect("accept call failed");
+                accepted_clone.borrow_mut().push(stream);
+                assert!(connections.number_of_connections() <= 2);
+            }
+        });
+
+        let mut clients = Vec::new();
+        for _ in 0..3 {
+            clients.push(TcpStream::connect(ADDR).await.expect("connect call failed"));
+        }
+
+        let wait_for_accepted = || async {
+            for _ in 0..100 {
+                if accepted.borrow().len() == 2 {
+                    break;
+                }
+                sleep(Duration::from_millis(1)).await;
+            }
+        };
+
+        wait_for_accepted().await;
+        sleep(Duration::from_millis(10)).await;
+        assert_eq!(accepted.borrow().len(), 2);
+
+        drop(accepted.borrow_mut().pop());
+        wait_for_accepted().await;
+        assert_eq!(accepted.borrow().len(), 2);
+        assert_eq!(clients.len(), 3);
+    }
+}
@@ -6,11 +6,12 @@ use std::io::Result;
 use std::mem::ManuallyDrop;
 use std::net::SocketAddr;
 
+use crate::future::AsyncFnMut;
 use crate::io::sys::{AsRawSocket, AsSocket, FromRawSocket, IntoRawSocket, RawSocket};
 use crate::io::{sys, AsyncAccept, AsyncBind, AsyncPollSocket, AsyncSocketClose};
-use crate::net::creators_of_sockets::new_tcp_socket;
+use crate::net::creators_of_sockets::{is_mptcp_socket, new_mptcp_socket, new_tcp_socket};
 use crate::net::tcp::TcpStream;
-use crate::net::{BindConfig, Listener, Socket};
+use crate::net::{BindConfig, Listener, Socket, SocketStats};
 use crate::runtime::local_executor;
 
 /// A TCP socket server, listening for connections.
@@ -35,6 +36,7 @@ use crate::runtime::local_executor;
 /// ```
 pub struct TcpListener {
     pub(crate) raw_socket: RawSocket,
+    stats: SocketStats,
 }
 
 impl From<TcpListener> for std::net::TcpListener {
@@ -48,6 +50,7 @@ impl From<std::net::TcpListener> for TcpListener {
     fn from(listener: std::net::TcpListener) -> Self {
         Self {
             raw_socket: sys::IntoRawSocket::into_raw_socket(listener),
+            stats: SocketStats::new(),
         }
     }
 }
@@ -103,23 +106,72 @@ impl AsSocket for TcpListener {}
 #[cfg(unix)]
 impl std::os::fd::FromRawFd for TcpListener {
     unsafe fn from_raw_fd(raw_fd: std::os::fd::RawFd) -> Self {
-        Self { raw_socket: raw_fd }
+        Self {
+            raw_socket: raw_fd,
+            stats: SocketStats::new(),
+        }
     }
 }
 
 #[cfg(windows)]
 impl std::os::windows::io::FromRawSocket for TcpListener {
     unsafe fn from_raw_socket(raw_socket: RawSocket) -> Self {
-        Self { raw_socket }
+        Self {
+            raw_socket,
+            stats: SocketStats::new(),
+        }
     }
 }
 
 impl FromRawSocket for TcpListener {}
 
+#[cfg(unix)]
+impl From<std::os::fd::OwnedFd> for TcpListener {
+    fn from(fd: std::os::fd::OwnedFd) -> Self {
+        unsafe { std::os::fd::FromRawFd::from_raw_fd(std::os::fd::IntoRawFd::into_raw_fd(fd)) }
+    }
+}
+
+#[cfg(unix)]
+impl From<TcpListener> for std::os::fd::OwnedFd {
+    fn from(listener: TcpListener) -> Self {
+        unsafe {
+            std::os::fd::FromRawFd::from_raw_fd(std::os::fd::IntoRawFd::into_raw_fd(listener))
+        }
+    }
+}
+
+#[cfg(windows)]
+impl From<std::os::windows::io::OwnedSocket> for TcpListener {
+    fn from(socket: std::os::windows::io::OwnedSocket) -> Self {
+        unsafe {
+            std::os::windows::io::FromRawSocket::from_raw_socket(
+                std::os::windows::io::IntoRawSocket::into_raw_socket(socket),
+            )
+        }
+    }
+}
+
+#[cfg(windows)]
+impl From<TcpListener> for std::os::windows::io::OwnedSocket {
+    fn from(listener: TcpListener) -> Self {
+        unsafe {
+            std::os::windows::io::FromRawSocket::from_raw_socket(
+                std::os::windows::io::IntoRawSocket::into_raw_socket(listener),
+            )
+        }
+    }
+}
+
 impl AsyncPollSocket for TcpListener {}
 
 impl Socket for TcpListener {
     type Addr = SocketAddr;
+
+    #[inline]
+    fn stats(&self) -> Option<&SocketStats> {
+        Some(&self.stats)
+    }
 }
 
 impl AsyncBind for TcpListener {
@@ -127,6 +179,14 @@ impl AsyncBind for TcpListener {
         new_tcp_socket(addr).await
     }
 
+    async fn new_socket_with_config(addr: &SocketAddr, config: &BindConfig) -> Result<RawSocket> {
+        if config.mptcp {
+            new_mptcp_socket(addr).await
+        } else {
+            new_tcp_socket(addr).await
+        }
+    }
+
     fn bind_and_listen_if_needed(
         sock_ref: SockRef,
         addr: SocketAddr,
@@ -140,6 +200,67 @@ impl AsyncBind for TcpListener {
     }
 }
 
+impl TcpListener {
+    /// Returns whether the listener uses multipath `TCP`.
+    ///
+    /// Read [`BindConfig::mptcp`] for more details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the protocol of the socket can't be read.
+    pub fn is_mptcp(&self) -> Result<bool> {
+        is_mptcp_socket(self.raw_socket)
+    }
+
+    /// Accepts connections in a loop and spawns a `local` task with the `handler`
+    /// for each accepted [`TcpStream`].
+    ///
+    /// The errors returned by the `handler` are ignored,
+    /// so the `handler` should process them by itself if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [`accept`](AsyncAccept::accept). The already spawned tasks
+    /// continue to process their connections.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::io::{AsyncBind, AsyncRecv, AsyncSend};
+    /// use orengine::net::{TcpListener, TcpStream};
+    ///
+    /// # async fn foo() -> std::io::Result<()> {
+    /// let mut listener = TcpListener::bind("127.0.0.1:8080").await?;
+    /// listener
+    ///     .for_each_connection(|mut stream: TcpStream| async move {
+    ///         let mut buf = [0u8; 1024];
+    ///         loop {
+    ///             let n = stream.recv_bytes(&mut buf).await?;
+    ///             if n == 0 {
+    ///                 return Ok(());
+    ///             }
+    ///
+    ///             stream.send_all_bytes(&buf[..n]).await?;
+    ///         }
+    ///     })
+    ///     .await
+    /// # }
+    /// ```
+    pub async fn for_each_connection<H>(&mut self, mut handler: H) -> Result<()>
+    where
+        H: AsyncFnMut<TcpStream, Output = Result<()>>,
+    {
+        loop {
+            let (stream, _) = self.accept().await?;
+            let handle_future = handler.call_mut(stream);
+
+            local_executor().spawn_local(async move {
+                let _ = handle_future.await;
+            });
+        }
+    }
+}
+
 impl AsyncAccept<TcpStream> for TcpListener {}
 
 impl AsyncSocketClose for TcpListener {}
@@ -174,6 +295,7 @@ impl Drop for TcpListener {
 mod tests {
     use super::*;
     use crate as orengine;
+    use crate::io::{AsyncConnectStream, AsyncRecv, AsyncSend};
     use crate::net::ReusePort;
     use crate::yield_now;
     use std::io;
@@ -233,4 +355,106 @@ mod tests {
         #[cfg(target_os = "linux")]
         test_listener_accept_with_config(&config.reuse_port(ReusePort::CPU), 4061).await;
     }
+
+    #[orengine::test::test_local]
+    fn test_bind_with_buffer_sizes() {
+        let config = BindConfig::default()
+            .recv_buffer_size(64 * 1024)
+            .send_buffer_size(32 * 1024);
+        let listener = TcpListener::bind_with_config("127.0.0.1:4064", &config)
+            .await
+            .expect("bind call failed");
+
+        let sock_ref = SockRef::from(&listener);
+        assert!(sock_ref.recv_buffer_size().unwrap() >= 64 * 1024);
+        assert!(sock_ref.send_buffer_size().unwrap() >= 32 * 1024);
+    }
+
+    #[orengine::test::test_local]
+    fn test_mptcp() {
+        const ADDR: &str = "127.0.0.1:4065";
+
+        let mut listener = TcpListener::bind_with_config(ADDR, &BindConfig::new().mptcp(true))
+            .await
+            .expect("bind call failed");
+        // It is MPTCP only if the kernel supports it.
+        listener.is_mptcp().expect("is_mptcp call failed");
+
+        let mut client = TcpStream::connect_mptcp(ADDR)
+            .await
+            .expect("connect_mptcp call failed");
+        let (mut server, _) = listener.accept().await.expect("accept call failed");
+        assert_eq!(
+            client.is_mptcp().expect("is_mptcp call failed"),
+            listener.is_mptcp().unwrap()
+        );
+
+        client.send_all_bytes(b"ping").await.expect("send failed");
+        let mut buf = [0u8; 4];
+        server
+            .recv_bytes_exact(&mut buf)
+            .await
+            .expect("recv failed");
+        assert_eq!(&buf, b"ping");
+
+        let tcp_listener = TcpListener::bind("127.0.0.1:4066")
+            .await
+            .expect("bind call failed");
+        assert!(!tcp_listener.is_mptcp().expect("is_mptcp call failed"));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[orengine::test::test_local]
+    fn test_transparent() {
+        const ADDR: &str = "127.0.0.1:4067";
+
+        let mut listener =
+            match TcpListener::bind_with_config(ADDR, &BindConfig::new().transparent(true)).await {
+                Ok(listener) => listener,
+                // It requires the CAP_NET_ADMIN capability.
+                Err(err) if err.kind() == io::ErrorKind::PermissionDenied => return,
+                Err(err) => panic!("bind call failed: {err}"),
+            };
+
+        let _client = TcpStream::connect(ADDR).await.expect("connect call failed");
+        let (server, _) = listener.accept().await.expect("accept call failed");
+
+        // The connection isn't redirected, so it is either not tracked by netfilter
+        // or its original destination is the listener.
+        if let Ok(original_dest_addr) = server.original_dest_addr() {
+            assert_eq!(original_dest_addr, ADDR.parse().unwrap());
+        }
+    }
+
+    #[orengine::test::test_local]
+    fn test_for_each_connection() {
+        const ADDR: &str = "127.0.0.1:6099";
+
+        let mut listener = TcpListener::bind(ADDR).await.expect("bind call failed");
+        local_executor().spawn_local(async move {
+            let _ = listener
+                .for_each_connection(|mut stream: TcpStream| async move {
+                    let mut buf = [0u8; 4];
+                    stream.recv_bytes_exact(&mut buf).await?;
+                    stream.send_all_bytes(&buf).await
+                })
+                .await;
+        });
+        yield_now().await;
+
+        for _ in 0..3 {
+            let mut stream = TcpStream::connect(ADDR).await.expect("connect call failed");
+            stream
+                .send_all_bytes(b"ping")
+                .await
+                .expect("send call failed");
+
+            let mut buf = [0u8; 4];
+            stream
+                .recv_bytes_exact(&mut buf)
+                .await
+                .expect("recv call failed");
+            assert_eq!(&buf, b"ping");
+        }
+    }
 }
@@ -1,5 +1,7 @@
+pub mod accept_limited;
 pub mod listener;
 pub mod stream;
 
+pub use accept_limited::{AcceptLimitedStream, LimitedTcpStream};
 pub use listener::TcpListener;
 pub use stream::TcpStream;
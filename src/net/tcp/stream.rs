@@ -2,11 +2,14 @@
 
 use crate::io::shutdown::AsyncShutdown;
 use crate::io::sys::{AsRawSocket, AsSocket, FromRawSocket, IntoRawSocket, RawSocket};
+use crate::io::Connect;
 use crate::io::{
     AsyncConnectStream, AsyncPeek, AsyncPollSocket, AsyncRecv, AsyncSend, AsyncSocketClose,
 };
-use crate::net::{Socket, Stream};
+use crate::net::creators_of_sockets::{is_mptcp_socket, new_mptcp_socket};
+use crate::net::{IntoSockAddr, Socket, SocketStats, Stream, ToSockAddrs};
 use crate::runtime::local_executor;
+use crate::utils::each_addr::each_addr;
 use socket2::{Domain, Protocol, Type};
 use std::fmt::{Debug, Formatter};
 use std::io::Result;
@@ -54,6 +57,7 @@ use std::net::SocketAddr;
 /// ```
 pub struct TcpStream {
     raw_socket: RawSocket,
+    stats: SocketStats,
 }
 
 #[cfg(unix)]
@@ -107,19 +111,61 @@ impl AsSocket for TcpStream {}
 #[cfg(unix)]
 impl std::os::fd::FromRawFd for TcpStream {
     unsafe fn from_raw_fd(raw_fd: std::os::fd::RawFd) -> Self {
-        Self { raw_socket: raw_fd }
+        Self {
+            raw_socket: raw_fd,
+            stats: SocketStats::new(),
+        }
     }
 }
 
 #[cfg(windows)]
 impl std::os::windows::io::FromRawSocket for TcpStream {
     unsafe fn from_raw_socket(raw_socket: RawSocket) -> Self {
-        Self { raw_socket }
+        Self {
+            raw_socket,
+            stats: SocketStats::new(),
+        }
     }
 }
 
 impl FromRawSocket for TcpStream {}
 
+#[cfg(unix)]
+impl From<std::os::fd::OwnedFd> for TcpStream {
+    fn from(fd: std::os::fd::OwnedFd) -> Self {
+        unsafe { std::os::fd::FromRawFd::from_raw_fd(std::os::fd::IntoRawFd::into_raw_fd(fd)) }
+    }
+}
+
+#[cfg(unix)]
+impl From<TcpStream> for std::os::fd::OwnedFd {
+    fn from(stream: TcpStream) -> Self {
+        unsafe { std::os::fd::FromRawFd::from_raw_fd(std::os::fd::IntoRawFd::into_raw_fd(stream)) }
+    }
+}
+
+#[cfg(windows)]
+impl From<std::os::windows::io::OwnedSocket> for TcpStream {
+    fn from(socket: std::os::windows::io::OwnedSocket) -> Self {
+        unsafe {
+            std::os::windows::io::FromRawSocket::from_raw_socket(
+                std::os::windows::io::IntoRawSocket::into_raw_socket(socket),
+            )
+        }
+    }
+}
+
+#[cfg(windows)]
+impl From<TcpStream> for std::os::windows::io::OwnedSocket {
+    fn from(stream: TcpStream) -> Self {
+        unsafe {
+            std::os::windows::io::FromRawSocket::from_raw_socket(
+                std::os::windows::io::IntoRawSocket::into_raw_socket(stream),
+            )
+        }
+    }
+}
+
 impl From<TcpStream> for std::net::TcpStream {
     fn from(stream: TcpStream) -> Self {
         unsafe { Self::from_raw_socket(ManuallyDrop::new(stream).raw_socket) }
@@ -130,6 +176,7 @@ impl From<std::net::TcpStream> for TcpStream {
     fn from(stream: std::net::TcpStream) -> Self {
         Self {
             raw_socket: IntoRawSocket::into_raw_socket(stream),
+            stats: SocketStats::new(),
         }
     }
 }
@@ -140,20 +187,122 @@ impl AsyncConnectStream for TcpStream {
             SocketAddr::V4(_) => Ok(Self {
                 raw_socket: crate::io::Socket::new(Domain::IPV4, Type::STREAM, Protocol::TCP)
                     .await?,
+                stats: SocketStats::new(),
             }),
 
             SocketAddr::V6(_) => Ok(Self {
                 raw_socket: crate::io::Socket::new(Domain::IPV6, Type::STREAM, Protocol::TCP)
                     .await?,
+                stats: SocketStats::new(),
             }),
         }
     }
 }
 
+impl TcpStream {
+    /// Opens a multipath `TCP` (`IPPROTO_MPTCP`) connection to a remote host.
+    ///
+    /// `MPTCP` allows the connection to use multiple network paths simultaneously,
+    /// it looks like a regular `TCP` connection to the application.
+    /// If the kernel doesn't support `MPTCP`, a regular `TCP` connection is opened.
+    /// The protocol of a socket can't be changed after its creation,
+    /// so `MPTCP` can be enabled only when the connection is opened.
+    ///
+    /// It will connect to the first valid address provided in the list of possible addresses.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the last failed address.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::net::TcpStream;
+    ///
+    /// # async fn foo() -> std::io::Result<()> {
+    /// let stream = TcpStream::connect_mptcp("127.0.0.1:8080").await?;
+    /// println!("MPTCP is used: {}", stream.is_mptcp()?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn connect_mptcp<A: ToSockAddrs<SocketAddr>>(addrs: A) -> Result<Self> {
+        each_addr(addrs, |addr| async move {
+            let stream = Self {
+                raw_socket: new_mptcp_socket(&addr).await?,
+                stats: SocketStats::new(),
+            };
+            Connect::new(stream.raw_socket, &addr.into_sock_addr()).await?;
+
+            Ok(stream)
+        })
+        .await
+    }
+
+    /// Returns the original destination address of a connection redirected by `iptables`
+    /// (`SO_ORIGINAL_DST` for IPv4 or `IP6T_SO_ORIGINAL_DST` for IPv6).
+    ///
+    /// A transparent proxy accepts connections redirected by the `REDIRECT` or `DNAT` targets,
+    /// so [`local_addr`](Socket::local_addr) is the address of the proxy, and this method
+    /// returns the address the client connected to.
+    /// Read [`BindConfig::transparent`](crate::net::BindConfig::transparent)
+    /// for the `TPROXY` target.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection has not been tracked by `netfilter`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use orengine::io::{AsyncAccept, AsyncBind, AsyncConnectStream};
+    /// use orengine::net::{TcpListener, TcpStream};
+    ///
+    /// # async fn foo() -> std::io::Result<()> {
+    /// let mut listener = TcpListener::bind("0.0.0.0:3128").await?;
+    /// let (client, _) = listener.accept().await?;
+    /// let backend = TcpStream::connect(client.original_dest_addr()?).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(target_os = "linux")]
+    pub fn original_dest_addr(&self) -> Result<SocketAddr> {
+        let borrowed_socket = AsSocket::as_socket(self);
+        let socket_ref = socket2::SockRef::from(&borrowed_socket);
+        let original_dst = if self.local_addr()?.is_ipv6() {
+            socket_ref.original_dst_ipv6()?
+        } else {
+            socket_ref.original_dst()?
+        };
+
+        original_dst.as_socket().ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "the original destination is not an IP address",
+            )
+        })
+    }
+
+    /// Returns whether the stream uses multipath `TCP`.
+    ///
+    /// Read [`connect_mptcp`](Self::connect_mptcp) for more details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the protocol of the socket can't be read.
+    pub fn is_mptcp(&self) -> Result<bool> {
+        is_mptcp_socket(self.raw_socket)
+    }
+}
+
 impl AsyncPollSocket for TcpStream {}
 
 impl Socket for TcpStream {
     type Addr = SocketAddr;
+
+    #[inline]
+    fn stats(&self) -> Option<&SocketStats> {
+        Some(&self.stats)
+    }
 }
 
 impl AsyncSend for TcpStream {}
@@ -566,4 +715,132 @@ mod tests {
             state_cond_var.notify_one();
         }
     }
+
+    #[orengine::test::test_local]
+    fn test_tcp_stream_stats() {
+        const ADDR: &str = "127.0.0.1:6096";
+
+        let listener = std::net::TcpListener::bind(ADDR).expect("std bind failed");
+        let server_thread = thread::spawn(move || {
+            use std::io::{Read, Write};
+
+            let mut stream = listener.accept().expect("accept failed").0;
+            let mut buf = vec![0u8; REQUEST.len()];
+            stream.read_exact(&mut buf).expect("std read failed");
+            stream.write_all(RESPONSE).expect("std write failed");
+        });
+
+        let mut stream = TcpStream::connect(ADDR).await.expect("connect failed");
+        assert_eq!(stream.stats().unwrap().bytes_sent(), 0);
+        assert_eq!(stream.stats().unwrap().bytes_recv(), 0);
+
+        stream.send_all_bytes(REQUEST).await.expect("send failed");
+        assert_eq!(stream.stats().unwrap().bytes_sent(), REQUEST.len() as u64);
+
+        let mut buf = vec![0u8; RESPONSE.len()];
+        stream
+            .recv_bytes_exact(&mut buf)
+            .await
+            .expect("recv failed");
+        assert_eq!(stream.stats().unwrap().bytes_recv(), RESPONSE.len() as u64);
+        assert_eq!(stream.stats().unwrap().send_errors(), 0);
+        assert_eq!(stream.stats().unwrap().recv_errors(), 0);
+
+        server_thread.join().expect("server thread join failed");
+    }
+
+    #[orengine::test::test_local]
+    fn test_tcp_stream_half_close() {
+        use crate::io::AsyncShutdown;
+
+        const ADDR: &str = "127.0.0.1:6097";
+
+        let listener = std::net::TcpListener::bind(ADDR).expect("std bind failed");
+        let server_thread = thread::spawn(move || {
+            use std::io::{Read, Write};
+
+            let mut stream = listener.accept().expect("accept failed").0;
+            let mut request = Vec::new();
+            // It reads until EOF, so it returns only after `shutdown_write`.
+            stream.read_to_end(&mut request).expect("std read failed");
+            assert_eq!(request, REQUEST);
+            stream.write_all(RESPONSE).expect("std write failed");
+        });
+
+        let mut stream = TcpStream::connect(ADDR).await.expect("connect failed");
+        stream.send_all_bytes(REQUEST).await.expect("send failed");
+        stream
+            .shutdown_write()
+            .await
+            .expect("shutdown_write failed");
+
+        let mut buf = vec![0u8; RESPONSE.len()];
+        stream
+            .recv_bytes_exact(&mut buf)
+            .await
+            .expect("recv failed");
+        assert_eq!(buf, RESPONSE);
+
+        let err = stream
+            .send_bytes(REQUEST)
+            .await
+            .expect_err("send after shutdown_write must fail");
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+
+        server_thread.join().expect("server thread join failed");
+    }
+
+    #[orengine::test::test_local]
+    fn test_tcp_rated_send_and_recv() {
+        use crate::io::{RatedRecv, RatedSend};
+
+        const ADDR: &str = "127.0.0.1:6102";
+
+        let listener = std::net::TcpListener::bind(ADDR).expect("std bind failed");
+        let server_thread = thread::spawn(move || {
+            use std::io::{Read, Write};
+
+            let mut stream = listener.accept().expect("accept failed").0;
+            let mut request = vec![0u8; REQUEST.len()];
+            for _ in 0..TIMES * 2 {
+                stream.read_exact(&mut request).expect("std read failed");
+                assert_eq!(request, REQUEST);
+                stream.write_all(RESPONSE).expect("std write failed");
+            }
+        });
+
+        let stream = TcpStream::connect(ADDR).await.expect("connect failed");
+        let mut buf = vec![0u8; RESPONSE.len()];
+
+        let mut stream = RatedSend::new(stream, Duration::from_millis(100));
+        for _ in 0..TIMES {
+            stream.send_all_bytes(REQUEST).await.expect("send failed");
+            stream
+                .get_mut()
+                .recv_bytes_exact(&mut buf)
+                .await
+                .expect("recv failed");
+            assert_eq!(buf, RESPONSE);
+        }
+        assert!(stream.meter().rate_bytes_per_sec() > 0.0);
+        assert!(stream.meter().rate_ops_per_sec() > 0.0);
+
+        let mut stream = RatedRecv::new(stream.into_inner(), Duration::from_millis(100));
+        for _ in 0..TIMES {
+            stream
+                .get_mut()
+                .send_all_bytes(REQUEST)
+                .await
+                .expect("send failed");
+            stream
+                .recv_bytes_exact(&mut buf)
+                .await
+                .expect("recv failed");
+            assert_eq!(buf, RESPONSE);
+        }
+        assert!(stream.meter().rate_bytes_per_sec() > 0.0);
+        assert!(stream.meter().rate_ops_per_sec() > 0.0);
+
+        server_thread.join().expect("server thread join failed");
+    }
 }
@@ -0,0 +1,361 @@
+//! This module contains [`Tun`] and [`Tap`] virtual network interfaces.
+use crate::fs::{File, OpenOptions};
+use crate::io::{AsyncRead, AsyncWrite};
+use std::ffi::{c_char, c_int, c_short, CStr};
+use std::io::{Error, ErrorKind, Result};
+use std::net::Ipv4Addr;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd, RawFd};
+use std::{mem, ptr};
+
+/// The path to the `TUN/TAP` character device.
+const TUN_DEVICE_PATH: &str = "/dev/net/tun";
+
+/// Creates an `ifreq` with the provided interface name.
+fn new_ifreq(name: &str) -> Result<libc::ifreq> {
+    if name.len() >= libc::IFNAMSIZ || name.as_bytes().contains(&0) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "interface name must be shorter than IFNAMSIZ and must not contain null bytes",
+        ));
+    }
+
+    // SAFETY: All zeros is a valid representation for `ifreq`.
+    let mut req = unsafe { mem::zeroed::<libc::ifreq>() };
+    // SAFETY: `name` is shorter than `ifr_name` and the rest of `ifr_name` is zeroed.
+    unsafe {
+        ptr::copy_nonoverlapping(
+            name.as_ptr().cast::<c_char>(),
+            req.ifr_name.as_mut_ptr(),
+            name.len(),
+        );
+    }
+
+    Ok(req)
+}
+
+/// Calls `ioctl` with the `req` on the `fd`.
+fn ioctl(fd: RawFd, request: libc::Ioctl, req: &mut libc::ifreq) -> Result<()> {
+    if unsafe { libc::ioctl(fd, request, ptr::from_mut(req)) } == -1 {
+        return Err(Error::last_os_error());
+    }
+
+    Ok(())
+}
+
+/// Calls `ioctl` with the `req` on a new `IPv4` socket to configure the interface.
+fn interface_ioctl(request: libc::Ioctl, req: &mut libc::ifreq) -> Result<()> {
+    let raw_fd = unsafe { libc::socket(libc::AF_INET, libc::SOCK_DGRAM | libc::SOCK_CLOEXEC, 0) };
+    if raw_fd == -1 {
+        return Err(Error::last_os_error());
+    }
+    let socket = unsafe { OwnedFd::from_raw_fd(raw_fd) };
+
+    ioctl(socket.as_raw_fd(), request, req)
+}
+
+/// Returns `sockaddr` with the provided `IPv4` address.
+fn ipv4_sockaddr(addr: Ipv4Addr) -> libc::sockaddr {
+    // SAFETY: All zeros is a valid representation for `sockaddr_in`.
+    let mut sockaddr_in = unsafe { mem::zeroed::<libc::sockaddr_in>() };
+    #[allow(clippy::cast_possible_truncation, reason = "libc::AF_INET is 2")]
+    {
+        sockaddr_in.sin_family = libc::AF_INET as libc::sa_family_t;
+    }
+    sockaddr_in.sin_addr.s_addr = u32::from(addr).to_be();
+
+    // SAFETY: `sockaddr_in` and `sockaddr` have the same size.
+    unsafe { mem::transmute::<libc::sockaddr_in, libc::sockaddr>(sockaddr_in) }
+}
+
+/// Virtual network interface backed by `/dev/net/tun`.
+struct Interface {
+    file: File,
+    name: String,
+}
+
+impl Interface {
+    /// Opens `/dev/net/tun` and creates the interface with the provided name and type flag.
+    async fn new(name: &str, flags: c_int) -> Result<Self> {
+        let file = File::open(TUN_DEVICE_PATH, &OpenOptions::new().read(true).write(true)).await?;
+        let mut req = new_ifreq(name)?;
+        #[allow(clippy::cast_possible_truncation, reason = "flags fit in c_short")]
+        {
+            req.ifr_ifru.ifru_flags = (flags | libc::IFF_NO_PI) as c_short;
+        }
+        ioctl(file.as_raw_fd(), libc::TUNSETIFF, &mut req)?;
+
+        // The kernel writes the name of the interface back (it replaces `%d` and
+        // generates a name if it was empty).
+        let name = unsafe { CStr::from_ptr(req.ifr_name.as_ptr()) }
+            .to_string_lossy()
+            .into_owned();
+
+        Ok(Self { file, name })
+    }
+
+    /// Creates an `ifreq` with the name of the interface.
+    fn ifreq(&self) -> Result<libc::ifreq> {
+        new_ifreq(&self.name)
+    }
+
+    fn set_mtu(&self, mtu: u32) -> Result<()> {
+        let mut req = self.ifreq()?;
+        req.ifr_ifru.ifru_mtu = c_int::try_from(mtu)
+            .map_err(|_| Error::new(ErrorKind::InvalidInput, "mtu is too large"))?;
+
+        interface_ioctl(libc::SIOCSIFMTU, &mut req)
+    }
+
+    fn set_up(&self, is_up: bool) -> Result<()> {
+        let mut req = self.ifreq()?;
+        interface_ioctl(libc::SIOCGIFFLAGS, &mut req)?;
+
+        #[allow(clippy::cast_possible_truncation, reason = "IFF_UP fits in c_short")]
+        let flag = libc::IFF_UP as c_short;
+        unsafe {
+            if is_up {
+                req.ifr_ifru.ifru_flags |= flag;
+            } else {
+                req.ifr_ifru.ifru_flags &= !flag;
+            }
+        }
+
+        interface_ioctl(libc::SIOCSIFFLAGS, &mut req)
+    }
+
+    fn set_addr(&self, addr: Ipv4Addr, prefix_len: u8) -> Result<()> {
+        if prefix_len > 32 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "prefix length must not be greater than 32",
+            ));
+        }
+
+        let mut req = self.ifreq()?;
+        req.ifr_ifru.ifru_addr = ipv4_sockaddr(addr);
+        interface_ioctl(libc::SIOCSIFADDR, &mut req)?;
+
+        let netmask = u32::MAX
+            .checked_shl(32 - u32::from(prefix_len))
+            .unwrap_or(0);
+        let mut req = self.ifreq()?;
+        req.ifr_ifru.ifru_netmask = ipv4_sockaddr(Ipv4Addr::from(netmask));
+
+        interface_ioctl(libc::SIOCSIFNETMASK, &mut req)
+    }
+}
+
+/// Generates a `TUN/TAP` interface type with the provided type flag.
+macro_rules! impl_interface {
+    ($(#[$meta:meta])* $name:ident, $flag:expr, $packet:literal) => {
+        $(#[$meta])*
+        pub struct $name {
+            interface: Interface,
+        }
+
+        impl $name {
+            /// Creates a new interface with the provided name.
+            ///
+            /// The name can contain `%d` (for example, `orengine%d`) that the kernel replaces
+            /// with a free number, or be empty to let the kernel choose the name.
+            /// Read the final name with [`name`](Self::name).
+            ///
+            /// The interface is removed when it is dropped.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the name is invalid, `/dev/net/tun` doesn't exist
+            /// or the process has no `CAP_NET_ADMIN` capability.
+            pub async fn new(name: &str) -> Result<Self> {
+                Ok(Self {
+                    interface: Interface::new(name, $flag).await?,
+                })
+            }
+
+            /// Returns the name of the interface.
+            pub fn name(&self) -> &str {
+                &self.interface.name
+            }
+
+            #[doc = concat!("Reads ", $packet, " from the interface into `buf`.")]
+            /// Returns the number of read bytes.
+            ///
+            /// It waits until the network stack sends a packet to the interface.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the interface is down.
+            pub async fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+                self.interface.file.read_bytes(buf).await
+            }
+
+            #[doc = concat!("Writes ", $packet, " from `buf` to the interface.")]
+            /// The network stack receives it as if it came from the network.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the packet is invalid or the interface is down.
+            pub async fn write(&mut self, buf: &[u8]) -> Result<usize> {
+                self.interface.file.write_bytes(buf).await
+            }
+
+            /// Sets the `MTU` of the interface.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the `MTU` is invalid for the interface.
+            pub fn set_mtu(&self, mtu: u32) -> Result<()> {
+                self.interface.set_mtu(mtu)
+            }
+
+            /// Brings the interface up or down.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the interface can't be configured.
+            pub fn set_up(&self, is_up: bool) -> Result<()> {
+                self.interface.set_up(is_up)
+            }
+
+            /// Sets the `IPv4` address and the network prefix length of the interface.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if `prefix_len` is greater than `32`
+            /// or the interface can't be configured.
+            pub fn set_addr(&self, addr: Ipv4Addr, prefix_len: u8) -> Result<()> {
+                self.interface.set_addr(addr, prefix_len)
+            }
+        }
+
+        impl AsRawFd for $name {
+            fn as_raw_fd(&self) -> RawFd {
+                self.interface.file.as_raw_fd()
+            }
+        }
+
+        impl std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("name", &self.interface.name)
+                    .field("raw_fd", &self.as_raw_fd())
+                    .finish()
+            }
+        }
+    };
+}
+
+impl_interface!(
+    /// A `TUN` virtual network interface that reads and writes `IP` packets.
+    ///
+    /// It is the foundation for `VPN` clients, network emulators and test harnesses
+    /// that inject packets into the network stack. Packets are read and written
+    /// with `IORING_OP_READ` and `IORING_OP_WRITE`.
+    ///
+    /// # OS Support
+    ///
+    /// This structure is only supported on `Linux`, and it requires
+    /// the `CAP_NET_ADMIN` capability.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use orengine::net::tun::Tun;
+    /// use std::net::Ipv4Addr;
+    ///
+    /// # async fn foo() -> std::io::Result<()> {
+    /// let mut tun = Tun::new("tun%d").await?;
+    /// tun.set_addr(Ipv4Addr::new(10, 0, 0, 1), 24)?;
+    /// tun.set_up(true)?;
+    ///
+    /// let mut packet = [0u8; 1500];
+    /// loop {
+    ///     let n = tun.read(&mut packet).await?;
+    ///     // process the IP packet in packet[..n]
+    /// }
+    /// # }
+    /// ```
+    Tun,
+    libc::IFF_TUN,
+    "an `IP` packet"
+);
+
+impl_interface!(
+    /// A `TAP` virtual network interface that reads and writes `Ethernet` frames.
+    ///
+    /// Read [`Tun`] for more details.
+    ///
+    /// # OS Support
+    ///
+    /// This structure is only supported on `Linux`, and it requires
+    /// the `CAP_NET_ADMIN` capability.
+    Tap,
+    libc::IFF_TAP,
+    "an `Ethernet` frame"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+
+    /// Returns `true` if the environment doesn't allow creating interfaces.
+    fn is_unavailable(err: &Error) -> bool {
+        matches!(
+            err.kind(),
+            ErrorKind::NotFound | ErrorKind::PermissionDenied
+        )
+    }
+
+    #[orengine::test::test_local]
+    fn test_tun() {
+        let mut tun = match Tun::new("orengine%d").await {
+            Ok(tun) => tun,
+            Err(err) if is_unavailable(&err) => return,
+            Err(err) => panic!("Tun::new failed: {err}"),
+        };
+        assert!(tun.name().starts_with("orengine"));
+
+        tun.set_mtu(1400).expect("set_mtu failed");
+        tun.set_addr(Ipv4Addr::new(10, 213, 0, 1), 24)
+            .expect("set_addr failed");
+        tun.set_up(true).expect("set_up failed");
+
+        let socket = std::net::UdpSocket::bind("10.213.0.1:0").expect("bind failed");
+        socket
+            .send_to(b"ping", "10.213.0.2:9999")
+            .expect("send_to failed");
+
+        // The kernel can send other packets (for example, IPv6 router solicitations).
+        let mut packet = [0u8; 1500];
+        let mut is_found = false;
+        for _ in 0..16 {
+            let n = tun.read(&mut packet).await.expect("read failed");
+            if n >= 20 && packet[0] >> 4 == 4 && packet[16..20] == [10, 213, 0, 2] {
+                assert_eq!(&packet[n - 4..n], b"ping");
+                is_found = true;
+                break;
+            }
+        }
+        assert!(is_found);
+    }
+
+    #[orengine::test::test_local]
+    fn test_tap() {
+        let tap = match Tap::new("orengine%d").await {
+            Ok(tap) => tap,
+            Err(err) if is_unavailable(&err) => return,
+            Err(err) => panic!("Tap::new failed: {err}"),
+        };
+        assert!(tap.name().starts_with("orengine"));
+        tap.set_up(true).expect("set_up failed");
+
+        assert_eq!(
+            Tap::new("a_very_long_interface_name")
+                .await
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidInput
+        );
+    }
+}
@@ -2,7 +2,7 @@ use crate::io::sys::{AsRawSocket, AsSocket, FromRawSocket, IntoRawSocket, RawSoc
 use crate::io::{
     AsyncPeek, AsyncPollSocket, AsyncRecv, AsyncSend, AsyncShutdown, AsyncSocketClose,
 };
-use crate::net::{ConnectedDatagram, Socket};
+use crate::net::{ConnectedDatagram, Socket, SocketStats};
 use crate::runtime::local_executor;
 use std::fmt::{Debug, Formatter};
 use std::mem::ManuallyDrop;
@@ -17,6 +17,11 @@ use std::net::SocketAddr;
 /// Although UDP is a connectionless protocol, this implementation provides an interface
 /// to set an address where data should be sent and received from.
 ///
+/// The kernel drops datagrams from other addresses, so all received datagrams
+/// originate from the connected peer. Sending on a connected socket is also cheaper
+/// than [`send_to`](crate::io::AsyncSendTo), because the kernel doesn't resolve the route
+/// for each datagram.
+///
 /// # Example
 ///
 /// ```rust
@@ -40,6 +45,7 @@ use std::net::SocketAddr;
 /// ```
 pub struct UdpConnectedSocket {
     raw_socket: RawSocket,
+    stats: SocketStats,
 }
 
 impl From<UdpConnectedSocket> for std::net::UdpSocket {
@@ -52,6 +58,7 @@ impl From<std::net::UdpSocket> for UdpConnectedSocket {
     fn from(connected_socket: std::net::UdpSocket) -> Self {
         Self {
             raw_socket: IntoRawSocket::into_raw_socket(connected_socket),
+            stats: SocketStats::new(),
         }
     }
 }
@@ -107,23 +114,70 @@ impl AsSocket for UdpConnectedSocket {}
 #[cfg(unix)]
 impl std::os::fd::FromRawFd for UdpConnectedSocket {
     unsafe fn from_raw_fd(raw_fd: std::os::fd::RawFd) -> Self {
-        Self { raw_socket: raw_fd }
+        Self {
+            raw_socket: raw_fd,
+            stats: SocketStats::new(),
+        }
     }
 }
 
 #[cfg(windows)]
 impl std::os::windows::io::FromRawSocket for UdpConnectedSocket {
     unsafe fn from_raw_socket(raw_socket: RawSocket) -> Self {
-        Self { raw_socket }
+        Self {
+            raw_socket,
+            stats: SocketStats::new(),
+        }
     }
 }
 
 impl FromRawSocket for UdpConnectedSocket {}
 
+#[cfg(unix)]
+impl From<std::os::fd::OwnedFd> for UdpConnectedSocket {
+    fn from(fd: std::os::fd::OwnedFd) -> Self {
+        unsafe { std::os::fd::FromRawFd::from_raw_fd(std::os::fd::IntoRawFd::into_raw_fd(fd)) }
+    }
+}
+
+#[cfg(unix)]
+impl From<UdpConnectedSocket> for std::os::fd::OwnedFd {
+    fn from(socket: UdpConnectedSocket) -> Self {
+        unsafe { std::os::fd::FromRawFd::from_raw_fd(std::os::fd::IntoRawFd::into_raw_fd(socket)) }
+    }
+}
+
+#[cfg(windows)]
+impl From<std::os::windows::io::OwnedSocket> for UdpConnectedSocket {
+    fn from(socket: std::os::windows::io::OwnedSocket) -> Self {
+        unsafe {
+            std::os::windows::io::FromRawSocket::from_raw_socket(
+                std::os::windows::io::IntoRawSocket::into_raw_socket(socket),
+            )
+        }
+    }
+}
+
+#[cfg(windows)]
+impl From<UdpConnectedSocket> for std::os::windows::io::OwnedSocket {
+    fn from(socket: UdpConnectedSocket) -> Self {
+        unsafe {
+            std::os::windows::io::FromRawSocket::from_raw_socket(
+                std::os::windows::io::IntoRawSocket::into_raw_socket(socket),
+            )
+        }
+    }
+}
+
 impl AsyncPollSocket for UdpConnectedSocket {}
 
 impl Socket for UdpConnectedSocket {
     type Addr = SocketAddr;
+
+    #[inline]
+    fn stats(&self) -> Option<&SocketStats> {
+        Some(&self.stats)
+    }
 }
 
 impl AsyncRecv for UdpConnectedSocket {}
@@ -250,6 +304,29 @@ mod tests {
         server_thread.join().expect("server thread join failed");
     }
 
+    #[orengine::test::test_local]
+    fn test_connected_udp_ignores_other_peers() {
+        const ADDR: &str = "127.0.0.1:11143";
+        const PEER_ADDR: &str = "127.0.0.1:11144";
+        const OTHER_ADDR: &str = "127.0.0.1:11145";
+
+        let peer = std::net::UdpSocket::bind(PEER_ADDR).expect("std bind failed");
+        let other = std::net::UdpSocket::bind(OTHER_ADDR).expect("std bind failed");
+
+        let socket = UdpSocket::bind(ADDR).await.expect("bind failed");
+        let mut connected_socket = socket.connect(PEER_ADDR).await.expect("connect failed");
+
+        other.send_to(b"other", ADDR).expect("std send_to failed");
+        peer.send_to(b"peer", ADDR).expect("std send_to failed");
+
+        let mut buf = [0u8; 16];
+        let n = connected_socket
+            .recv_bytes_with_timeout(&mut buf, Duration::from_secs(1))
+            .await
+            .expect("recv failed");
+        assert_eq!(&buf[..n], b"peer");
+    }
+
     #[orengine::test::test_local]
     fn test_timeout() {
         const ADDR: &str = "127.0.0.1:11141";
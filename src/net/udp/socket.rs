@@ -6,6 +6,8 @@ use std::net::SocketAddr;
 use socket2::{SockAddr, SockRef};
 
 use crate::io::sys::{AsRawSocket, AsSocket, FromRawSocket, IntoRawSocket, RawSocket};
+#[cfg(target_os = "linux")]
+use crate::io::AsyncRecvFromWithPktInfo;
 use crate::io::{
     sys, AsyncBind, AsyncConnectDatagram, AsyncPeekFrom, AsyncPollSocket, AsyncRecvFrom,
     AsyncSendTo, AsyncSocketClose,
@@ -13,7 +15,7 @@ use crate::io::{
 use crate::net::creators_of_sockets::new_udp_socket;
 use crate::net::udp::connected_socket::UdpConnectedSocket;
 use crate::net::BindConfig;
-use crate::net::{Datagram, Socket};
+use crate::net::{Datagram, Socket, SocketStats};
 use crate::runtime::local_executor;
 
 /// A UDP socket.
@@ -75,6 +77,7 @@ use crate::runtime::local_executor;
 /// ```
 pub struct UdpSocket {
     raw_socket: RawSocket,
+    stats: SocketStats,
 }
 
 impl From<UdpSocket> for std::net::UdpSocket {
@@ -87,6 +90,7 @@ impl From<std::net::UdpSocket> for UdpSocket {
     fn from(socket: std::net::UdpSocket) -> Self {
         Self {
             raw_socket: sys::IntoRawSocket::into_raw_socket(socket),
+            stats: SocketStats::new(),
         }
     }
 }
@@ -142,19 +146,61 @@ impl AsSocket for UdpSocket {}
 #[cfg(unix)]
 impl std::os::fd::FromRawFd for UdpSocket {
     unsafe fn from_raw_fd(raw_fd: std::os::fd::RawFd) -> Self {
-        Self { raw_socket: raw_fd }
+        Self {
+            raw_socket: raw_fd,
+            stats: SocketStats::new(),
+        }
     }
 }
 
 #[cfg(windows)]
 impl std::os::windows::io::FromRawSocket for UdpSocket {
     unsafe fn from_raw_socket(raw_socket: RawSocket) -> Self {
-        Self { raw_socket }
+        Self {
+            raw_socket,
+            stats: SocketStats::new(),
+        }
     }
 }
 
 impl FromRawSocket for UdpSocket {}
 
+#[cfg(unix)]
+impl From<std::os::fd::OwnedFd> for UdpSocket {
+    fn from(fd: std::os::fd::OwnedFd) -> Self {
+        unsafe { std::os::fd::FromRawFd::from_raw_fd(std::os::fd::IntoRawFd::into_raw_fd(fd)) }
+    }
+}
+
+#[cfg(unix)]
+impl From<UdpSocket> for std::os::fd::OwnedFd {
+    fn from(socket: UdpSocket) -> Self {
+        unsafe { std::os::fd::FromRawFd::from_raw_fd(std::os::fd::IntoRawFd::into_raw_fd(socket)) }
+    }
+}
+
+#[cfg(windows)]
+impl From<std::os::windows::io::OwnedSocket> for UdpSocket {
+    fn from(socket: std::os::windows::io::OwnedSocket) -> Self {
+        unsafe {
+            std::os::windows::io::FromRawSocket::from_raw_socket(
+                std::os::windows::io::IntoRawSocket::into_raw_socket(socket),
+            )
+        }
+    }
+}
+
+#[cfg(windows)]
+impl From<UdpSocket> for std::os::windows::io::OwnedSocket {
+    fn from(socket: UdpSocket) -> Self {
+        unsafe {
+            std::os::windows::io::FromRawSocket::from_raw_socket(
+                std::os::windows::io::IntoRawSocket::into_raw_socket(socket),
+            )
+        }
+    }
+}
+
 impl AsyncBind for UdpSocket {
     async fn new_socket(addr: &Self::Addr) -> Result<RawSocket> {
         new_udp_socket(addr).await
@@ -175,6 +221,9 @@ impl AsyncPollSocket for UdpSocket {}
 
 impl AsyncRecvFrom for UdpSocket {}
 
+#[cfg(target_os = "linux")]
+impl AsyncRecvFromWithPktInfo for UdpSocket {}
+
 impl AsyncPeekFrom for UdpSocket {}
 
 impl AsyncSendTo for UdpSocket {}
@@ -183,6 +232,11 @@ impl AsyncSocketClose for UdpSocket {}
 
 impl Socket for UdpSocket {
     type Addr = SocketAddr;
+
+    #[inline]
+    fn stats(&self) -> Option<&SocketStats> {
+        Some(&self.stats)
+    }
 }
 
 impl Datagram for UdpSocket {
@@ -396,6 +450,28 @@ mod tests {
         .await;
     }
 
+    #[orengine::test::test_local]
+    fn test_tos_and_traffic_class() {
+        use crate::net::DscpClass;
+
+        let socket = UdpSocket::bind("127.0.0.1:10143")
+            .await
+            .expect("bind failed");
+        socket.set_tos(DscpClass::EF.tos()).expect("set_tos failed");
+        assert_eq!(socket.tos().expect("tos failed"), 0xb8);
+
+        // IPv6 can be disabled in the environment.
+        if let Ok(socket_v6) = UdpSocket::bind("[::1]:10144").await {
+            socket_v6
+                .set_traffic_class(DscpClass::AF41.tos())
+                .expect("set_traffic_class failed");
+            assert_eq!(
+                socket_v6.traffic_class().expect("traffic_class failed"),
+                DscpClass::AF41.tos()
+            );
+        }
+    }
+
     #[orengine::test::test_local]
     fn test_socket() {
         const SERVER_ADDR: &str = "127.0.0.1:10090";
@@ -553,4 +629,41 @@ mod tests {
             Err(err) => assert_eq!(err.kind(), io::ErrorKind::TimedOut, "{err}"),
         }
     }
+
+    #[cfg(target_os = "linux")]
+    #[orengine::test::test_local]
+    fn test_recv_from_with_pktinfo() {
+        let mut server = UdpSocket::bind("0.0.0.0:10142").await.expect("bind failed");
+        let client = std::net::UdpSocket::bind("127.0.0.1:0").expect("std bind failed");
+        let mut buf = [0u8; REQUEST.len()];
+
+        client
+            .send_to(REQUEST, "127.0.0.1:10142")
+            .expect("std send failed");
+        server.poll_recv().await.expect("poll failed");
+        let err = server
+            .recv_from_with_pktinfo(&mut buf)
+            .await
+            .expect_err("recv_from_with_pktinfo must fail without IP_PKTINFO");
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+
+        server
+            .set_recv_pktinfo(true)
+            .expect("set_recv_pktinfo failed");
+        client
+            .send_to(REQUEST, "127.0.0.1:10142")
+            .expect("std send failed");
+        let (n, peer, pkt_info) = server
+            .recv_from_with_pktinfo(&mut buf)
+            .await
+            .expect("recv_from_with_pktinfo failed");
+
+        assert_eq!(&buf[..n], REQUEST);
+        assert_eq!(peer, client.local_addr().unwrap());
+        assert_eq!(
+            pkt_info.local_addr(),
+            std::net::IpAddr::from([127, 0, 0, 1])
+        );
+        assert_ne!(pkt_info.interface_index(), 0);
+    }
 }
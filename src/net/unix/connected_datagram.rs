@@ -3,7 +3,7 @@ use crate::io::{
     AsyncPeek, AsyncPollSocket, AsyncRecv, AsyncSend, AsyncShutdown, AsyncSocketClose,
 };
 use crate::net::unix::unix_impl_socket;
-use crate::net::{ConnectedDatagram, Socket};
+use crate::net::{ConnectedDatagram, Socket, SocketStats};
 use crate::runtime::local_executor;
 use std::fmt::{Debug, Formatter};
 use std::mem::ManuallyDrop;
@@ -40,6 +40,7 @@ use std::mem::ManuallyDrop;
 /// ```
 pub struct UnixConnectedDatagram {
     raw_socket: RawSocket,
+    stats: SocketStats,
 }
 
 impl From<UnixConnectedDatagram> for std::os::unix::net::UnixDatagram {
@@ -52,6 +53,7 @@ impl From<std::os::unix::net::UnixDatagram> for UnixConnectedDatagram {
     fn from(connected_socket: std::os::unix::net::UnixDatagram) -> Self {
         Self {
             raw_socket: IntoRawSocket::into_raw_socket(connected_socket),
+            stats: SocketStats::new(),
         }
     }
 }
@@ -83,12 +85,29 @@ impl AsSocket for UnixConnectedDatagram {}
 #[cfg(unix)]
 impl std::os::fd::FromRawFd for UnixConnectedDatagram {
     unsafe fn from_raw_fd(raw_fd: std::os::fd::RawFd) -> Self {
-        Self { raw_socket: raw_fd }
+        Self {
+            raw_socket: raw_fd,
+            stats: SocketStats::new(),
+        }
     }
 }
 
 impl FromRawSocket for UnixConnectedDatagram {}
 
+impl From<std::os::fd::OwnedFd> for UnixConnectedDatagram {
+    fn from(fd: std::os::fd::OwnedFd) -> Self {
+        unsafe { std::os::fd::FromRawFd::from_raw_fd(std::os::fd::IntoRawFd::into_raw_fd(fd)) }
+    }
+}
+
+impl From<UnixConnectedDatagram> for std::os::fd::OwnedFd {
+    fn from(datagram: UnixConnectedDatagram) -> Self {
+        unsafe {
+            std::os::fd::FromRawFd::from_raw_fd(std::os::fd::IntoRawFd::into_raw_fd(datagram))
+        }
+    }
+}
+
 impl AsyncPollSocket for UnixConnectedDatagram {}
 
 impl Socket for UnixConnectedDatagram {
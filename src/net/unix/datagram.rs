@@ -15,7 +15,7 @@ use crate::net::creators_of_sockets::new_unix_datagram;
 use crate::net::unix::connected_datagram::UnixConnectedDatagram;
 use crate::net::unix::unix_impl_socket;
 use crate::net::BindConfig;
-use crate::net::{Datagram, Socket};
+use crate::net::{Datagram, Socket, SocketStats};
 use crate::runtime::local_executor;
 use crate::utils::each_addr::each_addr;
 
@@ -82,6 +82,7 @@ use crate::utils::each_addr::each_addr;
 /// ```
 pub struct UnixDatagram {
     raw_socket: RawSocket,
+    stats: SocketStats,
 }
 
 impl From<UnixDatagram> for std::os::unix::net::UnixDatagram {
@@ -94,6 +95,7 @@ impl From<std::os::unix::net::UnixDatagram> for UnixDatagram {
     fn from(socket: std::os::unix::net::UnixDatagram) -> Self {
         Self {
             raw_socket: IntoRawSocket::into_raw_socket(socket),
+            stats: SocketStats::new(),
         }
     }
 }
@@ -124,7 +126,10 @@ impl AsSocket for UnixDatagram {}
 
 impl std::os::fd::FromRawFd for UnixDatagram {
     unsafe fn from_raw_fd(raw_fd: std::os::fd::RawFd) -> Self {
-        Self { raw_socket: raw_fd }
+        Self {
+            raw_socket: raw_fd,
+            stats: SocketStats::new(),
+        }
     }
 }
 
@@ -136,6 +141,20 @@ impl Socket for UnixDatagram {
 
 impl FromRawSocket for UnixDatagram {}
 
+impl From<std::os::fd::OwnedFd> for UnixDatagram {
+    fn from(fd: std::os::fd::OwnedFd) -> Self {
+        unsafe { std::os::fd::FromRawFd::from_raw_fd(std::os::fd::IntoRawFd::into_raw_fd(fd)) }
+    }
+}
+
+impl From<UnixDatagram> for std::os::fd::OwnedFd {
+    fn from(datagram: UnixDatagram) -> Self {
+        unsafe {
+            std::os::fd::FromRawFd::from_raw_fd(std::os::fd::IntoRawFd::into_raw_fd(datagram))
+        }
+    }
+}
+
 impl AsyncBind for UnixDatagram {
     async fn new_socket(_: &Self::Addr) -> Result<RawSocket> {
         new_unix_datagram().await
@@ -164,7 +183,10 @@ impl AsyncBind for UnixDatagram {
 
             sock_ref.bind(&addr.into_sock_addr())?;
 
-            Ok(Self { raw_socket })
+            Ok(Self {
+                raw_socket,
+                stats: SocketStats::new(),
+            })
         })
         .await
     }
@@ -1,9 +1,7 @@
 //! This module contains [`UnixListener`].
-use socket2::{SockAddr, SockRef};
+use socket2::SockRef;
 use std::ffi::c_int;
 use std::fmt::{Debug, Formatter};
-use std::io;
-use std::io::ErrorKind::InvalidInput;
 use std::io::Result;
 use std::mem::ManuallyDrop;
 
@@ -11,10 +9,10 @@ use crate::io::sys::{
     AsRawSocket, AsSocket, BorrowedSocket, FromRawSocket, IntoRawSocket, RawSocket,
 };
 use crate::io::{AsyncAccept, AsyncBind, AsyncPollSocket, AsyncSocketClose};
-use crate::net::addr::ToSockAddrs;
+use crate::net::addr::{IntoSockAddr, ToSockAddrs};
 use crate::net::creators_of_sockets::new_unix_stream;
 use crate::net::unix::{unix_impl_socket, UnixStream};
-use crate::net::{BindConfig, Listener, Socket};
+use crate::net::{BindConfig, Listener, Socket, SocketStats};
 use crate::runtime::local_executor;
 use crate::utils::each_addr::each_addr;
 
@@ -28,6 +26,13 @@ use crate::utils::each_addr::each_addr;
 ///
 /// [`UnixListener`] is automatically closed after it is dropped.
 ///
+/// # Abstract namespace
+///
+/// On `Linux` and `Android`, the listener can be bound to an address in the abstract namespace
+/// created by [`UnixAddr::from_abstract_name`](crate::net::unix::UnixAddr::from_abstract_name).
+/// Such an address doesn't create a file, and it is released when the last socket
+/// bound to it is closed, so the name can be reused without removing a stale socket file.
+///
 /// # Example
 ///
 /// ```rust
@@ -44,6 +49,7 @@ use crate::utils::each_addr::each_addr;
 /// ```
 pub struct UnixListener {
     pub(crate) raw_socket: RawSocket,
+    stats: SocketStats,
 }
 
 impl From<UnixListener> for std::os::unix::net::UnixListener {
@@ -60,6 +66,7 @@ impl From<std::os::unix::net::UnixListener> for UnixListener {
     fn from(listener: std::os::unix::net::UnixListener) -> Self {
         Self {
             raw_socket: IntoRawSocket::into_raw_socket(listener),
+            stats: SocketStats::new(),
         }
     }
 }
@@ -90,12 +97,29 @@ impl AsSocket for UnixListener {}
 
 impl std::os::fd::FromRawFd for UnixListener {
     unsafe fn from_raw_fd(raw_fd: std::os::fd::RawFd) -> Self {
-        Self { raw_socket: raw_fd }
+        Self {
+            raw_socket: raw_fd,
+            stats: SocketStats::new(),
+        }
     }
 }
 
 impl FromRawSocket for UnixListener {}
 
+impl From<std::os::fd::OwnedFd> for UnixListener {
+    fn from(fd: std::os::fd::OwnedFd) -> Self {
+        unsafe { std::os::fd::FromRawFd::from_raw_fd(std::os::fd::IntoRawFd::into_raw_fd(fd)) }
+    }
+}
+
+impl From<UnixListener> for std::os::fd::OwnedFd {
+    fn from(listener: UnixListener) -> Self {
+        unsafe {
+            std::os::fd::FromRawFd::from_raw_fd(std::os::fd::IntoRawFd::into_raw_fd(listener))
+        }
+    }
+}
+
 impl AsyncPollSocket for UnixListener {}
 
 impl Socket for UnixListener {
@@ -112,10 +136,7 @@ impl AsyncBind for UnixListener {
         addr: Self::Addr,
         config: &BindConfig,
     ) -> Result<()> {
-        sock_ref
-            .bind(&SockAddr::unix(addr.as_pathname().ok_or_else(|| {
-                io::Error::new(InvalidInput, "Invalid address.")
-            })?)?)?;
+        sock_ref.bind(&addr.into_sock_addr())?;
         #[allow(clippy::cast_possible_truncation, reason = "we have to cast it")]
         sock_ref.listen(config.backlog_size as c_int)?;
 
@@ -135,7 +156,18 @@ impl AsyncBind for UnixListener {
             let borrow_socket = unsafe { BorrowedSocket::borrow_raw(raw_socket) };
             let sock_ref = socket2::SockRef::from(&borrow_socket);
 
-            Self::bind_and_listen_if_needed(sock_ref, addr, config).map(|()| Self { raw_socket })
+            if let Some(recv_buffer_size) = config.recv_buffer_size {
+                sock_ref.set_recv_buffer_size(recv_buffer_size as usize)?;
+            }
+
+            if let Some(send_buffer_size) = config.send_buffer_size {
+                sock_ref.set_send_buffer_size(send_buffer_size as usize)?;
+            }
+
+            Self::bind_and_listen_if_needed(sock_ref, addr, config).map(|()| Self {
+                raw_socket,
+                stats: SocketStats::new(),
+            })
         })
         .await
     }
@@ -176,6 +208,7 @@ mod tests {
     use super::*;
     use crate as orengine;
     use crate::fs;
+    use std::io;
     use std::time::Duration;
 
     #[orengine::test::test_local]
@@ -230,4 +263,40 @@ mod tests {
 
         drop(stream);
     }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[orengine::test::test_local]
+    fn test_abstract_namespace() {
+        use crate::io::{AsyncConnectStream, AsyncRecv, AsyncSend};
+        use crate::net::unix::UnixAddr;
+        use crate::net::Stream;
+
+        const NAME: &[u8] = b"orengine_test_listener_abstract";
+
+        for _ in 0..2 {
+            let addr = UnixAddr::from_abstract_name(NAME).expect("from_abstract_name failed");
+            // The previous listener has released the name when it was dropped.
+            let mut listener = UnixListener::bind(addr.clone())
+                .await
+                .expect("bind call failed");
+            assert_eq!(
+                listener.local_addr().unwrap().as_abstract_name(),
+                Some(NAME)
+            );
+
+            let mut client = UnixStream::connect(addr)
+                .await
+                .expect("connect call failed");
+            assert_eq!(client.peer_addr().unwrap().as_abstract_name(), Some(NAME));
+
+            let (mut server, _) = listener.accept().await.expect("accept call failed");
+            client.send_all_bytes(b"ping").await.expect("send failed");
+            let mut buf = [0u8; 4];
+            server
+                .recv_bytes_exact(&mut buf)
+                .await
+                .expect("recv failed");
+            assert_eq!(&buf, b"ping");
+        }
+    }
 }
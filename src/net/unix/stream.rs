@@ -7,7 +7,7 @@ use crate::io::{
 };
 use crate::net::creators_of_sockets::new_unix_stream;
 use crate::net::unix::unix_impl_socket;
-use crate::net::{Socket, Stream};
+use crate::net::{Socket, SocketStats, Stream};
 use crate::runtime::local_executor;
 use std::fmt::{Debug, Formatter};
 use std::io::Result;
@@ -23,6 +23,12 @@ use std::mem::ManuallyDrop;
 ///
 /// [`UnixStream`] is automatically closed after it is dropped.
 ///
+/// # Abstract namespace
+///
+/// On `Linux` and `Android`, the stream can connect to an address in the abstract namespace
+/// created by [`UnixAddr::from_abstract_name`](crate::net::unix::UnixAddr::from_abstract_name).
+/// Read [`UnixListener`](crate::net::UnixListener) for more details.
+///
 /// # Example
 ///
 /// ```rust
@@ -58,6 +64,7 @@ use std::mem::ManuallyDrop;
 /// ```
 pub struct UnixStream {
     raw_socket: RawSocket,
+    stats: SocketStats,
 }
 
 impl std::os::fd::IntoRawFd for UnixStream {
@@ -86,12 +93,27 @@ impl AsSocket for UnixStream {}
 
 impl std::os::fd::FromRawFd for UnixStream {
     unsafe fn from_raw_fd(raw_fd: std::os::fd::RawFd) -> Self {
-        Self { raw_socket: raw_fd }
+        Self {
+            raw_socket: raw_fd,
+            stats: SocketStats::new(),
+        }
     }
 }
 
 impl FromRawSocket for UnixStream {}
 
+impl From<std::os::fd::OwnedFd> for UnixStream {
+    fn from(fd: std::os::fd::OwnedFd) -> Self {
+        unsafe { std::os::fd::FromRawFd::from_raw_fd(std::os::fd::IntoRawFd::into_raw_fd(fd)) }
+    }
+}
+
+impl From<UnixStream> for std::os::fd::OwnedFd {
+    fn from(stream: UnixStream) -> Self {
+        unsafe { std::os::fd::FromRawFd::from_raw_fd(std::os::fd::IntoRawFd::into_raw_fd(stream)) }
+    }
+}
+
 impl From<UnixStream> for std::os::unix::net::UnixStream {
     fn from(stream: UnixStream) -> Self {
         unsafe { Self::from_raw_socket(ManuallyDrop::new(stream).raw_socket) }
@@ -102,6 +124,7 @@ impl From<std::os::unix::net::UnixStream> for UnixStream {
     fn from(stream: std::os::unix::net::UnixStream) -> Self {
         Self {
             raw_socket: IntoRawSocket::into_raw_socket(stream),
+            stats: SocketStats::new(),
         }
     }
 }
@@ -116,6 +139,7 @@ impl AsyncConnectStream for UnixStream {
     async fn new_for_addr(_: &Self::Addr) -> Result<Self> {
         Ok(Self {
             raw_socket: new_unix_stream().await?,
+            stats: SocketStats::new(),
         })
     }
 }
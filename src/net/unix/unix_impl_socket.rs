@@ -9,6 +9,11 @@ mod unix_impl_socket {
             fn is_unix(&self) -> bool {
                 true
             }
+
+            #[inline]
+            fn stats(&self) -> Option<&crate::net::SocketStats> {
+                Some(&self.stats)
+            }
         };
     }
 
@@ -32,6 +32,19 @@ pub enum Call {
     ///
     /// * calling task must be shared (else you don't need any [`Calls`](Call))
     PushCurrentTaskAtTheStartOfLIFOSharedQueue,
+    /// Moves current `local` task to the back of the queue of local tasks (`FIFO`)
+    /// and resumes it in the current round after all other ready local tasks.
+    ///
+    /// Unlike [`PushCurrentTaskAtTheStartOfLIFOSharedQueue`](Self::PushCurrentTaskAtTheStartOfLIFOSharedQueue),
+    /// the background work of the executor (polling I/O completions, waking sleeping tasks)
+    /// is __not__ done before the task is resumed.
+    ///
+    /// # Safety
+    ///
+    /// * task must return [`Poll::Pending`](std::task::Poll::Pending) immediately after calling this function
+    ///
+    /// * calling task must be local
+    YieldCurrentLocalTask,
     /// Pushes current task to the given `AtomicTaskList`.
     ///
     /// # Safety
@@ -144,6 +157,7 @@ impl Debug for Call {
             Self::PushCurrentTaskAtTheStartOfLIFOSharedQueue => {
                 write!(f, "Call::YieldCurrentSharedTask")
             }
+            Self::YieldCurrentLocalTask => write!(f, "Call::YieldCurrentLocalTask"),
             Self::PushCurrentTaskTo(_) => write!(f, "Call::PushCurrentTaskTo"),
             Self::PushCurrentTaskToAndRemoveItIfCounterIsZero(_, _, _) => {
                 write!(f, "Call::PushCurrentTaskToAndRemoveItIfCounterIsZero")
@@ -0,0 +1,26 @@
+use std::time::Instant;
+
+/// `Clock` is a source of time for the [`Executor`](crate::Executor).
+///
+/// The [`Executor`](crate::Executor) uses it to get the
+/// [`start time of the current round`](crate::Executor::start_round_time), therefore
+/// it affects [`sleep`](crate::sleep()), deadlines and timeouts.
+///
+/// By default, [`SystemClock`] is used. It can be replaced
+/// via [`Config::set_clock`](crate::runtime::Config::set_clock),
+/// for example, with [`FakeClock`](crate::test::FakeClock) in tests.
+pub trait Clock: Send + Sync {
+    /// Returns the current time.
+    fn now(&self) -> Instant;
+}
+
+/// `SystemClock` is a [`Clock`] that returns [`Instant::now`].
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    #[inline]
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
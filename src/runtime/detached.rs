@@ -0,0 +1,73 @@
+//! This module contains [`run_on_new_thread`] that runs a future on a detached executor.
+use crate::runtime::Config;
+use crate::{BlockOnError, Executor};
+use std::future::Future;
+use std::thread::{self, JoinHandle};
+
+/// Runs the `future` on a new [`Executor`] in a freshly spawned OS thread.
+///
+/// It initializes the [`Executor`] with the provided `config` on the new thread,
+/// runs the `future` to completion with
+/// [`run_and_block_on_shared`](Executor::run_and_block_on_shared)
+/// and returns the output of the `future` via the [`JoinHandle`].
+///
+/// The executor is stopped after the `future` completes, so tasks spawned by the `future`
+/// and not completed by that moment are not executed.
+///
+/// It is the simplest way to run isolated `orengine` tasks from a thread
+/// without an [`Executor`], for example, from a `Tokio` or `async-std` runtime
+/// (join the handle with their `spawn_blocking` to not block the runtime).
+///
+/// # Panics
+///
+/// [`JoinHandle::join`] returns `Err` if the `config` is invalid, the `future` panics
+/// or undefined behavior happened in the executor.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::runtime::{run_on_new_thread, Config};
+/// use orengine::sleep;
+/// use std::time::Duration;
+///
+/// let handle = run_on_new_thread(Config::default(), async {
+///     sleep(Duration::from_millis(1)).await;
+///
+///     42
+/// });
+///
+/// assert_eq!(handle.join().unwrap(), 42);
+/// ```
+pub fn run_on_new_thread<T, F>(config: Config, future: F) -> JoinHandle<T>
+where
+    T: Send + 'static,
+    F: Future<Output = T> + Send + 'static,
+{
+    thread::spawn(move || {
+        Executor::init_with_config(config)
+            .run_and_block_on_shared(future)
+            .map_err(BlockOnError::resume_unwind)
+            .expect("undefined behavior happened in the detached executor")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::local_executor;
+    use crate::yield_now;
+
+    #[test]
+    fn test_run_on_new_thread() {
+        let current_thread = thread::current().id();
+        let handle = run_on_new_thread(Config::default().disable_work_sharing(), async move {
+            assert_ne!(thread::current().id(), current_thread);
+            local_executor().spawn_local(async {});
+            yield_now().await;
+
+            String::from("done")
+        });
+
+        assert_eq!(handle.join().unwrap(), "done");
+    }
+}
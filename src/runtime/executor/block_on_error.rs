@@ -0,0 +1,86 @@
+//! This module contains [`BlockOnError`].
+use std::any::Any;
+use std::fmt::{Debug, Display, Formatter};
+
+/// `BlockOnError` is an error of blocking on a future.
+///
+/// It is returned by [`Executor::run_and_block_on_local`](crate::Executor::run_and_block_on_local)
+/// and [`Executor::run_and_block_on_shared`](crate::Executor::run_and_block_on_shared)
+/// when the future is not completed.
+pub enum BlockOnError {
+    /// The executor has been stopped by [`stop_executor`](crate::stop_executor)
+    /// or [`stop_all_executors`](crate::stop_all_executors) before the future is completed.
+    ExecutorStopped,
+    /// The executor has been killed by a panic. It contains the payload of the panic.
+    ///
+    /// Use [`std::panic::resume_unwind`] to propagate the panic.
+    ExecutorKilledByPanic(Box<dyn Any + Send>),
+}
+
+impl BlockOnError {
+    /// Returns the message of the panic if `self` is [`ExecutorKilledByPanic`](Self::ExecutorKilledByPanic)
+    /// and the payload is a string.
+    pub fn panic_message(&self) -> Option<&str> {
+        match self {
+            Self::ExecutorStopped => None,
+            Self::ExecutorKilledByPanic(payload) => payload
+                .downcast_ref::<&'static str>()
+                .copied()
+                .or_else(|| payload.downcast_ref::<String>().map(String::as_str)),
+        }
+    }
+
+    /// Propagates the panic if `self` is [`ExecutorKilledByPanic`](Self::ExecutorKilledByPanic),
+    /// otherwise returns `self`.
+    ///
+    /// # Panics
+    ///
+    /// If `self` is [`ExecutorKilledByPanic`](Self::ExecutorKilledByPanic).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use orengine::{BlockOnError, Executor};
+    ///
+    /// let res = Executor::init()
+    ///     .run_and_block_on_local(async { 42 })
+    ///     .map_err(BlockOnError::resume_unwind)
+    ///     .expect("the executor has been stopped");
+    /// ```
+    #[must_use]
+    pub fn resume_unwind(self) -> Self {
+        match self {
+            Self::ExecutorStopped => self,
+            Self::ExecutorKilledByPanic(payload) => std::panic::resume_unwind(payload),
+        }
+    }
+}
+
+impl Debug for BlockOnError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ExecutorStopped => f.write_str("ExecutorStopped"),
+            Self::ExecutorKilledByPanic(_) => f
+                .debug_tuple("ExecutorKilledByPanic")
+                .field(&self.panic_message().unwrap_or("Box<dyn Any>"))
+                .finish(),
+        }
+    }
+}
+
+impl Display for BlockOnError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ExecutorStopped => f.write_str(
+                "The executor has been stopped by stop_all_executors \
+                or stop_executor not in block_on future.",
+            ),
+            Self::ExecutorKilledByPanic(_) => match self.panic_message() {
+                Some(msg) => write!(f, "The executor has been killed by a panic: {msg}"),
+                None => f.write_str("The executor has been killed by a panic."),
+            },
+        }
+    }
+}
+
+impl std::error::Error for BlockOnError {}
@@ -1,4 +1,6 @@
 use crate::io::IoWorkerConfig;
+use crate::runtime::executor::metrics::ProfilingHook;
+use crate::runtime::{Clock, SystemClock, TaskId};
 use crate::utils::SpinLock;
 use crate::BUG_MESSAGE;
 use std::mem::discriminant;
@@ -31,6 +33,56 @@ impl ConfigStats {
 /// A shared config of state of the all runtime.
 static GLOBAL_CONFIG_STATS: SpinLock<ConfigStats> = SpinLock::new(ConfigStats::new());
 
+/// `WatermarkEvent` is passed to the [`WatermarkCallback`] when the number of `local` tasks
+/// in the queue of the [`Executor`](crate::runtime::executor::Executor) crosses the watermarks.
+///
+/// Read [`Config::set_queue_watermark`] for more details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatermarkEvent {
+    /// The number of `local` tasks has reached the `high` watermark.
+    High,
+    /// The number of `local` tasks has fallen below the `low` watermark
+    /// after it had reached the `high` watermark.
+    Low,
+}
+
+/// `WatermarkCallback` is called synchronously by the
+/// [`Executor`](crate::runtime::executor::Executor) with a [`WatermarkEvent`].
+///
+/// Read [`Config::set_queue_watermark`] for more details.
+pub type WatermarkCallback = dyn Fn(WatermarkEvent) + Send + Sync;
+
+/// `QueueWatermark` contains the watermarks of the queue of `local` tasks and
+/// the [`WatermarkCallback`].
+///
+/// Read [`Config::set_queue_watermark`] for more details.
+#[derive(Clone, Copy)]
+pub struct QueueWatermark {
+    /// The number of `local` tasks after which [`WatermarkEvent::High`] is emitted.
+    pub high: usize,
+    /// The number of `local` tasks below which [`WatermarkEvent::Low`] is emitted.
+    pub low: usize,
+    /// The callback that receives [`WatermarkEvents`](WatermarkEvent).
+    pub callback: &'static WatermarkCallback,
+}
+
+impl PartialEq for QueueWatermark {
+    fn eq(&self, other: &Self) -> bool {
+        self.high == other.high
+            && self.low == other.low
+            && std::ptr::addr_eq(self.callback, other.callback)
+    }
+}
+
+impl Eq for QueueWatermark {}
+
+/// `TaskPanicHandler` is called synchronously by the
+/// [`Executor`](crate::runtime::executor::Executor) with the payload of the panic
+/// and the [`TaskId`] of the panicked task.
+///
+/// Read [`Config::set_task_panic_handler`] for more details.
+pub type TaskPanicHandler = dyn Fn(&(dyn std::any::Any + Send), TaskId) + Send + Sync;
+
 /// The default [`buffers`](crate::io::Buffer) capacity.
 pub const DEFAULT_BUF_CAP: u32 = 4096;
 
@@ -42,6 +94,14 @@ pub(crate) struct ValidConfig {
     pub(crate) number_of_thread_workers: usize,
     /// If it is `usize::MAX`, it means that work sharing is disabled.
     pub(crate) work_sharing_level: usize,
+    pub(crate) clock: &'static dyn Clock,
+    /// If it is `usize::MAX`, it means that the queue of local tasks is unbounded.
+    pub(crate) max_local_queue: usize,
+    pub(crate) queue_watermark: Option<QueueWatermark>,
+    pub(crate) detach_guard: bool,
+    pub(crate) profiling_hook: Option<&'static ProfilingHook>,
+    pub(crate) queue_imbalance_threshold: Option<f32>,
+    pub(crate) task_panic_handler: Option<&'static TaskPanicHandler>,
 }
 
 impl ValidConfig {
@@ -96,6 +156,31 @@ impl Drop for ValidConfig {
 ///   how many tasks the [`Executor`](crate::runtime::executor::Executor) can hold before assigning
 ///   them to the shared queue.
 ///   If [`usize::MAX`] is provided, work sharing will be disabled.
+///
+/// - `clock`: The [`Clock`] that is used by the [`Executor`](crate::runtime::executor::Executor)
+///   to get the current time. [`SystemClock`] is used by default.
+///
+/// - `max_local_queue`: The maximum number of `local` tasks in the queue after which
+///   [`spawn_local_bounded`](crate::Executor::spawn_local_bounded) waits.
+///   If [`usize::MAX`] is provided, the queue is unbounded.
+///
+/// - `queue_watermark`: An optional [`QueueWatermark`] of the queue of `local` tasks.
+///   Read [`Config::set_queue_watermark`] for more details.
+///
+/// - `detach_guard`: Whether the [`Executor`](crate::runtime::executor::Executor) asserts
+///   (with `debug_assertions`) that no tasks spawned by
+///   [`spawn_local_detached`](crate::Executor::spawn_local_detached) are running when it stops.
+///
+/// - `profiling_hook`: An optional [`ProfilingHook`] that is called at the end of each round
+///   of the run-loop. Read [`Config::set_profiling_hook`] for more details.
+///
+/// - `queue_imbalance_threshold`: An optional threshold of the
+///   [`queue imbalance`](crate::runtime::ExecutorMetrics::queue_imbalance)
+///   after which a warning is logged.
+///   Read [`Config::set_queue_imbalance_threshold`] for more details.
+///
+/// - `task_panic_handler`: An optional [`TaskPanicHandler`] that is called when a task panics.
+///   Read [`Config::set_task_panic_handler`] for more details.
 #[derive(Clone, Copy)]
 pub struct Config {
     /// The size of the [`buffers`](crate::io::Buffer).
@@ -111,6 +196,24 @@ pub struct Config {
     /// them to the shared queue.
     /// If [`usize::MAX`] is provided, work sharing will be disabled.
     work_sharing_level: usize,
+    /// The [`Clock`] that is used by the [`Executor`](crate::runtime::executor::Executor)
+    /// to get the current time.
+    clock: &'static dyn Clock,
+    /// The maximum number of `local` tasks in the queue after which
+    /// [`spawn_local_bounded`](crate::Executor::spawn_local_bounded) waits.
+    /// If [`usize::MAX`] is provided, the queue is unbounded.
+    max_local_queue: usize,
+    /// An optional [`QueueWatermark`] of the queue of `local` tasks.
+    queue_watermark: Option<QueueWatermark>,
+    /// Whether the [`Executor`](crate::runtime::executor::Executor) asserts
+    /// (with `debug_assertions`) that no detached tasks are running when it stops.
+    detach_guard: bool,
+    /// An optional [`ProfilingHook`] that is called at the end of each round of the run-loop.
+    profiling_hook: Option<&'static ProfilingHook>,
+    /// An optional threshold of the queue imbalance after which a warning is logged.
+    queue_imbalance_threshold: Option<f32>,
+    /// An optional [`TaskPanicHandler`] that is called when a task panics.
+    task_panic_handler: Option<&'static TaskPanicHandler>,
 }
 
 const AN_ATTEMPT_TO_CREATE_EXECUTOR_WITH_WORK_SHARING_AND_IO_WORKER: &str = "\
@@ -141,6 +244,51 @@ const AN_ATTEMPT_TO_CREATE_EXECUTOR_WITH_WORK_SHARING_AND_WITHOUT_THREAD_POOL: &
     This is unacceptable because an Executor who does not have a \
     thread pool cannot take on a task that requires a thread pool.";
 
+/// An error returned when a [`Config`] can't be used to create an
+/// [`Executor`](crate::runtime::executor::Executor).
+///
+/// All executors with enabled work sharing must have the same set of workers,
+/// because an executor can take a task that requires a worker it doesn't have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigError {
+    /// The [`IoWorkerConfig`] is invalid. It contains the description of the error.
+    InvalidIoWorkerConfig(&'static str),
+    /// The config enables work sharing and the IO worker, but another executor
+    /// was created with work sharing and without the IO worker.
+    WorkSharingWithIoWorker,
+    /// The config enables work sharing and disables the IO worker, but another executor
+    /// was created with work sharing and with the IO worker.
+    WorkSharingWithoutIoWorker,
+    /// The config enables work sharing and the thread pool, but another executor
+    /// was created with work sharing and without the thread pool.
+    WorkSharingWithThreadPool,
+    /// The config enables work sharing and disables the thread pool, but another executor
+    /// was created with work sharing and with the thread pool.
+    WorkSharingWithoutThreadPool,
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidIoWorkerConfig(err) => write!(f, "Invalid IO worker config: {err}"),
+            Self::WorkSharingWithIoWorker => {
+                f.write_str(AN_ATTEMPT_TO_CREATE_EXECUTOR_WITH_WORK_SHARING_AND_IO_WORKER)
+            }
+            Self::WorkSharingWithoutIoWorker => {
+                f.write_str(AN_ATTEMPT_TO_CREATE_EXECUTOR_WITH_WORK_SHARING_AND_WITHOUT_IO_WORKER)
+            }
+            Self::WorkSharingWithThreadPool => {
+                f.write_str(AN_ATTEMPT_TO_CREATE_EXECUTOR_WITH_WORK_SHARING_AND_THREAD_POOL)
+            }
+            Self::WorkSharingWithoutThreadPool => {
+                f.write_str(AN_ATTEMPT_TO_CREATE_EXECUTOR_WITH_WORK_SHARING_AND_WITHOUT_THREAD_POOL)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
 impl Config {
     /// Returns a default [`Config`].
     pub const fn default() -> Self {
@@ -149,6 +297,13 @@ impl Config {
             io_worker_config: Some(IoWorkerConfig::default()),
             number_of_thread_workers: 1,
             work_sharing_level: 7,
+            clock: &SystemClock,
+            max_local_queue: usize::MAX,
+            queue_watermark: None,
+            detach_guard: false,
+            profiling_hook: None,
+            queue_imbalance_threshold: None,
+            task_panic_handler: None,
         }
     }
 
@@ -266,66 +421,471 @@ impl Config {
         self
     }
 
-    /// Validates the configuration.
+    /// Returns the [`Clock`] that is used by the
+    /// [`Executor`](crate::runtime::executor::Executor) to get the current time.
+    pub const fn clock(&self) -> &'static dyn Clock {
+        self.clock
+    }
+
+    /// Sets the [`Clock`] that is used by the [`Executor`](crate::runtime::executor::Executor)
+    /// to get the current time.
+    ///
+    /// It affects [`sleep`](crate::sleep()), deadlines and timeouts,
+    /// therefore it allows using [`FakeClock`](crate::test::FakeClock) in tests.
     #[must_use]
-    pub(crate) fn validate(self) -> ValidConfig {
-        if self.work_sharing_level != usize::MAX {
-            let mut shared_config_stats = GLOBAL_CONFIG_STATS.lock();
+    pub const fn set_clock(mut self, clock: &'static dyn Clock) -> Self {
+        self.clock = clock;
 
-            if self.io_worker_config.is_some() {
-                if shared_config_stats.number_of_executors_with_work_sharing_and_without_io_worker
-                    != 0
-                {
-                    panic!("{AN_ATTEMPT_TO_CREATE_EXECUTOR_WITH_WORK_SHARING_AND_IO_WORKER}");
-                }
+        self
+    }
 
-                shared_config_stats.number_of_executors_with_enabled_io_worker_and_work_sharing +=
-                    1;
-            } else {
-                if shared_config_stats.number_of_executors_with_enabled_io_worker_and_work_sharing
-                    != 0
-                {
-                    panic!(
-                        "{AN_ATTEMPT_TO_CREATE_EXECUTOR_WITH_WORK_SHARING_AND_WITHOUT_IO_WORKER}"
-                    );
-                }
+    /// Returns the maximum number of `local` tasks in the queue after which
+    /// [`spawn_local_bounded`](crate::Executor::spawn_local_bounded) waits.
+    /// If [`usize::MAX`] is returned, the queue is unbounded.
+    pub const fn max_local_queue(&self) -> usize {
+        self.max_local_queue
+    }
 
-                shared_config_stats.number_of_executors_with_work_sharing_and_without_io_worker +=
-                    1;
-            }
+    /// Sets the maximum number of `local` tasks in the queue after which
+    /// [`spawn_local_bounded`](crate::Executor::spawn_local_bounded) waits until
+    /// the [`Executor`](crate::runtime::executor::Executor) drains the queue below the limit.
+    ///
+    /// It protects the executor from the memory explosion when a producer spawns tasks
+    /// faster than they are executed. If [`usize::MAX`] is provided, the queue is unbounded.
+    #[must_use]
+    pub const fn set_max_local_queue(mut self, max_local_queue: usize) -> Self {
+        if max_local_queue == 0 {
+            self.max_local_queue = 1;
+        } else {
+            self.max_local_queue = max_local_queue;
+        }
 
-            if self.is_thread_pool_enabled() {
-                if shared_config_stats.number_of_executors_with_work_sharing_and_without_thread_pool
-                    != 0
-                {
-                    panic!("{AN_ATTEMPT_TO_CREATE_EXECUTOR_WITH_WORK_SHARING_AND_THREAD_POOL}");
-                }
+        self
+    }
 
-                shared_config_stats
-                    .number_of_executors_with_enabled_thread_pool_and_work_sharing += 1;
-            } else {
-                if shared_config_stats.number_of_executors_with_enabled_thread_pool_and_work_sharing
-                    != 0
-                {
-                    panic!(
-                        "{AN_ATTEMPT_TO_CREATE_EXECUTOR_WITH_WORK_SHARING_AND_WITHOUT_THREAD_POOL}"
-                    );
-                }
+    /// Returns the optional [`QueueWatermark`] of the queue of `local` tasks.
+    pub const fn queue_watermark(&self) -> Option<QueueWatermark> {
+        self.queue_watermark
+    }
 
-                shared_config_stats
-                    .number_of_executors_with_work_sharing_and_without_thread_pool += 1;
+    /// Sets the watermarks of the queue of `local` tasks and the [`WatermarkCallback`].
+    ///
+    /// The [`Executor`](crate::runtime::executor::Executor) checks the number of `local` tasks
+    /// every round. When it reaches `high`, the `callback` is called with
+    /// [`WatermarkEvent::High`]. When it falls below `low` after that, the `callback` is called
+    /// with [`WatermarkEvent::Low`].
+    ///
+    /// The `callback` is called synchronously by the executor, so it must be fast.
+    /// It allows signaling the upstream backpressure (for example, to stop accepting
+    /// new connections) without polling the number of tasks.
+    ///
+    /// # Panics
+    ///
+    /// If `low` is greater than `high`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::runtime::{Config, WatermarkEvent};
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    ///
+    /// static IS_OVERLOADED: AtomicBool = AtomicBool::new(false);
+    ///
+    /// let config = Config::default().set_queue_watermark(10_000, 1_000, &|event| {
+    ///     IS_OVERLOADED.store(event == WatermarkEvent::High, Ordering::Relaxed);
+    /// });
+    /// ```
+    #[must_use]
+    pub const fn set_queue_watermark(
+        mut self,
+        high: usize,
+        low: usize,
+        callback: &'static WatermarkCallback,
+    ) -> Self {
+        assert!(
+            low <= high,
+            "The low watermark must be less than or equal to the high watermark."
+        );
+
+        self.queue_watermark = Some(QueueWatermark {
+            high,
+            low,
+            callback,
+        });
+
+        self
+    }
+
+    /// Disables the watermarks of the queue of `local` tasks.
+    #[must_use]
+    pub const fn disable_queue_watermark(mut self) -> Self {
+        self.queue_watermark = None;
+
+        self
+    }
+
+    /// Returns whether the detach guard is enabled.
+    ///
+    /// Read [`Config::enable_detach_guard`] for more details.
+    pub const fn is_detach_guard_enabled(&self) -> bool {
+        self.detach_guard
+    }
+
+    /// Enables the detach guard.
+    ///
+    /// With enabled detach guard and `debug_assertions`,
+    /// the [`Executor`](crate::runtime::executor::Executor) panics if it stops while some tasks
+    /// spawned by [`spawn_local_detached`](crate::Executor::spawn_local_detached)
+    /// are still running. It helps to find leaked fire-and-forget tasks.
+    ///
+    /// It is disabled by default.
+    #[must_use]
+    pub const fn enable_detach_guard(mut self) -> Self {
+        self.detach_guard = true;
+
+        self
+    }
+
+    /// Disables the detach guard.
+    #[must_use]
+    pub const fn disable_detach_guard(mut self) -> Self {
+        self.detach_guard = false;
+
+        self
+    }
+
+    /// Returns the optional [`ProfilingHook`].
+    ///
+    /// Read [`Config::set_profiling_hook`] for more details.
+    pub const fn profiling_hook(&self) -> Option<&'static ProfilingHook> {
+        self.profiling_hook
+    }
+
+    /// Sets the [`ProfilingHook`] that is called at the end of each round of the run-loop
+    /// with the [`RoundProfile`](crate::runtime::RoundProfile) of this round.
+    ///
+    /// The `hook` is called synchronously by the
+    /// [`Executor`](crate::runtime::executor::Executor), so it must be fast.
+    /// It allows finding out where the time of the executor goes
+    /// (for example, to detect rounds with long background work) without an external profiler.
+    ///
+    /// When the hook is not set, the executor doesn't collect the profiles at all.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::runtime::Config;
+    /// use std::time::Duration;
+    ///
+    /// let config = Config::default().set_profiling_hook(&|profile| {
+    ///     if profile.background_work_duration() > Duration::from_millis(1) {
+    ///         eprintln!("slow background work: {profile:?}");
+    ///     }
+    /// });
+    /// ```
+    #[must_use]
+    pub const fn set_profiling_hook(mut self, hook: &'static ProfilingHook) -> Self {
+        self.profiling_hook = Some(hook);
+
+        self
+    }
+
+    /// Disables the [`ProfilingHook`].
+    #[must_use]
+    pub const fn disable_profiling_hook(mut self) -> Self {
+        self.profiling_hook = None;
+
+        self
+    }
+
+    /// Returns the optional threshold of the
+    /// [`queue imbalance`](crate::runtime::ExecutorMetrics::queue_imbalance).
+    ///
+    /// Read [`Config::set_queue_imbalance_threshold`] for more details.
+    pub const fn queue_imbalance_threshold(&self) -> Option<f32> {
+        self.queue_imbalance_threshold
+    }
+
+    /// Sets the threshold of the
+    /// [`queue imbalance`](crate::runtime::ExecutorMetrics::queue_imbalance).
+    ///
+    /// When the queue imbalance of the [`Executor`](crate::runtime::executor::Executor)
+    /// exceeds the `threshold`, a warning is logged (with `tracing` if the feature is enabled,
+    /// otherwise to `stderr`). The warning is logged again only after the imbalance
+    /// has fallen below the `threshold`, so an overloaded executor doesn't flood the log.
+    ///
+    /// It has no effect if work sharing is disabled. It is disabled by default.
+    ///
+    /// # Panics
+    ///
+    /// If `threshold` is less than `1.0` or is `NaN`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::runtime::Config;
+    ///
+    /// // Warn when the executor holds 4 times more shared tasks than the average executor.
+    /// let config = Config::default().set_queue_imbalance_threshold(4.0);
+    /// ```
+    #[must_use]
+    pub const fn set_queue_imbalance_threshold(mut self, threshold: f32) -> Self {
+        assert!(
+            threshold >= 1.0,
+            "The queue imbalance threshold must be greater than or equal to 1.0."
+        );
+
+        self.queue_imbalance_threshold = Some(threshold);
+
+        self
+    }
+
+    /// Disables warnings about the queue imbalance.
+    #[must_use]
+    pub const fn disable_queue_imbalance_threshold(mut self) -> Self {
+        self.queue_imbalance_threshold = None;
+
+        self
+    }
+
+    /// Returns the optional [`TaskPanicHandler`].
+    ///
+    /// Read [`Config::set_task_panic_handler`] for more details.
+    pub const fn task_panic_handler(&self) -> Option<&'static TaskPanicHandler> {
+        self.task_panic_handler
+    }
+
+    /// Sets the [`TaskPanicHandler`] that is called when a task panics.
+    ///
+    /// With the handler, the [`Executor`](crate::runtime::executor::Executor) catches panics
+    /// of tasks, calls the `handler` with the payload of the panic and the [`TaskId`]
+    /// of the panicked task and drops the task. The executor continues to execute other tasks.
+    ///
+    /// Without the handler (by default), a panic of a task unwinds through the executor
+    /// and stops it, and the executor doesn't pay for catching panics.
+    ///
+    /// Tasks that wait for the panicked task are never woken. It is true for the future
+    /// provided to [`run_and_block_on_local`](crate::Executor::run_and_block_on_local)
+    /// or [`run_and_block_on_shared`](crate::Executor::run_and_block_on_shared) too,
+    /// so the `handler` should stop the executor if it is needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::runtime::Config;
+    ///
+    /// let config = Config::default().set_task_panic_handler(&|payload, task_id| {
+    ///     let msg = payload
+    ///         .downcast_ref::<&str>()
+    ///         .copied()
+    ///         .unwrap_or("Box<dyn Any>");
+    ///
+    ///     eprintln!("task {task_id:?} panicked: {msg}");
+    /// });
+    /// ```
+    #[must_use]
+    pub const fn set_task_panic_handler(mut self, handler: &'static TaskPanicHandler) -> Self {
+        self.task_panic_handler = Some(handler);
+
+        self
+    }
+
+    /// Disables the [`TaskPanicHandler`], so a panic of a task unwinds through the executor.
+    #[must_use]
+    pub const fn disable_task_panic_handler(mut self) -> Self {
+        self.task_panic_handler = None;
+
+        self
+    }
+
+    /// Validates the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConfigError`] if the configuration is invalid or incompatible with
+    /// configurations of other executors with enabled work sharing.
+    pub(crate) fn validate(self) -> Result<ValidConfig, ConfigError> {
+        if let Some(io_worker_config) = self.io_worker_config {
+            if let Err(err) = io_worker_config.validate() {
+                return Err(ConfigError::InvalidIoWorkerConfig(err));
             }
         }
 
-        ValidConfig {
+        if self.work_sharing_level != usize::MAX {
+            self.register_work_sharing_in(&mut GLOBAL_CONFIG_STATS.lock())?;
+        }
+
+        Ok(ValidConfig {
             buffer_cap: self.buffer_cap,
             io_worker_config: self.io_worker_config,
             number_of_thread_workers: self.number_of_thread_workers,
             work_sharing_level: self.work_sharing_level,
+            clock: self.clock,
+            max_local_queue: self.max_local_queue,
+            queue_watermark: self.queue_watermark,
+            detach_guard: self.detach_guard,
+            profiling_hook: self.profiling_hook,
+            queue_imbalance_threshold: self.queue_imbalance_threshold,
+            task_panic_handler: self.task_panic_handler,
+        })
+    }
+
+    /// Checks that the config with enabled work sharing is compatible with the configs
+    /// counted in `stats` and counts it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConfigError`] if the config is incompatible with the counted configs.
+    fn register_work_sharing_in(&self, stats: &mut ConfigStats) -> Result<(), ConfigError> {
+        if self.io_worker_config.is_some() {
+            if stats.number_of_executors_with_work_sharing_and_without_io_worker != 0 {
+                return Err(ConfigError::WorkSharingWithIoWorker);
+            }
+        } else if stats.number_of_executors_with_enabled_io_worker_and_work_sharing != 0 {
+            return Err(ConfigError::WorkSharingWithoutIoWorker);
+        }
+
+        if self.is_thread_pool_enabled() {
+            if stats.number_of_executors_with_work_sharing_and_without_thread_pool != 0 {
+                return Err(ConfigError::WorkSharingWithThreadPool);
+            }
+        } else if stats.number_of_executors_with_enabled_thread_pool_and_work_sharing != 0 {
+            return Err(ConfigError::WorkSharingWithoutThreadPool);
+        }
+
+        if self.io_worker_config.is_some() {
+            stats.number_of_executors_with_enabled_io_worker_and_work_sharing += 1;
+        } else {
+            stats.number_of_executors_with_work_sharing_and_without_io_worker += 1;
+        }
+
+        if self.is_thread_pool_enabled() {
+            stats.number_of_executors_with_enabled_thread_pool_and_work_sharing += 1;
+        } else {
+            stats.number_of_executors_with_work_sharing_and_without_thread_pool += 1;
+        }
+
+        Ok(())
+    }
+}
+
+/// Reports that the environment variable `name` has an invalid `value` and is ignored.
+fn warn_invalid_env_var(name: &str, value: &str, expected: &str) {
+    #[cfg(feature = "tracing")]
+    tracing::warn!("{name}={value} is invalid and ignored: expected {expected}");
+
+    #[cfg(not(feature = "tracing"))]
+    eprintln!("orengine: {name}={value} is invalid and ignored: expected {expected}");
+}
+
+/// Parses the environment variable `name` with the provided `value` as a number.
+/// Returns `None` and reports it if it is invalid.
+fn parse_env_var<T: std::str::FromStr + PartialOrd + Default>(
+    name: &str,
+    value: &str,
+) -> Option<T> {
+    match value.trim().parse::<T>() {
+        Ok(number) if number > T::default() => Some(number),
+        _ => {
+            warn_invalid_env_var(name, value, "a positive integer");
+
+            None
         }
     }
 }
 
+impl Config {
+    /// Returns [`Config::default`] overridden by the `ORENGINE_*` environment variables.
+    ///
+    /// Read [`Config::from_env_with_defaults`] for more details.
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self::from_env_with_defaults(Self::default())
+    }
+
+    /// Returns `defaults` overridden by the `ORENGINE_*` environment variables.
+    /// It allows tuning deployed services without recompilation.
+    ///
+    /// The supported variables are:
+    ///
+    /// - `ORENGINE_WORK_SHARING=on|off`: enables or disables the work sharing;
+    ///
+    /// - `ORENGINE_WORK_SHARING_LEVEL=<positive integer>`: sets
+    ///   the [`work sharing level`](Config::set_work_sharing_level) and enables the work sharing
+    ///   if `ORENGINE_WORK_SHARING` is not `off`;
+    ///
+    /// - `ORENGINE_THREAD_WORKERS=<integer>`: sets the
+    ///   [`number of blocking workers`](Config::set_numbers_of_blocking_workers),
+    ///   `0` disables the thread pool;
+    ///
+    /// - `ORENGINE_IO_SQ_SIZE=<positive integer>`: sets the number of entries of `io_uring`
+    ///   if the IO worker is enabled.
+    ///
+    /// Unset variables are ignored. Invalid values are ignored too, and a warning
+    /// is written to `stderr` (or with [`tracing`] if the `tracing` feature is enabled).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::runtime::Config;
+    ///
+    /// // ORENGINE_THREAD_WORKERS=4 ./service
+    /// let config = Config::from_env_with_defaults(Config::default().disable_work_sharing());
+    /// ```
+    #[must_use]
+    pub fn from_env_with_defaults(defaults: Self) -> Self {
+        Self::from_vars_with_defaults(defaults, |name| std::env::var(name).ok())
+    }
+
+    /// Returns `defaults` overridden by the `ORENGINE_*` variables returned by `get_var`.
+    fn from_vars_with_defaults(defaults: Self, get_var: impl Fn(&str) -> Option<String>) -> Self {
+        const WORK_SHARING: &str = "ORENGINE_WORK_SHARING";
+        const WORK_SHARING_LEVEL: &str = "ORENGINE_WORK_SHARING_LEVEL";
+        const THREAD_WORKERS: &str = "ORENGINE_THREAD_WORKERS";
+        const IO_SQ_SIZE: &str = "ORENGINE_IO_SQ_SIZE";
+
+        let mut config = defaults;
+
+        if let Some(value) = get_var(WORK_SHARING_LEVEL) {
+            if let Some(level) = parse_env_var(WORK_SHARING_LEVEL, &value) {
+                config = config.set_work_sharing_level(level);
+            }
+        }
+
+        if let Some(value) = get_var(WORK_SHARING) {
+            match value.trim() {
+                "on" => config = config.enable_work_sharing(),
+                "off" => config = config.disable_work_sharing(),
+                _ => warn_invalid_env_var(WORK_SHARING, &value, "`on` or `off`"),
+            }
+        }
+
+        if let Some(value) = get_var(THREAD_WORKERS) {
+            match value.trim().parse::<usize>() {
+                Ok(number) => config = config.set_numbers_of_blocking_workers(number),
+                Err(_) => warn_invalid_env_var(THREAD_WORKERS, &value, "an integer"),
+            }
+        }
+
+        if let Some(value) = get_var(IO_SQ_SIZE) {
+            if let Some(size) = parse_env_var(IO_SQ_SIZE, &value) {
+                if let Some(io_worker_config) = config.io_worker_config {
+                    let io_worker_config = io_worker_config.sq_entries(size);
+                    if let Err(err) = io_worker_config.validate() {
+                        warn_invalid_env_var(IO_SQ_SIZE, &value, err);
+                    } else {
+                        config.io_worker_config = Some(io_worker_config);
+                    }
+                } else {
+                    warn_invalid_env_var(IO_SQ_SIZE, &value, "the IO worker to be enabled");
+                }
+            }
+        }
+
+        config
+    }
+}
+
 impl From<&ValidConfig> for Config {
     fn from(config: &ValidConfig) -> Self {
         Self {
@@ -333,6 +893,13 @@ impl From<&ValidConfig> for Config {
             io_worker_config: config.io_worker_config,
             number_of_thread_workers: config.number_of_thread_workers,
             work_sharing_level: config.work_sharing_level,
+            clock: config.clock,
+            max_local_queue: config.max_local_queue,
+            queue_watermark: config.queue_watermark,
+            detach_guard: config.detach_guard,
+            profiling_hook: config.profiling_hook,
+            queue_imbalance_threshold: config.queue_imbalance_threshold,
+            task_panic_handler: config.task_panic_handler,
         }
     }
 }
@@ -343,6 +910,21 @@ impl PartialEq for Config {
             && discriminant(&self.io_worker_config) == discriminant(&other.io_worker_config)
             && self.number_of_thread_workers == other.number_of_thread_workers
             && self.work_sharing_level == other.work_sharing_level
+            && std::ptr::addr_eq(self.clock, other.clock)
+            && self.max_local_queue == other.max_local_queue
+            && self.queue_watermark == other.queue_watermark
+            && self.detach_guard == other.detach_guard
+            && match (self.profiling_hook, other.profiling_hook) {
+                (Some(hook), Some(other_hook)) => std::ptr::addr_eq(hook, other_hook),
+                (None, None) => true,
+                _ => false,
+            }
+            && self.queue_imbalance_threshold == other.queue_imbalance_threshold
+            && match (self.task_panic_handler, other.task_panic_handler) {
+                (Some(handler), Some(other_handler)) => std::ptr::addr_eq(handler, other_handler),
+                (None, None) => true,
+                _ => false,
+            }
     }
 }
 
@@ -372,6 +954,57 @@ pub(crate) mod tests {
         assert!(prev < NUMBER_OF_TESTS, "{}", BUG_MESSAGE);
     }
 
+    #[test]
+    fn test_config_from_vars() {
+        let vars = |name: &str| match name {
+            "ORENGINE_WORK_SHARING_LEVEL" => Some("128".to_string()),
+            "ORENGINE_THREAD_WORKERS" => Some("4".to_string()),
+            "ORENGINE_IO_SQ_SIZE" => Some("512".to_string()),
+            _ => None,
+        };
+        let config =
+            Config::from_vars_with_defaults(Config::default().disable_work_sharing(), vars);
+        assert_eq!(config.work_sharing_level, 128);
+        assert_eq!(config.number_of_thread_workers(), 4);
+        assert_eq!(
+            config
+                .io_worker_config()
+                .unwrap()
+                .io_uring
+                .number_of_entries,
+            512
+        );
+
+        let vars = |name: &str| match name {
+            "ORENGINE_WORK_SHARING" => Some("off".to_string()),
+            "ORENGINE_WORK_SHARING_LEVEL" => Some("128".to_string()),
+            "ORENGINE_THREAD_WORKERS" => Some("many".to_string()),
+            "ORENGINE_IO_SQ_SIZE" => Some("0".to_string()),
+            _ => None,
+        };
+        let config = Config::from_vars_with_defaults(Config::default(), vars);
+        assert!(!config.is_work_sharing_enabled());
+        assert_eq!(
+            config.number_of_thread_workers(),
+            Config::default().number_of_thread_workers()
+        );
+        assert_eq!(
+            config
+                .io_worker_config()
+                .unwrap()
+                .io_uring
+                .number_of_entries,
+            Config::default()
+                .io_worker_config()
+                .unwrap()
+                .io_uring
+                .number_of_entries
+        );
+
+        let config = Config::from_vars_with_defaults(Config::default(), |_| None);
+        assert!(config == Config::default());
+    }
+
     fn get_lock() -> std::sync::MutexGuard<'static, ()> {
         LOCK.lock().unwrap_or_else(|e| {
             LOCK.clear_poison();
@@ -382,7 +1015,7 @@ pub(crate) mod tests {
     #[orengine::test::test_local]
     fn test_default_config() {
         let lock = get_lock();
-        let config = Config::default().validate();
+        let config = Config::default().validate().unwrap();
         assert_eq!(config.buffer_cap, DEFAULT_BUF_CAP);
         assert!(config.io_worker_config.is_some());
         assert!(config.is_thread_pool_enabled());
@@ -399,9 +1032,11 @@ pub(crate) mod tests {
             .set_io_worker_config(None)
             .unwrap()
             .set_numbers_of_blocking_workers(0)
-            .disable_work_sharing();
+            .disable_work_sharing()
+            .set_queue_imbalance_threshold(2.0);
 
-        let config = config.validate();
+        let config = config.validate().unwrap();
+        assert_eq!(config.queue_imbalance_threshold, Some(2.0));
         assert_eq!(config.buffer_cap, 1024);
         assert!(config.io_worker_config.is_none());
         assert!(!config.is_thread_pool_enabled());
@@ -439,12 +1074,13 @@ pub(crate) mod tests {
     fn test_config_first_case_panic() {
         // with io worker and work sharing
         handle_panic_in_config_test(|| {
-            let _first_config = Config::default().validate();
+            let _first_config = Config::default().validate().unwrap();
             let _second_config = Config::default()
                 .set_io_worker_config(None)
                 .unwrap()
                 .enable_work_sharing()
-                .validate();
+                .validate()
+                .unwrap();
         });
     }
 
@@ -461,8 +1097,9 @@ pub(crate) mod tests {
                 .set_io_worker_config(None)
                 .unwrap()
                 .enable_work_sharing()
-                .validate();
-            let _second_config = Config::default().validate();
+                .validate()
+                .unwrap();
+            let _second_config = Config::default().validate().unwrap();
         });
     }
 
@@ -478,8 +1115,9 @@ pub(crate) mod tests {
             let _first_config = Config::default()
                 .set_numbers_of_blocking_workers(0)
                 .enable_work_sharing()
-                .validate();
-            let _second_config = Config::default().validate();
+                .validate()
+                .unwrap();
+            let _second_config = Config::default().validate().unwrap();
         });
     }
 
@@ -492,11 +1130,77 @@ pub(crate) mod tests {
     fn test_config_fourth_case_panic() {
         // with thread pool and work sharing
         handle_panic_in_config_test(|| {
-            let _first_config = Config::default().validate();
+            let _first_config = Config::default().validate().unwrap();
             let _second_config = Config::default()
                 .set_numbers_of_blocking_workers(0)
                 .enable_work_sharing()
-                .validate();
+                .validate()
+                .unwrap();
         });
     }
+
+    #[test]
+    fn test_config_error_invalid_io_worker_config() {
+        let config = Config {
+            io_worker_config: Some(crate::io::IoWorkerConfig::default().submit_batch_size(0)),
+            ..Config::default().disable_work_sharing()
+        };
+
+        assert_eq!(
+            config.validate().err(),
+            Some(ConfigError::InvalidIoWorkerConfig(
+                "io_uring: submit_batch_size must be greater than 0"
+            ))
+        );
+    }
+
+    /// Counts `first` in new stats and returns the result of counting `second` in them.
+    fn register_work_sharing_after(first: &Config, second: &Config) -> Result<(), ConfigError> {
+        let mut stats = ConfigStats::new();
+        first
+            .register_work_sharing_in(&mut stats)
+            .expect("the first config must be compatible with new stats");
+
+        second.register_work_sharing_in(&mut stats)
+    }
+
+    #[test]
+    fn test_config_error_work_sharing_with_io_worker() {
+        let without_io_worker = Config::default().set_io_worker_config(None).unwrap();
+
+        assert_eq!(
+            register_work_sharing_after(&without_io_worker, &Config::default()),
+            Err(ConfigError::WorkSharingWithIoWorker)
+        );
+    }
+
+    #[test]
+    fn test_config_error_work_sharing_without_io_worker() {
+        let without_io_worker = Config::default().set_io_worker_config(None).unwrap();
+
+        assert_eq!(
+            register_work_sharing_after(&Config::default(), &without_io_worker),
+            Err(ConfigError::WorkSharingWithoutIoWorker)
+        );
+    }
+
+    #[test]
+    fn test_config_error_work_sharing_with_thread_pool() {
+        let without_thread_pool = Config::default().set_numbers_of_blocking_workers(0);
+
+        assert_eq!(
+            register_work_sharing_after(&without_thread_pool, &Config::default()),
+            Err(ConfigError::WorkSharingWithThreadPool)
+        );
+    }
+
+    #[test]
+    fn test_config_error_work_sharing_without_thread_pool() {
+        let without_thread_pool = Config::default().set_numbers_of_blocking_workers(0);
+
+        assert_eq!(
+            register_work_sharing_after(&Config::default(), &without_thread_pool),
+            Err(ConfigError::WorkSharingWithoutThreadPool)
+        );
+    }
 }
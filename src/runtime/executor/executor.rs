@@ -1,28 +1,37 @@
 use crate::bug_message::BUG_MESSAGE;
-use crate::io::sys::WorkerSys;
-use crate::io::worker::{get_local_worker_ref, init_local_worker, IoWorker};
+use crate::io::worker::{get_local_worker_ref, init_local_worker, IoWorker, LocalWorker};
 use crate::io::{init_local_buf_pool, uninit_local_buf_pool};
 use crate::runtime::call::Call;
-use crate::runtime::config::{Config, ValidConfig};
+use crate::runtime::config::{Config, ConfigError, TaskPanicHandler, ValidConfig, WatermarkEvent};
+use crate::runtime::executor::block_on_error::BlockOnError;
 use crate::runtime::executor::end_local_thread_and_write_into_ptr::EndLocalThreadAndWriteIntoPtr;
-use crate::runtime::global_state::{register_local_executor, SubscribedState};
+use crate::runtime::executor::metrics::{ExecutorMetrics, RoundProfile};
+use crate::runtime::executor::snapshot::ExecutorSnapshot;
+use crate::runtime::executor::spawn_local_bounded::SpawnLocalBounded;
+use crate::runtime::executor::task_priority::TaskPriority;
+use crate::runtime::global_state::{
+    lock_and_get_global_state, register_local_executor, stop_executor, SubscribedState,
+};
 #[cfg(not(feature = "disable_send_task_to"))]
 use crate::runtime::interaction_between_executors::{Interactor, SendTaskResult};
 use crate::runtime::local_thread_pool::LocalThreadWorkerPool;
 use crate::runtime::task::{Task, TaskPool};
 use crate::runtime::waker::create_waker;
 use crate::runtime::{get_core_id_for_executor, ExecutorSharedTaskList, Locality};
+use crate::sleep::timer_wheel::TimerWheel;
 use crate::utils::{assert_hint, CoreId, ProgressiveTimeout};
 use fastrand::Rng;
+use std::any::Any;
 use std::cell::UnsafeCell;
-use std::collections::{BTreeMap, VecDeque};
+use std::collections::VecDeque;
 use std::future::Future;
+use std::panic::AssertUnwindSafe;
 use std::pin::Pin;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::task::{Context, Poll};
 use std::time::{Duration, Instant};
-use std::{mem, thread};
+use std::{mem, panic, ptr, thread};
 
 macro_rules! shrink {
     ($list:expr) => {
@@ -137,6 +146,7 @@ pub fn local_executor() -> &'static mut Executor {
 pub struct Executor {
     core_id: CoreId,
     id: usize,
+    name: Option<&'static str>,
     config: ValidConfig,
     subscribed_state: Arc<SubscribedState>,
     task_pool: TaskPool,
@@ -145,21 +155,45 @@ pub struct Executor {
 
     exec_series: usize,
     current_call: Call,
+    /// The ambient deadline of the currently polled task.
+    /// Read [`deadline`](crate::deadline) for more details.
+    ambient_deadline: Option<Instant>,
     start_round_time: Instant,
     /// `start_round_time` + 100 microseconds
     #[cfg(target_os = "linux")]
     start_round_time_for_deadlines: Instant,
 
     local_tasks: VecDeque<Task>,
+    /// The number of local tasks that have been yielded by
+    /// [`YieldCurrentLocalTask`](Call::YieldCurrentLocalTask) and must be resumed
+    /// in the current round.
+    number_of_local_tasks_yielded_in_round: usize,
+    /// Tasks that wait in [`spawn_local_bounded`](Executor::spawn_local_bounded)
+    /// until `local_tasks` has room.
+    tasks_waiting_for_local_queue: VecDeque<Task>,
+    /// Whether the `high` watermark of the queue of local tasks has been reached
+    /// and the `low` one has not been reached since then.
+    is_above_queue_watermark: bool,
+    /// The number of running tasks spawned by
+    /// [`spawn_local_detached`](Executor::spawn_local_detached).
+    number_of_detached_tasks: usize,
     shared_tasks: VecDeque<Task>,
+    /// `shared` tasks spawned with [`TaskPriority::High`]. They are never shared.
+    high_priority_shared_tasks: VecDeque<Task>,
+    /// `shared` tasks spawned with [`TaskPriority::Low`]. They are never shared.
+    low_priority_shared_tasks: VecDeque<Task>,
     shared_tasks_list: Option<Arc<ExecutorSharedTaskList>>,
     #[cfg(not(feature = "disable_send_task_to"))]
     interactor: Interactor,
 
-    local_worker: &'static mut Option<WorkerSys>,
+    local_worker: &'static mut Option<LocalWorker>,
     thread_pool: LocalThreadWorkerPool,
 
-    local_sleeping_tasks: BTreeMap<Instant, Task>,
+    local_sleeping_tasks: TimerWheel<Task>,
+    /// The buffer for the expired sleeping tasks to avoid allocations.
+    expired_sleeping_tasks: Vec<Task>,
+
+    metrics: ExecutorMetrics,
 }
 
 /// The next id of the executor. It is used to generate the unique executor id.
@@ -172,6 +206,22 @@ pub(crate) static FREE_EXECUTOR_ID: AtomicUsize = AtomicUsize::new(0);
 /// other executors shared lists.
 const MAX_NUMBER_OF_TASKS_TAKEN: usize = 16;
 
+/// Reports that the queue imbalance of the executor with `executor_id` has exceeded
+/// the `threshold`.
+fn warn_queue_imbalance(executor_id: usize, queue_imbalance: f32, threshold: f32) {
+    #[cfg(feature = "tracing")]
+    tracing::warn!(
+        "executor {executor_id} has {queue_imbalance:.2} times more shared tasks \
+         than the average executor (threshold is {threshold})"
+    );
+
+    #[cfg(not(feature = "tracing"))]
+    eprintln!(
+        "orengine: executor {executor_id} has {queue_imbalance:.2} times more shared tasks \
+         than the average executor (threshold is {threshold})"
+    );
+}
+
 impl Executor {
     /// Initializes the executor in the current thread with provided config on the given core.
     ///
@@ -190,16 +240,50 @@ impl Executor {
     /// });
     /// ex.run()
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the `config` is invalid. Use
+    /// [`try_init_on_core_with_config`](Self::try_init_on_core_with_config)
+    /// to handle the [`ConfigError`].
     pub fn init_on_core_with_config(core_id: CoreId, config: Config) -> &'static mut Self {
+        Self::try_init_on_core_with_config(core_id, config).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Initializes the executor in the current thread with provided config on the given core
+    /// or returns a [`ConfigError`] if the `config` is invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConfigError`] if the `config` is invalid or incompatible with configs
+    /// of other executors with enabled work sharing.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use orengine::runtime::Config;
+    /// use orengine::Executor;
+    /// use orengine::utils::get_core_ids;
+    ///
+    /// let cores = get_core_ids().unwrap();
+    /// match Executor::try_init_on_core_with_config(cores[0], Config::default()) {
+    ///     Ok(ex) => ex.run(),
+    ///     Err(err) => eprintln!("Can't create an executor: {err}"),
+    /// }
+    /// ```
+    pub fn try_init_on_core_with_config(
+        core_id: CoreId,
+        config: Config,
+    ) -> Result<&'static mut Self, ConfigError> {
         if get_local_executor_ref().is_some() {
             println!(
                 "There is already an initialized executor in the current thread!\
              Not re-initializing."
             );
-            return local_executor();
+            return Ok(local_executor());
         }
 
-        let valid_config = config.validate();
+        let valid_config = config.validate()?;
         crate::utils::core::set_for_current(core_id);
         let executor_id = FREE_EXECUTOR_ID.fetch_add(1, Ordering::Relaxed);
         let (shared_tasks, shared_tasks_list_cap) = if valid_config.is_work_sharing_enabled() {
@@ -211,6 +295,7 @@ impl Executor {
             (None, 0)
         };
         let number_of_thread_workers = valid_config.number_of_thread_workers;
+        let now = valid_config.clock.now();
 
         unsafe {
             if let Some(io_config) = valid_config.io_worker_config {
@@ -223,30 +308,41 @@ impl Executor {
             *get_local_executor_ref() = Some(Self {
                 core_id,
                 id: executor_id,
+                name: None,
                 config: valid_config,
                 current_call: Call::default(),
+                ambient_deadline: None,
                 task_pool: TaskPool::default(),
                 subscribed_state: Arc::new(SubscribedState::new()),
                 rng: Rng::new(),
                 progressive_timeout: ProgressiveTimeout::new(),
 
                 exec_series: 0,
-                start_round_time: Instant::now(),
+                start_round_time: now,
                 #[cfg(target_os = "linux")]
-                start_round_time_for_deadlines: Instant::now() + Duration::from_micros(100),
+                start_round_time_for_deadlines: now + Duration::from_micros(100),
 
                 local_tasks: VecDeque::new(),
+                number_of_local_tasks_yielded_in_round: 0,
+                tasks_waiting_for_local_queue: VecDeque::new(),
+                is_above_queue_watermark: false,
+                number_of_detached_tasks: 0,
                 shared_tasks: VecDeque::with_capacity(shared_tasks_list_cap),
+                high_priority_shared_tasks: VecDeque::new(),
+                low_priority_shared_tasks: VecDeque::new(),
                 shared_tasks_list: shared_tasks,
 
                 #[cfg(not(feature = "disable_send_task_to"))]
                 interactor: Interactor::new(),
                 local_worker: get_local_worker_ref(),
                 thread_pool: LocalThreadWorkerPool::new(number_of_thread_workers),
-                local_sleeping_tasks: BTreeMap::new(),
+                local_sleeping_tasks: TimerWheel::new(now),
+                expired_sleeping_tasks: Vec::new(),
+
+                metrics: ExecutorMetrics::new(),
             });
 
-            local_executor()
+            Ok(local_executor())
         }
     }
 
@@ -285,10 +381,36 @@ impl Executor {
     /// });
     /// ex.run();
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the `config` is invalid. Use [`try_init_with_config`](Self::try_init_with_config)
+    /// to handle the [`ConfigError`].
     pub fn init_with_config(config: Config) -> &'static mut Self {
         Self::init_on_core_with_config(get_core_id_for_executor(), config)
     }
 
+    /// Initializes the executor in the current thread with provided config
+    /// or returns a [`ConfigError`] if the `config` is invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConfigError`] if the `config` is invalid or incompatible with configs
+    /// of other executors with enabled work sharing.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use orengine::runtime::Config;
+    /// use orengine::Executor;
+    ///
+    /// let ex = Executor::try_init_with_config(Config::default()).expect("invalid config");
+    /// ex.run();
+    /// ```
+    pub fn try_init_with_config(config: Config) -> Result<&'static mut Self, ConfigError> {
+        Self::try_init_on_core_with_config(get_core_id_for_executor(), config)
+    }
+
     /// Initializes the executor in the current thread.
     ///
     /// # Example
@@ -312,6 +434,39 @@ impl Executor {
         self.id
     }
 
+    /// Returns the name of the executor if it was set by [`set_name`](Self::set_name).
+    pub fn name(&self) -> Option<&'static str> {
+        self.name
+    }
+
+    /// Sets the name of the executor.
+    ///
+    /// Unlike the [`id`](Self::id), the name doesn't change between runs, so the executor
+    /// can be stopped by [`stop_executor_by_name`](crate::runtime::stop_executor_by_name).
+    ///
+    /// Names are not checked for uniqueness. Read
+    /// [`stop_executor_by_name`](crate::runtime::stop_executor_by_name) to know which executor
+    /// it stops if several executors have the same name.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use orengine::Executor;
+    /// use orengine::runtime::stop_executor_by_name;
+    ///
+    /// let executor = Executor::init();
+    /// executor.set_name("io");
+    ///
+    /// executor.spawn_local(async {
+    ///     stop_executor_by_name("io").unwrap();
+    /// });
+    /// executor.run();
+    /// ```
+    pub fn set_name(&mut self, name: &'static str) {
+        self.name = Some(name);
+        lock_and_get_global_state().set_executor_name(self.id, name);
+    }
+
     /// Returns a reference to the [`TaskPool`] of the executor.
     pub(crate) fn task_pool(&mut self) -> &mut TaskPool {
         &mut self.task_pool
@@ -330,7 +485,18 @@ impl Executor {
     pub(crate) fn add_task_at_the_start_of_lifo_shared_queue(&mut self, task: Task) {
         debug_assert!(!task.is_local());
 
-        self.shared_tasks.push_front(task);
+        self.shared_tasks_with_priority(task.priority())
+            .push_front(task);
+    }
+
+    /// Returns the queue of `shared` tasks with the provided [`priority`](TaskPriority).
+    #[inline]
+    fn shared_tasks_with_priority(&mut self, priority: TaskPriority) -> &mut VecDeque<Task> {
+        match priority {
+            TaskPriority::High => &mut self.high_priority_shared_tasks,
+            TaskPriority::Normal => &mut self.shared_tasks,
+            TaskPriority::Low => &mut self.low_priority_shared_tasks,
+        }
     }
 
     /// Returns a reference to the subscribed state of the executor.
@@ -354,6 +520,23 @@ impl Executor {
         Config::from(&self.config)
     }
 
+    /// Returns the ambient deadline of the currently polled task.
+    ///
+    /// Read [`deadline`](crate::deadline) for more details.
+    #[inline]
+    pub fn ambient_deadline(&self) -> Option<Instant> {
+        self.ambient_deadline
+    }
+
+    /// Replaces the ambient deadline of the currently polled task and returns the previous one.
+    #[inline]
+    pub(crate) fn replace_ambient_deadline(
+        &mut self,
+        deadline: Option<Instant>,
+    ) -> Option<Instant> {
+        mem::replace(&mut self.ambient_deadline, deadline)
+    }
+
     /// Returns when current round started.
     pub fn start_round_time(&self) -> Instant {
         self.start_round_time
@@ -370,7 +553,7 @@ impl Executor {
     /// # Behavior on fallback OS
     ///
     /// In fallback, we can't guarantee the 100 microseconds addition sufficiency,
-    /// therefore it is a synonymous to [`Instant::now`] there.
+    /// therefore it is a synonymous to [`Clock::now`](crate::runtime::Clock::now) there.
     pub fn start_round_time_for_deadlines(&self) -> Instant {
         #[cfg(target_os = "linux")]
         {
@@ -379,7 +562,7 @@ impl Executor {
 
         #[cfg(not(target_os = "linux"))]
         {
-            Instant::now()
+            self.config.clock.now()
         }
     }
 
@@ -388,6 +571,45 @@ impl Executor {
         self.shared_tasks_list.as_ref()
     }
 
+    /// Spawns or stops threads of the thread pool used by [`asyncify`](crate::asyncify)
+    /// to make it have `number_of_workers` threads.
+    ///
+    /// It allows adaptive systems to dial up the number of threads under CPU-intensive load
+    /// (e.g., compression) and to dial it down during I/O-bound phases.
+    ///
+    /// Shrinking is cooperative: excess threads finish the jobs that have already been sent
+    /// to them and only then exit.
+    ///
+    /// # Panics
+    ///
+    /// If the thread pool is disabled or `number_of_workers` is 0: resizing can't enable
+    /// or disable the thread pool.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::local_executor;
+    ///
+    /// # async fn foo() {
+    /// local_executor().resize_thread_pool(8);
+    /// // CPU-intensive phase
+    /// local_executor().resize_thread_pool(1);
+    /// # }
+    /// ```
+    pub fn resize_thread_pool(&mut self, number_of_workers: usize) {
+        assert!(
+            self.config.is_thread_pool_enabled(),
+            "resize_thread_pool: the thread pool is disabled"
+        );
+        assert_ne!(
+            number_of_workers, 0,
+            "resize_thread_pool: the thread pool can't be disabled by resizing"
+        );
+
+        self.thread_pool.resize(number_of_workers);
+        self.config.number_of_thread_workers = number_of_workers;
+    }
+
     /// Returns a reference to the local tasks queue.
     #[inline]
     pub fn local_queue(&mut self) -> &mut VecDeque<Task> {
@@ -396,13 +618,71 @@ impl Executor {
 
     /// Returns a reference to the `sleeping_tasks`.
     #[inline]
-    pub(crate) fn sleeping_tasks(&mut self) -> &mut BTreeMap<Instant, Task> {
+    pub(crate) fn sleeping_tasks(&mut self) -> &mut TimerWheel<Task> {
         &mut self.local_sleeping_tasks
     }
 
     /// Returns the number of spawned tasks (shared and local).
     pub(crate) fn number_of_spawned_tasks(&self) -> usize {
-        self.shared_tasks.len() + self.local_tasks.len()
+        self.shared_tasks.len()
+            + self.high_priority_shared_tasks.len()
+            + self.low_priority_shared_tasks.len()
+            + self.local_tasks.len()
+    }
+
+    /// Returns the number of submitted I/O operations that are not completed yet.
+    pub(crate) fn number_of_active_io_operations(&self) -> usize {
+        self.local_worker
+            .as_ref()
+            .map_or(0, IoWorker::number_of_active_tasks)
+    }
+
+    /// Returns the number of sleeping tasks.
+    pub(crate) fn number_of_sleeping_tasks(&self) -> usize {
+        self.local_sleeping_tasks.len()
+    }
+
+    /// Returns [`metrics`](ExecutorMetrics) of the executor.
+    #[inline]
+    pub fn metrics(&self) -> ExecutorMetrics {
+        self.metrics
+    }
+
+    /// Returns a [`snapshot`](ExecutorSnapshot) of the state of the executor.
+    ///
+    /// It doesn't change the state, so it can be called at any point for debugging.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::local_executor;
+    ///
+    /// # async fn foo() {
+    /// println!("{:?}", local_executor().snapshot());
+    /// # }
+    /// ```
+    pub fn snapshot(&self) -> ExecutorSnapshot {
+        let mut sleeping_task_deadlines: Vec<Instant> =
+            self.local_sleeping_tasks.deadlines().collect();
+        sleeping_task_deadlines.sort_unstable();
+
+        ExecutorSnapshot::new(
+            self.local_tasks.len() + self.tasks_waiting_for_local_queue.len(),
+            self.shared_tasks.len()
+                + self.high_priority_shared_tasks.len()
+                + self.low_priority_shared_tasks.len()
+                + self.shared_tasks_list.as_ref().map_or(0, |list| list.len()),
+            sleeping_task_deadlines,
+            self.number_of_active_io_operations(),
+            self.thread_pool.number_of_pending_jobs(),
+            self.exec_series,
+        )
+    }
+
+    /// Returns a mutable reference to [`metrics`](ExecutorMetrics) of the executor.
+    #[inline]
+    pub(crate) fn metrics_mut(&mut self) -> &mut ExecutorMetrics {
+        &mut self.metrics
     }
 
     /// Invokes the current [`Call`].
@@ -419,6 +699,23 @@ impl Executor {
         self.current_call = call;
     }
 
+    /// Yields the current `local` task with
+    /// [`YieldCurrentLocalTask`](Call::YieldCurrentLocalTask): the task is resumed
+    /// in the current round after all other ready local tasks.
+    ///
+    /// Read [`local_yield_now`](crate::yield_now::local_yield_now) for more details.
+    ///
+    /// # Safety
+    ///
+    /// * the current task must return [`Poll::Pending`](std::task::Poll::Pending)
+    ///   immediately after calling this function
+    ///
+    /// * the current task must be local
+    #[inline]
+    pub unsafe fn yield_current_local_task(&mut self) {
+        unsafe { self.invoke_call(Call::YieldCurrentLocalTask) };
+    }
+
     /// Processing current [`Call`]. It is taken out [`exec_task_now`](Executor::exec_task_now)
     /// to allow the compiler to decide whether to inline this function.
     #[inline(never)]
@@ -426,7 +723,13 @@ impl Executor {
         match mem::take(&mut self.current_call) {
             Call::None => {}
             Call::PushCurrentTaskAtTheStartOfLIFOSharedQueue => {
-                self.shared_tasks.push_front(task);
+                self.add_task_at_the_start_of_lifo_shared_queue(task);
+            }
+            Call::YieldCurrentLocalTask => {
+                debug_assert!(task.is_local(), "Try to yield `shared` task as `local`!");
+
+                self.local_tasks.push_front(task);
+                self.number_of_local_tasks_yielded_in_round += 1;
             }
             Call::PushCurrentTaskTo(task_list) => unsafe { (*task_list).push(task) },
             Call::PushCurrentTaskToAndRemoveItIfCounterIsZero(task_list, counter, order) => {
@@ -485,8 +788,14 @@ impl Executor {
     /// # Attention
     ///
     /// Execute [`tasks`](Task) only by this method or [`exec_task`](Executor::exec_task)!
+    ///
+    /// # Tracing
+    ///
+    /// With the `tracing` feature, each poll of the task is wrapped in a `task` span
+    /// with the id of the executor.
     pub fn exec_task_now(&mut self, mut task: Task) {
         self.exec_series += 1;
+        self.metrics.inc_tasks(task.is_local());
 
         let future = unsafe { &mut *task.future_ptr() };
         #[cfg(debug_assertions)]
@@ -497,9 +806,38 @@ impl Executor {
 
         let waker = create_waker(&mut task);
         let mut context = Context::from_waker(&waker);
-        let poll_res = unsafe { Pin::new_unchecked(future) }
-            .as_mut()
-            .poll(&mut context);
+        // The task can be executed inside another one (read `exec_task`),
+        // but it must not inherit the ambient deadline of the outer task.
+        let outer_ambient_deadline = self.ambient_deadline.take();
+        #[cfg(feature = "tracing")]
+        let span = tracing::trace_span!("task", id = self.id, is_local = task.is_local()).entered();
+        let poll_res = if let Some(handler) = self.config.task_panic_handler {
+            let poll_res = panic::catch_unwind(AssertUnwindSafe(|| {
+                unsafe { Pin::new_unchecked(&mut *future) }
+                    .as_mut()
+                    .poll(&mut context)
+            }));
+
+            match poll_res {
+                Ok(poll_res) => poll_res,
+                Err(payload) => {
+                    #[cfg(feature = "tracing")]
+                    span.exit();
+                    self.ambient_deadline = outer_ambient_deadline;
+                    mem::forget(waker);
+                    self.handle_task_panic(task, &*payload, handler);
+
+                    return;
+                }
+            }
+        } else {
+            unsafe { Pin::new_unchecked(future) }
+                .as_mut()
+                .poll(&mut context)
+        };
+        #[cfg(feature = "tracing")]
+        span.exit();
+        self.ambient_deadline = outer_ambient_deadline;
         #[cfg(debug_assertions)]
         unsafe {
             task.is_executing.as_ref().store(false, Ordering::SeqCst);
@@ -526,6 +864,29 @@ impl Executor {
         mem::forget(waker);
     }
 
+    /// Calls the [`TaskPanicHandler`] with the `payload` of the panic of the `task`
+    /// and drops the `task`.
+    #[cold]
+    fn handle_task_panic(
+        &mut self,
+        task: Task,
+        payload: &(dyn Any + Send),
+        handler: &TaskPanicHandler,
+    ) {
+        #[cfg(debug_assertions)]
+        unsafe {
+            task.is_executing.as_ref().store(false, Ordering::SeqCst);
+        }
+
+        self.current_call = Call::None;
+        handler(payload, task.id());
+
+        unsafe {
+            ptr::drop_in_place(task.future_ptr());
+            task.release(self);
+        };
+    }
+
     /// Executes a provided [`task`](Task) in the current [`executor`](Executor).
     ///
     /// # Attention
@@ -565,9 +926,47 @@ impl Executor {
     /// Creates a `shared` [`task`](Task) from a provided [`future`](Future)
     /// and executes it in the current [`executor`](Executor).
     ///
+    /// # The difference between `exec_shared_future` and [`spawn_shared`](Executor::spawn_shared)
+    ///
+    /// `exec_shared_future` polls the future immediately, before it returns, so the future
+    /// runs until its first suspension point. After that the task is woken and shared
+    /// like any other `shared` task. [`spawn_shared`](Executor::spawn_shared) only enqueues
+    /// the task, and it is polled when the executor reaches it in the queue
+    /// (or by another executor if work sharing is enabled).
+    ///
+    /// Use `exec_shared_future` when the caller relies on the side effects
+    /// of the beginning of the future (for example, it registers itself somewhere)
+    /// or to reduce the latency of the new task.
+    ///
+    /// # Calling from a running task
+    ///
+    /// It is safe to call it from a running task. The current task is not suspended:
+    /// it continues after the new task is suspended or completed. If the chain of nested
+    /// executions is too deep, the new task is enqueued instead of being executed immediately
+    /// (read [`exec_task`](Executor::exec_task)).
+    ///
     /// # Attention
     ///
     /// Execute [`Future`] only by this method!
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::local_executor;
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// # async fn foo() {
+    /// let is_started = Arc::new(AtomicBool::new(false));
+    /// let is_started_clone = is_started.clone();
+    ///
+    /// local_executor().exec_shared_future(async move {
+    ///     is_started_clone.store(true, Ordering::Relaxed);
+    /// });
+    ///
+    /// assert!(is_started.load(Ordering::Relaxed));
+    /// # }
+    /// ```
     #[inline]
     pub fn exec_shared_future<F>(&mut self, future: F)
     where
@@ -611,6 +1010,136 @@ impl Executor {
         self.local_tasks.push_back(task);
     }
 
+    /// Creates a fire-and-forget `local` [`task`](Task) from a provided `'static`
+    /// [`future`](Future) and enqueues it.
+    ///
+    /// It works like [`spawn_local`](Executor::spawn_local), but the `'static` bound makes
+    /// the intent explicit: the task is not awaited by anyone and can outlive its caller.
+    ///
+    /// The executor counts running detached tasks. If the
+    /// [`detach guard`](Config::enable_detach_guard) is enabled, the executor panics
+    /// (with `debug_assertions`) if it stops while some detached tasks are still running.
+    ///
+    /// # The difference between shared and local tasks
+    ///
+    /// Read it in [`Executor`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::local_executor;
+    ///
+    /// # async fn foo() {
+    /// local_executor().spawn_local_detached(async {
+    ///     println!("Hello from a detached task!");
+    /// });
+    /// # }
+    /// ```
+    #[inline]
+    pub fn spawn_local_detached<F>(&mut self, future: F)
+    where
+        F: Future<Output = ()> + 'static,
+    {
+        self.number_of_detached_tasks += 1;
+        self.spawn_local(async move {
+            future.await;
+            local_executor().number_of_detached_tasks -= 1;
+        });
+    }
+
+    /// Returns the number of running tasks spawned by
+    /// [`spawn_local_detached`](Executor::spawn_local_detached).
+    pub const fn number_of_detached_tasks(&self) -> usize {
+        self.number_of_detached_tasks
+    }
+
+    /// Creates a `local` [`task`](Task) from a provided [`future`](Future) and enqueues it
+    /// if the queue of local tasks has fewer tasks than
+    /// [`max_local_queue`](Config::set_max_local_queue).
+    ///
+    /// Otherwise, the returned future suspends the calling task until the executor drains
+    /// the queue below the limit and only then enqueues the new task. It allows a producer
+    /// to not spawn tasks faster than the executor can execute them.
+    ///
+    /// # The difference between shared and local tasks
+    ///
+    /// Read it in [`Executor`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::local_executor;
+    ///
+    /// # async fn foo() {
+    /// for i in 0..1_000_000 {
+    ///     local_executor().spawn_local_bounded(async move {
+    ///         println!("{i}");
+    ///     }).await;
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn spawn_local_bounded<F>(&mut self, future: F) -> SpawnLocalBounded<F>
+    where
+        F: Future<Output = ()>,
+    {
+        if self.has_room_in_local_queue() {
+            self.spawn_local(future);
+
+            return SpawnLocalBounded::new(None);
+        }
+
+        SpawnLocalBounded::new(Some(future))
+    }
+
+    /// Returns whether the queue of local tasks has fewer tasks than
+    /// [`max_local_queue`](Config::set_max_local_queue).
+    #[inline]
+    pub(crate) fn has_room_in_local_queue(&self) -> bool {
+        self.local_tasks.len() < self.config.max_local_queue
+    }
+
+    /// Saves the task that waits for room in the queue of local tasks.
+    /// It is woken when the queue drains below [`max_local_queue`](Config::set_max_local_queue).
+    #[inline]
+    pub(crate) fn wait_for_room_in_local_queue(&mut self, task: Task) {
+        self.tasks_waiting_for_local_queue.push_back(task);
+    }
+
+    /// Wakes the tasks that wait for room in the queue of local tasks while it has room.
+    #[inline]
+    fn wake_tasks_waiting_for_local_queue(&mut self) {
+        while self.has_room_in_local_queue() {
+            let Some(task) = self.tasks_waiting_for_local_queue.pop_front() else {
+                break;
+            };
+
+            if task.is_local() {
+                self.spawn_local_task(task);
+            } else {
+                self.spawn_shared_task(task);
+            }
+        }
+    }
+
+    /// Calls the [`WatermarkCallback`](crate::runtime::WatermarkCallback) if the number
+    /// of local tasks has crossed the watermarks.
+    #[inline]
+    fn check_queue_watermark(&mut self) {
+        let Some(watermark) = self.config.queue_watermark else {
+            return;
+        };
+
+        let number_of_local_tasks = self.local_tasks.len();
+        if !self.is_above_queue_watermark && number_of_local_tasks >= watermark.high {
+            self.is_above_queue_watermark = true;
+            (watermark.callback)(WatermarkEvent::High);
+        } else if self.is_above_queue_watermark && number_of_local_tasks < watermark.low {
+            self.is_above_queue_watermark = false;
+            (watermark.callback)(WatermarkEvent::Low);
+        }
+    }
+
     /// Creates a `shared` [`task`](Task) from a provided [`future`](Future) and enqueues it.
     ///
     /// # Attention
@@ -640,23 +1169,28 @@ impl Executor {
     /// Read it in [`Executor`].
     #[inline]
     pub fn spawn_shared_task(&mut self, task: Task) {
-        fn try_flush(executor: &mut Executor) {
-            if let Some(mut shared_tasks_list) = unsafe {
-                executor
-                    .shared_tasks_list
-                    .as_ref()
-                    .unwrap_unchecked()
-                    .try_lock_and_return_as_vec()
-            } {
-                let number_of_shared = (executor.shared_tasks.len() >> 1) + 1;
-                for task in executor.shared_tasks.drain(..number_of_shared) {
-                    shared_tasks_list.push(task);
-                }
+        fn flush(executor: &mut Executor) {
+            let shared_tasks_list =
+                unsafe { executor.shared_tasks_list.as_ref().unwrap_unchecked() };
+            let number_of_shared = (executor.shared_tasks.len() >> 1) + 1;
+            executor.metrics.add_work_shares_given(number_of_shared);
+            for task in executor.shared_tasks.drain(..number_of_shared) {
+                shared_tasks_list.push(task);
             }
+
+            executor.update_queue_imbalance();
         }
 
         debug_assert!(!task.is_local(), "Try to spawn `local` task as `shared`!");
 
+        // Prioritized tasks are never shared.
+        let priority = task.priority();
+        if priority != TaskPriority::Normal {
+            self.shared_tasks_with_priority(priority).push_front(task);
+
+            return;
+        }
+
         #[allow(clippy::branches_sharing_code, reason = "It is more readable")]
         if self.config.is_work_sharing_enabled() {
             if self.shared_tasks.len() <= self.config.work_sharing_level {
@@ -664,7 +1198,7 @@ impl Executor {
                 self.shared_tasks.push_back(task);
             } else {
                 // Slow path
-                try_flush(self);
+                flush(self);
 
                 self.shared_tasks.push_back(task);
             }
@@ -673,6 +1207,40 @@ impl Executor {
         }
     }
 
+    /// Creates a `shared` [`task`](Task) from a provided [`future`](Future) and enqueues it
+    /// with the provided [`priority`](TaskPriority).
+    ///
+    /// [`TaskPriority::Normal`] is the same as [`spawn_shared`](Executor::spawn_shared).
+    /// Tasks with other priorities are never shared with other executors.
+    ///
+    /// The priority is kept by the task, so when the task yields or is woken up after waiting,
+    /// it is enqueued with the same priority.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::local_executor;
+    /// use orengine::runtime::TaskPriority;
+    ///
+    /// # async fn foo() {
+    /// local_executor().spawn_shared_with_priority(TaskPriority::Low, async {
+    ///     // compaction
+    /// });
+    /// local_executor().spawn_shared_with_priority(TaskPriority::High, async {
+    ///     // health check, it is executed before the compaction
+    /// });
+    /// # }
+    /// ```
+    #[inline]
+    pub fn spawn_shared_with_priority<F>(&mut self, priority: TaskPriority, future: F)
+    where
+        F: Future<Output = ()> + Send,
+    {
+        let mut task = unsafe { Task::from_future(future, Locality::shared()) };
+        task.data.set_priority(priority);
+        self.spawn_shared_task(task);
+    }
+
     /// Calls [`spawn_local_task`](Executor::spawn_local_task)
     /// or [`spawn_shared_task`](Executor::spawn_shared_task) depending on
     /// the [`locality`](Locality) of the provided [`task`](Task).
@@ -869,20 +1437,12 @@ impl Executor {
             return;
         }
 
-        if let Some(shared_task_list) = self.shared_tasks_list.as_mut() {
-            if let Some(mut shared_task_list) = shared_task_list.try_lock_and_return_as_vec() {
-                if !shared_task_list.is_empty() {
-                    let limit = self.config.work_sharing_level - self.shared_tasks.len(); // Always bigger than 0, because of previous checks
-                    for _ in 0..limit {
-                        if let Some(task) = shared_task_list.pop() {
-                            self.shared_tasks.push_back(task);
-                        }
-                    }
-
-                    shrink!(shared_task_list);
+        if let Some(shared_task_list) = self.shared_tasks_list.as_ref() {
+            if !shared_task_list.is_empty() {
+                let limit = self.config.work_sharing_level - self.shared_tasks.len(); // Always bigger than 0, because of previous checks
+                shared_task_list.take_batch(&mut self.shared_tasks, limit);
 
-                    return;
-                }
+                return;
             }
 
             unsafe {
@@ -900,11 +1460,52 @@ impl Executor {
                             return;
                         }
 
-                        list.take_batch(&mut self.shared_tasks, limit);
+                        let taken = list.take_batch(&mut self.shared_tasks, limit);
+                        self.metrics.inc_steal_attempts(taken > 0);
                     }
                 });
             }
+
+            self.update_queue_imbalance();
+        }
+    }
+
+    /// Recalculates the [`queue imbalance`](ExecutorMetrics::queue_imbalance)
+    /// and logs a warning if it has exceeded the
+    /// [`threshold`](Config::set_queue_imbalance_threshold).
+    ///
+    /// It must be called only with enabled work sharing.
+    fn update_queue_imbalance(&mut self) {
+        let own_list = unsafe { self.shared_tasks_list.as_ref().unwrap_unchecked() };
+        let number_of_own_tasks = self.shared_tasks.len() + own_list.len();
+        let mut queue_imbalance = 1.0;
+
+        unsafe {
+            self.subscribed_state.with_tasks_lists(|lists| {
+                let number_of_tasks =
+                    number_of_own_tasks + lists.iter().map(|list| list.len()).sum::<usize>();
+                if number_of_tasks == 0 {
+                    return;
+                }
+
+                #[allow(
+                    clippy::cast_precision_loss,
+                    reason = "The imbalance is approximate anyway"
+                )]
+                {
+                    let average = number_of_tasks as f32 / (lists.len() + 1) as f32;
+                    queue_imbalance = number_of_own_tasks as f32 / average;
+                }
+            });
+        }
+
+        if let Some(threshold) = self.config.queue_imbalance_threshold {
+            if queue_imbalance > threshold && self.metrics.queue_imbalance() <= threshold {
+                warn_queue_imbalance(self.id, queue_imbalance, threshold);
+            }
         }
+
+        self.metrics.set_queue_imbalance(queue_imbalance);
     }
 
     /// Allows the OS to run other threads.
@@ -924,30 +1525,45 @@ impl Executor {
     /// if there are no sleeping tasks.
     #[inline]
     fn check_sleeping_tasks(&mut self) -> Option<Duration> {
-        if !self.local_sleeping_tasks.is_empty() {
-            self.start_round_time = Instant::now();
+        if self.local_sleeping_tasks.is_empty() {
+            return None;
+        }
 
-            while let Some((time_to_wake, task)) = self.local_sleeping_tasks.pop_first() {
-                if time_to_wake <= self.start_round_time {
-                    if task.is_local() {
-                        self.exec_task(task);
-                    } else {
-                        self.spawn_shared_task(task);
-                    }
-                } else {
-                    self.local_sleeping_tasks.insert(time_to_wake, task);
-                    return Some(self.start_round_time - time_to_wake);
-                }
+        self.start_round_time = self.config.clock.now();
+
+        let mut expired = mem::take(&mut self.expired_sleeping_tasks);
+        self.local_sleeping_tasks
+            .expire(self.start_round_time, &mut expired);
+        #[allow(
+            clippy::iter_with_drain,
+            reason = "It keeps the capacity of the buffer"
+        )]
+        for task in expired.drain(..) {
+            if task.is_local() {
+                self.exec_task(task);
+            } else {
+                self.spawn_shared_task(task);
             }
         }
+        self.expired_sleeping_tasks = expired;
 
-        None
+        self.local_sleeping_tasks
+            .next_expiration()
+            .map(|next_expiration| next_expiration.saturating_duration_since(self.start_round_time))
+    }
+
+    /// Returns the current time if the [`ProfilingHook`](crate::runtime::ProfilingHook)
+    /// is set, otherwise returns `None` to avoid reading the clock.
+    #[inline]
+    fn profiling_now(&self) -> Option<Instant> {
+        self.config.profiling_hook.map(|_| self.config.clock.now())
     }
 
     /// Prepares the executor for the next round.
     fn prepare_to_new_round(&mut self) {
         self.exec_series = 0;
-        self.start_round_time = Instant::now();
+        self.metrics.inc_rounds();
+        self.start_round_time = self.config.clock.now();
         #[cfg(target_os = "linux")]
         {
             self.start_round_time_for_deadlines =
@@ -967,8 +1583,10 @@ impl Executor {
 
         let mut task;
 
-        let number_of_local_tasks_in_this_round = self.local_tasks.len();
-        for _ in 0..number_of_local_tasks_in_this_round {
+        // Tasks yielded outside the round are already counted in the length of the queue.
+        self.number_of_local_tasks_yielded_in_round = 0;
+        let mut number_of_local_tasks_in_this_round = self.local_tasks.len();
+        while number_of_local_tasks_in_this_round > 0 {
             assert_hint(
                 !self.local_tasks.is_empty(),
                 "number_of_local_tasks_in_this_round is invalid",
@@ -976,6 +1594,21 @@ impl Executor {
 
             task = unsafe { self.local_tasks.pop_back().unwrap_unchecked() };
             self.exec_task(task);
+
+            // Tasks yielded with Call::YieldCurrentLocalTask are resumed in this round.
+            number_of_local_tasks_in_this_round -= 1;
+            number_of_local_tasks_in_this_round +=
+                mem::take(&mut self.number_of_local_tasks_yielded_in_round);
+        }
+
+        let number_of_high_priority_tasks_in_this_round = self.high_priority_shared_tasks.len();
+        for _ in 0..number_of_high_priority_tasks_in_this_round {
+            task = unsafe {
+                self.high_priority_shared_tasks
+                    .pop_back()
+                    .unwrap_unchecked()
+            };
+            self.exec_task(task);
         }
 
         let number_of_shared_tasks_in_this_round = self.shared_tasks.len();
@@ -987,6 +1620,20 @@ impl Executor {
                 break;
             }
         }
+
+        // Low priority tasks are throttled only if there is other shared work.
+        let number_of_low_priority_tasks_in_this_round =
+            if number_of_high_priority_tasks_in_this_round + number_of_shared_tasks_in_this_round
+                == 0
+            {
+                self.low_priority_shared_tasks.len()
+            } else {
+                self.low_priority_shared_tasks.len().div_ceil(4)
+            };
+        for _ in 0..number_of_low_priority_tasks_in_this_round {
+            task = unsafe { self.low_priority_shared_tasks.pop_back().unwrap_unchecked() };
+            self.exec_task(task);
+        }
     }
 
     /// Stop the executor with all necessary actions.
@@ -996,34 +1643,31 @@ impl Executor {
     /// Called after [`check_version_and_update_if_needed`](SubscribedState::check_version_and_update_if_needed).
     #[inline(never)]
     unsafe fn graceful_stop(&mut self) {
+        debug_assert!(
+            !self.config.detach_guard || self.number_of_detached_tasks == 0,
+            "The executor with id {} has been stopped while {} detached tasks are still running.",
+            self.id,
+            self.number_of_detached_tasks
+        );
+
         uninit_local_buf_pool();
         if self.config.is_work_sharing_enabled() {
             unsafe {
                 self.subscribed_state.with_tasks_lists(|lists| {
                     if let Some(first_neighbor) = lists.first() {
-                        let mut shared_tasks_list_of_current_executor = vec![];
-                        loop {
-                            if let Some(mut tasks_list) = self
-                                .shared_tasks_list
-                                .as_ref()
-                                .unwrap()
-                                .try_lock_and_return_as_vec()
-                            {
-                                shared_tasks_list_of_current_executor.extend(tasks_list.drain(..));
-                                break;
-                            }
+                        let shared_tasks = self
+                            .high_priority_shared_tasks
+                            .drain(..)
+                            .chain(self.shared_tasks.drain(..))
+                            .chain(self.low_priority_shared_tasks.drain(..));
+                        for task in shared_tasks {
+                            first_neighbor.push(task);
                         }
 
-                        let mut tasks_list;
-                        loop {
-                            if let Some(tasks_list_) = first_neighbor.try_lock_and_return_as_vec() {
-                                tasks_list = tasks_list_;
-                                break;
-                            }
+                        let shared_tasks_list = self.shared_tasks_list.as_ref().unwrap();
+                        while let Some(task) = shared_tasks_list.pop() {
+                            first_neighbor.push(task);
                         }
-
-                        tasks_list.extend(self.shared_tasks.drain(..));
-                        tasks_list.extend(shared_tasks_list_of_current_executor);
                     }
                 });
             }
@@ -1038,11 +1682,9 @@ macro_rules! generate_run_and_block_on_function {
         let mut res = None;
         let static_future = EndLocalThreadAndWriteIntoPtr::new(&mut res, $future);
         $func($executor, static_future);
-        $executor.run();
-        res.ok_or(
-            "The process has been stopped by stop_all_executors \
-                or stop_executor not in block_on future.",
-        )
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| $executor.run()))
+            .map_err(BlockOnError::ExecutorKilledByPanic)?;
+        res.ok_or(BlockOnError::ExecutorStopped)
     }};
 }
 
@@ -1083,6 +1725,8 @@ impl Executor {
             }
 
             self.prepare_to_new_round();
+            let round_start_time = self.start_round_time;
+            let metrics_at_round_start = self.metrics;
 
             #[cfg(not(feature = "disable_send_task_to"))]
             {
@@ -1093,9 +1737,15 @@ impl Executor {
                 );
             }
             self.exec_cpu_tasks();
+            let background_work_start = self.profiling_now();
+            self.wake_tasks_waiting_for_local_queue();
+            self.check_queue_watermark();
             self.take_work_if_needed();
             self.thread_pool.poll(&mut self.local_tasks);
             let nearest_timeout_option = self.check_sleeping_tasks();
+            let background_work_duration = background_work_start
+                .map(|start| self.config.clock.now().saturating_duration_since(start));
+            let number_of_local_tasks_before_io = self.local_tasks.len();
 
             // We need to consider 8 cases from 3 variables:
             // has cpu work (self.number_of_spawned_tasks() != 0 or self.config.is_work_sharing_enabled()),
@@ -1110,7 +1760,9 @@ impl Executor {
                             // case 1: we don't have cpu work, but we have sleeping tasks and io work
                             worker.must_poll(Some(nearest_timeout.min(max_timeout)));
                         } else {
-                            // case 2: we don't have cpu work nor sleeping tasks, but we io work
+                            // case 2: we don't have cpu work nor sleeping tasks, but we io work.
+                            // It is not `None`, because stop signals and tasks from other
+                            // threads can't wake up the worker (read `IoWorker::must_poll`).
                             worker.must_poll(Some(max_timeout));
                         }
                     } else {
@@ -1118,7 +1770,7 @@ impl Executor {
                         // case 3: we have cpu work, sleeping tasks and io work
                         // case 4: we have cpu work, io work, but we don't have sleeping tasks
                         self.progressive_timeout.reset();
-                        worker.must_poll(None);
+                        worker.must_poll(Some(Duration::ZERO));
                     }
                 } else if !has_cpu_work {
                     let max_timeout = self.progressive_timeout.timeout();
@@ -1164,6 +1816,22 @@ impl Executor {
                 }
             }
 
+            if let Some(hook) = self.config.profiling_hook {
+                let metrics = self.metrics;
+                hook(&RoundProfile::new(
+                    metrics.local_tasks_total() - metrics_at_round_start.local_tasks_total(),
+                    metrics.shared_tasks_total() - metrics_at_round_start.shared_tasks_total(),
+                    self.local_tasks
+                        .len()
+                        .saturating_sub(number_of_local_tasks_before_io) as u64,
+                    background_work_duration.unwrap_or_default(),
+                    self.config
+                        .clock
+                        .now()
+                        .saturating_duration_since(round_start_time),
+                ));
+            }
+
             shrink!(self.local_tasks);
         }
 
@@ -1228,6 +1896,43 @@ impl Executor {
         self.run();
     }
 
+    /// Runs the executor with a local task and stops the executor when
+    /// the `signal` future completes.
+    ///
+    /// It replaces the manual pattern of storing the executor id
+    /// and calling [`stop_executor`](crate::stop_executor) when the shutdown is requested.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use orengine::{Executor, sleep};
+    /// use std::time::Duration;
+    ///
+    /// let mut executor = Executor::init();
+    ///
+    /// executor.run_with_shutdown_signal(
+    ///     sleep(Duration::from_secs(3)), // any future that resolves on the shutdown signal
+    ///     async {
+    ///         println!("Hello from an async runtime!");
+    ///     },
+    /// );
+    ///
+    /// println!("Hello from a sync runtime after at least 3 seconds");
+    /// ```
+    pub fn run_with_shutdown_signal<Sig, Fut>(&mut self, signal: Sig, future: Fut)
+    where
+        Sig: Future<Output = ()>,
+        Fut: Future<Output = ()>,
+    {
+        let id = self.id;
+        self.spawn_local(async move {
+            signal.await;
+            stop_executor(id);
+        });
+
+        self.run_with_local_future(future);
+    }
+
     /// Runs the executor with a local task and blocks on it. The executor will be stopped
     /// after the task completes.
     ///
@@ -1237,7 +1942,15 @@ impl Executor {
     ///
     /// # Returns
     ///
-    /// It returns `Err(&'static msg)` if undefined behavior happened or `Ok(T)` if everything is ok.
+    /// It returns `Ok(T)` if the future is completed,
+    /// [`Err(BlockOnError::ExecutorStopped)`](BlockOnError::ExecutorStopped) if the executor
+    /// has been stopped before the future is completed
+    /// or [`Err(BlockOnError::ExecutorKilledByPanic)`](BlockOnError::ExecutorKilledByPanic)
+    /// if a task has panicked.
+    ///
+    /// # Errors
+    ///
+    /// Read the [`Returns`](#returns) section.
     ///
     /// # Example
     ///
@@ -1262,7 +1975,7 @@ impl Executor {
     pub fn run_and_block_on_local<T, Fut: Future<Output = T>>(
         &'static mut self,
         future: Fut,
-    ) -> Result<T, &'static str> {
+    ) -> Result<T, BlockOnError> {
         generate_run_and_block_on_function!(Self::spawn_local, future, self)
     }
 
@@ -1275,7 +1988,15 @@ impl Executor {
     ///
     /// # Returns
     ///
-    /// It returns `Err(&'static msg)` if undefined behavior happened or `Ok(T)` if everything is ok.
+    /// It returns `Ok(T)` if the future is completed,
+    /// [`Err(BlockOnError::ExecutorStopped)`](BlockOnError::ExecutorStopped) if the executor
+    /// has been stopped before the future is completed
+    /// or [`Err(BlockOnError::ExecutorKilledByPanic)`](BlockOnError::ExecutorKilledByPanic)
+    /// if a task has panicked.
+    ///
+    /// # Errors
+    ///
+    /// Read the [`Returns`](#returns) section.
     ///
     /// # Example
     ///
@@ -1298,7 +2019,7 @@ impl Executor {
     pub fn run_and_block_on_shared<T, Fut: Future<Output = T> + Send>(
         &'static mut self,
         future: Fut,
-    ) -> Result<T, &'static str> {
+    ) -> Result<T, BlockOnError> {
         generate_run_and_block_on_function!(Self::spawn_shared, future, self)
     }
 }
@@ -1340,6 +2061,33 @@ mod tests {
         assert_eq!(&vec![10, 20, 30], &*arr.borrow()); // 20, 30 because we don't use the list here
     }
 
+    #[orengine::test::test_local]
+    fn test_spawn_shared_and_exec_shared_future() {
+        use std::sync::atomic::AtomicUsize;
+        use std::sync::Arc;
+
+        let executor = local_executor();
+        let counter = Arc::new(AtomicUsize::new(0));
+
+        let counter_clone = counter.clone();
+        executor.spawn_shared(async move {
+            counter_clone.fetch_add(1, Ordering::SeqCst);
+        });
+        assert_eq!(counter.load(Ordering::SeqCst), 0);
+
+        let counter_clone = counter.clone();
+        executor.exec_shared_future(async move {
+            counter_clone.fetch_add(10, Ordering::SeqCst);
+            yield_now().await;
+            counter_clone.fetch_add(100, Ordering::SeqCst);
+        });
+        assert_eq!(counter.load(Ordering::SeqCst), 10);
+
+        while counter.load(Ordering::SeqCst) != 111 {
+            yield_now().await;
+        }
+    }
+
     #[test]
     fn test_run_and_block_on() {
         #[allow(clippy::unused_async, reason = "It is a test.")]
@@ -1348,9 +2096,342 @@ mod tests {
         }
 
         Executor::init_with_config(Config::default().disable_work_sharing());
-        assert_eq!(Ok(42), local_executor().run_and_block_on_local(async_42()));
+        assert!(matches!(
+            local_executor().run_and_block_on_local(async_42()),
+            Ok(42)
+        ));
     }
 
+    #[test]
+    fn test_run_and_block_on_errors() {
+        Executor::init_with_config(Config::default().disable_work_sharing());
+        let res = local_executor().run_and_block_on_local(async {
+            crate::stop_executor(local_executor().id());
+            std::future::pending::<()>().await;
+        });
+        assert!(matches!(res, Err(BlockOnError::ExecutorStopped)));
+
+        thread::spawn(|| {
+            Executor::init_with_config(Config::default().disable_work_sharing());
+            let err = local_executor()
+                .run_and_block_on_local(async {
+                    yield_now().await;
+                    panic!("test panic");
+                })
+                .unwrap_err();
+            assert!(matches!(err, BlockOnError::ExecutorKilledByPanic(_)));
+            assert_eq!(err.panic_message(), Some("test panic"));
+        })
+        .join()
+        .expect("the panic is not caught");
+    }
+
+    #[test]
+    fn test_task_panic_handler() {
+        static PANICS: std::sync::Mutex<Vec<String>> = std::sync::Mutex::new(Vec::new());
+
+        Executor::init_with_config(
+            Config::default()
+                .disable_work_sharing()
+                .set_task_panic_handler(&|payload, _| {
+                    let msg = payload.downcast_ref::<&str>().copied().unwrap_or_default();
+                    PANICS.lock().unwrap().push(msg.to_string());
+                }),
+        );
+        let res = local_executor().run_and_block_on_local(async {
+            let dropped = Local::new(false);
+            let dropped_clone = dropped.clone();
+            local_executor().spawn_local(async move {
+                struct SetOnDrop(Local<bool>);
+
+                impl Drop for SetOnDrop {
+                    fn drop(&mut self) {
+                        *self.0.borrow_mut() = true;
+                    }
+                }
+
+                let _guard = SetOnDrop(dropped_clone);
+                yield_now().await;
+                panic!("test panic");
+            });
+
+            for _ in 0..3 {
+                yield_now().await;
+            }
+
+            assert!(*dropped.borrow());
+            assert_eq!(*PANICS.lock().unwrap(), vec!["test panic".to_string()]);
+        });
+        assert!(res.is_ok(), "{res:?}");
+    }
+
+    #[test]
+    fn test_queue_watermark() {
+        static EVENTS: std::sync::Mutex<Vec<WatermarkEvent>> = std::sync::Mutex::new(Vec::new());
+
+        Executor::init_with_config(
+            Config::default()
+                .disable_work_sharing()
+                .set_queue_watermark(8, 2, &|event| EVENTS.lock().unwrap().push(event)),
+        );
+        let res = local_executor().run_and_block_on_local(async {
+            for _ in 0..10 {
+                local_executor().spawn_local(async {});
+            }
+            yield_now().await;
+            assert_eq!(*EVENTS.lock().unwrap(), vec![WatermarkEvent::High]);
+
+            yield_now().await;
+            assert_eq!(
+                *EVENTS.lock().unwrap(),
+                vec![WatermarkEvent::High, WatermarkEvent::Low]
+            );
+        });
+        assert!(res.is_ok(), "{res:?}");
+    }
+
+    #[test]
+    fn test_snapshot() {
+        Executor::init_with_config(Config::default().disable_work_sharing());
+        let res = local_executor().run_and_block_on_local(async {
+            let snapshot = local_executor().snapshot();
+            assert_eq!(snapshot.local_queue_len(), 0);
+            assert!(snapshot.sleeping_task_deadlines().is_empty());
+
+            for secs in [20, 10] {
+                local_executor().spawn_local(async move {
+                    crate::sleep(Duration::from_secs(secs)).await;
+                });
+            }
+            yield_now().await;
+
+            for _ in 0..3 {
+                local_executor().spawn_local(async {});
+            }
+
+            let snapshot = local_executor().snapshot();
+            assert_eq!(snapshot.local_queue_len(), 3);
+            assert_eq!(snapshot.global_queue_len(), 0);
+            assert_eq!(snapshot.io_pending_count(), 0);
+            assert_eq!(snapshot.thread_pool_busy(), 0);
+            assert!(snapshot.exec_series() > 0);
+
+            let deadlines = snapshot.sleeping_task_deadlines();
+            assert_eq!(deadlines.len(), 2);
+            assert_eq!(deadlines[1] - deadlines[0], Duration::from_secs(10));
+            assert!(format!("{snapshot:?}").contains("local_queue_len: 3"));
+        });
+        assert!(res.is_ok(), "{res:?}");
+    }
+
+    #[test]
+    fn test_submit_batch_size() {
+        use crate::io::IoWorkerConfig;
+
+        Executor::init_with_config(
+            Config::default()
+                .disable_work_sharing()
+                .set_io_worker_config(Some(IoWorkerConfig::default().submit_batch_size(1)))
+                .unwrap(),
+        );
+        let res = local_executor().run_and_block_on_local(async {
+            for _ in 0..3 {
+                crate::sleep_precise(Duration::from_micros(100)).await;
+            }
+        });
+        assert!(res.is_ok(), "{res:?}");
+    }
+
+    #[test]
+    fn test_resize_thread_pool() {
+        Executor::init_with_config(
+            Config::default()
+                .disable_work_sharing()
+                .set_numbers_of_blocking_workers(1),
+        );
+        let res = local_executor().run_and_block_on_local(async {
+            let executed = Local::new(0);
+
+            for number_of_workers in [4, 1, 2] {
+                local_executor().resize_thread_pool(number_of_workers);
+                assert_eq!(
+                    local_executor().config().number_of_thread_workers(),
+                    number_of_workers
+                );
+
+                for _ in 0..number_of_workers * 2 {
+                    crate::asyncify!(|| {
+                        std::thread::sleep(Duration::from_millis(1));
+                    })
+                    .await;
+                    *executed.borrow_mut() += 1;
+                }
+            }
+
+            assert_eq!(*executed.borrow(), 14);
+        });
+        assert!(res.is_ok(), "{res:?}");
+    }
+
+    #[test]
+    fn test_spawn_shared_with_priority() {
+        use std::sync::Mutex;
+
+        static EXECUTED: Mutex<Vec<TaskPriority>> = Mutex::new(Vec::new());
+
+        Executor::init_with_config(Config::default().disable_work_sharing());
+        let res = local_executor().run_and_block_on_local(async {
+            for priority in [TaskPriority::Low, TaskPriority::Normal, TaskPriority::High] {
+                for _ in 0..4 {
+                    local_executor().spawn_shared_with_priority(priority, async move {
+                        EXECUTED.lock().unwrap().push(priority);
+                    });
+                }
+            }
+
+            crate::yield_now().await;
+            crate::yield_now().await;
+
+            let executed = EXECUTED.lock().unwrap().clone();
+            assert_eq!(executed.len(), 12);
+            assert!(executed[..4].iter().all(|p| *p == TaskPriority::High));
+            assert!(executed[4..8].iter().all(|p| *p == TaskPriority::Normal));
+            assert!(executed[8..].iter().all(|p| *p == TaskPriority::Low));
+        });
+        assert!(res.is_ok(), "{res:?}");
+    }
+
+    #[test]
+    fn test_priority_of_yielded_and_woken_tasks() {
+        use crate::sync::{AsyncWaitGroup, WaitGroup};
+        use std::sync::Mutex;
+
+        static EXECUTED: Mutex<Vec<(TaskPriority, usize)>> = Mutex::new(Vec::new());
+
+        async fn wait_for_executed(len: usize) {
+            while EXECUTED.lock().unwrap().len() < len {
+                crate::yield_now().await;
+            }
+        }
+
+        Executor::init_with_config(Config::default().disable_work_sharing());
+        let res = local_executor().run_and_block_on_local(async {
+            let priorities = [TaskPriority::High, TaskPriority::Normal, TaskPriority::Low];
+            let wait_groups: Vec<_> = priorities
+                .iter()
+                .map(|_| {
+                    let wg = Arc::new(WaitGroup::new());
+                    wg.inc();
+
+                    wg
+                })
+                .collect();
+
+            for (priority, wg) in priorities.iter().rev().zip(wait_groups.iter().rev()) {
+                let (priority, wg) = (*priority, wg.clone());
+                local_executor().spawn_shared_with_priority(priority, async move {
+                    EXECUTED.lock().unwrap().push((priority, 0));
+                    crate::yield_now().await;
+                    EXECUTED.lock().unwrap().push((priority, 1));
+                    wg.wait().await;
+                    EXECUTED.lock().unwrap().push((priority, 2));
+                });
+            }
+
+            wait_for_executed(6).await;
+            // Woken tasks are enqueued in the order of `done` calls, but executed by priority.
+            for wg in &wait_groups {
+                wg.done();
+            }
+            wait_for_executed(9).await;
+
+            let expected: Vec<_> = (0..3)
+                .flat_map(|step| priorities.iter().map(move |priority| (*priority, step)))
+                .collect();
+            assert_eq!(*EXECUTED.lock().unwrap(), expected);
+        });
+        assert!(res.is_ok(), "{res:?}");
+    }
+
+    #[test]
+    fn test_profiling_hook() {
+        use std::sync::atomic::AtomicU64;
+
+        static ROUNDS: AtomicU64 = AtomicU64::new(0);
+        static LOCAL_TASKS_EXECUTED: AtomicU64 = AtomicU64::new(0);
+
+        Executor::init_with_config(Config::default().disable_work_sharing().set_profiling_hook(
+            &|profile| {
+                assert!(profile.background_work_duration() <= profile.round_duration());
+                ROUNDS.fetch_add(1, Ordering::Relaxed);
+                LOCAL_TASKS_EXECUTED.fetch_add(profile.local_tasks_executed(), Ordering::Relaxed);
+            },
+        ));
+        let res = local_executor().run_and_block_on_local(async {
+            for _ in 0..5 {
+                yield_now().await;
+            }
+        });
+        assert!(res.is_ok(), "{res:?}");
+
+        assert!(ROUNDS.load(Ordering::Relaxed) >= 5);
+        assert!(LOCAL_TASKS_EXECUTED.load(Ordering::Relaxed) >= 6);
+    }
+
+    #[test]
+    fn test_spawn_local_detached() {
+        Executor::init_with_config(
+            Config::default()
+                .disable_work_sharing()
+                .enable_detach_guard(),
+        );
+        let res = local_executor().run_and_block_on_local(async {
+            let executed = Local::new(0);
+            for _ in 0..3 {
+                let executed = executed.clone();
+                local_executor().spawn_local_detached(async move {
+                    yield_now().await;
+                    *executed.borrow_mut() += 1;
+                });
+            }
+            assert_eq!(local_executor().number_of_detached_tasks(), 3);
+
+            while local_executor().number_of_detached_tasks() > 0 {
+                yield_now().await;
+            }
+            assert_eq!(*executed.borrow(), 3);
+        });
+        assert!(res.is_ok(), "{res:?}");
+    }
+
+    #[cfg(not(feature = "disable_send_task_to"))]
+    #[orengine::test::test_local]
+    fn test_run_with_shutdown_signal() {
+        use crate::sync::{CrossChannel, SendResult};
+        use std::thread;
+
+        let (signal_sender, mut signal_receiver) = CrossChannel::new().split();
+        let (started_sender, mut started_receiver) = CrossChannel::new().split();
+        let handle = thread::spawn(move || {
+            let ex = Executor::init_with_config(Config::default().disable_work_sharing());
+            ex.run_with_shutdown_signal(
+                async move {
+                    signal_receiver.recv().await;
+                },
+                async move {
+                    started_sender.send(()).unwrap();
+                },
+            );
+        });
+
+        started_receiver.recv().await.unwrap();
+        assert!(!handle.is_finished());
+
+        assert!(matches!(signal_sender.send(()), SendResult::Ok));
+        while !handle.is_finished() {
+            yield_now().await;
+        }
+    }
     // TODO put this into a separate test
     // fn wait_for_config_tests_ready() {
     //     let _unused = config::tests::WAS_READY
@@ -0,0 +1,473 @@
+use crate::io::{AsyncAccept, AsyncBind, AsyncRecv, AsyncSend};
+use crate::local_executor;
+use crate::net::TcpListener;
+use crate::runtime::Executor;
+use std::fmt::Display;
+use std::io::Write;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::time::Duration;
+
+/// `ExecutorMetrics` contains counters of the run-loop of the [`Executor`].
+///
+/// All counters are monotonic and are counted from the initialization of the [`Executor`].
+/// The only gauge is [`queue_imbalance`](Self::queue_imbalance).
+///
+/// Use [`Executor::metrics`] to get them or
+/// [`Executor::metrics_to_prometheus`] to export them.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ExecutorMetrics {
+    local_tasks_total: u64,
+    shared_tasks_total: u64,
+    io_ops_total: u64,
+    work_shares_given_total: u64,
+    steal_attempts_total: u64,
+    steal_successes_total: u64,
+    rounds_total: u64,
+    queue_imbalance: f32,
+}
+
+impl ExecutorMetrics {
+    /// Creates a new `ExecutorMetrics` with zeroed counters.
+    pub(crate) const fn new() -> Self {
+        Self {
+            local_tasks_total: 0,
+            shared_tasks_total: 0,
+            io_ops_total: 0,
+            work_shares_given_total: 0,
+            steal_attempts_total: 0,
+            steal_successes_total: 0,
+            rounds_total: 0,
+            queue_imbalance: 0.0,
+        }
+    }
+
+    /// Returns how many times `local` tasks have been executed.
+    pub const fn local_tasks_total(&self) -> u64 {
+        self.local_tasks_total
+    }
+
+    /// Returns how many times `shared` tasks have been executed.
+    pub const fn shared_tasks_total(&self) -> u64 {
+        self.shared_tasks_total
+    }
+
+    /// Returns the number of submitted I/O operations.
+    pub const fn io_ops_total(&self) -> u64 {
+        self.io_ops_total
+    }
+
+    /// Returns the number of `shared` tasks given to other executors via work sharing.
+    pub const fn work_shares_given_total(&self) -> u64 {
+        self.work_shares_given_total
+    }
+
+    /// Returns the number of attempts to take `shared` tasks from other executors.
+    pub const fn steal_attempts_total(&self) -> u64 {
+        self.steal_attempts_total
+    }
+
+    /// Returns the number of attempts to take `shared` tasks from other executors
+    /// that have taken at least one task.
+    pub const fn steal_successes_total(&self) -> u64 {
+        self.steal_successes_total
+    }
+
+    /// Returns the number of rounds of the run-loop.
+    pub const fn rounds_total(&self) -> u64 {
+        self.rounds_total
+    }
+
+    /// Returns the ratio of the number of `shared` tasks of the executor
+    /// to the average number of `shared` tasks of all executors with work sharing.
+    ///
+    /// `1.0` means that the load is balanced, values above `1.0` mean that the executor
+    /// is more loaded than others. It is updated only when the executor shares or takes work,
+    /// so it is `0.0` for executors without work sharing.
+    ///
+    /// Read [`Config::set_queue_imbalance_threshold`](crate::runtime::Config::set_queue_imbalance_threshold)
+    /// to get warnings about the imbalance.
+    pub const fn queue_imbalance(&self) -> f32 {
+        self.queue_imbalance
+    }
+
+    /// Increments the number of executed tasks.
+    #[inline]
+    pub(crate) fn inc_tasks(&mut self, is_local: bool) {
+        if is_local {
+            self.local_tasks_total += 1;
+        } else {
+            self.shared_tasks_total += 1;
+        }
+    }
+
+    /// Increments the number of submitted I/O operations.
+    #[inline]
+    pub(crate) fn inc_io_ops(&mut self) {
+        self.io_ops_total += 1;
+    }
+
+    /// Adds the number of `shared` tasks given to other executors.
+    #[inline]
+    pub(crate) fn add_work_shares_given(&mut self, number_of_tasks: usize) {
+        self.work_shares_given_total += number_of_tasks as u64;
+    }
+
+    /// Records an attempt to take `shared` tasks from another executor.
+    #[inline]
+    pub(crate) fn inc_steal_attempts(&mut self, is_successful: bool) {
+        self.steal_attempts_total += 1;
+        if is_successful {
+            self.steal_successes_total += 1;
+        }
+    }
+
+    /// Sets the [`queue imbalance`](Self::queue_imbalance).
+    #[inline]
+    pub(crate) fn set_queue_imbalance(&mut self, queue_imbalance: f32) {
+        self.queue_imbalance = queue_imbalance;
+    }
+
+    /// Increments the number of rounds of the run-loop.
+    #[inline]
+    pub(crate) fn inc_rounds(&mut self) {
+        self.rounds_total += 1;
+    }
+}
+
+/// `RoundProfile` describes a single round of the run-loop of the [`Executor`].
+///
+/// It is passed to the [`ProfilingHook`] at the end of each round.
+/// Read [`Config::set_profiling_hook`](crate::runtime::Config::set_profiling_hook)
+/// for more details.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RoundProfile {
+    local_tasks_executed: u64,
+    shared_tasks_executed: u64,
+    io_completions: u64,
+    background_work_duration: Duration,
+    round_duration: Duration,
+}
+
+impl RoundProfile {
+    /// Creates a new `RoundProfile`.
+    pub(crate) const fn new(
+        local_tasks_executed: u64,
+        shared_tasks_executed: u64,
+        io_completions: u64,
+        background_work_duration: Duration,
+        round_duration: Duration,
+    ) -> Self {
+        Self {
+            local_tasks_executed,
+            shared_tasks_executed,
+            io_completions,
+            background_work_duration,
+            round_duration,
+        }
+    }
+
+    /// Returns how many times `local` tasks have been executed in the round.
+    pub const fn local_tasks_executed(&self) -> u64 {
+        self.local_tasks_executed
+    }
+
+    /// Returns how many times `shared` tasks have been executed in the round.
+    pub const fn shared_tasks_executed(&self) -> u64 {
+        self.shared_tasks_executed
+    }
+
+    /// Returns the number of tasks woken by completed I/O operations in the round.
+    pub const fn io_completions(&self) -> u64 {
+        self.io_completions
+    }
+
+    /// Returns how long the background work of the round took.
+    ///
+    /// The background work is everything between the execution of the tasks and the polling
+    /// of the I/O worker: waking sleeping tasks, taking shared work,
+    /// polling the thread pool, etc.
+    pub const fn background_work_duration(&self) -> Duration {
+        self.background_work_duration
+    }
+
+    /// Returns how long the whole round took, including the polling of the I/O worker
+    /// and the sleeping of the [`Executor`].
+    pub const fn round_duration(&self) -> Duration {
+        self.round_duration
+    }
+}
+
+/// `ProfilingHook` is called synchronously by the [`Executor`] with a [`RoundProfile`]
+/// at the end of each round of the run-loop.
+///
+/// Read [`Config::set_profiling_hook`](crate::runtime::Config::set_profiling_hook)
+/// for more details.
+pub type ProfilingHook = dyn Fn(&RoundProfile) + Send + Sync;
+
+/// Writes a single metric in the Prometheus text format.
+fn write_metric(
+    writer: &mut impl Write,
+    name: &str,
+    metric_type: &str,
+    help: &str,
+    executor_id: usize,
+    value: impl Display,
+) -> std::io::Result<()> {
+    writeln!(writer, "# HELP {name} {help}")?;
+    writeln!(writer, "# TYPE {name} {metric_type}")?;
+    writeln!(writer, "{name}{{executor_id=\"{executor_id}\"}} {value}")
+}
+
+impl Executor {
+    /// Writes [`metrics`](ExecutorMetrics) of the executor
+    /// and the numbers of its ready and sleeping tasks in the Prometheus text format.
+    ///
+    /// All metrics are labelled with `executor_id`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::Executor;
+    ///
+    /// let ex = Executor::init();
+    /// let mut buf = Vec::new();
+    ///
+    /// ex.metrics_to_prometheus(&mut buf).unwrap();
+    /// assert!(String::from_utf8(buf).unwrap().contains("orengine_local_tasks_total"));
+    /// ```
+    pub fn metrics_to_prometheus(&self, writer: &mut impl Write) -> std::io::Result<()> {
+        let id = self.id();
+        let metrics = self.metrics();
+
+        write_metric(
+            writer,
+            "orengine_local_tasks_total",
+            "counter",
+            "Number of executions of local tasks.",
+            id,
+            metrics.local_tasks_total(),
+        )?;
+        write_metric(
+            writer,
+            "orengine_global_tasks_total",
+            "counter",
+            "Number of executions of shared tasks.",
+            id,
+            metrics.shared_tasks_total(),
+        )?;
+        write_metric(
+            writer,
+            "orengine_io_ops_total",
+            "counter",
+            "Number of submitted I/O operations.",
+            id,
+            metrics.io_ops_total(),
+        )?;
+        write_metric(
+            writer,
+            "orengine_work_shares_given_total",
+            "counter",
+            "Number of shared tasks given to other executors.",
+            id,
+            metrics.work_shares_given_total(),
+        )?;
+        write_metric(
+            writer,
+            "orengine_steal_attempts_total",
+            "counter",
+            "Number of attempts to take shared tasks from other executors.",
+            id,
+            metrics.steal_attempts_total(),
+        )?;
+        write_metric(
+            writer,
+            "orengine_steal_successes_total",
+            "counter",
+            "Number of successful attempts to take shared tasks from other executors.",
+            id,
+            metrics.steal_successes_total(),
+        )?;
+        write_metric(
+            writer,
+            "orengine_rounds_total",
+            "counter",
+            "Number of rounds of the run-loop.",
+            id,
+            metrics.rounds_total(),
+        )?;
+        write_metric(
+            writer,
+            "orengine_queue_imbalance",
+            "gauge",
+            "Ratio of the number of shared tasks to the average across executors.",
+            id,
+            metrics.queue_imbalance(),
+        )?;
+        write_metric(
+            writer,
+            "orengine_sleeping_tasks",
+            "gauge",
+            "Number of sleeping tasks.",
+            id,
+            self.number_of_sleeping_tasks() as u64,
+        )?;
+        write_metric(
+            writer,
+            "orengine_ready_tasks",
+            "gauge",
+            "Number of tasks ready to be executed.",
+            id,
+            self.number_of_spawned_tasks() as u64,
+        )
+    }
+}
+
+/// Response on a request with a path other than `/metrics`.
+const NOT_FOUND_RESPONSE: &[u8] =
+    b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+
+/// Binds a minimal HTTP server on `0.0.0.0:port` that serves
+/// [`metrics`](Executor::metrics_to_prometheus) of the
+/// [`local executor`](local_executor) on `GET /metrics`.
+///
+/// The server handles connections one by one and closes each connection after the response,
+/// so it is suitable only for scraping.
+/// It never returns unless an error occurs while binding or accepting.
+///
+/// # Example
+///
+/// ```no_run
+/// use orengine::runtime::metrics_http_server;
+/// use orengine::Executor;
+///
+/// Executor::init().run_with_local_future(async {
+///     metrics_http_server(9100).await.unwrap();
+/// });
+/// ```
+pub async fn metrics_http_server(port: u16) -> std::io::Result<()> {
+    let mut listener = TcpListener::bind(SocketAddr::from((Ipv4Addr::UNSPECIFIED, port))).await?;
+    let mut request = vec![0u8; 1024];
+
+    loop {
+        let (mut stream, _) = listener.accept().await?;
+        let Ok(n) = stream.recv_bytes(&mut request).await else {
+            continue;
+        };
+
+        let response = if request[..n].starts_with(b"GET /metrics") {
+            let mut body = Vec::new();
+            local_executor().metrics_to_prometheus(&mut body)?;
+
+            let mut response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\n\
+                 Content-Length: {}\r\nConnection: close\r\n\r\n",
+                body.len()
+            )
+            .into_bytes();
+            response.extend_from_slice(&body);
+
+            response
+        } else {
+            NOT_FOUND_RESPONSE.to_vec()
+        };
+
+        // The client may have disconnected, it is not a reason to stop the server.
+        let _ = stream.send_all_bytes(&response).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as orengine;
+    use crate::io::{AsyncConnectStream, AsyncRecv, AsyncSend};
+    use crate::net::TcpStream;
+    use crate::runtime::{run_on_new_thread, Config};
+    use crate::{local_executor, yield_now};
+
+    #[orengine::test::test_local]
+    fn test_metrics() {
+        let before = local_executor().metrics();
+
+        local_executor().spawn_local(async {});
+        yield_now().await;
+
+        let after = local_executor().metrics();
+        assert!(after.local_tasks_total() > before.local_tasks_total());
+        assert!(after.rounds_total() > before.rounds_total());
+
+        let mut buf = Vec::new();
+        local_executor()
+            .metrics_to_prometheus(&mut buf)
+            .expect("metrics_to_prometheus failed");
+        let text = String::from_utf8(buf).expect("invalid utf8");
+        let id = local_executor().id();
+
+        assert!(text.contains("# TYPE orengine_local_tasks_total counter"));
+        assert!(text.contains(&format!(
+            "orengine_local_tasks_total{{executor_id=\"{id}\"}} {}",
+            local_executor().metrics().local_tasks_total()
+        )));
+        assert!(text.contains("# TYPE orengine_sleeping_tasks gauge"));
+    }
+
+    #[test]
+    fn test_work_sharing_metrics() {
+        let handle = run_on_new_thread(Config::default(), async {
+            let before = local_executor().metrics();
+
+            for _ in 0..64 {
+                local_executor().spawn_shared(async {});
+            }
+
+            let after = local_executor().metrics();
+            assert!(after.work_shares_given_total() > before.work_shares_given_total());
+            assert!(after.queue_imbalance() > 0.0);
+            assert!(after.steal_successes_total() <= after.steal_attempts_total());
+
+            yield_now().await;
+
+            let mut buf = Vec::new();
+            local_executor()
+                .metrics_to_prometheus(&mut buf)
+                .expect("metrics_to_prometheus failed");
+
+            String::from_utf8(buf).expect("invalid utf8")
+        });
+        let text = handle.join().expect("the executor panicked");
+
+        assert!(text.contains("# TYPE orengine_steal_attempts_total counter"));
+        assert!(text.contains("# TYPE orengine_queue_imbalance gauge"));
+    }
+
+    #[orengine::test::test_local]
+    fn test_metrics_http_server() {
+        const PORT: u16 = 6097;
+
+        local_executor().spawn_local(async {
+            let _ = super::metrics_http_server(PORT).await;
+        });
+        yield_now().await;
+
+        let mut stream = TcpStream::connect(("127.0.0.1", PORT))
+            .await
+            .expect("connect failed");
+        stream
+            .send_all_bytes(b"GET /metrics HTTP/1.1\r\n\r\n")
+            .await
+            .expect("send failed");
+
+        let mut response = Vec::new();
+        let mut buf = vec![0u8; 4096];
+        loop {
+            let n = stream.recv_bytes(&mut buf).await.expect("recv failed");
+            if n == 0 {
+                break;
+            }
+            response.extend_from_slice(&buf[..n]);
+        }
+
+        let response = String::from_utf8(response).expect("invalid utf8");
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains("orengine_io_ops_total"));
+    }
+}
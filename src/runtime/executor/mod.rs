@@ -1,8 +1,18 @@
+pub mod block_on_error;
 pub mod config;
 mod end_local_thread_and_write_into_ptr;
 pub mod executor;
 pub(crate) mod executors_on_cores_table;
+pub mod metrics;
+pub mod snapshot;
+pub mod spawn_local_bounded;
+pub mod task_priority;
 
+pub use block_on_error::BlockOnError;
 pub use config::*;
 pub use executor::*;
 pub(crate) use executors_on_cores_table::get_core_id_for_executor;
+pub use metrics::{metrics_http_server, ExecutorMetrics, ProfilingHook, RoundProfile};
+pub use snapshot::ExecutorSnapshot;
+pub use spawn_local_bounded::SpawnLocalBounded;
+pub use task_priority::TaskPriority;
@@ -0,0 +1,70 @@
+//! This module contains [`ExecutorSnapshot`].
+use std::time::Instant;
+
+/// `ExecutorSnapshot` is the state of the [`Executor`](crate::Executor) at some point.
+///
+/// It is returned by [`Executor::snapshot`](crate::Executor::snapshot) and is supposed
+/// to be printed with [`Debug`] for debugging, for example, when the application hangs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExecutorSnapshot {
+    local_queue_len: usize,
+    global_queue_len: usize,
+    sleeping_task_deadlines: Vec<Instant>,
+    io_pending_count: usize,
+    thread_pool_busy: usize,
+    exec_series: usize,
+}
+
+impl ExecutorSnapshot {
+    /// Creates a new `ExecutorSnapshot`.
+    pub(crate) const fn new(
+        local_queue_len: usize,
+        global_queue_len: usize,
+        sleeping_task_deadlines: Vec<Instant>,
+        io_pending_count: usize,
+        thread_pool_busy: usize,
+        exec_series: usize,
+    ) -> Self {
+        Self {
+            local_queue_len,
+            global_queue_len,
+            sleeping_task_deadlines,
+            io_pending_count,
+            thread_pool_busy,
+            exec_series,
+        }
+    }
+
+    /// Returns the number of `local` tasks that are ready to be executed,
+    /// including the tasks waiting for room in the bounded local queue.
+    pub const fn local_queue_len(&self) -> usize {
+        self.local_queue_len
+    }
+
+    /// Returns the number of `shared` tasks that are ready to be executed,
+    /// including the tasks in the list for work sharing.
+    pub const fn global_queue_len(&self) -> usize {
+        self.global_queue_len
+    }
+
+    /// Returns the sorted deadlines of the sleeping tasks.
+    pub fn sleeping_task_deadlines(&self) -> &[Instant] {
+        &self.sleeping_task_deadlines
+    }
+
+    /// Returns the number of submitted I/O operations that are not completed yet.
+    pub const fn io_pending_count(&self) -> usize {
+        self.io_pending_count
+    }
+
+    /// Returns the number of jobs of [`asyncify`](crate::asyncify) that are not completed yet.
+    pub const fn thread_pool_busy(&self) -> usize {
+        self.thread_pool_busy
+    }
+
+    /// Returns the number of tasks executed in a row without returning
+    /// to the run-loop of the [`Executor`](crate::Executor).
+    pub const fn exec_series(&self) -> usize {
+        self.exec_series
+    }
+}
@@ -0,0 +1,89 @@
+//! This module contains [`SpawnLocalBounded`].
+use crate::get_task_from_context;
+use crate::runtime::local_executor;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// `SpawnLocalBounded` is a [`Future`] that spawns a `local` task when the queue
+/// of `local` tasks of the current [`Executor`](crate::Executor) has room for it.
+///
+/// It is returned by [`Executor::spawn_local_bounded`](crate::Executor::spawn_local_bounded).
+pub struct SpawnLocalBounded<F: Future<Output = ()>> {
+    future: Option<F>,
+}
+
+impl<F: Future<Output = ()>> SpawnLocalBounded<F> {
+    /// Creates a new `SpawnLocalBounded` that spawns the `future` when it is polled.
+    ///
+    /// If `future` is `None`, it returns [`Poll::Ready`] immediately.
+    pub(crate) const fn new(future: Option<F>) -> Self {
+        Self { future }
+    }
+}
+
+impl<F: Future<Output = ()>> Future for SpawnLocalBounded<F> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // The future is never pinned, so it can be moved out.
+        let this = unsafe { self.get_unchecked_mut() };
+        let Some(future) = this.future.take() else {
+            return Poll::Ready(());
+        };
+
+        let executor = local_executor();
+        if executor.has_room_in_local_queue() {
+            executor.spawn_local(future);
+
+            return Poll::Ready(());
+        }
+
+        this.future = Some(future);
+        executor.wait_for_room_in_local_queue(unsafe { get_task_from_context!(cx) });
+
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::runtime::Config;
+    use crate::{local_executor, yield_now, Executor, Local};
+    use std::sync::mpsc;
+    use std::thread;
+
+    #[test]
+    fn test_spawn_local_bounded() {
+        const N: usize = 100;
+        const LIMIT: usize = 4;
+
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let ex = Executor::init_with_config(
+                Config::default()
+                    .disable_work_sharing()
+                    .set_max_local_queue(LIMIT),
+            );
+            let res = ex.run_and_block_on_local(async {
+                let executed = Local::new(0);
+                for _ in 0..N {
+                    let executed = executed.clone();
+                    local_executor()
+                        .spawn_local_bounded(async move {
+                            *executed.borrow_mut() += 1;
+                        })
+                        .await;
+                    assert!(local_executor().local_queue().len() <= LIMIT);
+                }
+
+                while *executed.borrow() < N {
+                    yield_now().await;
+                }
+            });
+            sender.send(res.is_ok()).unwrap();
+        });
+
+        assert!(receiver.recv().unwrap());
+    }
+}
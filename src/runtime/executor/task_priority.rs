@@ -0,0 +1,26 @@
+/// `TaskPriority` is the priority of a `shared` task spawned with
+/// [`Executor::spawn_shared_with_priority`](crate::Executor::spawn_shared_with_priority).
+///
+/// In each round the [`Executor`](crate::Executor) executes `shared` tasks in the following
+/// order:
+///
+/// 1. all [`High`](TaskPriority::High) tasks;
+///
+/// 2. all [`Normal`](TaskPriority::Normal) tasks;
+///
+/// 3. a quarter of [`Low`](TaskPriority::Low) tasks (at least one), or all of them if there
+///    were no other `shared` tasks in this round.
+///
+/// So, latency-sensitive tasks (accepting connections, health checks) run ahead of
+/// bulk work (data ingestion, compaction), and bulk work is never starved.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TaskPriority {
+    /// Executed before all other `shared` tasks.
+    High,
+    /// The priority of tasks spawned with [`spawn_shared`](crate::Executor::spawn_shared).
+    #[default]
+    Normal,
+    /// Executed after all other `shared` tasks. Only a part of them is executed in each round
+    /// if there are other `shared` tasks.
+    Low,
+}
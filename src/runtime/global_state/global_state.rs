@@ -2,14 +2,20 @@ use crate::runtime::global_state::subscribed_state::SubscribedState;
 #[cfg(not(feature = "disable_send_task_to"))]
 use crate::runtime::interaction_between_executors::SyncBatchOptimizedTaskQueue;
 use crate::runtime::ExecutorSharedTaskList;
-use crate::utils::vec_map::VecMap;
+#[cfg(not(feature = "disable_send_task_to"))]
+use crate::runtime::{Locality, Task};
 use crate::utils::{SpinLock, SpinLockGuard};
 use crate::{local_executor, Executor};
+use std::collections::BTreeMap;
+use std::fmt;
+#[cfg(not(feature = "disable_send_task_to"))]
+use std::future::Future;
 use std::sync::Arc;
 
 /// Contains [`SubscribedState`] and, optionally (`cfg(not(feature = "disable_send_task_to"))`),
 /// [`SyncBatchOptimizedTaskQueue`].
 pub(crate) struct StateOfAliveExecutor {
+    pub(crate) name: Option<&'static str>,
     pub(crate) subscribed_state: Arc<SubscribedState>,
     #[cfg(not(feature = "disable_send_task_to"))]
     pub(crate) task_queue: Arc<SyncBatchOptimizedTaskQueue>,
@@ -19,6 +25,7 @@ impl StateOfAliveExecutor {
     /// Creates a new `StateOfAliveExecutor` of the provided [`Executor`](crate::Executor).
     fn new(executor: &Executor) -> Self {
         Self {
+            name: executor.name(),
             subscribed_state: executor.subscribed_state(),
             #[cfg(not(feature = "disable_send_task_to"))]
             task_queue: executor.interactor().shared_task_list(),
@@ -35,7 +42,7 @@ impl StateOfAliveExecutor {
 /// [`GLOBAL_STATE`] and [`SubscribedState`] form `Shared RWLock`.
 pub struct GlobalState {
     version: usize,
-    states_of_alive_executors: VecMap<StateOfAliveExecutor>,
+    states_of_alive_executors: BTreeMap<usize, StateOfAliveExecutor>,
     lists: Vec<Arc<ExecutorSharedTaskList>>,
 }
 
@@ -44,7 +51,7 @@ impl GlobalState {
     const fn new() -> Self {
         Self {
             version: 0,
-            states_of_alive_executors: VecMap::new(),
+            states_of_alive_executors: BTreeMap::new(),
             lists: Vec::new(),
         }
     }
@@ -74,7 +81,7 @@ impl GlobalState {
         // It is necessary to reset the flag to false on re-initialization.
         executor.subscribed_state().set_is_stopped(false);
 
-        for (_, state) in self.states_of_alive_executors.iter() {
+        for state in self.states_of_alive_executors.values() {
             state.subscribed_state.set_version(self.version);
         }
     }
@@ -86,7 +93,7 @@ impl GlobalState {
             .retain(|alive_executor_id, state| {
                 state.subscribed_state.set_version(self.version);
 
-                alive_executor_id != id
+                *alive_executor_id != id
             });
         self.lists.retain(|list| list.executor_id() != id);
     }
@@ -103,7 +110,7 @@ impl GlobalState {
     }
 
     /// Returns a shared reference to all alive executors.
-    pub(crate) fn alive_executors(&self) -> &VecMap<StateOfAliveExecutor> {
+    pub(crate) fn alive_executors(&self) -> &BTreeMap<usize, StateOfAliveExecutor> {
         &self.states_of_alive_executors
     }
 
@@ -114,10 +121,29 @@ impl GlobalState {
 
     /// Returns ids of all alive executors.
     pub fn executors_ids(&self) -> Vec<usize> {
+        self.states_of_alive_executors.keys().copied().collect()
+    }
+
+    /// Sets the name of the alive executor with the given id.
+    pub(crate) fn set_executor_name(&mut self, id: usize, name: &'static str) {
+        if let Some(state) = self.states_of_alive_executors.get_mut(&id) {
+            state.name = Some(name);
+        }
+    }
+
+    /// Returns the id of the alive executor with the given name.
+    ///
+    /// If several alive executors have this name, it returns the lowest id of them.
+    pub fn executor_id_by_name(&self, name: &str) -> Option<usize> {
         self.states_of_alive_executors
             .iter()
-            .map(|(id, _)| id)
-            .collect()
+            .find(|(_, state)| state.name == Some(name))
+            .map(|(id, _)| *id)
+    }
+
+    /// Returns the number of alive executors.
+    pub fn number_of_executors(&self) -> usize {
+        self.states_of_alive_executors.len()
     }
 
     /// Returns ids of executors with `work_sharing` enabled.
@@ -125,7 +151,7 @@ impl GlobalState {
         self.states_of_alive_executors
             .iter()
             .filter(|(_, state)| state.subscribed_state.tasks_lists().is_some())
-            .map(|(id, _)| id)
+            .map(|(id, _)| *id)
             .collect()
     }
 }
@@ -176,6 +202,33 @@ pub fn lock_and_get_global_state() -> SpinLockGuard<'static, GlobalState> {
     GLOBAL_STATE.lock()
 }
 
+/// Returns ids of all alive executors.
+///
+/// It allows reaching every executor, for example, with [`spawn_on_executor`].
+///
+/// # Example
+///
+#[cfg_attr(not(feature = "disable_send_task_to"), doc = "```no_run")]
+#[cfg_attr(feature = "disable_send_task_to", doc = "```ignore")]
+/// use orengine::runtime::{all_executor_ids, spawn_on_executor};
+///
+/// fn broadcast_config_update(new_value: usize) {
+///     for id in all_executor_ids() {
+///         let _ = spawn_on_executor(id, async move {
+///             // update the local cache with `new_value`
+///         });
+///     }
+/// }
+/// ```
+pub fn all_executor_ids() -> Vec<usize> {
+    lock_and_get_global_state().executors_ids()
+}
+
+/// Returns the number of alive executors.
+pub fn executor_count() -> usize {
+    lock_and_get_global_state().number_of_executors()
+}
+
 /// Registers the executor of the current thread (by calling [`local_executor()`](local_executor))
 /// and notifies all executors.
 pub(crate) fn register_local_executor() {
@@ -240,6 +293,117 @@ pub fn stop_all_executors() {
     lock_and_get_global_state().stop_all_executors();
 }
 
+/// Stops the executor with the given [`name`](Executor::set_name).
+///
+/// Names are not unique. If several alive executors have this name, it stops
+/// the one with the lowest [`id`](Executor::id) (the one initialized first).
+/// Call it again to stop the next one.
+///
+/// # Errors
+///
+/// Returns [`ExecutorNotFound`] if there is no alive executor with the given name.
+///
+/// # Do not use it in tests!
+///
+/// Reuse [`Executor`]. Read about it in [`test module`](crate::test).
+pub fn stop_executor_by_name(name: &str) -> Result<(), ExecutorNotFound> {
+    let mut global_state = lock_and_get_global_state();
+    let id = global_state
+        .executor_id_by_name(name)
+        .ok_or(ExecutorNotFound)?;
+    global_state.stop_executor(id);
+
+    Ok(())
+}
+
+/// `ExecutorNotFound` is returned by [`stop_executor_by_name`]
+/// if there is no alive executor with the given name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutorNotFound;
+
+impl fmt::Display for ExecutorNotFound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Executor with the given name is not found.")
+    }
+}
+
+impl std::error::Error for ExecutorNotFound {}
+
+/// `SpawnError` is returned by [`spawn_on_executor`] if the task can't be spawned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpawnError {
+    /// The executor with the given id is not registered or has been stopped.
+    ExecutorNotFound,
+}
+
+impl fmt::Display for SpawnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ExecutorNotFound => write!(f, "Executor with the given id is not found."),
+        }
+    }
+}
+
+impl std::error::Error for SpawnError {}
+
+/// Spawns a `local` task with the provided future on the executor with the given id.
+///
+/// The task is executed only by this executor, because `local` tasks can't be shared.
+/// It allows routing tasks to the specific core (for example, to the core that owns
+/// some resource).
+///
+/// Unlike [`Executor::send_local_future_to_executor`], it doesn't accumulate the task in
+/// the batch of the current executor, so the task is available for the target executor
+/// immediately after the call.
+///
+/// # Errors
+///
+/// Returns [`SpawnError::ExecutorNotFound`] if the executor with the given id
+/// is not registered or has been stopped.
+///
+/// If `Ok` is returned, the task is appended to the alive executor, but it is not executed
+/// if the executor is stopped before it takes the task (as any other task of the stopped executor).
+///
+/// # Panics
+///
+/// If it is called not in an [`Executor`] thread.
+///
+/// # Example
+///
+/// ```no_run
+/// use orengine::runtime::spawn_on_executor;
+///
+/// # fn foo(gpu_executor_id: usize) {
+/// spawn_on_executor(gpu_executor_id, async {
+///     // work with the resource that is owned by the executor
+/// })
+/// .expect("The executor has been stopped");
+/// # }
+/// ```
+#[cfg(not(feature = "disable_send_task_to"))]
+pub fn spawn_on_executor<Fut>(executor_id: usize, future: Fut) -> Result<(), SpawnError>
+where
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    // The lock is held until the task is appended, so the executor can't be stopped
+    // between the check and the append, and the task is never appended to a stopped executor.
+    let global_state = lock_and_get_global_state();
+    let state = global_state
+        .alive_executors()
+        .get(&executor_id)
+        .ok_or(SpawnError::ExecutorNotFound)?;
+
+    let task = unsafe { Task::from_future(future, Locality::local()) };
+    state.task_queue.append_task(
+        task,
+        #[cfg(debug_assertions)]
+        executor_id,
+    );
+    drop(global_state);
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -271,4 +435,180 @@ mod tests {
 
         println!("4");
     }
+
+    #[orengine::test::test_local]
+    fn test_all_executor_ids_and_executor_count() {
+        use std::sync::mpsc;
+
+        let mut ids = Vec::new();
+        for _ in 0..2 {
+            let (id_sender, id_receiver) = mpsc::channel();
+            thread::spawn(move || {
+                let ex = Executor::init_with_config(Config::default().disable_work_sharing());
+                id_sender.send(ex.id()).unwrap();
+                ex.run();
+            });
+            ids.push(id_receiver.recv().unwrap());
+        }
+
+        while !ids.iter().all(|id| all_executor_ids().contains(id)) {
+            sleep(Duration::from_millis(1)).await;
+        }
+        // Other tests start and stop executors concurrently.
+        assert!(executor_count() >= 2);
+        {
+            let global_state = lock_and_get_global_state();
+            assert_eq!(
+                global_state.executors_ids().len(),
+                global_state.number_of_executors()
+            );
+        }
+
+        stop_executor(ids[0]);
+        let all_ids = all_executor_ids();
+        assert!(!all_ids.contains(&ids[0]));
+        assert!(all_ids.contains(&ids[1]));
+
+        stop_executor(ids[1]);
+        assert!(!all_executor_ids().contains(&ids[1]));
+    }
+
+    #[cfg(not(feature = "disable_send_task_to"))]
+    #[orengine::test::test_local]
+    fn test_spawn_on_executor() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::{mpsc, Arc};
+
+        let (id_sender, id_receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let ex = Executor::init_with_config(Config::default().disable_work_sharing());
+            id_sender.send(ex.id()).unwrap();
+            ex.run();
+        });
+        let id = id_receiver.recv().unwrap();
+
+        while !all_executor_ids().contains(&id) {
+            sleep(Duration::from_millis(1)).await;
+        }
+        assert!(executor_count() >= 1);
+
+        let was_executed = Arc::new(AtomicBool::new(false));
+        let was_executed_clone = was_executed.clone();
+        spawn_on_executor(id, async move {
+            assert_eq!(local_executor().id(), id);
+            was_executed_clone.store(true, Ordering::Release);
+            stop_executor(id);
+        })
+        .expect("spawn_on_executor failed");
+
+        while all_executor_ids().contains(&id) {
+            sleep(Duration::from_millis(1)).await;
+        }
+
+        assert!(was_executed.load(Ordering::Acquire));
+        assert_eq!(
+            spawn_on_executor(id, async {}),
+            Err(SpawnError::ExecutorNotFound)
+        );
+    }
+
+    #[cfg(not(feature = "disable_send_task_to"))]
+    #[orengine::test::test_local]
+    fn test_register_executor_after_many_executors_that_have_never_run() {
+        use crate::runtime::executor::executor::FREE_EXECUTOR_ID;
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::{mpsc, Arc};
+
+        // Like 1000 executors that have been initialized, but have never run.
+        FREE_EXECUTOR_ID.fetch_add(1000, Ordering::Relaxed);
+
+        let (id_sender, id_receiver) = mpsc::channel();
+        let handle = thread::spawn(move || {
+            let ex = Executor::init_with_config(Config::default().disable_work_sharing());
+            id_sender.send(ex.id()).unwrap();
+            ex.run();
+        });
+        let id = id_receiver.recv().unwrap();
+
+        while !all_executor_ids().contains(&id) {
+            assert!(!handle.is_finished(), "the executor failed to register");
+            sleep(Duration::from_millis(1)).await;
+        }
+
+        let was_executed = Arc::new(AtomicBool::new(false));
+        let was_executed_clone = was_executed.clone();
+        spawn_on_executor(id, async move {
+            was_executed_clone.store(true, Ordering::Release);
+            stop_executor(id);
+        })
+        .expect("spawn_on_executor failed");
+
+        while all_executor_ids().contains(&id) {
+            sleep(Duration::from_millis(1)).await;
+        }
+
+        assert!(was_executed.load(Ordering::Acquire));
+        assert!(!lock_and_get_global_state()
+            .alive_executors()
+            .contains_key(&id));
+    }
+
+    #[orengine::test::test_local]
+    fn test_stop_executor_by_name() {
+        const NAME: &str = "test_stop_executor_by_name";
+
+        let handle = thread::spawn(move || {
+            let ex = Executor::init_with_config(Config::default().disable_work_sharing());
+            ex.set_name(NAME);
+            ex.run();
+        });
+
+        while lock_and_get_global_state()
+            .executor_id_by_name(NAME)
+            .is_none()
+        {
+            sleep(Duration::from_millis(1)).await;
+        }
+
+        assert_eq!(stop_executor_by_name(NAME), Ok(()));
+        while !handle.is_finished() {
+            sleep(Duration::from_millis(1)).await;
+        }
+
+        assert_eq!(stop_executor_by_name(NAME), Err(ExecutorNotFound));
+    }
+
+    #[orengine::test::test_local]
+    fn test_stop_executor_by_duplicate_name() {
+        use std::sync::mpsc;
+
+        const NAME: &str = "test_stop_executor_by_duplicate_name";
+
+        let mut ids = Vec::new();
+        for _ in 0..2 {
+            let (id_sender, id_receiver) = mpsc::channel();
+            thread::spawn(move || {
+                let ex = Executor::init_with_config(Config::default().disable_work_sharing());
+                ex.set_name(NAME);
+                id_sender.send(ex.id()).unwrap();
+                ex.run();
+            });
+            ids.push(id_receiver.recv().unwrap());
+        }
+        ids.sort_unstable();
+
+        while !ids.iter().all(|id| all_executor_ids().contains(id)) {
+            sleep(Duration::from_millis(1)).await;
+        }
+
+        assert_eq!(stop_executor_by_name(NAME), Ok(()));
+        let all_ids = all_executor_ids();
+        assert!(!all_ids.contains(&ids[0]));
+        assert!(all_ids.contains(&ids[1]));
+
+        assert_eq!(stop_executor_by_name(NAME), Ok(()));
+        assert!(!all_executor_ids().contains(&ids[1]));
+
+        assert_eq!(stop_executor_by_name(NAME), Err(ExecutorNotFound));
+    }
 }
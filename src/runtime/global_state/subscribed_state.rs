@@ -3,11 +3,11 @@ use crate::runtime::interaction_between_executors::{
     Interactor, SharedTaskListForSendTo, SyncBatchOptimizedTaskQueue,
 };
 use crate::runtime::{lock_and_get_global_state, ExecutorSharedTaskList};
-#[cfg(not(feature = "disable_send_task_to"))]
-use crate::utils::vec_map::VecMap;
 use crate::BUG_MESSAGE;
 use crossbeam::utils::CachePadded;
 use std::cell::UnsafeCell;
+#[cfg(not(feature = "disable_send_task_to"))]
+use std::collections::BTreeMap;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering::{Acquire, Release};
 use std::sync::Arc;
@@ -20,7 +20,7 @@ struct Inner {
     tasks_lists: Option<Vec<Arc<ExecutorSharedTaskList>>>,
     /// Used in `send_task_to`.
     #[cfg(not(feature = "disable_send_task_to"))]
-    executors_task_lists: VecMap<Arc<SyncBatchOptimizedTaskQueue>>,
+    executors_task_lists: BTreeMap<usize, Arc<SyncBatchOptimizedTaskQueue>>,
 }
 
 /// `SubscribedState` contains the image of the [`shared state`](crate::runtime::global_state::global_state::GLOBAL_STATE)
@@ -46,7 +46,7 @@ impl SubscribedState {
                 is_stopped: false,
                 tasks_lists: None,
                 #[cfg(not(feature = "disable_send_task_to"))]
-                executors_task_lists: VecMap::new(),
+                executors_task_lists: BTreeMap::new(),
             }),
         }
     }
@@ -127,10 +127,7 @@ impl SubscribedState {
 
             inner.processed_version = current_version;
             let shared_state = lock_and_get_global_state();
-            let found = shared_state
-                .alive_executors()
-                .iter()
-                .any(|(alive_executor_id, _)| alive_executor_id == executor_id);
+            let found = shared_state.alive_executors().contains_key(&executor_id);
 
             if !found {
                 inner.is_stopped = true;
@@ -153,7 +150,7 @@ impl SubscribedState {
                     .for_each(|(id, state)| {
                         inner
                             .executors_task_lists
-                            .insert(id, state.task_queue.clone());
+                            .insert(*id, state.task_queue.clone());
                     });
 
                 interactor.shared_task_lists_mut().clear();
@@ -164,7 +161,7 @@ impl SubscribedState {
                     .for_each(|(id, state)| {
                         interactor
                             .shared_task_lists_mut()
-                            .insert(id, SharedTaskListForSendTo::new(state.task_queue.clone()));
+                            .insert(*id, SharedTaskListForSendTo::new(state.task_queue.clone()));
                     });
             }
             // endregion
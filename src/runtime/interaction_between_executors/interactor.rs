@@ -1,7 +1,6 @@
 use crate::runtime::interaction_between_executors::{SendTaskResult, SyncBatchOptimizedTaskQueue};
 use crate::runtime::Task;
-use crate::utils::vec_map::VecMap;
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, VecDeque};
 use std::mem;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -27,7 +26,7 @@ impl SharedTaskListForSendTo {
 /// `Interactor` isolates the logic of interacting with other executors.
 pub(crate) struct Interactor {
     shared_task_list: Arc<SyncBatchOptimizedTaskQueue>,
-    all: VecMap<SharedTaskListForSendTo>,
+    all: BTreeMap<usize, SharedTaskListForSendTo>,
     id_to_retry: Vec<usize>,
     last_executed_time: Instant,
 }
@@ -37,7 +36,7 @@ impl Interactor {
     pub(crate) fn new() -> Self {
         Self {
             shared_task_list: Arc::new(SyncBatchOptimizedTaskQueue::new()),
-            all: VecMap::new(),
+            all: BTreeMap::new(),
             id_to_retry: Vec::new(),
             last_executed_time: unsafe { mem::zeroed() },
         }
@@ -49,7 +48,9 @@ impl Interactor {
     }
 
     /// Returns a mutable reference to the `shared` task lists of executors.
-    pub(crate) fn shared_task_lists_mut(&mut self) -> &mut VecMap<SharedTaskListForSendTo> {
+    pub(crate) fn shared_task_lists_mut(
+        &mut self,
+    ) -> &mut BTreeMap<usize, SharedTaskListForSendTo> {
         &mut self.all
     }
 
@@ -59,7 +60,7 @@ impl Interactor {
         task: Task,
         executor_id: usize,
     ) -> SendTaskResult {
-        if let Some(shared_task_list) = self.all.get_mut(executor_id) {
+        if let Some(shared_task_list) = self.all.get_mut(&executor_id) {
             if task.is_local() {
                 shared_task_list.local_task_batch.push_back(task);
             } else {
@@ -78,7 +79,7 @@ impl Interactor {
     fn flush(&mut self) {
         debug_assert!(self.id_to_retry.is_empty());
 
-        for (id, shared_task_list_) in self.all.iter_mut() {
+        for (&id, shared_task_list_) in &mut self.all {
             let shared_task_list = &shared_task_list_.shared_task_list;
 
             #[cfg(not(debug_assertions))]
@@ -109,7 +110,7 @@ impl Interactor {
     /// It doesn't guarantee that the tasks will be flushed, but the chance is very high.
     fn retry_flush(&mut self) {
         for id in self.id_to_retry.drain(..) {
-            let shared_task_list = unsafe { self.all.get_mut(id).unwrap_unchecked() };
+            let shared_task_list = unsafe { self.all.get_mut(&id).unwrap_unchecked() };
 
             #[cfg(not(debug_assertions))]
             {
@@ -1,6 +1,7 @@
 use crate::runtime::Task;
 use crate::utils::never_wait_lock::NeverWaitLock;
 use std::collections::VecDeque;
+use std::hint::spin_loop;
 
 /// `LocalAndSharedTaskDeque` stores `local` and `shared` tasks deque.
 struct LocalAndSharedTaskDeque {
@@ -75,6 +76,26 @@ impl SyncBatchOptimizedTaskQueue {
         false
     }
 
+    /// Appends the task to the [`LocalAndSharedTaskDeque`], spinning until it is available.
+    pub(crate) fn append_task(&self, task: Task, #[cfg(debug_assertions)] executor_id: usize) {
+        let mut local_tasks = VecDeque::new();
+        let mut shared_tasks = VecDeque::new();
+        if task.is_local() {
+            local_tasks.push_back(task);
+        } else {
+            shared_tasks.push_back(task);
+        }
+
+        while !self.try_to_append_tasks(
+            &mut local_tasks,
+            &mut shared_tasks,
+            #[cfg(debug_assertions)]
+            executor_id,
+        ) {
+            spin_loop();
+        }
+    }
+
     /// Takes all tasks to the `local` and `shared` task queues if [`LocalAndSharedTaskDeque`]
     /// is available.
     ///
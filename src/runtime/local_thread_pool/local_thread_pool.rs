@@ -50,7 +50,8 @@ impl ThreadWorker {
                 Ok(worker_task) => {
                     unsafe {
                         (*worker_task.job)();
-                        self.result_list.push(worker_task.task);
+                        // The result list is drained only by the executor that owns the task.
+                        self.result_list.push_task_of_owner(worker_task.task);
                     };
                 }
                 Err(_) => return,
@@ -62,30 +63,46 @@ impl ThreadWorker {
 /// This structure represents a pool of worker threads.
 pub(crate) struct LocalThreadWorkerPool {
     wait: usize,
-    workers: Box<[crossbeam::channel::Sender<ThreadWorkerTask>]>,
+    workers: Vec<crossbeam::channel::Sender<ThreadWorkerTask>>,
     result_list: Arc<SyncTaskList>,
 }
 
 impl LocalThreadWorkerPool {
     /// Creates a new instance of `LocalThreadWorkerPool`.
     pub(crate) fn new(number_of_workers: usize) -> Self {
-        let mut workers = Vec::with_capacity(number_of_workers);
-        let result_list = Arc::new(SyncTaskList::new());
-        for _ in 0..number_of_workers {
-            let (mut worker, sender) = ThreadWorker::new(result_list.clone());
+        let mut pool = Self {
+            wait: 0,
+            workers: Vec::with_capacity(number_of_workers),
+            result_list: Arc::new(SyncTaskList::new()),
+        };
+        pool.resize(number_of_workers);
+
+        pool
+    }
+
+    /// Spawns or stops workers to make the [`pool`](LocalThreadWorkerPool)
+    /// have `number_of_workers` workers.
+    ///
+    /// Stopping is cooperative: the channel of an excess worker is closed,
+    /// so the worker executes already sent jobs and only then exits.
+    pub(crate) fn resize(&mut self, number_of_workers: usize) {
+        while self.workers.len() < number_of_workers {
+            let (mut worker, sender) = ThreadWorker::new(self.result_list.clone());
 
             thread::spawn(move || {
                 worker.run();
             });
 
-            workers.push(sender);
+            self.workers.push(sender);
         }
 
-        Self {
-            wait: 0,
-            workers: workers.into_boxed_slice(),
-            result_list,
-        }
+        self.workers.truncate(number_of_workers);
+    }
+
+    /// Returns the number of pushed tasks whose jobs are not completed yet.
+    #[inline]
+    pub(crate) fn number_of_pending_jobs(&self) -> usize {
+        self.wait
     }
 
     /// Pushes a task to the [`pool`](LocalThreadWorkerPool).
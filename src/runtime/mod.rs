@@ -1,10 +1,12 @@
 pub mod asyncify;
 pub mod call;
+pub mod clock;
+pub mod detached;
 pub mod executor;
 pub mod get_task_from_context;
 pub mod global_state;
 #[cfg(not(feature = "disable_send_task_to"))]
-mod interaction_between_executors;
+pub(crate) mod interaction_between_executors;
 pub(super) mod local_thread_pool;
 pub mod task;
 pub mod waker;
@@ -13,6 +15,13 @@ pub use executor::{local_executor, Executor};
 
 pub use asyncify::*;
 pub use call::*;
+pub use clock::{Clock, SystemClock};
+pub use detached::run_on_new_thread;
 pub use executor::*;
-pub use global_state::{lock_and_get_global_state, stop_all_executors, stop_executor};
+#[cfg(not(feature = "disable_send_task_to"))]
+pub use global_state::spawn_on_executor;
+pub use global_state::{
+    all_executor_ids, executor_count, lock_and_get_global_state, stop_all_executors, stop_executor,
+    stop_executor_by_name, ExecutorNotFound, SpawnError,
+};
 pub use task::*;
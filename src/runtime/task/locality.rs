@@ -9,7 +9,21 @@ pub(crate) const IS_LOCAL_SHIFT: i128 = 127;
 ///
 /// `TASK_MASK` is the mask for the task associated with the current tagged ptr.
 #[cfg(target_pointer_width = "64")]
-pub(crate) const TASK_MASK: i128 = !(1 << IS_LOCAL_SHIFT);
+pub(crate) const TASK_MASK: i128 = !(IS_LOCAL_MASK | PRIORITY_MASK);
+
+/// In systems with 64-bit pointers, two bits below the task-locality flag are reserved
+/// for the [`TaskPriority`](crate::runtime::TaskPriority) of the task.
+///
+/// `PRIORITY_SHIFT` is the shift amount for the task priority.
+#[cfg(target_pointer_width = "64")]
+pub(crate) const PRIORITY_SHIFT: i128 = 125;
+
+/// In systems with 64-bit pointers, two bits below the task-locality flag are reserved
+/// for the [`TaskPriority`](crate::runtime::TaskPriority) of the task.
+///
+/// `PRIORITY_MASK` is the mask for the task priority associated with the current tagged ptr.
+#[cfg(target_pointer_width = "64")]
+pub(crate) const PRIORITY_MASK: i128 = 0b11 << PRIORITY_SHIFT;
 
 /// In systems with 64-bit pointers, the high bit is reserved for the task-locality flag
 /// caused by the fact that `*mut dyn` can be safely cast to `i128`.
@@ -1,15 +1,16 @@
 use crate::runtime::Task;
-use crate::utils::never_wait_lock::NeverWaitLock;
-use crate::utils::SpinLockGuard;
+use crossbeam::queue::SegQueue;
 use std::collections::VecDeque;
-use std::ptr;
 
 /// `SharedExecutorTaskList` is a list of tasks that can be shared between executors.
 ///
 /// All tasks in the list must be `shared` and their futures must implement `Send`.
+///
+/// It is based on the lock-free [`SegQueue`], so the executor that shares its tasks
+/// and the executors that take them never wait for each other.
 pub(crate) struct ExecutorSharedTaskList {
     executor_id: usize,
-    list: NeverWaitLock<Vec<Task>>,
+    list: SegQueue<Task>,
 }
 
 impl ExecutorSharedTaskList {
@@ -17,7 +18,7 @@ impl ExecutorSharedTaskList {
     pub(crate) const fn new(executor_id: usize) -> Self {
         Self {
             executor_id,
-            list: NeverWaitLock::new(Vec::new()),
+            list: SegQueue::new(),
         }
     }
 
@@ -27,26 +28,43 @@ impl ExecutorSharedTaskList {
         self.executor_id
     }
 
-    /// Returns the `SpinLockGuard<Vec<Task>>` of the underlying list if it is not locked.
-    /// Otherwise, returns `None`.
-    pub(crate) fn try_lock_and_return_as_vec(&self) -> Option<SpinLockGuard<Vec<Task>>> {
-        self.list.try_lock()
+    /// Returns whether the list is empty.
+    #[inline]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns the number of tasks in the list.
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Pushes a task to the list.
+    #[inline]
+    pub(crate) fn push(&self, task: Task) {
+        self.list.push(task);
+    }
+
+    /// Pops a task from the list.
+    #[inline]
+    pub(crate) fn pop(&self) -> Option<Task> {
+        self.list.pop()
     }
 
     /// Takes at most `limit` tasks from the list and puts them in `other_list`.
+    ///
+    /// Returns the number of taken tasks.
     #[inline]
-    pub(crate) fn take_batch(&self, other_list: &mut VecDeque<Task>, limit: usize) {
-        if let Some(mut guard) = self.list.try_lock() {
-            let number_of_elems = guard.len().min(limit);
-            let new_len = guard.len() - number_of_elems;
-            let mut first_index = new_len;
-
-            while first_index != guard.len() {
-                other_list.push_back(unsafe { ptr::read(guard.get_unchecked(first_index)) });
-                first_index += 1;
+    pub(crate) fn take_batch(&self, other_list: &mut VecDeque<Task>, limit: usize) -> usize {
+        for taken in 0..limit {
+            match self.list.pop() {
+                Some(task) => other_list.push_back(task),
+                None => return taken,
             }
-            unsafe { guard.set_len(new_len) };
         }
+
+        limit
     }
 }
 
@@ -1,12 +1,19 @@
 use crate::runtime::call::Call;
 use crate::runtime::task::task_data::TaskData;
-use crate::runtime::{Locality, TaskPool};
+use crate::runtime::{Locality, TaskPool, TaskPriority};
 use crate::{local_executor, Executor};
 use std::future::Future;
 use std::panic::{RefUnwindSafe, UnwindSafe};
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
+/// `TaskId` identifies a [`Task`] while it is alive.
+///
+/// It is the address of the future of the task, so it can be reused
+/// after the task is completed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TaskId(usize);
+
 /// `Task` is a wrapper of a future.
 ///
 /// If `debug_assertions` is enabled, it keeps additional information to check
@@ -49,12 +56,27 @@ impl Task {
         self.data.future_ptr()
     }
 
+    /// Returns the [`TaskId`] of the task.
+    #[inline]
+    pub fn id(&self) -> TaskId {
+        TaskId(self.future_ptr().cast::<()>() as usize)
+    }
+
     /// Returns whether the task is local or not.
     #[inline]
     pub fn is_local(&self) -> bool {
         self.data.is_local()
     }
 
+    /// Returns the [`TaskPriority`] of the task.
+    ///
+    /// It is [`TaskPriority::Normal`] unless the task is spawned with
+    /// [`Executor::spawn_shared_with_priority`].
+    #[inline]
+    pub fn priority(&self) -> TaskPriority {
+        self.data.priority()
+    }
+
     /// Puts it back to the [`TaskPool`](TaskPool). It is unsafe because you
     /// have to think about making sure it is no longer used.
     ///
@@ -1,21 +1,25 @@
-use crate::runtime::Locality;
+use crate::runtime::{Locality, TaskPriority};
 #[cfg(target_pointer_width = "64")]
-use crate::runtime::{IS_LOCAL_MASK, TASK_MASK};
+use crate::runtime::{IS_LOCAL_MASK, PRIORITY_MASK, PRIORITY_SHIFT, TASK_MASK};
 use std::future::Future;
 
-/// `*mut dyn Future<Output = ()>` and the [`locality`](Locality) information associated
-/// with the [`Task`](crate::runtime::Task).
+/// `*mut dyn Future<Output = ()>`, the [`locality`](Locality) and the [`priority`](TaskPriority)
+/// information associated with the [`Task`](crate::runtime::Task).
 ///
 /// In systems with 64-bit pointers, the high bit is reserved for the task-locality flag
+/// and the next two bits are reserved for the priority
 /// caused by the fact that `*mut dyn` can be safely cast to `i128`.
 ///
-/// In systems with 32-bit pointers or 16-bit pointers, an extra `bool` is used.
+/// In systems with 32-bit pointers or 16-bit pointers, an extra `bool`
+/// and an extra [`TaskPriority`] are used.
 #[derive(Clone, Copy)]
 pub(crate) struct TaskData {
     #[cfg(not(target_pointer_width = "64"))]
     future_ptr: *mut dyn Future<Output = ()>,
     #[cfg(not(target_pointer_width = "64"))]
     is_local: bool,
+    #[cfg(not(target_pointer_width = "64"))]
+    priority: TaskPriority,
     #[cfg(target_pointer_width = "64")]
     future_tagged_ptr: *mut dyn Future<Output = ()>,
 }
@@ -28,6 +32,7 @@ impl TaskData {
         return Self {
             future_ptr: future,
             is_local: locality.value,
+            priority: TaskPriority::Normal,
         };
 
         #[cfg(target_pointer_width = "64")]
@@ -86,6 +91,25 @@ impl TaskData {
         }
     }
 
+    /// Replaces the bits of the tagged ptr selected by `mask` with `tags`.
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    #[allow(clippy::transmute_undefined_repr, reason = "dark magic")]
+    fn replace_tags(&mut self, mask: i128, tags: i128) {
+        let future_tagged_ptr = unsafe {
+            std::mem::transmute::<*mut dyn Future<Output = ()>, i128>(self.future_tagged_ptr)
+        };
+
+        let tagged_ptr = future_tagged_ptr & !mask;
+        let tagged_ptr = tagged_ptr | tags;
+
+        #[allow(clippy::useless_transmute, reason = "false positive")]
+        {
+            self.future_tagged_ptr =
+                unsafe { std::mem::transmute::<i128, *mut dyn Future<Output = ()>>(tagged_ptr) };
+        }
+    }
+
     /// Sets locality for the `TaskData`.
     #[inline]
     pub(crate) fn set_locality(&mut self, locality: Locality) {
@@ -94,6 +118,16 @@ impl TaskData {
             self.is_local = locality.value;
         }
 
+        #[cfg(target_pointer_width = "64")]
+        self.replace_tags(IS_LOCAL_MASK, locality.value);
+    }
+
+    /// Returns the [`TaskPriority`] of the `TaskData`.
+    #[inline]
+    pub(crate) fn priority(&self) -> TaskPriority {
+        #[cfg(not(target_pointer_width = "64"))]
+        return self.priority;
+
         #[cfg(target_pointer_width = "64")]
         #[allow(clippy::transmute_undefined_repr, reason = "dark magic")]
         {
@@ -101,17 +135,32 @@ impl TaskData {
                 std::mem::transmute::<*mut dyn Future<Output = ()>, i128>(self.future_tagged_ptr)
             };
 
-            let tagged_ptr = future_tagged_ptr & !IS_LOCAL_MASK;
-            let tagged_ptr = tagged_ptr | locality.value;
-
-            #[allow(clippy::useless_transmute, reason = "false positive")]
-            {
-                self.future_tagged_ptr = unsafe {
-                    std::mem::transmute::<i128, *mut dyn Future<Output = ()>>(tagged_ptr)
-                };
+            match (future_tagged_ptr & PRIORITY_MASK) >> PRIORITY_SHIFT {
+                0 => TaskPriority::Normal,
+                1 => TaskPriority::High,
+                _ => TaskPriority::Low,
             }
         }
     }
+
+    /// Sets the [`TaskPriority`] for the `TaskData`.
+    #[inline]
+    pub(crate) fn set_priority(&mut self, priority: TaskPriority) {
+        #[cfg(not(target_pointer_width = "64"))]
+        {
+            self.priority = priority;
+        }
+
+        #[cfg(target_pointer_width = "64")]
+        {
+            let priority: i128 = match priority {
+                TaskPriority::Normal => 0,
+                TaskPriority::High => 1,
+                TaskPriority::Low => 2,
+            };
+            self.replace_tags(PRIORITY_MASK, priority << PRIORITY_SHIFT);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -142,6 +191,13 @@ mod tests {
         assert!(!task.data.is_local());
         assert!(!task.is_local());
 
+        assert_eq!(task.data.priority(), TaskPriority::Normal);
+        for priority in [TaskPriority::High, TaskPriority::Low, TaskPriority::Normal] {
+            task.data.set_priority(priority);
+            assert_eq!(task.data.priority(), priority);
+            assert!(!task.data.is_local());
+        }
+
         local_executor().exec_task(task);
 
         assert!(*res.borrow());
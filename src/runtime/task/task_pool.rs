@@ -3,13 +3,13 @@ use crate::runtime::task::task_data::TaskData;
 use crate::runtime::{Locality, Task};
 use ahash::AHashMap;
 use std::future::Future;
-use std::mem::size_of;
+use std::mem::{align_of, align_of_val, size_of, size_of_val};
 
 /// A pool of tasks.
 #[derive(Default)]
 pub(crate) struct TaskPool {
-    /// Key is a size.
-    storage: AHashMap<usize, Vec<Task>>,
+    /// Key is a size and an alignment.
+    storage: AHashMap<(usize, usize), Vec<Task>>,
 }
 
 impl TaskPool {
@@ -17,7 +17,7 @@ impl TaskPool {
     #[inline]
     pub(crate) fn acquire<F: Future<Output = ()>>(future: F, locality: Locality) -> Task {
         let executor = local_executor();
-        let size = size_of::<F>();
+        let key = (size_of::<F>(), align_of::<F>());
         #[cfg(debug_assertions)]
         let executor_id = if cfg!(test) {
             usize::MAX
@@ -25,37 +25,104 @@ impl TaskPool {
             executor.id()
         };
 
-        let pool = executor.task_pool().storage.entry(size).or_default();
-        if let Some(task) = pool.pop() {
-            let future_ptr: *mut F = unsafe { &mut *task.future_ptr().cast::<F>() };
-            unsafe {
-                future_ptr.write(future);
-            }
+        let pool = executor.task_pool().storage.entry(key).or_default();
+        let task = pool.pop();
+        let future_ptr: *mut F = task.as_ref().map_or_else(
+            || Box::into_raw(Box::<F>::new_uninit()).cast::<F>(),
+            |task| task.future_ptr().cast::<F>(),
+        );
+        unsafe {
+            future_ptr.write(future);
+        }
 
-            task
-        } else {
-            let future_ptr: *mut F = unsafe { &mut *(Box::into_raw(Box::new(future))) as *mut _ };
-            Task {
-                data: TaskData::new(future_ptr as *mut _, locality),
+        // `F` is not required to be `'static` (for example, the future of
+        // `run_and_block_on_local` can borrow), so the lifetime of the future is erased.
+        #[allow(
+            clippy::transmute_ptr_to_ptr,
+            reason = "A pointer cast can't extend the lifetime of a trait object"
+        )]
+        let future_ptr = unsafe {
+            std::mem::transmute::<*mut (dyn Future<Output = ()> + '_), *mut dyn Future<Output = ()>>(
+                future_ptr,
+            )
+        };
+        let data = TaskData::new(future_ptr, locality);
+
+        // The pooled task can contain a future of another type with the same layout,
+        // so the vtable and the locality must be replaced.
+        task.map_or_else(
+            || Task {
+                data,
                 #[cfg(debug_assertions)]
                 executor_id,
                 #[cfg(debug_assertions)]
                 is_executing: crate::utils::Ptr::new(std::sync::atomic::AtomicBool::new(false)),
-            }
-        }
+            },
+            |mut task| {
+                task.data = data;
+
+                task
+            },
+        )
     }
 
     /// Puts a task into the pool.
     #[inline]
     pub fn put(&mut self, task: Task) {
-        let size = size_of_val(unsafe { &*task.future_ptr() });
-        if let Some(pool) = self.storage.get_mut(&size) {
+        let future = unsafe { &*task.future_ptr() };
+        let key = (size_of_val(future), align_of_val(future));
+        if let Some(pool) = self.storage.get_mut(&key) {
             pool.push(task);
             return;
         }
 
         // A task that have been allocated in another thread ended up here
 
-        self.storage.insert(size, vec![task]);
+        self.storage.insert(key, vec![task]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as orengine;
+    use crate::local_executor;
+    use crate::runtime::{Locality, Task};
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[orengine::test::test_local]
+    fn test_task_pool_reuses_tasks_of_other_types() {
+        let result = Rc::new(Cell::new(0));
+
+        let result_clone = result.clone();
+        local_executor().exec_local_future(async move {
+            result_clone.set(result_clone.get() + 1);
+        });
+
+        // The same layout as the previous future, but another type.
+        let result_clone = result.clone();
+        local_executor().exec_local_future(async move {
+            result_clone.set(result_clone.get() + 10);
+        });
+
+        assert_eq!(result.get(), 11);
+    }
+
+    #[orengine::test::test_local]
+    fn test_task_pool_replaces_locality_of_reused_tasks() {
+        let task = unsafe { Task::from_future(async {}, Locality::shared()) };
+        assert!(!task.is_local());
+        let future_ptr = task.future_ptr().cast::<u8>();
+        unsafe { task.release(local_executor()) };
+
+        let task = unsafe { Task::from_future(async {}, Locality::local()) };
+        assert_eq!(
+            task.future_ptr().cast::<u8>(),
+            future_ptr,
+            "the task is not reused"
+        );
+        assert!(task.is_local());
+
+        unsafe { task.release(local_executor()) };
     }
 }
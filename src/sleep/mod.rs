@@ -1,11 +1,15 @@
 use crate::get_task_from_context;
 use crate::runtime::local_executor;
-use std::collections::btree_map::Entry::{Occupied, Vacant};
 use std::future::Future;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 use std::time::{Duration, Instant};
 
+pub mod precise;
+pub(crate) mod timer_wheel;
+
+pub use precise::sleep_precise;
+
 /// `Sleep` implements the [`Future`] trait. It waits at least until `sleep_until` and works only
 /// in `orengine` runtime.
 pub struct Sleep {
@@ -24,19 +28,9 @@ impl Future for Sleep {
         } else {
             this.was_yielded = true;
             let task = unsafe { get_task_from_context!(cx) };
-
-            loop {
-                let sleeping_tasks_map = local_executor().sleeping_tasks();
-                match sleeping_tasks_map.entry(this.sleep_until) {
-                    Occupied(_) => {
-                        this.sleep_until += Duration::from_nanos(1);
-                    }
-                    Vacant(entry) => {
-                        entry.insert(task);
-                        break;
-                    }
-                }
-            }
+            local_executor()
+                .sleeping_tasks()
+                .insert(task, this.sleep_until);
 
             Poll::Pending
         }
@@ -0,0 +1,113 @@
+//! This module contains [`sleep_precise`].
+#[cfg(target_os = "linux")]
+use crate as orengine;
+#[cfg(target_os = "linux")]
+use crate::io::io_request_data::{IoRequestData, IoRequestDataPtr};
+#[cfg(target_os = "linux")]
+use crate::io::worker::{get_local_worker_ref, local_worker, IoWorker};
+#[cfg(target_os = "linux")]
+use io_uring::types::Timespec;
+#[cfg(target_os = "linux")]
+use orengine_macros::poll_for_io_request;
+#[cfg(target_os = "linux")]
+use std::future::Future;
+#[cfg(target_os = "linux")]
+use std::pin::Pin;
+#[cfg(target_os = "linux")]
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+/// `timeout` io operation. It completes with `ETIME` after the `timespec` has elapsed.
+#[cfg(target_os = "linux")]
+#[repr(C)]
+struct Timeout {
+    timespec: Timespec,
+    io_request_data: Option<IoRequestData>,
+}
+
+#[cfg(target_os = "linux")]
+impl Future for Timeout {
+    type Output = std::io::Result<usize>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let ret;
+
+        poll_for_io_request!((
+            local_worker().timeout(&raw const this.timespec, unsafe {
+                IoRequestDataPtr::new(this.io_request_data.as_mut().unwrap_unchecked())
+            }),
+            ret
+        ));
+    }
+}
+
+/// Sleeps at least for `duration` with sub-millisecond precision.
+/// It works only in `orengine` runtime.
+///
+/// Unlike [`sleep`](crate::sleep()), which is woken by the executor with
+/// millisecond granularity, it submits an `io_uring` timeout, so the task is woken
+/// as soon as the kernel fires it. It is useful for network pacing, media workloads and tests
+/// that need exact timing.
+///
+/// If the executor has no io worker or `io_uring` is not available, it works like
+/// [`sleep`](crate::sleep()).
+///
+/// # Panics
+///
+/// If the kernel rejects the timeout operation.
+///
+/// # Example
+///
+/// ```no_run
+/// use orengine::sleep_precise;
+/// use std::time::Duration;
+///
+/// orengine::Executor::init().run_with_local_future(async {
+///     sleep_precise(Duration::from_micros(250)).await;
+///     println!("Hello after at least 250 micros!");
+/// });
+/// ```
+#[allow(
+    clippy::future_not_send,
+    reason = "It is not `Send` only on platforms without `io_uring`, it is fine"
+)]
+pub async fn sleep_precise(duration: Duration) {
+    #[cfg(target_os = "linux")]
+    {
+        if get_local_worker_ref().is_some() {
+            let res = Timeout {
+                timespec: Timespec::from(duration),
+                io_request_data: None,
+            }
+            .await;
+
+            match res {
+                Ok(_) => {}
+                Err(err) if err.raw_os_error() == Some(libc::ETIME) => {}
+                Err(err) => panic!("sleep_precise failed: {err}"),
+            }
+
+            return;
+        }
+    }
+
+    crate::sleep(duration).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use std::time::Instant;
+
+    #[orengine::test::test_local]
+    fn test_sleep_precise() {
+        for micros in [50, 300, 1500] {
+            let duration = Duration::from_micros(micros);
+            let start = Instant::now();
+            sleep_precise(duration).await;
+            assert!(start.elapsed() >= duration);
+        }
+    }
+}
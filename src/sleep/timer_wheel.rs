@@ -0,0 +1,304 @@
+//! This module contains [`TimerWheel`].
+use std::mem;
+use std::time::{Duration, Instant};
+
+/// The number of bits of the slot index.
+const SLOT_BITS: u32 = 6;
+/// The number of slots on each level of the [`TimerWheel`].
+const NUMBER_OF_SLOTS: usize = 1 << SLOT_BITS;
+/// The mask of the slot index.
+const SLOT_MASK: u64 = NUMBER_OF_SLOTS as u64 - 1;
+/// The number of levels of the [`TimerWheel`].
+///
+/// With 1 millisecond granularity the levels cover about 4.6 hours,
+/// more distant deadlines are stored in the overflow slot.
+const NUMBER_OF_LEVELS: u32 = 4;
+/// The duration of one tick of the [`TimerWheel`].
+pub(crate) const GRANULARITY: Duration = Duration::from_millis(1);
+
+/// A value with its deadline.
+struct Entry<T> {
+    deadline: Instant,
+    value: T,
+}
+
+/// One level of the [`TimerWheel`]. A slot of the level `n` covers `64^n` ticks.
+struct Level<T> {
+    slots: [Vec<Entry<T>>; NUMBER_OF_SLOTS],
+    /// The bitmap of non-empty slots.
+    occupied: u64,
+}
+
+impl<T> Level<T> {
+    /// Creates a new empty `Level`.
+    fn new() -> Self {
+        Self {
+            slots: std::array::from_fn(|_| Vec::new()),
+            occupied: 0,
+        }
+    }
+
+    /// Takes all entries from the slot.
+    #[inline]
+    fn take_slot(&mut self, slot: usize) -> Vec<Entry<T>> {
+        self.occupied &= !(1 << slot);
+        mem::take(&mut self.slots[slot])
+    }
+}
+
+/// `TimerWheel` is a hierarchical hashed timing wheel.
+///
+/// It stores values (sleeping tasks) with their deadlines and returns them after
+/// the deadlines have passed. Both [`insert`](Self::insert) and [`expire`](Self::expire)
+/// are `O(1)` on average regardless of the number of timers, unlike a `BTreeMap`.
+///
+/// Deadlines are rounded up to [`GRANULARITY`], so values are never returned before
+/// their deadlines.
+pub(crate) struct TimerWheel<T> {
+    start: Instant,
+    /// The next tick to be processed.
+    elapsed: u64,
+    levels: [Level<T>; NUMBER_OF_LEVELS as usize],
+    overflow: Vec<Entry<T>>,
+    len: usize,
+}
+
+impl<T> TimerWheel<T> {
+    /// Creates a new empty `TimerWheel` that counts ticks from `start`.
+    pub(crate) fn new(start: Instant) -> Self {
+        Self {
+            start,
+            elapsed: 0,
+            levels: std::array::from_fn(|_| Level::new()),
+            overflow: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of values in the wheel.
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the wheel is empty.
+    #[inline]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the deadlines of all values in the wheel in unspecified order.
+    pub(crate) fn deadlines(&self) -> impl Iterator<Item = Instant> + '_ {
+        self.levels
+            .iter()
+            .flat_map(|level| level.slots.iter().flatten())
+            .chain(&self.overflow)
+            .map(|entry| entry.deadline)
+    }
+
+    /// Returns the first tick that starts at or after the `instant`.
+    #[inline]
+    fn tick_at_or_after(&self, instant: Instant) -> u64 {
+        let nanos = instant.saturating_duration_since(self.start).as_nanos();
+
+        u64::try_from(nanos.div_ceil(GRANULARITY.as_nanos())).unwrap_or(u64::MAX)
+    }
+
+    /// Returns the last tick that starts at or before the `instant`.
+    #[inline]
+    fn tick_at_or_before(&self, instant: Instant) -> u64 {
+        let nanos = instant.saturating_duration_since(self.start).as_nanos();
+
+        u64::try_from(nanos / GRANULARITY.as_nanos()).unwrap_or(u64::MAX)
+    }
+
+    /// Returns the start of the `tick`.
+    #[inline]
+    #[allow(
+        clippy::cast_possible_truncation,
+        reason = "GRANULARITY is less than u64::MAX nanoseconds"
+    )]
+    fn instant_of_tick(&self, tick: u64) -> Instant {
+        self.start + Duration::from_nanos(tick.saturating_mul(GRANULARITY.as_nanos() as u64))
+    }
+
+    /// Places the entry into the slot that matches its deadline relative to `elapsed`.
+    #[inline]
+    fn place(&mut self, entry: Entry<T>) {
+        let tick = self.tick_at_or_after(entry.deadline).max(self.elapsed);
+        let level = ((self.elapsed ^ tick) | SLOT_MASK).ilog2() / SLOT_BITS;
+        if level >= NUMBER_OF_LEVELS {
+            self.overflow.push(entry);
+            return;
+        }
+
+        #[allow(clippy::cast_possible_truncation, reason = "It is masked")]
+        let slot = ((tick >> (level * SLOT_BITS)) & SLOT_MASK) as usize;
+        let level = &mut self.levels[level as usize];
+        level.slots[slot].push(entry);
+        level.occupied |= 1 << slot;
+    }
+
+    /// Inserts the value that should be returned by [`expire`](Self::expire)
+    /// after the `deadline`.
+    #[inline]
+    pub(crate) fn insert(&mut self, value: T, deadline: Instant) {
+        self.len += 1;
+        self.place(Entry { deadline, value });
+    }
+
+    /// Moves the entries of the higher levels that become due in the `tick` to the lower levels.
+    fn cascade(&mut self, tick: u64) {
+        if tick & ((1 << (NUMBER_OF_LEVELS * SLOT_BITS)) - 1) == 0 {
+            for entry in mem::take(&mut self.overflow) {
+                self.place(entry);
+            }
+        }
+
+        for level in (1..NUMBER_OF_LEVELS).rev() {
+            let shift = level * SLOT_BITS;
+            if tick & ((1 << shift) - 1) != 0 {
+                continue;
+            }
+
+            #[allow(clippy::cast_possible_truncation, reason = "It is masked")]
+            let slot = ((tick >> shift) & SLOT_MASK) as usize;
+            let level = &mut self.levels[level as usize];
+            if level.occupied & (1 << slot) != 0 {
+                for entry in level.take_slot(slot) {
+                    self.place(entry);
+                }
+            }
+        }
+    }
+
+    /// Appends all values whose deadlines have passed by `now` to `expired`
+    /// in the order of their deadlines.
+    pub(crate) fn expire(&mut self, now: Instant, expired: &mut Vec<T>) {
+        let now_tick = self.tick_at_or_before(now);
+        if self.len == 0 {
+            self.elapsed = self.elapsed.max(now_tick.saturating_add(1));
+            return;
+        }
+
+        while self.elapsed <= now_tick {
+            let tick = self.elapsed;
+            if tick & SLOT_MASK == 0 {
+                self.cascade(tick);
+            }
+
+            #[allow(clippy::cast_possible_truncation, reason = "It is masked")]
+            let slot = (tick & SLOT_MASK) as usize;
+            if self.levels[0].occupied & (1 << slot) != 0 {
+                let mut entries = self.levels[0].take_slot(slot);
+                entries.sort_unstable_by_key(|entry| entry.deadline);
+                self.len -= entries.len();
+                expired.extend(entries.into_iter().map(|entry| entry.value));
+            }
+
+            // Skip the empty slots of the lowest level up to the next cascade.
+            self.elapsed = if self.levels[0].occupied == 0 {
+                (tick | SLOT_MASK) + 1
+            } else {
+                tick + 1
+            }
+            .min(now_tick + 1);
+        }
+    }
+
+    /// Returns the nearest instant when [`expire`](Self::expire) can return a value,
+    /// or [`None`] if the wheel is empty.
+    ///
+    /// It is never later than the nearest deadline rounded up to [`GRANULARITY`].
+    pub(crate) fn next_expiration(&self) -> Option<Instant> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let mut nearest_tick = u64::MAX;
+        for (level, Level { occupied, .. }) in (0..NUMBER_OF_LEVELS).zip(&self.levels) {
+            if *occupied == 0 {
+                continue;
+            }
+
+            let shift = level * SLOT_BITS;
+            let block_start = self.elapsed & !((1 << (shift + SLOT_BITS)) - 1);
+            let tick = block_start | (u64::from(occupied.trailing_zeros()) << shift);
+            nearest_tick = nearest_tick.min(tick.max(self.elapsed));
+        }
+
+        let nearest = self.instant_of_tick(nearest_tick);
+        let nearest_in_overflow = self.overflow.iter().map(|entry| entry.deadline).min();
+
+        Some(nearest_in_overflow.map_or(nearest, |instant| instant.min(nearest)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timer_wheel_order() {
+        let start = Instant::now();
+        let mut wheel = TimerWheel::new(start);
+        let mut expired = Vec::new();
+
+        for (value, millis) in [(3, 30), (1, 10), (4, 200), (2, 10_000), (0, 0)] {
+            wheel.insert(value, start + Duration::from_millis(millis));
+        }
+        wheel.insert(5, start + Duration::from_secs(24 * 60 * 60));
+        assert_eq!(wheel.len(), 6);
+        assert_eq!(wheel.next_expiration(), Some(start));
+        let mut deadlines: Vec<_> = wheel.deadlines().collect();
+        deadlines.sort_unstable();
+        assert_eq!(deadlines.len(), 6);
+        assert_eq!(deadlines[0], start);
+        assert_eq!(deadlines[5], start + Duration::from_secs(24 * 60 * 60));
+
+        wheel.expire(start + Duration::from_millis(5), &mut expired);
+        assert_eq!(expired, vec![0]);
+
+        wheel.expire(start + Duration::from_millis(300), &mut expired);
+        assert_eq!(expired, vec![0, 1, 3, 4]);
+        assert!(wheel.next_expiration().unwrap() <= start + Duration::from_secs(10));
+
+        wheel.expire(start + Duration::from_secs(10), &mut expired);
+        assert_eq!(expired, vec![0, 1, 3, 4, 2]);
+        assert_eq!(wheel.len(), 1);
+
+        wheel.expire(start + Duration::from_secs(24 * 60 * 60 - 1), &mut expired);
+        assert_eq!(wheel.len(), 1);
+        wheel.expire(start + Duration::from_secs(24 * 60 * 60), &mut expired);
+        assert_eq!(expired, vec![0, 1, 3, 4, 2, 5]);
+        assert!(wheel.is_empty());
+        assert_eq!(wheel.next_expiration(), None);
+    }
+
+    #[test]
+    fn test_timer_wheel_expires_in_time() {
+        let start = Instant::now();
+        let mut wheel = TimerWheel::new(start);
+        let mut expired = Vec::new();
+        let deadline = |i: u64| start + Duration::from_micros(i * 7919 % 5_000_000);
+
+        for i in 0..10_000 {
+            wheel.insert(i, deadline(i));
+        }
+
+        let mut now = start;
+        while !wheel.is_empty() {
+            now += Duration::from_micros(2731);
+            wheel.expire(now, &mut expired);
+            for &i in &expired {
+                assert!(deadline(i) <= now);
+                assert!(deadline(i) + GRANULARITY + Duration::from_micros(2731) > now);
+            }
+            expired.clear();
+
+            if let Some(next_expiration) = wheel.next_expiration() {
+                assert!(next_expiration <= now + Duration::from_secs(5));
+            }
+        }
+    }
+}
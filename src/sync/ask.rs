@@ -0,0 +1,279 @@
+//! This module contains the ask channel: [`channel`], [`Requester`], [`Responder`]
+//! and [`ReplyHandle`].
+//!
+//! The ask channel implements the request-response pattern.
+use crate::runtime::call::Call;
+use crate::runtime::{local_executor, Task};
+use crate::sync::{AsyncChannel, AsyncReceiver, AsyncSender, Channel, RecvResult, SendResult};
+use crate::utils::SpinLock;
+use crate::{get_task_from_context, panic_if_local_in_future};
+use std::future::Future;
+use std::panic::{RefUnwindSafe, UnwindSafe};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+/// The state of the reply of one request.
+struct ReplyState<Resp> {
+    response: Option<Resp>,
+    waiter: Option<Task>,
+    is_handle_dropped: bool,
+}
+
+/// The slot for the reply of one request. It is shared between the [`ReplyHandle`]
+/// and the [`WaitReply`] future.
+struct ReplySlot<Resp> {
+    state: SpinLock<ReplyState<Resp>>,
+}
+
+unsafe impl<Resp: Send> Sync for ReplySlot<Resp> {}
+#[allow(
+    clippy::non_send_fields_in_send_ty,
+    reason = "We guarantee that `ReplySlot<Resp>` is `Send`"
+)]
+unsafe impl<Resp: Send> Send for ReplySlot<Resp> {}
+
+/// `ReplyHandle` delivers the response to the [`Requester`] that waits for it.
+///
+/// If it is dropped without [`replying`](Self::reply),
+/// [`Requester::ask`] returns [`None`].
+pub struct ReplyHandle<Resp> {
+    slot: Arc<ReplySlot<Resp>>,
+}
+
+impl<Resp> ReplyHandle<Resp> {
+    /// Delivers the response to the [`Requester`] and wakes it.
+    pub fn reply(self, response: Resp) {
+        let waiter = {
+            let mut state = self.slot.state.lock();
+            state.response = Some(response);
+            state.waiter.take()
+        };
+
+        if let Some(task) = waiter {
+            local_executor().spawn_shared_task(task);
+        }
+    }
+}
+
+impl<Resp> Drop for ReplyHandle<Resp> {
+    fn drop(&mut self) {
+        let waiter = {
+            let mut state = self.slot.state.lock();
+            state.is_handle_dropped = true;
+            state.waiter.take()
+        };
+
+        if let Some(task) = waiter {
+            local_executor().spawn_shared_task(task);
+        }
+    }
+}
+
+/// A [`Future`] that waits for the reply to a request.
+///
+/// Returns [`None`] if the [`ReplyHandle`] has been dropped without replying.
+struct WaitReply<Resp> {
+    slot: Arc<ReplySlot<Resp>>,
+}
+
+impl<Resp> Future for WaitReply<Resp> {
+    type Output = Option<Resp>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        panic_if_local_in_future!(cx, "Requester");
+
+        let mut state = self.slot.state.lock();
+        if let Some(response) = state.response.take() {
+            return Poll::Ready(Some(response));
+        }
+
+        if state.is_handle_dropped {
+            return Poll::Ready(None);
+        }
+
+        unsafe {
+            state.waiter = Some(get_task_from_context!(cx));
+            local_executor().invoke_call(Call::ReleaseAtomicBool(state.leak_to_atomic()));
+        }
+
+        Poll::Pending
+    }
+}
+
+impl<Resp> UnwindSafe for WaitReply<Resp> {}
+impl<Resp> RefUnwindSafe for WaitReply<Resp> {}
+
+/// The requesting half of the ask channel. It is created by [`channel`] and can be cloned.
+///
+/// Read [`channel`] for more details.
+pub struct Requester<Req, Resp> {
+    requests: Arc<Channel<(Req, ReplyHandle<Resp>)>>,
+}
+
+impl<Req, Resp> Requester<Req, Resp> {
+    /// Sends the request and waits until the [`Responder`] replies.
+    ///
+    /// Returns [`None`] if the channel is closed or if the [`ReplyHandle`] has been dropped
+    /// without replying.
+    ///
+    /// # Panics
+    ///
+    /// If it is polled by a `local` task with `debug_assertions`.
+    #[allow(
+        clippy::future_not_send,
+        reason = "It is not `Send` only when Req or Resp is not `Send`, it is fine"
+    )]
+    pub async fn ask(&self, request: Req) -> Option<Resp> {
+        let slot = Arc::new(ReplySlot {
+            state: SpinLock::new(ReplyState {
+                response: None,
+                waiter: None,
+                is_handle_dropped: false,
+            }),
+        });
+        let handle = ReplyHandle { slot: slot.clone() };
+
+        match self.requests.send((request, handle)).await {
+            SendResult::Ok => WaitReply { slot }.await,
+            SendResult::Closed(_) => None,
+        }
+    }
+}
+
+impl<Req, Resp> Clone for Requester<Req, Resp> {
+    fn clone(&self) -> Self {
+        Self {
+            requests: self.requests.clone(),
+        }
+    }
+}
+
+/// The responding half of the ask channel. It is created by [`channel`].
+///
+/// Read [`channel`] for more details.
+pub struct Responder<Req, Resp> {
+    requests: Arc<Channel<(Req, ReplyHandle<Resp>)>>,
+}
+
+impl<Req, Resp> Responder<Req, Resp> {
+    /// Receives the next request and the [`ReplyHandle`] to reply to it.
+    ///
+    /// Returns [`None`] if the channel is closed.
+    ///
+    /// # Panics
+    ///
+    /// If it is polled by a `local` task with `debug_assertions`.
+    #[allow(
+        clippy::future_not_send,
+        reason = "It is not `Send` only when Req or Resp is not `Send`, it is fine"
+    )]
+    pub async fn recv(&self) -> Option<(Req, ReplyHandle<Resp>)> {
+        match self.requests.recv().await {
+            RecvResult::Ok(request) => Some(request),
+            RecvResult::Closed => None,
+        }
+    }
+
+    /// Closes the channel. All waiting and future [`asks`](Requester::ask) return [`None`].
+    #[allow(
+        clippy::future_not_send,
+        reason = "It is not `Send` only when Req or Resp is not `Send`, it is fine"
+    )]
+    pub async fn close(&self) {
+        self.requests.close().await;
+    }
+}
+
+/// Creates an unbounded ask channel and returns its [`Requester`] and [`Responder`].
+///
+/// The ask channel implements the actor-style request-response pattern:
+/// [`Requester::ask`] sends the request and waits for the response that is delivered
+/// by the [`ReplyHandle`] received with the request by [`Responder::recv`].
+///
+/// # The ask channel and `shared` tasks
+///
+/// The ask channel can be shared between threads, so it can be used only by `shared` tasks.
+///
+/// Read [`Executor`](crate::Executor) for more details.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::sync::ask;
+///
+/// # async fn foo() {
+/// let (requester, responder) = ask::channel::<usize, usize>();
+///
+/// // in the actor task
+/// while let Some((request, reply_handle)) = responder.recv().await {
+///     reply_handle.reply(request * 2);
+/// }
+///
+/// // in a client task
+/// let response = requester.ask(21).await;
+/// assert_eq!(response, Some(42));
+/// # }
+/// ```
+pub fn channel<Req, Resp>() -> (Requester<Req, Resp>, Responder<Req, Resp>) {
+    let requests = Arc::new(Channel::unbounded());
+
+    (
+        Requester {
+            requests: requests.clone(),
+        },
+        Responder { requests },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::sync::{AsyncWaitGroup, WaitGroup};
+    use crate::test::sched_future_to_another_thread;
+
+    const PAR: usize = 10;
+
+    #[orengine::test::test_shared]
+    fn test_ask_channel() {
+        let (requester, responder) = channel::<usize, usize>();
+        let wg = Arc::new(WaitGroup::new());
+
+        for i in 0..PAR {
+            let requester = requester.clone();
+            let wg = wg.clone();
+            wg.inc();
+
+            sched_future_to_another_thread(async move {
+                assert_eq!(requester.ask(i).await, Some(i * 2));
+                wg.done();
+            });
+        }
+
+        for _ in 0..PAR {
+            let (request, reply_handle) = responder.recv().await.unwrap();
+            reply_handle.reply(request * 2);
+        }
+
+        wg.wait().await;
+    }
+
+    #[orengine::test::test_shared]
+    fn test_ask_channel_without_reply() {
+        let (requester, responder) = channel::<usize, usize>();
+        let wg = Arc::new(WaitGroup::new());
+        wg.inc();
+
+        let wg_clone = wg.clone();
+        sched_future_to_another_thread(async move {
+            drop(responder.recv().await.unwrap());
+            responder.close().await;
+            wg_clone.done();
+        });
+
+        assert_eq!(requester.ask(1).await, None);
+        wg.wait().await;
+        assert_eq!(requester.ask(2).await, None);
+    }
+}
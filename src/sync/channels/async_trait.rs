@@ -276,6 +276,11 @@ pub trait AsyncSender<T> {
     ///
     /// Else, the value is immediately sent.
     ///
+    /// It never waits, but it can wake a waiting receiver, so it must be called in a thread
+    /// of an [`Executor`](crate::Executor). Use [`CrossSender::send`](crate::sync::CrossSender::send)
+    /// of the [`CrossChannel`](crate::sync::CrossChannel) to send values from threads without
+    /// an [`Executor`](crate::Executor) such as thread pool workers.
+    ///
     /// You can find an example in [`AsyncSender::try_send`].
     ///
     /// # Example
@@ -613,6 +618,9 @@ pub trait AsyncReceiver<T> {
     ///
     /// Else, the value is immediately received.
     ///
+    /// It never waits, but it can wake a waiting sender, so it must be called in a thread
+    /// of an [`Executor`](crate::Executor).
+    ///
     /// # Example
     ///
     /// ```rust
@@ -0,0 +1,352 @@
+//! This module contains [`CrossChannel`].
+use crate::future::AsyncIterator;
+use crate::get_task_from_context;
+use crate::runtime::executor::get_local_executor_ref;
+use crate::runtime::interaction_between_executors::SyncBatchOptimizedTaskQueue;
+use crate::runtime::{local_executor, Task};
+use crate::sync::SendResult;
+use crate::utils::SpinLock;
+use crossbeam::queue::SegQueue;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+/// The receiver's task that waits for a value.
+struct WaitingReceiver {
+    task: Task,
+    executor_id: usize,
+    task_queue: Arc<SyncBatchOptimizedTaskQueue>,
+}
+
+impl WaitingReceiver {
+    /// Wakes the task on the executor that parked it.
+    ///
+    /// It can be called in a thread without an [`Executor`](crate::Executor).
+    #[inline]
+    fn wake(self) {
+        if let Some(executor) = get_local_executor_ref()
+            .as_mut()
+            .filter(|executor| executor.id() == self.executor_id)
+        {
+            executor.spawn_task(self.task);
+        } else {
+            self.task_queue.append_task(
+                self.task,
+                #[cfg(debug_assertions)]
+                self.executor_id,
+            );
+        }
+    }
+}
+
+/// This is the internal data structure for the [`cross channel`](CrossChannel).
+struct Inner<T> {
+    queue: SegQueue<T>,
+    waiting_receiver: SpinLock<Option<WaitingReceiver>>,
+    number_of_senders: AtomicUsize,
+    is_receiver_dropped: AtomicBool,
+}
+
+impl<T> Inner<T> {
+    /// Wakes the waiting receiver if it exists.
+    #[inline]
+    fn wake_receiver(&self) {
+        let waiting_receiver = self.waiting_receiver.lock().take();
+        if let Some(waiting_receiver) = waiting_receiver {
+            waiting_receiver.wake();
+        }
+    }
+}
+
+unsafe impl<T: Send> Sync for Inner<T> {}
+#[allow(
+    clippy::non_send_fields_in_send_ty,
+    reason = "We guarantee that `Inner<T>` is `Send`"
+)]
+unsafe impl<T: Send> Send for Inner<T> {}
+
+/// This struct represents a future that waits for a value to be
+/// received from the [`cross channel`](CrossChannel).
+///
+/// If the channel is empty, the task is parked and is woken by the sender
+/// via the task queue of the executor that parked it.
+pub struct WaitCrossRecv<'future, T> {
+    inner: &'future Inner<T>,
+}
+
+impl<T> Future for WaitCrossRecv<'_, T> {
+    type Output = Option<T>;
+
+    #[inline]
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let inner = self.inner;
+        if let Some(value) = inner.queue.pop() {
+            return Poll::Ready(Some(value));
+        }
+
+        let mut waiting_receiver = inner.waiting_receiver.lock();
+        // The sender pushes the value before locking `waiting_receiver`,
+        // so we need to check the queue again with the lock.
+        if let Some(value) = inner.queue.pop() {
+            return Poll::Ready(Some(value));
+        }
+
+        if inner.number_of_senders.load(Ordering::Acquire) == 0 {
+            return Poll::Ready(None);
+        }
+
+        let executor = local_executor();
+        *waiting_receiver = Some(WaitingReceiver {
+            task: unsafe { get_task_from_context!(cx) },
+            executor_id: executor.id(),
+            task_queue: executor.interactor().shared_task_list(),
+        });
+
+        Poll::Pending
+    }
+}
+
+unsafe impl<T: Send> Send for WaitCrossRecv<'_, T> {}
+
+/// The sending half of the [`CrossChannel`]. It can be cloned and sent to other executors.
+///
+/// Read [`CrossChannel`] for more details.
+pub struct CrossSender<T> {
+    inner: Arc<Inner<T>>,
+}
+
+impl<T> CrossSender<T> {
+    /// Sends the value to the [`CrossReceiver`] and wakes it if it waits for a value.
+    ///
+    /// It never waits, because the channel is unbounded, so it can be called
+    /// in a thread without an [`Executor`](crate::Executor).
+    ///
+    /// Returns [`SendResult::Closed`] with the value if the [`CrossReceiver`] has been dropped.
+    #[inline]
+    pub fn send(&self, value: T) -> SendResult<T> {
+        if self.inner.is_receiver_dropped.load(Ordering::Acquire) {
+            return SendResult::Closed(value);
+        }
+
+        self.inner.queue.push(value);
+        self.inner.wake_receiver();
+
+        SendResult::Ok
+    }
+}
+
+impl<T> Clone for CrossSender<T> {
+    fn clone(&self) -> Self {
+        self.inner.number_of_senders.fetch_add(1, Ordering::Relaxed);
+
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> Drop for CrossSender<T> {
+    fn drop(&mut self) {
+        if self.inner.number_of_senders.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.inner.wake_receiver();
+        }
+    }
+}
+
+/// The receiving half of the [`CrossChannel`]. It can be sent to another executor.
+///
+/// Read [`CrossChannel`] for more details.
+pub struct CrossReceiver<T> {
+    inner: Arc<Inner<T>>,
+}
+
+impl<T> CrossReceiver<T> {
+    /// Returns a future that receives a value from the channel.
+    ///
+    /// The future returns [`None`] if all [`CrossSenders`](CrossSender) have been dropped
+    /// and the channel is empty.
+    #[inline]
+    pub fn recv(&mut self) -> WaitCrossRecv<'_, T> {
+        WaitCrossRecv { inner: &self.inner }
+    }
+
+    /// Returns a value from the channel if it is not empty.
+    #[inline]
+    pub fn try_recv(&mut self) -> Option<T> {
+        self.inner.queue.pop()
+    }
+}
+
+impl<T> AsyncIterator for CrossReceiver<T> {
+    type Item = T;
+
+    #[allow(
+        clippy::future_not_send,
+        reason = "It is not `Send` only when T is not `Send`, it is fine"
+    )]
+    async fn next(&mut self) -> Option<T> {
+        self.recv().await
+    }
+}
+
+impl<T> Drop for CrossReceiver<T> {
+    fn drop(&mut self) {
+        self.inner
+            .is_receiver_dropped
+            .store(true, Ordering::Release);
+    }
+}
+
+/// An unbounded channel for sending values between executors.
+///
+/// Unlike the [`Channel`](crate::sync::Channel), it can be used by `local` tasks,
+/// because only the receiver can wait: the sender pushes the value into a lock-free queue and,
+/// if the receiver waits for a value, sends its task to the task queue of the executor
+/// that parked it.
+///
+/// It is split into one [`CrossReceiver`] and [`CrossSenders`](CrossSender) which can be cloned.
+///
+/// # Example
+///
+/// ```no_run
+/// use orengine::sync::CrossChannel;
+/// use orengine::runtime::spawn_on_executor;
+///
+/// # async fn foo(another_executor_id: usize) {
+/// let (sender, mut receiver) = CrossChannel::new().split();
+///
+/// spawn_on_executor(another_executor_id, async move {
+///     for i in 0..10 {
+///         sender.send(i).unwrap();
+///     }
+/// }).unwrap();
+///
+/// while let Some(value) = receiver.recv().await {
+///     println!("{value}");
+/// }
+/// # }
+/// ```
+pub struct CrossChannel<T> {
+    inner: Arc<Inner<T>>,
+}
+
+impl<T> CrossChannel<T> {
+    /// Creates a new `CrossChannel`.
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                queue: SegQueue::new(),
+                waiting_receiver: SpinLock::new(None),
+                number_of_senders: AtomicUsize::new(1),
+                is_receiver_dropped: AtomicBool::new(false),
+            }),
+        }
+    }
+
+    /// Splits the channel into the [`CrossSender`] and the [`CrossReceiver`].
+    pub fn split(self) -> (CrossSender<T>, CrossReceiver<T>) {
+        (
+            CrossSender {
+                inner: self.inner.clone(),
+            },
+            CrossReceiver { inner: self.inner },
+        )
+    }
+}
+
+impl<T> Default for CrossChannel<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl<T: Send> Send for CrossChannel<T> {}
+unsafe impl<T: Send> Send for CrossSender<T> {}
+unsafe impl<T: Send> Sync for CrossSender<T> {}
+unsafe impl<T: Send> Send for CrossReceiver<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::runtime::Config;
+    use crate::{stop_executor, yield_now, Executor};
+    use std::thread;
+
+    #[orengine::test::test_local]
+    fn test_cross_channel_same_executor() {
+        let (sender, mut receiver) = CrossChannel::new().split();
+
+        local_executor().spawn_local(async move {
+            for i in 0..10 {
+                sender.send(i).unwrap();
+                yield_now().await;
+            }
+        });
+
+        let mut res = Vec::new();
+        while let Some(value) = receiver.recv().await {
+            res.push(value);
+        }
+        assert_eq!(res, (0..10).collect::<Vec<_>>());
+    }
+
+    #[orengine::test::test_local]
+    fn test_cross_channel_between_executors() {
+        const N: usize = 1000;
+
+        let (sender, mut receiver) = CrossChannel::new().split();
+
+        thread::spawn(move || {
+            let ex = Executor::init_with_config(Config::default().disable_work_sharing());
+            let id = ex.id();
+            ex.spawn_local(async move {
+                for i in 0..N {
+                    sender.send(i).unwrap();
+                    if i % 10 == 0 {
+                        yield_now().await;
+                    }
+                }
+
+                drop(sender);
+                stop_executor(id);
+            });
+            ex.run();
+        });
+
+        let mut res = Vec::with_capacity(N);
+        while let Some(value) = receiver.recv().await {
+            res.push(value);
+        }
+        assert_eq!(res, (0..N).collect::<Vec<_>>());
+
+        let (sender, receiver) = CrossChannel::new().split();
+        drop(receiver);
+        assert!(matches!(sender.send(1), SendResult::Closed(1)));
+    }
+
+    #[orengine::test::test_local]
+    fn test_cross_channel_from_thread_without_executor() {
+        const N: usize = 100;
+
+        let (sender, mut receiver) = CrossChannel::new().split();
+
+        thread::spawn(move || {
+            for i in 0..N {
+                sender.send(i).unwrap();
+                if i % 10 == 0 {
+                    // Let the receiver wait, so the sender wakes it.
+                    thread::sleep(std::time::Duration::from_millis(1));
+                }
+            }
+        });
+
+        let mut res = Vec::with_capacity(N);
+        while let Some(value) = receiver.recv().await {
+            res.push(value);
+        }
+        assert_eq!(res, (0..N).collect::<Vec<_>>());
+    }
+}
@@ -1,10 +1,11 @@
+use crate::future::AsyncIterator;
 use crate::get_task_from_context;
 use crate::runtime::local_executor;
 use crate::sync::channels::pools::{channel_inner_vec_deque_pool, DequesPoolGuard};
 use crate::sync::channels::states::{RecvCallState, SendCallState};
 use crate::sync::{
-    AsyncChannel, AsyncReceiver, AsyncSender, RecvInResult, SendResult, TryRecvInResult,
-    TrySendResult,
+    AsyncChannel, AsyncReceiver, AsyncSender, RecvInResult, RecvResult, SendResult,
+    TryRecvInResult, TrySendResult,
 };
 use std::cell::UnsafeCell;
 use std::collections::VecDeque;
@@ -420,6 +421,18 @@ impl<T> AsyncReceiver<T> for LocalReceiver<'_, T> {
     }
 }
 
+impl<T> AsyncIterator for LocalReceiver<'_, T> {
+    type Item = T;
+
+    #[allow(clippy::future_not_send, reason = "Because it is `local`")]
+    async fn next(&mut self) -> Option<T> {
+        match self.recv().await {
+            RecvResult::Ok(value) => Some(value),
+            RecvResult::Closed => None,
+        }
+    }
+}
+
 impl<T> Clone for LocalReceiver<'_, T> {
     fn clone(&self) -> Self {
         LocalReceiver {
@@ -1,9 +1,13 @@
 pub mod async_trait;
+#[cfg(not(feature = "disable_send_task_to"))]
+pub mod cross;
 pub mod local;
 pub(super) mod pools;
 pub mod shared;
 pub(super) mod states;
 
 pub use async_trait::*;
+#[cfg(not(feature = "disable_send_task_to"))]
+pub use cross::*;
 pub use local::*;
 pub use shared::*;
@@ -1,19 +1,24 @@
+use crate::future::AsyncIterator;
 use crate::runtime::call::Call;
 use crate::runtime::local_executor;
 use crate::sync::channels::pools::{channel_inner_vec_deque_pool, DequesPoolGuard};
 use crate::sync::channels::states::{RecvCallState, SendCallState};
-use crate::sync::mutexes::naive_shared::NaiveMutex;
+use crate::sync::mutexes::naive_shared::{NaiveMutex, NaiveMutexGuard};
 use crate::sync::{
-    AsyncChannel, AsyncMutex, AsyncReceiver, AsyncSender, RecvInResult, SendResult,
+    AsyncChannel, AsyncMutex, AsyncReceiver, AsyncSender, RecvInResult, RecvResult, SendResult,
     TryRecvInResult, TrySendResult,
 };
-use crate::{get_task_from_context, panic_if_local_in_future};
+use crate::{get_task_from_context, panic_if_local_in_future, yield_now};
+use crossbeam::utils::CachePadded;
 use std::collections::VecDeque;
 use std::future::Future;
 use std::mem::ManuallyDrop;
+use std::ops::Deref;
 use std::panic::{RefUnwindSafe, UnwindSafe};
 use std::ptr;
 use std::ptr::copy_nonoverlapping;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering::{Acquire, Release};
 use std::task::{Context, Poll};
 
 /// This is the internal data structure for the [`channel`](Channel).
@@ -22,10 +27,47 @@ use std::task::{Context, Poll};
 struct Inner<T> {
     storage: VecDeque<T>,
     is_closed: bool,
-    capacity: usize,
     deques: DequesPoolGuard<T>,
 }
 
+/// The state of the [`channel`](Channel): the locked [`Inner`], the capacity and the number
+/// of values in the storage that can be read without the lock.
+struct State<T> {
+    inner: NaiveMutex<Inner<T>>,
+    len: CachePadded<AtomicUsize>,
+    capacity: usize,
+}
+
+impl<T> State<T> {
+    /// Creates a new [`State`] with the provided capacity.
+    fn new(capacity: usize) -> Self {
+        Self {
+            inner: NaiveMutex::new(Inner {
+                storage: VecDeque::with_capacity(if capacity == usize::MAX { 0 } else { capacity }),
+                is_closed: false,
+                deques: channel_inner_vec_deque_pool().get(),
+            }),
+            len: CachePadded::new(AtomicUsize::new(0)),
+            capacity,
+        }
+    }
+
+    /// Updates the lock-free number of values. It must be called with the lock held
+    /// after every change of the storage.
+    #[inline]
+    fn update_len(&self, inner_lock: &NaiveMutexGuard<'_, Inner<T>>) {
+        self.len.store(inner_lock.storage.len(), Release);
+    }
+}
+
+impl<T> Deref for State<T> {
+    type Target = NaiveMutex<Inner<T>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
 unsafe impl<T: Send> Sync for Inner<T> {}
 #[allow(
     clippy::non_send_fields_in_send_ty,
@@ -71,7 +113,7 @@ macro_rules! acquire_lock {
 /// If [`WaitSend::poll`] is not called.
 #[repr(C)]
 pub struct WaitSend<'future, T> {
-    inner: &'future NaiveMutex<Inner<T>>,
+    inner: &'future State<T>,
     call_state: SendCallState,
     value: ManuallyDrop<T>,
     #[cfg(debug_assertions)]
@@ -81,7 +123,7 @@ pub struct WaitSend<'future, T> {
 impl<'future, T> WaitSend<'future, T> {
     /// Creates a new [`WaitSend`].
     #[inline]
-    fn new(value: T, inner: &'future NaiveMutex<Inner<T>>) -> Self {
+    fn new(value: T, inner: &'future State<T>) -> Self {
         Self {
             inner,
             call_state: SendCallState::FirstCall,
@@ -127,7 +169,7 @@ impl<T> Future for WaitSend<'_, T> {
                 }
 
                 let len = inner_lock.storage.len();
-                if len >= inner_lock.capacity {
+                if len >= this.inner.capacity {
                     let task = unsafe { get_task_from_context!(cx) };
                     inner_lock.deques.senders.push_back((
                         task,
@@ -142,6 +184,7 @@ impl<T> Future for WaitSend<'_, T> {
                         .storage
                         .push_back(ManuallyDrop::take(&mut this.value));
                 }
+                this.inner.update_len(&inner_lock);
 
                 Poll::Ready(SendResult::Ok)
             }
@@ -174,7 +217,7 @@ impl<T> Drop for WaitSend<'_, T> {
 /// gets parked in the list of waiting receivers.
 #[repr(C)]
 pub struct WaitRecv<'future, T> {
-    inner: &'future NaiveMutex<Inner<T>>,
+    inner: &'future State<T>,
     call_state: RecvCallState,
     slot: *mut T,
 }
@@ -182,7 +225,7 @@ pub struct WaitRecv<'future, T> {
 impl<'future, T> WaitRecv<'future, T> {
     /// Creates a new [`WaitRecv`].
     #[inline]
-    fn new(inner: &'future NaiveMutex<Inner<T>>, slot: *mut T) -> Self {
+    fn new(inner: &'future State<T>, slot: *mut T) -> Self {
         Self {
             inner,
             call_state: RecvCallState::FirstCall,
@@ -242,6 +285,8 @@ impl<T> Future for WaitRecv<'_, T> {
                         call_state.write(SendCallState::WokenToReturnReady);
                         local_executor().exec_task(task);
                     }
+                } else {
+                    this.inner.update_len(&inner_lock);
                 }
 
                 Poll::Ready(RecvInResult::Ok)
@@ -266,7 +311,7 @@ impl<T: RefUnwindSafe> RefUnwindSafe for WaitRecv<'_, T> {}
     clippy::future_not_send,
     reason = "It is not `Send` only when T is not `Send`, it is fine"
 )]
-async fn close<T>(inner: &NaiveMutex<Inner<T>>) {
+async fn close<T>(inner: &State<T>) {
     let mut inner_lock = inner.lock().await;
     inner_lock.is_closed = true;
     let executor = local_executor();
@@ -286,6 +331,56 @@ async fn close<T>(inner: &NaiveMutex<Inner<T>>) {
     }
 }
 
+// region peek
+
+/// A reference to the next value of the [`Channel`] returned by
+/// [`Channel::peek`] and [`Receiver::peek`].
+///
+/// The value stays in the channel. While the `PeekRef` is alive, the channel is locked,
+/// so other tasks that call `send` or `recv` wait until it is dropped.
+/// Therefore, drop it as soon as possible and never hold it across an `.await`.
+pub struct PeekRef<'channel, T> {
+    inner_lock: NaiveMutexGuard<'channel, Inner<T>>,
+}
+
+impl<T> Deref for PeekRef<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        // The value of a waiting sender stays valid until it is woken up,
+        // and it can't be woken up while the channel is locked.
+        self.inner_lock.storage.front().unwrap_or_else(|| unsafe {
+            &*self.inner_lock.deques.senders.front().unwrap_unchecked().2
+        })
+    }
+}
+
+/// Waits for a value in the [`channel`](Channel) and returns a [`PeekRef`] to it
+/// without consuming it.
+#[allow(
+    clippy::future_not_send,
+    reason = "It is not `Send` only when T is not `Send`, it is fine"
+)]
+async fn peek<T>(inner: &State<T>) -> RecvResult<PeekRef<'_, T>> {
+    loop {
+        if let Some(inner_lock) = inner.try_lock() {
+            if inner_lock.is_closed {
+                return RecvResult::Closed;
+            }
+
+            // The storage is empty while senders wait only in zero-capacity channels.
+            // Their values are peeked in place, so the capacity is never exceeded.
+            if !inner_lock.storage.is_empty() || !inner_lock.deques.senders.is_empty() {
+                return RecvResult::Ok(PeekRef { inner_lock });
+            }
+        }
+
+        yield_now().await;
+    }
+}
+
+// endregion
+
 macro_rules! generate_try_send {
     () => {
         fn try_send(&self, value: T) -> TrySendResult<T> {
@@ -309,11 +404,12 @@ macro_rules! generate_try_send {
                     }
 
                     let len = inner_lock.storage.len();
-                    if len >= inner_lock.capacity {
+                    if len >= self.inner.capacity {
                         return TrySendResult::Full(value);
                     }
 
                     inner_lock.storage.push_back(value);
+                    self.inner.update_len(&inner_lock);
 
                     TrySendResult::Ok
                 }
@@ -347,13 +443,13 @@ macro_rules! generate_try_send {
 /// }
 /// ```
 pub struct Sender<'channel, T> {
-    inner: &'channel NaiveMutex<Inner<T>>,
+    inner: &'channel State<T>,
 }
 
 impl<'channel, T> Sender<'channel, T> {
     /// Creates a new [`Sender`].
     #[inline]
-    fn new(inner: &'channel NaiveMutex<Inner<T>>) -> Self {
+    fn new(inner: &'channel State<T>) -> Self {
         Self { inner }
     }
 }
@@ -430,6 +526,8 @@ macro_rules! generate_try_recv_in {
                             call_state.write(SendCallState::WokenToReturnReady);
                             local_executor().exec_task(task);
                         }
+                    } else {
+                        self.inner.update_len(&inner_lock);
                     }
 
                     TryRecvInResult::Ok
@@ -463,15 +561,27 @@ macro_rules! generate_try_recv_in {
 ///     assert_eq!(res, 1);
 /// }
 pub struct Receiver<'channel, T> {
-    inner: &'channel NaiveMutex<Inner<T>>,
+    inner: &'channel State<T>,
 }
 
 impl<'channel, T> Receiver<'channel, T> {
     /// Creates a new [`Receiver`].
     #[inline]
-    fn new(inner: &'channel NaiveMutex<Inner<T>>) -> Self {
+    fn new(inner: &'channel State<T>) -> Self {
         Self { inner }
     }
+
+    /// Waits for the next value of the [`Channel`] and returns a [`PeekRef`] to it
+    /// without consuming it.
+    ///
+    /// Read [`Channel::peek`] for more details.
+    #[allow(
+        clippy::future_not_send,
+        reason = "It is not `Send` only when T is not `Send`, it is fine"
+    )]
+    pub async fn peek(&self) -> RecvResult<PeekRef<'channel, T>> {
+        peek(self.inner).await
+    }
 }
 
 impl<T> AsyncReceiver<T> for Receiver<'_, T> {
@@ -494,6 +604,21 @@ impl<T> AsyncReceiver<T> for Receiver<'_, T> {
     }
 }
 
+impl<T> AsyncIterator for Receiver<'_, T> {
+    type Item = T;
+
+    #[allow(
+        clippy::future_not_send,
+        reason = "It is not `Send` only when T is not `Send`, it is fine"
+    )]
+    async fn next(&mut self) -> Option<T> {
+        match self.recv().await {
+            RecvResult::Ok(value) => Some(value),
+            RecvResult::Closed => None,
+        }
+    }
+}
+
 impl<T> Clone for Receiver<'_, T> {
     fn clone(&self) -> Self {
         Receiver { inner: self.inner }
@@ -559,7 +684,94 @@ impl<T: RefUnwindSafe> RefUnwindSafe for Receiver<'_, T> {}
 /// }
 /// ```
 pub struct Channel<T> {
-    inner: NaiveMutex<Inner<T>>,
+    inner: State<T>,
+}
+
+impl<T> Channel<T> {
+    /// Returns the number of values in the [`channel`](Channel).
+    ///
+    /// It doesn't lock the channel, so the value can be outdated by the time it is used.
+    /// It doesn't count values of senders that wait for capacity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::sync::Channel;
+    ///
+    /// const MAX_BACKLOG: usize = 1024;
+    ///
+    /// fn should_shed_load<T>(requests: &Channel<T>) -> bool {
+    ///     requests.len() >= MAX_BACKLOG
+    /// }
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len.load(Acquire)
+    }
+
+    /// Returns the capacity of the [`channel`](Channel).
+    ///
+    /// It is [`usize::MAX`] for [`unbounded`](AsyncChannel::unbounded) channels.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity
+    }
+
+    /// Returns whether the [`channel`](Channel) has no values.
+    ///
+    /// Read [`len`](Self::len) for more details.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns whether the [`channel`](Channel) has no capacity for new values,
+    /// so the next [`send`](AsyncSender::send) waits.
+    ///
+    /// Read [`len`](Self::len) for more details.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len() >= self.capacity()
+    }
+
+    /// Waits for the next value of the [`channel`](Channel) and returns a [`PeekRef`] to it
+    /// without consuming it. Returns [`RecvResult::Closed`] if the channel is closed.
+    ///
+    /// The value is received by the next `recv` after the [`PeekRef`] is dropped.
+    /// While the [`PeekRef`] is alive, other tasks that call `send` or `recv` wait.
+    ///
+    /// It waits for a value by yielding, so it is not supposed to be used to wait
+    /// for values of a rarely used channel.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::sync::{AsyncReceiver, Channel};
+    ///
+    /// enum Message {
+    ///     Ping,
+    ///     Data(Vec<u8>),
+    /// }
+    ///
+    /// # async fn handle_data(channel: &Channel<Message>) {}
+    ///
+    /// async fn dispatch(channel: &Channel<Message>) {
+    ///     let is_data = matches!(*channel.peek().await.unwrap(), Message::Data(_));
+    ///
+    ///     if is_data {
+    ///         handle_data(channel).await;
+    ///     } else {
+    ///         channel.recv().await.unwrap();
+    ///     }
+    /// }
+    /// ```
+    #[allow(
+        clippy::future_not_send,
+        reason = "It is not `Send` only when T is not `Send`, it is fine"
+    )]
+    pub async fn peek(&self) -> RecvResult<PeekRef<'_, T>> {
+        peek(&self.inner).await
+    }
 }
 
 impl<T> AsyncChannel<T> for Channel<T> {
@@ -592,23 +804,13 @@ impl<T> AsyncChannel<T> for Channel<T> {
     /// ```
     fn bounded(capacity: usize) -> Self {
         Self {
-            inner: NaiveMutex::new(Inner {
-                storage: VecDeque::with_capacity(capacity),
-                capacity,
-                is_closed: false,
-                deques: channel_inner_vec_deque_pool().get(),
-            }),
+            inner: State::new(capacity),
         }
     }
 
     fn unbounded() -> Self {
         Self {
-            inner: NaiveMutex::new(Inner {
-                storage: VecDeque::with_capacity(0),
-                capacity: usize::MAX,
-                is_closed: false,
-                deques: channel_inner_vec_deque_pool().get(),
-            }),
+            inner: State::new(usize::MAX),
         }
     }
 
@@ -759,6 +961,74 @@ mod tests {
     use crate::utils::{get_core_ids, SpinLock};
     use crate::{local_executor, sleep, yield_now};
 
+    #[orengine::test::test_shared]
+    fn test_shared_channel_peek() {
+        let ch = Arc::new(Channel::bounded(2));
+        ch.send(1).await.unwrap();
+        ch.send(2).await.unwrap();
+
+        {
+            let peeked = ch.peek().await.unwrap();
+            assert_eq!(*peeked, 1);
+            assert!(matches!(ch.try_recv(), TryRecvResult::Locked));
+        }
+        assert_eq!(ch.recv().await.unwrap(), 1);
+        assert_eq!(*ch.split().1.peek().await.unwrap(), 2);
+        assert_eq!(ch.recv().await.unwrap(), 2);
+
+        let ch_clone = ch.clone();
+        sched_future_to_another_thread(async move {
+            sleep(Duration::from_millis(1)).await;
+            ch_clone.send(3).await.unwrap();
+        });
+        assert_eq!(*ch.peek().await.unwrap(), 3);
+        assert_eq!(ch.recv().await.unwrap(), 3);
+
+        ch.close().await;
+        assert!(matches!(ch.peek().await, RecvResult::Closed));
+    }
+
+    #[orengine::test::test_shared]
+    fn test_shared_channel_len() {
+        let ch = Channel::bounded(2);
+        assert_eq!(ch.capacity(), 2);
+        assert!(ch.is_empty());
+
+        ch.send(1).await.unwrap();
+        assert_eq!(ch.len(), 1);
+        assert!(!ch.is_full());
+
+        assert!(matches!(ch.try_send(2), TrySendResult::Ok));
+        assert_eq!(ch.len(), 2);
+        assert!(ch.is_full());
+
+        assert_eq!(ch.recv().await.unwrap(), 1);
+        assert_eq!(ch.len(), 1);
+        assert!(matches!(ch.try_recv(), TryRecvResult::Ok(2)));
+        assert!(ch.is_empty());
+
+        let unbounded = Channel::<usize>::unbounded();
+        assert_eq!(unbounded.capacity(), usize::MAX);
+        assert!(!unbounded.is_full());
+    }
+
+    #[orengine::test::test_shared]
+    fn test_zero_capacity_shared_channel_peek() {
+        let ch = Arc::new(Channel::bounded(0));
+        let ch_clone = ch.clone();
+
+        sched_future_to_another_thread(async move {
+            ch_clone.send(1).await.unwrap();
+        });
+
+        let peeked = ch.peek().await.unwrap();
+        assert_eq!(*peeked, 1);
+        drop(peeked);
+        assert_eq!(ch.len(), 0);
+        assert!(ch.is_full());
+        assert_eq!(ch.recv().await.unwrap(), 1);
+    }
+
     #[orengine::test::test_shared]
     fn test_zero_capacity_shared_channel() {
         let ch = Arc::new(Channel::bounded(0));
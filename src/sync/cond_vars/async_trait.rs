@@ -73,6 +73,10 @@ pub trait AsyncCondVar {
     /// mutex is unlocked are candidates to wake this [`Task`] up. When this
     /// function call returns, the lock specified will have been re-acquired.
     ///
+    /// Prefer it to a single [`wait`](Self::wait): the task can be woken up
+    /// while the condition is still not met, for example, by [`notify_all`]
+    /// or because another task changed the state before this task re-acquired the lock.
+    ///
     /// [`notify_one`]: Self::notify_one
     /// [`notify_all`]: Self::notify_all
     /// [`Task`]: crate::runtime::Task
@@ -110,7 +114,7 @@ pub trait AsyncCondVar {
     async fn wait_while<'mutex, T>(
         &self,
         guard: <Self::SubscribableMutex<T> as AsyncMutex<T>>::Guard<'mutex>,
-        predicate: impl Fn(&mut T) -> bool,
+        mut predicate: impl FnMut(&mut T) -> bool,
     ) -> <Self::SubscribableMutex<T> as AsyncMutex<T>>::Guard<'mutex>
     where
         T: ?Sized + 'mutex,
@@ -125,6 +129,11 @@ pub trait AsyncCondVar {
 
     /// Notifies one waiting task.
     ///
+    /// It wakes up at most one task, so it is cheap and doesn't wake up tasks
+    /// that will go back to waiting. Use it when any of the waiting tasks can handle
+    /// the change, for example, when one item is added to a queue.
+    /// Use [`notify_all`](Self::notify_all) when every waiting task must see the change.
+    ///
     /// # Attention
     ///
     /// Drop a lock before call [`notify_one`](Self::notify_one).
@@ -149,6 +158,12 @@ pub trait AsyncCondVar {
 
     /// Notifies all waiting tasks.
     ///
+    /// It wakes up all tasks that were waiting when it was called. Tasks that start waiting
+    /// while they are being woken up are not notified by this call.
+    /// Use it when the change affects every waiting task, for example, when a shutdown
+    /// flag is set or when waiting tasks check different conditions.
+    /// Otherwise, prefer [`notify_one`](Self::notify_one).
+    ///
     /// # Attention
     ///
     /// Drop a lock before call [`notify_all`](Self::notify_all).
@@ -202,4 +217,34 @@ mod tests {
         })
         .await;
     }
+
+    #[orengine::test::test_local]
+    fn test_cond_var_wait_while_multiple_notifications() {
+        const NEEDED: usize = 3;
+
+        let cvar = LocalCondVar::new();
+        let counter = LocalMutex::new(0);
+        local_scope(|scope| async {
+            scope.spawn(async {
+                for _ in 0..NEEDED {
+                    sleep(Duration::from_millis(1)).await;
+
+                    *counter.lock().await += 1;
+                    cvar.notify_one();
+                }
+            });
+
+            let mut number_of_checks = 0;
+            let counter_lock = cvar
+                .wait_while(counter.lock().await, |counter| {
+                    number_of_checks += 1;
+                    *counter < NEEDED
+                })
+                .await;
+
+            assert_eq!(*counter_lock, NEEDED);
+            assert_eq!(number_of_checks, NEEDED + 1);
+        })
+        .await;
+    }
 }
@@ -6,6 +6,7 @@ use crate::utils::{acquire_task_vec_from_pool, TaskVecFromPool};
 use std::cell::UnsafeCell;
 use std::future::Future;
 use std::marker::PhantomData;
+use std::mem;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
@@ -13,7 +14,8 @@ use std::task::{Context, Poll};
 enum WaitState {
     /// Default state.
     Sleep,
-    /// The [`WaitLocalCondVar`] is parked and will be woken up when [`LocalCondVar::notify`] is called.
+    /// The [`WaitLocalCondVar`] is parked and will be woken up when
+    /// [`LocalCondVar::notify_one`] or [`LocalCondVar::notify_all`] is called.
     Wake,
     /// The [`WaitLocalCondVar`] has been woken up, and it is parked on a [`LocalMutex`],
     /// because the [`LocalMutex`] is locked.
@@ -181,8 +183,13 @@ impl AsyncCondVar for LocalCondVar {
 
     fn notify_all(&self) {
         let executor = local_executor();
-        let wait_queue = unsafe { &mut *self.wait_queue.get() };
-        while let Some(task) = wait_queue.pop() {
+        // Take the whole wait queue first, because woken tasks are executed immediately
+        // and can wait again. They must not be woken up by the same notification.
+        let mut waiters = mem::replace(
+            unsafe { &mut *self.wait_queue.get() },
+            acquire_task_vec_from_pool(),
+        );
+        for task in waiters.drain(..) {
             executor.exec_task(task);
         }
     }
@@ -330,6 +337,34 @@ mod tests {
         assert!(start.elapsed() >= TIME_TO_SLEEP);
     }
 
+    #[orengine::test::test_local]
+    fn test_local_cond_var_notify_all_wakes_each_waiter_once() {
+        const NUMBER_OF_WAITERS: usize = 5;
+
+        let pair = Rc::new((LocalMutex::new(0), LocalCondVar::new()));
+        for _ in 0..NUMBER_OF_WAITERS {
+            let pair = pair.clone();
+            local_executor().spawn_local(async move {
+                let (lock, cvar) = &*pair;
+                let mut number_of_wakes = lock.lock().await;
+                loop {
+                    number_of_wakes = cvar.wait(number_of_wakes).await;
+                    *number_of_wakes += 1;
+                }
+            });
+        }
+
+        // Let all waiters start waiting.
+        crate::yield_now().await;
+
+        let (lock, cvar) = &*pair;
+        cvar.notify_all();
+        assert_eq!(*lock.lock().await, NUMBER_OF_WAITERS);
+
+        cvar.notify_one();
+        assert_eq!(*lock.lock().await, NUMBER_OF_WAITERS + 1);
+    }
+
     #[orengine::test::test_local]
     fn test_local_cond_var_notify_one_with_drop_guard() {
         test_notify_one(true).await;
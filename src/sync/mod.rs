@@ -1,33 +1,44 @@
+#[cfg(not(feature = "disable_send_task_to"))]
+pub use channels::cross::{CrossChannel, CrossReceiver, CrossSender};
 pub use channels::{
     async_trait::*,
     local::{LocalChannel, LocalReceiver, LocalSender},
-    shared::{Channel, Receiver, Sender},
+    shared::{Channel, PeekRef, Receiver, Sender},
 };
 pub use cond_vars::{async_trait::*, local::LocalCondVar, shared::CondVar};
 pub use mutexes::{
     async_trait::*,
-    local::{LocalMutex, LocalMutexGuard},
+    local::{LocalMutex, LocalMutexGuard, LocalMutexOwnedGuard},
     naive_shared::{NaiveMutex, NaiveMutexGuard},
     smart_shared::{Mutex, MutexGuard},
     subscribable_trait::AsyncSubscribableMutex,
 };
-pub use onces::{async_trait::*, local::LocalOnce, shared::Once, state::*};
+pub use onces::{
+    async_trait::*, local::LocalOnce, local_cell::LocalOnceCell, local_lazy::LocalLazy,
+    shared::Once, state::*,
+};
+pub use rcu::{LocalRcu, LocalRcuReadGuard};
 pub use rw_locks::{
     async_trait::*,
     local::{LocalRWLock, LocalReadLockGuard, LocalWriteLockGuard},
     lock_status::*,
-    naive_shared::{RWLock, ReadLockGuard, WriteLockGuard},
+    naive_shared::{RWLock, RWLockConfig, ReadLockGuard, WriteLockGuard},
 };
 pub use scopes::{
     local::{local_scope, LocalScope},
     shared::{shared_scope, Scope},
 };
 pub use wait_groups::{async_trait::*, local::LocalWaitGroup, shared::WaitGroup};
+pub use watch::{WatchReceiver, WatchSender};
 
+pub mod ask;
 pub mod channels;
 pub mod cond_vars;
 pub mod mutexes;
 pub mod onces;
+pub mod rcu;
 pub mod rw_locks;
 pub mod scopes;
+pub mod spsc;
 pub mod wait_groups;
+pub mod watch;
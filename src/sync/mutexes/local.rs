@@ -12,6 +12,7 @@ use std::future::Future;
 use std::mem::ManuallyDrop;
 use std::ops::{Deref, DerefMut};
 use std::pin::Pin;
+use std::rc::Rc;
 use std::task::{Context, Poll};
 
 /// An RAII implementation of a "scoped lock" of a mutex. When this structure is
@@ -79,6 +80,52 @@ impl<T: ?Sized> Drop for LocalMutexGuard<'_, T> {
     }
 }
 
+/// An RAII implementation of a "scoped lock" of a mutex that owns an [`Rc`] of the mutex.
+/// When this structure is dropped (falls out of scope), the lock will be unlocked.
+///
+/// Unlike [`LocalMutexGuard`], it doesn't borrow the mutex, so it can be passed
+/// to helper async functions that have no reference to the mutex.
+///
+/// This structure is created by the [`lock_owned`](LocalMutex::lock_owned)
+/// and [`try_lock_owned`](LocalMutex::try_lock_owned) methods on [`LocalMutex`].
+pub struct LocalMutexOwnedGuard<T: ?Sized> {
+    local_mutex: Rc<LocalMutex<T>>,
+}
+
+impl<T: ?Sized> LocalMutexOwnedGuard<T> {
+    /// Creates a new [`LocalMutexOwnedGuard`].
+    #[inline]
+    fn new(local_mutex: Rc<LocalMutex<T>>) -> Self {
+        Self { local_mutex }
+    }
+
+    /// Returns a reference to the original [`LocalMutex`].
+    #[inline]
+    pub fn mutex(&self) -> &Rc<LocalMutex<T>> {
+        &self.local_mutex
+    }
+}
+
+impl<T: ?Sized> Deref for LocalMutexOwnedGuard<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.local_mutex.value.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for LocalMutexOwnedGuard<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { &mut *self.local_mutex.value.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for LocalMutexOwnedGuard<T> {
+    fn drop(&mut self) {
+        unsafe { self.local_mutex.unlock() };
+    }
+}
+
 /// `LocalMutexWait` is a future that will be resolved when the lock is acquired.
 #[repr(C)]
 pub struct LocalMutexWait<'mutex, T: ?Sized> {
@@ -196,6 +243,50 @@ impl<T> LocalMutex<T> {
     }
 }
 
+impl<T: ?Sized> LocalMutex<T> {
+    /// Locks the mutex and returns a [`LocalMutexOwnedGuard`] that owns the provided [`Rc`].
+    ///
+    /// It waits for the lock as [`lock`](AsyncMutex::lock) does.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use orengine::sync::{LocalMutex, LocalMutexOwnedGuard};
+    ///
+    /// # async fn write_to_the_log_file(line: &str) {}
+    ///
+    /// async fn flush(mut lines: LocalMutexOwnedGuard<Vec<String>>) {
+    ///     for line in lines.drain(..) {
+    ///         write_to_the_log_file(&line).await;
+    ///     }
+    /// }
+    ///
+    /// async fn log(buffer: Rc<LocalMutex<Vec<String>>>, line: String) {
+    ///     let mut guard = buffer.lock_owned().await;
+    ///     guard.push(line);
+    ///
+    ///     if guard.len() >= 64 {
+    ///         flush(guard).await;
+    ///     }
+    /// }
+    /// ```
+    #[allow(clippy::future_not_send, reason = "Because it is `local`")]
+    pub async fn lock_owned(self: Rc<Self>) -> LocalMutexOwnedGuard<T> {
+        unsafe { self.lock().await.leak() };
+
+        LocalMutexOwnedGuard::new(self)
+    }
+
+    /// Tries to lock the mutex. If it is successful, returns a [`LocalMutexOwnedGuard`]
+    /// that owns the provided [`Rc`], otherwise returns `None`.
+    pub fn try_lock_owned(self: Rc<Self>) -> Option<LocalMutexOwnedGuard<T>> {
+        unsafe { self.try_lock()?.leak() };
+
+        Some(LocalMutexOwnedGuard::new(self))
+    }
+}
+
 impl<T: ?Sized> AsyncMutex<T> for LocalMutex<T> {
     type Guard<'mutex>
         = LocalMutexGuard<'mutex, T>
@@ -329,6 +420,40 @@ mod tests {
         .await;
     }
 
+    #[orengine::test::test_local]
+    fn test_local_mutex_lock_owned() {
+        #[allow(clippy::future_not_send, reason = "Because it is `local`")]
+        async fn push_after_sleep(mut guard: LocalMutexOwnedGuard<Vec<usize>>, value: usize) {
+            sleep(Duration::from_millis(1)).await;
+            guard.push(value);
+        }
+
+        let mutex = Rc::new(LocalMutex::new(Vec::new()));
+
+        local_scope(|scope| async {
+            let guard = mutex.clone().lock_owned().await;
+            assert!(mutex.clone().try_lock_owned().is_none());
+
+            scope.exec(async {
+                let guard = mutex.clone().lock_owned().await;
+                push_after_sleep(guard, 2).await;
+            });
+
+            push_after_sleep(guard, 1).await;
+        })
+        .await;
+
+        let guard = mutex
+            .clone()
+            .try_lock_owned()
+            .expect("mutex must be unlocked");
+        assert_eq!(*guard, vec![1, 2]);
+        assert!(mutex.is_locked());
+        drop(guard);
+        assert!(!mutex.is_locked());
+        assert_eq!(Rc::strong_count(&mutex), 1);
+    }
+
     #[orengine::test::test_local]
     fn test_try_local_mutex() {
         const SLEEP_DURATION: Duration = Duration::from_millis(1);
@@ -0,0 +1,259 @@
+use crate::get_task_from_context;
+use crate::runtime::{local_executor, Task};
+use std::cell::{Cell, UnsafeCell};
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Current state of the [`LocalOnceCell`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum CellState {
+    /// The value is not initialized and no task is initializing it.
+    Uninit,
+    /// A task is initializing the value.
+    Initializing,
+    /// The value is initialized.
+    Init,
+}
+
+/// `WaitInitialization` is a future that waits until a task that initializes
+/// the [`LocalOnceCell`] completes or stops the initialization.
+struct WaitInitialization<'cell, T> {
+    cell: &'cell LocalOnceCell<T>,
+    was_called: bool,
+}
+
+impl<T> Future for WaitInitialization<'_, T> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = unsafe { self.get_unchecked_mut() };
+        if this.was_called || this.cell.state.get() != CellState::Initializing {
+            return Poll::Ready(());
+        }
+
+        this.was_called = true;
+        let task = unsafe { get_task_from_context!(cx) };
+        unsafe { &mut *this.cell.waiting_tasks.get() }.push(task);
+
+        Poll::Pending
+    }
+}
+
+/// `InitializationGuard` wakes up all tasks waiting for the initialization
+/// of the [`LocalOnceCell`] when it is dropped.
+///
+/// If the initialization has not been completed (the initializer panicked
+/// or the future was dropped), it resets the state, so the next caller initializes the value.
+struct InitializationGuard<'cell, T> {
+    cell: &'cell LocalOnceCell<T>,
+}
+
+impl<T> Drop for InitializationGuard<'_, T> {
+    fn drop(&mut self) {
+        if self.cell.state.get() == CellState::Initializing {
+            self.cell.state.set(CellState::Uninit);
+        }
+
+        let waiting_tasks = std::mem::take(unsafe { &mut *self.cell.waiting_tasks.get() });
+        let executor = local_executor();
+        for task in waiting_tasks {
+            executor.exec_task(task);
+        }
+    }
+}
+
+/// `LocalOnceCell` is a cell that is initialized at most once by an asynchronous function.
+///
+/// Unlike [`std::sync::OnceLock`], it can `await` the initialization,
+/// which is needed for lazy connection setup or config file loading.
+///
+/// While one task initializes the value, other tasks calling
+/// [`get_or_init`](Self::get_or_init) wait until the value is stored.
+/// If the initialization is not completed (the future was dropped or panicked),
+/// one of the waiting tasks initializes the value with its own function.
+///
+/// Use [`LocalOnce`](crate::sync::LocalOnce) if you need to call a function
+/// only once without storing a value.
+///
+/// # The difference between `LocalOnceCell` and `std::sync::OnceLock`
+///
+/// The `LocalOnceCell` works with `local tasks`.
+///
+/// Read [`Executor`](crate::Executor) for more details.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::sync::LocalOnceCell;
+///
+/// # async fn load_config() -> String { String::new() }
+/// # async fn foo() {
+/// let config = LocalOnceCell::new();
+///
+/// let value: &String = config.get_or_init(|| async {
+///     load_config().await
+/// }).await;
+///
+/// assert_eq!(config.get(), Some(value));
+/// # }
+/// ```
+pub struct LocalOnceCell<T> {
+    state: Cell<CellState>,
+    value: UnsafeCell<Option<T>>,
+    waiting_tasks: UnsafeCell<Vec<Task>>,
+    // impl !Send
+    no_send_marker: PhantomData<*const ()>,
+}
+
+impl<T> LocalOnceCell<T> {
+    /// Creates a new uninitialized `LocalOnceCell`.
+    pub const fn new() -> Self {
+        Self {
+            state: Cell::new(CellState::Uninit),
+            value: UnsafeCell::new(None),
+            waiting_tasks: UnsafeCell::new(Vec::new()),
+            no_send_marker: PhantomData,
+        }
+    }
+
+    /// Returns the value if the `LocalOnceCell` is initialized.
+    #[inline]
+    pub fn get(&self) -> Option<&T> {
+        if self.state.get() == CellState::Init {
+            return unsafe { &*self.value.get() }.as_ref();
+        }
+
+        None
+    }
+
+    /// Returns whether the `LocalOnceCell` is initialized.
+    #[inline]
+    pub fn is_initialized(&self) -> bool {
+        self.state.get() == CellState::Init
+    }
+
+    /// Returns the value, initializing it with `init` if it is not initialized.
+    ///
+    /// The first caller runs `init`. All callers that call it while the value
+    /// is initializing wait until the value is stored.
+    /// Next calls return the stored value without waiting.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::sync::LocalOnceCell;
+    ///
+    /// # async fn foo() {
+    /// let cell = LocalOnceCell::new();
+    ///
+    /// assert_eq!(*cell.get_or_init(|| async { 42 }).await, 42);
+    /// assert_eq!(*cell.get_or_init(|| async { 0 }).await, 42);
+    /// # }
+    /// ```
+    #[allow(clippy::future_not_send, reason = "Because it is `local`")]
+    pub async fn get_or_init<F, Fut>(&self, init: F) -> &T
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = T>,
+    {
+        loop {
+            match self.state.get() {
+                CellState::Init => return unsafe { self.get().unwrap_unchecked() },
+                CellState::Initializing => {
+                    WaitInitialization {
+                        cell: self,
+                        was_called: false,
+                    }
+                    .await;
+                }
+                CellState::Uninit => break,
+            }
+        }
+
+        self.state.set(CellState::Initializing);
+        let guard = InitializationGuard { cell: self };
+        let value = init().await;
+
+        unsafe { *self.value.get() = Some(value) };
+        self.state.set(CellState::Init);
+        drop(guard);
+
+        unsafe { self.get().unwrap_unchecked() }
+    }
+
+    /// Returns the value if the `LocalOnceCell` is initialized, consuming it.
+    #[inline]
+    pub fn into_inner(self) -> Option<T> {
+        self.value.into_inner()
+    }
+}
+
+impl<T> Default for LocalOnceCell<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl<T> Sync for LocalOnceCell<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::sync::local_scope;
+    use crate::{sleep, Local};
+    use std::time::Duration;
+
+    #[orengine::test::test_local]
+    fn test_local_once_cell() {
+        let cell = LocalOnceCell::new();
+        let number_of_inits = Local::new(0);
+        assert_eq!(cell.get(), None);
+        assert!(!cell.is_initialized());
+
+        local_scope(|scope| async {
+            for _ in 0..10 {
+                scope.spawn(async {
+                    let value = cell
+                        .get_or_init(|| async {
+                            *number_of_inits.borrow_mut() += 1;
+                            sleep(Duration::from_millis(1)).await;
+
+                            42
+                        })
+                        .await;
+                    assert_eq!(*value, 42);
+                });
+            }
+        })
+        .await;
+
+        assert_eq!(*number_of_inits.borrow(), 1);
+        assert!(cell.is_initialized());
+        assert_eq!(cell.get(), Some(&42));
+        assert_eq!(cell.into_inner(), Some(42));
+    }
+
+    #[orengine::test::test_local]
+    fn test_local_once_cell_interrupted_init() {
+        let cell = LocalOnceCell::new();
+
+        {
+            let mut interrupted = std::pin::pin!(cell.get_or_init(|| async {
+                std::future::pending::<()>().await;
+
+                1
+            }));
+            let is_pending =
+                std::future::poll_fn(|cx| Poll::Ready(interrupted.as_mut().poll(cx).is_pending()))
+                    .await;
+            assert!(is_pending);
+        }
+
+        assert_eq!(cell.get(), None);
+        assert_eq!(*cell.get_or_init(|| async { 2 }).await, 2);
+        assert_eq!(cell.get(), Some(&2));
+    }
+}
@@ -0,0 +1,104 @@
+use crate::sync::LocalOnceCell;
+use std::future::Future;
+
+/// `LocalLazy` is a value that is initialized by an asynchronous function on the first access.
+///
+/// It is a [`LocalOnceCell`] with the initializer provided at construction,
+/// so it is simpler to use when the initializer is known in advance.
+///
+/// The initializer is `Fn`, because it is called again if the previous initialization
+/// is not completed (the future was dropped or panicked).
+///
+/// # The difference between `LocalLazy` and `std::sync::LazyLock`
+///
+/// The `LocalLazy` works with `local tasks` and can `await` the initialization.
+///
+/// Read [`Executor`](crate::Executor) for more details.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::sync::LocalLazy;
+///
+/// # async fn connect() -> u32 { 1 }
+/// # async fn foo() {
+/// let connection = LocalLazy::new(|| async { connect().await });
+///
+/// let first: &u32 = connection.get().await; // connects
+/// let second: &u32 = connection.get().await; // returns the same connection
+///
+/// assert!(std::ptr::eq(first, second));
+/// # }
+/// ```
+pub struct LocalLazy<T, F> {
+    cell: LocalOnceCell<T>,
+    init: F,
+}
+
+impl<T, F> LocalLazy<T, F> {
+    /// Creates a new `LocalLazy` with the given initializer.
+    pub const fn new(init: F) -> Self {
+        Self {
+            cell: LocalOnceCell::new(),
+            init,
+        }
+    }
+
+    /// Returns the value if it is initialized.
+    #[inline]
+    pub fn get_if_initialized(&self) -> Option<&T> {
+        self.cell.get()
+    }
+
+    /// Returns the value, initializing it on the first call.
+    ///
+    /// Read [`LocalOnceCell::get_or_init`] for more details.
+    #[allow(clippy::future_not_send, reason = "Because it is `local`")]
+    pub async fn get<Fut>(&self) -> &T
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = T>,
+    {
+        self.cell.get_or_init(|| (self.init)()).await
+    }
+
+    /// Returns the value if it is initialized, consuming the `LocalLazy`.
+    #[inline]
+    pub fn into_inner(self) -> Option<T> {
+        self.cell.into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::sync::local_scope;
+    use crate::{sleep, Local};
+    use std::time::Duration;
+
+    #[orengine::test::test_local]
+    fn test_local_lazy() {
+        let number_of_inits = Local::new(0);
+        let lazy = LocalLazy::new(|| async {
+            *number_of_inits.borrow_mut() += 1;
+            sleep(Duration::from_millis(1)).await;
+
+            String::from("value")
+        });
+        assert_eq!(lazy.get_if_initialized(), None);
+
+        local_scope(|scope| async {
+            for _ in 0..10 {
+                scope.spawn(async {
+                    assert_eq!(lazy.get().await, "value");
+                });
+            }
+        })
+        .await;
+
+        assert_eq!(*number_of_inits.borrow(), 1);
+        assert_eq!(lazy.get_if_initialized().map(String::as_str), Some("value"));
+        assert_eq!(lazy.into_inner().as_deref(), Some("value"));
+    }
+}
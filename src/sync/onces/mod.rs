@@ -1,9 +1,13 @@
 pub mod async_trait;
 pub mod local;
+pub mod local_cell;
+pub mod local_lazy;
 pub mod shared;
 pub mod state;
 
 pub use async_trait::*;
 pub use local::*;
+pub use local_cell::*;
+pub use local_lazy::*;
 pub use shared::*;
 pub use state::*;
@@ -0,0 +1,277 @@
+//! This module contains [`LocalRcu`], a `read-copy-update` cell for `local tasks`,
+//! and its read guard [`LocalRcuReadGuard`].
+use std::cell::Cell;
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+use std::ops::Deref;
+use std::ptr::NonNull;
+
+/// `Version` is a value of the [`LocalRcu`] with the number of its readers.
+///
+/// Each [`update`](LocalRcu::update) creates a new version. The replaced version is retired
+/// and freed when the last of its readers is dropped.
+struct Version<T> {
+    value: T,
+    number_of_readers: Cell<usize>,
+    is_retired: Cell<bool>,
+}
+
+impl<T> Version<T> {
+    /// Allocates a new `Version` and leaks it.
+    fn alloc(value: T) -> NonNull<Self> {
+        NonNull::from(Box::leak(Box::new(Self {
+            value,
+            number_of_readers: Cell::new(0),
+            is_retired: Cell::new(false),
+        })))
+    }
+
+    /// Retires the version. It is freed now, if it has no readers,
+    /// or when the last of its readers is dropped.
+    ///
+    /// # Safety
+    ///
+    /// The version is allocated by [`alloc`](Self::alloc) and is not retired yet.
+    unsafe fn retire(version: NonNull<Self>) {
+        if unsafe { version.as_ref() }.number_of_readers.get() == 0 {
+            drop(unsafe { Box::from_raw(version.as_ptr()) });
+        } else {
+            unsafe { version.as_ref() }.is_retired.set(true);
+        }
+    }
+}
+
+/// `LocalRcuReadGuard` is a reference to the value of the [`LocalRcu`]
+/// returned by [`LocalRcu::read`].
+///
+/// The value it points to is never changed or freed while the guard exists,
+/// even if the [`LocalRcu`] is [`updated`](LocalRcu::update).
+pub struct LocalRcuReadGuard<'rcu, T> {
+    version: NonNull<Version<T>>,
+    phantom_data: PhantomData<&'rcu LocalRcu<T>>,
+}
+
+impl<T> Deref for LocalRcuReadGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &unsafe { self.version.as_ref() }.value
+    }
+}
+
+impl<T> Drop for LocalRcuReadGuard<'_, T> {
+    fn drop(&mut self) {
+        let version = unsafe { self.version.as_ref() };
+        let number_of_readers = version.number_of_readers.get() - 1;
+        version.number_of_readers.set(number_of_readers);
+
+        if number_of_readers == 0 && version.is_retired.get() {
+            drop(unsafe { Box::from_raw(self.version.as_ptr()) });
+        }
+    }
+}
+
+/// `LocalRcu` is a `read-copy-update` cell that allows reading the value without locking.
+///
+/// [`read`](Self::read) never waits and never copies the value.
+/// [`update`](Self::update) builds a new value from the current one and replaces it.
+/// Readers that got the old value keep reading it until they drop their guards,
+/// and the old value is freed after the last of its readers is dropped,
+/// even if readers of newer values still exist.
+///
+/// It is useful for values that are read often and updated rarely,
+/// such as configs or routing tables.
+///
+/// # The difference between `LocalRcu` and [`LocalRWLock`](crate::sync::LocalRWLock)
+///
+/// Readers of the `LocalRcu` never wait for writers and writers never wait for readers,
+/// but each update allocates a new value.
+///
+/// The `LocalRcu` works with `local tasks`.
+///
+/// Read [`Executor`](crate::Executor) for more details.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::sync::LocalRcu;
+///
+/// # async fn foo() {
+/// let routes = LocalRcu::new(vec!["/".to_string()]);
+///
+/// let old_routes = routes.read();
+/// routes.update(|routes| {
+///     let mut new_routes = routes.clone();
+///     new_routes.push("/health".to_string());
+///
+///     new_routes
+/// });
+///
+/// assert_eq!(old_routes.len(), 1);
+/// assert_eq!(routes.read().len(), 2);
+/// # }
+/// ```
+pub struct LocalRcu<T> {
+    current: Cell<NonNull<Version<T>>>,
+    // impl !Send
+    no_send_marker: PhantomData<*const ()>,
+}
+
+impl<T> LocalRcu<T> {
+    /// Creates a new `LocalRcu` with the given value.
+    pub fn new(value: T) -> Self {
+        Self {
+            current: Cell::new(Version::alloc(value)),
+            no_send_marker: PhantomData,
+        }
+    }
+
+    /// Returns a guard to the current value.
+    ///
+    /// It never waits and the guard is not affected by next [`updates`](Self::update).
+    #[inline]
+    pub fn read(&self) -> LocalRcuReadGuard<'_, T> {
+        let version = self.current.get();
+        let number_of_readers = &unsafe { version.as_ref() }.number_of_readers;
+        number_of_readers.set(number_of_readers.get() + 1);
+
+        LocalRcuReadGuard {
+            version,
+            phantom_data: PhantomData,
+        }
+    }
+
+    /// Replaces the current value with the value returned by `f`.
+    ///
+    /// `f` receives the current value, so it can clone it and change the copy.
+    /// Guards that were returned by [`read`](Self::read) before the update
+    /// keep pointing to the old value.
+    pub fn update(&self, f: impl FnOnce(&T) -> T) {
+        // The guard keeps the current value alive even if `f` updates the `LocalRcu`.
+        let guard = self.read();
+        let new_version = Version::alloc(f(&guard));
+
+        let old_version = self.current.replace(new_version);
+        unsafe { Version::retire(old_version) };
+
+        drop(guard);
+    }
+
+    /// Replaces the current value with `value`.
+    ///
+    /// Read [`update`](Self::update) for more details.
+    #[inline]
+    pub fn replace(&self, value: T) {
+        self.update(|_| value);
+    }
+
+    /// Returns the current value, consuming the `LocalRcu`.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        let this = ManuallyDrop::new(self);
+
+        unsafe { Box::from_raw(this.current.get().as_ptr()) }.value
+    }
+}
+
+impl<T: Default> Default for LocalRcu<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> Drop for LocalRcu<T> {
+    fn drop(&mut self) {
+        // Guards borrow the `LocalRcu`, so the current version has no readers.
+        drop(unsafe { Box::from_raw(self.current.get().as_ptr()) });
+    }
+}
+
+unsafe impl<T> Sync for LocalRcu<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use std::rc::Rc;
+
+    #[orengine::test::test_local]
+    fn test_local_rcu() {
+        let rcu = LocalRcu::new(1);
+        let first = rcu.read();
+
+        rcu.update(|value| value + 1);
+        let second = rcu.read();
+        rcu.replace(3);
+
+        assert_eq!(*first, 1);
+        assert_eq!(*second, 2);
+        assert_eq!(*rcu.read(), 3);
+
+        crate::yield_now().await;
+        assert_eq!(*first, 1);
+
+        drop(first);
+        drop(second);
+        assert_eq!(rcu.into_inner(), 3);
+    }
+
+    #[orengine::test::test_local]
+    fn test_local_rcu_frees_retired_values() {
+        let value = Rc::new(());
+        let rcu = LocalRcu::new(value.clone());
+
+        let guard = rcu.read();
+        rcu.replace(Rc::new(()));
+        assert_eq!(Rc::strong_count(&value), 2);
+
+        drop(guard);
+        assert_eq!(Rc::strong_count(&value), 1);
+
+        let replaced_in_update = Rc::new(());
+        rcu.update(|_| {
+            rcu.replace(replaced_in_update.clone());
+
+            Rc::new(())
+        });
+        assert_eq!(Rc::strong_count(&replaced_in_update), 1);
+    }
+
+    #[orengine::test::test_local]
+    fn test_local_rcu_frees_each_version_after_its_readers() {
+        let versions: Vec<Rc<usize>> = (0..4).map(Rc::new).collect();
+        let rcu = LocalRcu::new(versions[0].clone());
+
+        let first_reader_of_0 = rcu.read();
+        let second_reader_of_0 = rcu.read();
+        rcu.replace(versions[1].clone());
+        let reader_of_1 = rcu.read();
+        rcu.replace(versions[2].clone());
+        let reader_of_2 = rcu.read();
+        rcu.replace(versions[3].clone());
+
+        assert_eq!(**first_reader_of_0, 0);
+        assert_eq!(**second_reader_of_0, 0);
+        assert_eq!(**reader_of_1, 1);
+        assert_eq!(**reader_of_2, 2);
+        assert_eq!(**rcu.read(), 3);
+
+        // The version 1 is freed while the older version 0 is still read.
+        drop(reader_of_1);
+        assert_eq!(Rc::strong_count(&versions[1]), 1);
+        assert_eq!(Rc::strong_count(&versions[0]), 2);
+
+        drop(first_reader_of_0);
+        assert_eq!(Rc::strong_count(&versions[0]), 2);
+        drop(second_reader_of_0);
+        assert_eq!(Rc::strong_count(&versions[0]), 1);
+
+        assert_eq!(Rc::strong_count(&versions[2]), 2);
+        drop(reader_of_2);
+        assert_eq!(Rc::strong_count(&versions[2]), 1);
+
+        assert_eq!(Rc::strong_count(&versions[3]), 2);
+        drop(rcu);
+        assert_eq!(Rc::strong_count(&versions[3]), 1);
+    }
+}
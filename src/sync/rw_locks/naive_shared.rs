@@ -5,8 +5,8 @@
 use std::cell::UnsafeCell;
 use std::mem::ManuallyDrop;
 use std::ops::{Deref, DerefMut};
-use std::sync::atomic::AtomicIsize;
 use std::sync::atomic::Ordering::{Acquire, Relaxed, Release};
+use std::sync::atomic::{AtomicIsize, AtomicUsize};
 
 use crate::sync::{AsyncRWLock, AsyncReadLockGuard, AsyncWriteLockGuard, LockStatus};
 use crate::yield_now;
@@ -120,6 +120,72 @@ unsafe impl<T: ?Sized + Send> Send for WriteLockGuard<'_, T> {}
 
 // endregion
 
+/// `RWLockConfig` defines which side an [`RWLock`] prefers when both readers
+/// and writers are waiting.
+///
+/// It is set at construction with [`RWLock::with_config`].
+///
+/// - [`read_preferring`](Self::read_preferring) (default) lets new readers acquire the lock
+///   while any reader holds it. It gives the best throughput for read-heavy workloads,
+///   but a continuous stream of readers can starve writers forever.
+///
+/// - [`write_preferring`](Self::write_preferring) blocks new readers while a writer is waiting.
+///   Writers acquire the lock as soon as current readers release it, but a continuous
+///   stream of writers can starve readers.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RWLockConfig {
+    is_write_preferring: bool,
+}
+
+impl RWLockConfig {
+    /// Returns a read-preferring `RWLockConfig`.
+    ///
+    /// Read [`RWLockConfig`] for more details.
+    pub const fn read_preferring() -> Self {
+        Self {
+            is_write_preferring: false,
+        }
+    }
+
+    /// Returns a write-preferring `RWLockConfig`.
+    ///
+    /// Read [`RWLockConfig`] for more details.
+    pub const fn write_preferring() -> Self {
+        Self {
+            is_write_preferring: true,
+        }
+    }
+
+    /// Returns whether the config is write-preferring.
+    pub const fn is_write_preferring(&self) -> bool {
+        self.is_write_preferring
+    }
+}
+
+/// Decrements the number of waiting writers of an [`RWLock`] when dropped,
+/// so a cancelled [`write`](RWLock::write) doesn't block readers forever.
+struct WaitingWriter<'rw_lock> {
+    number_of_waiting_writers: &'rw_lock AtomicUsize,
+}
+
+impl<'rw_lock> WaitingWriter<'rw_lock> {
+    /// Increments the number of waiting writers and returns a new `WaitingWriter`.
+    #[inline]
+    fn new(number_of_waiting_writers: &'rw_lock AtomicUsize) -> Self {
+        number_of_waiting_writers.fetch_add(1, Release);
+
+        Self {
+            number_of_waiting_writers,
+        }
+    }
+}
+
+impl Drop for WaitingWriter<'_> {
+    fn drop(&mut self) {
+        self.number_of_waiting_writers.fetch_sub(1, Release);
+    }
+}
+
 /// An asynchronous version of a [`reader-writer lock`](std::sync::RwLock).
 ///
 /// This type of lock allows a number of readers or at most one writer at any
@@ -144,6 +210,12 @@ unsafe impl<T: ?Sized + Send> Send for WriteLockGuard<'_, T> {}
 ///
 /// Read [`Executor`](crate::Executor) for more details.
 ///
+/// # Read and write preference
+///
+/// By default, the `RWLock` is read-preferring and a continuous stream of readers
+/// can starve writers. Use [`RWLock::with_config`] with [`RWLockConfig::write_preferring`]
+/// for write-heavy workloads. Read [`RWLockConfig`] for more details.
+///
 /// # Example
 ///
 /// ```rust
@@ -166,20 +238,49 @@ unsafe impl<T: ?Sized + Send> Send for WriteLockGuard<'_, T> {}
 #[repr(C)]
 pub struct RWLock<T: ?Sized> {
     number_of_readers: CachePadded<AtomicIsize>,
+    number_of_waiting_writers: CachePadded<AtomicUsize>,
+    config: RWLockConfig,
     value: UnsafeCell<T>,
 }
 
 impl<T: ?Sized> RWLock<T> {
-    /// Creates a new `RWLock` with the given value.
+    /// Creates a new read-preferring `RWLock` with the given value.
+    ///
+    /// A continuous stream of readers can starve writers of this lock.
+    /// Read [`RWLockConfig`] for more details.
     pub const fn new(value: T) -> Self
+    where
+        T: Sized,
+    {
+        Self::with_config(value, RWLockConfig::read_preferring())
+    }
+
+    /// Creates a new `RWLock` with the given value and [`RWLockConfig`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orengine::sync::{RWLock, RWLockConfig};
+    ///
+    /// // Configuration is updated rarely, but updates must not wait for readers forever.
+    /// let config = RWLock::with_config(String::new(), RWLockConfig::write_preferring());
+    /// ```
+    pub const fn with_config(value: T, config: RWLockConfig) -> Self
     where
         T: Sized,
     {
         Self {
             number_of_readers: CachePadded::new(AtomicIsize::new(0)),
+            number_of_waiting_writers: CachePadded::new(AtomicUsize::new(0)),
+            config,
             value: UnsafeCell::new(value),
         }
     }
+
+    /// Returns the [`RWLockConfig`] of the `RWLock`.
+    pub const fn config(&self) -> RWLockConfig {
+        self.config
+    }
 }
 
 impl<T: ?Sized> AsyncRWLock<T> for RWLock<T> {
@@ -213,10 +314,20 @@ impl<T: ?Sized> AsyncRWLock<T> for RWLock<T> {
     where
         T: 'rw_lock,
     {
+        if let Some(guard) = self.try_write() {
+            return guard;
+        }
+
+        let _waiting_writer = self
+            .config
+            .is_write_preferring()
+            .then(|| WaitingWriter::new(&self.number_of_waiting_writers));
+
         loop {
-            match self.try_write() {
-                Some(guard) => return guard,
-                None => yield_now().await,
+            yield_now().await;
+
+            if let Some(guard) = self.try_write() {
+                return guard;
             }
         }
     }
@@ -253,6 +364,10 @@ impl<T: ?Sized> AsyncRWLock<T> for RWLock<T> {
 
     #[inline]
     fn try_read(&self) -> Option<Self::ReadLockGuard<'_>> {
+        if self.config.is_write_preferring() && self.number_of_waiting_writers.load(Acquire) > 0 {
+            return None;
+        }
+
         loop {
             let number_of_readers = self.number_of_readers.load(Acquire);
             if number_of_readers >= 0 {
@@ -453,6 +568,37 @@ mod tests {
         .await;
     }
 
+    #[orengine::test::test_shared]
+    fn test_rw_lock_preference() {
+        for config in [
+            RWLockConfig::read_preferring(),
+            RWLockConfig::write_preferring(),
+        ] {
+            let rw_lock = RWLock::with_config(0, config);
+
+            shared_scope(|scope| async {
+                let read_guard = rw_lock.read().await;
+
+                scope.exec(async {
+                    *rw_lock.write().await += 1;
+                });
+
+                // The writer is waiting for the reader now.
+                assert_eq!(
+                    rw_lock.try_read().is_some(),
+                    !config.is_write_preferring(),
+                    "unexpected try_read result with {config:?}"
+                );
+
+                drop(read_guard);
+            })
+            .await;
+
+            assert_eq!(*rw_lock.read().await, 1);
+            assert_eq!(rw_lock.number_of_waiting_writers.load(SeqCst), 0);
+        }
+    }
+
     #[orengine::test::test_shared]
     fn test_try_naive_rw_lock() {
         const NUMBER_OF_READERS: isize = 5;
@@ -0,0 +1,31 @@
+//! This module contains [`resume_child_panics`].
+use std::any::Any;
+
+/// Returns the message of the panic if the `payload` is a string.
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&'static str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("Box<dyn Any>")
+}
+
+/// Re-raises panics of the child tasks of a scope in the parent task.
+///
+/// If only one child task has panicked, its panic is resumed as is.
+/// Panics of multiple child tasks are chained into one panic with all messages.
+/// It does nothing if `panics` is empty.
+pub(crate) fn resume_child_panics(mut panics: Vec<Box<dyn Any + Send>>) {
+    match panics.len() {
+        0 => {}
+        1 => std::panic::resume_unwind(panics.pop().expect("checked above")),
+        number_of_panics => {
+            let messages: Vec<&str> = panics.iter().map(|p| panic_message(&**p)).collect();
+
+            panic!(
+                "{number_of_panics} tasks of the scope have panicked: {}",
+                messages.join("; ")
+            );
+        }
+    }
+}
@@ -1,8 +1,12 @@
 use crate::runtime::{local_executor, Locality};
+use crate::sync::scopes::child_panics::resume_child_panics;
 use crate::sync::{AsyncWaitGroup, LocalWaitGroup};
 use crate::yield_now;
+use std::any::Any;
+use std::cell::RefCell;
 use std::future::Future;
 use std::marker::PhantomData;
+use std::panic::AssertUnwindSafe;
 use std::pin::Pin;
 use std::task::Poll;
 
@@ -17,6 +21,7 @@ use std::task::Poll;
 /// See [`local_scope`] for details.
 pub struct LocalScope<'scope> {
     wg: LocalWaitGroup,
+    panics: RefCell<Vec<Box<dyn Any + Send>>>,
     _scope: PhantomData<&'scope ()>,
     no_send_marker: PhantomData<*mut ()>,
 }
@@ -124,6 +129,8 @@ impl<'scope> LocalScope<'scope> {
 
 /// `LocalScopedHandle` is a wrapper of `Future<Output = ()>`
 /// to decrement the wait group when the future is done.
+///
+/// If the future panics, the panic is stored in the scope and the future is considered done.
 #[repr(C)]
 pub(crate) struct LocalScopedHandle<'scope, Fut: Future<Output = ()>> {
     scope: &'scope LocalScope<'scope>,
@@ -140,9 +147,14 @@ impl<Fut: Future<Output = ()>> Future for LocalScopedHandle<'_, Fut> {
         let this = unsafe { self.get_unchecked_mut() };
 
         let mut pinned_future = unsafe { Pin::new_unchecked(&mut this.fut) };
-        match pinned_future.as_mut().poll(cx) {
-            Poll::Pending => Poll::Pending,
-            Poll::Ready(()) => {
+        match std::panic::catch_unwind(AssertUnwindSafe(|| pinned_future.as_mut().poll(cx))) {
+            Ok(Poll::Pending) => Poll::Pending,
+            Ok(Poll::Ready(())) => {
+                this.scope.wg.done();
+                Poll::Ready(())
+            }
+            Err(payload) => {
+                this.scope.panics.borrow_mut().push(payload);
                 this.scope.wg.done();
                 Poll::Ready(())
             }
@@ -159,6 +171,12 @@ impl<Fut: Future<Output = ()>> Future for LocalScopedHandle<'_, Fut> {
 /// Unlike non-scoped tasks, scoped tasks can borrow non-`'static` data,
 /// as the scope guarantees all tasks will be awaited at the end of the scope.
 ///
+/// # Panics
+///
+/// If a scoped task panics, the panic is caught and re-raised in the task that awaits
+/// the scope after all scoped tasks are done. Panics of multiple scoped tasks
+/// are chained into one panic with all messages.
+///
 /// # The difference between `local_scope` and [`shared_scope`](crate::sync::shared_scope)
 ///
 /// The `local_scope` works with `local tasks`.
@@ -204,6 +222,7 @@ where
 {
     let scope = LocalScope {
         wg: LocalWaitGroup::new(),
+        panics: RefCell::new(Vec::new()),
         _scope: PhantomData,
         no_send_marker: PhantomData,
     };
@@ -214,6 +233,8 @@ where
     static_scope.wg.wait().await;
 
     yield_now().await; // You can't call 2 local_scopes in the same task if you don't yield
+
+    resume_child_panics(scope.panics.take());
 }
 
 /// ```compile_fail
@@ -419,4 +440,31 @@ mod tests {
             ROUND.store(i, Relaxed);
         }
     }
+
+    #[orengine::test::test_local]
+    #[should_panic(expected = "child panic")]
+    fn test_local_scope_propagates_panic() {
+        local_scope(|scope| async {
+            scope.spawn(async {
+                yield_now().await;
+                panic!("child panic");
+            });
+        })
+        .await;
+    }
+
+    #[orengine::test::test_local]
+    #[should_panic(expected = "2 tasks of the scope have panicked: first; second")]
+    fn test_local_scope_chains_panics() {
+        local_scope(|scope| async {
+            scope.exec(async {
+                panic!("first");
+            });
+            scope.spawn(async {
+                yield_now().await;
+                panic!("second");
+            });
+        })
+        .await;
+    }
 }
@@ -1,3 +1,4 @@
+mod child_panics;
 pub mod local;
 pub mod shared;
 
@@ -1,7 +1,11 @@
 use crate::runtime::{local_executor, Locality};
+use crate::sync::scopes::child_panics::resume_child_panics;
 use crate::sync::{AsyncWaitGroup, WaitGroup};
+use crate::utils::SpinLock;
 use crate::{panic_if_local_in_future, yield_now};
+use std::any::Any;
 use std::future::Future;
+use std::panic::AssertUnwindSafe;
 use std::pin::Pin;
 use std::task::Poll;
 
@@ -16,6 +20,7 @@ use std::task::Poll;
 /// See [`shared_scope`] for details.
 pub struct Scope<'scope> {
     wg: WaitGroup,
+    panics: SpinLock<Vec<Box<dyn Any + Send>>>,
     _scope: std::marker::PhantomData<&'scope ()>,
 }
 
@@ -122,6 +127,8 @@ unsafe impl Sync for Scope<'_> {}
 
 /// `ScopedHandle` is a wrapper of `Future<Output = ()>`
 /// to decrement the wait group when the future is done.
+///
+/// If the future panics, the panic is stored in the scope and the future is considered done.
 #[repr(C)]
 pub(crate) struct ScopedHandle<'scope, Fut: Future<Output = ()> + Send> {
     scope: &'scope Scope<'scope>,
@@ -136,9 +143,14 @@ impl<Fut: Future<Output = ()> + Send> Future for ScopedHandle<'_, Fut> {
         let this = unsafe { self.get_unchecked_mut() };
 
         let mut pinned_future = unsafe { Pin::new_unchecked(&mut this.fut) };
-        match pinned_future.as_mut().poll(cx) {
-            Poll::Pending => Poll::Pending,
-            Poll::Ready(()) => {
+        match std::panic::catch_unwind(AssertUnwindSafe(|| pinned_future.as_mut().poll(cx))) {
+            Ok(Poll::Pending) => Poll::Pending,
+            Ok(Poll::Ready(())) => {
+                this.scope.wg.done();
+                Poll::Ready(())
+            }
+            Err(payload) => {
+                this.scope.panics.lock().push(payload);
                 this.scope.wg.done();
                 Poll::Ready(())
             }
@@ -158,6 +170,12 @@ unsafe impl<F: Future<Output = ()> + Send> Sync for ScopedHandle<'_, F> {}
 /// Unlike non-scoped tasks, scoped tasks can borrow non-`'static` data,
 /// as the scope guarantees all tasks will be awaited at the end of the scope.
 ///
+/// # Panics
+///
+/// If a scoped task panics, the panic is caught and re-raised in the task that awaits
+/// the scope after all scoped tasks are done. Panics of multiple scoped tasks
+/// are chained into one panic with all messages.
+///
 /// # The difference between `shared_scope` and [`local_scope`](crate::sync::local_scope)
 ///
 /// The `shared_scope` works with `shared tasks` and its tasks can be shared between threads.
@@ -206,6 +224,7 @@ where
 {
     let scope = Scope {
         wg: WaitGroup::new(),
+        panics: SpinLock::new(Vec::new()),
         _scope: std::marker::PhantomData,
     };
     let static_scope = unsafe { std::mem::transmute::<&_, &'static Scope<'static>>(&scope) };
@@ -215,6 +234,8 @@ where
     scope.wg.wait().await;
 
     yield_now().await; // You can't call 2 shared_scopes in the same task if you don't yield
+
+    resume_child_panics(std::mem::take(&mut *scope.panics.lock()));
 }
 
 /// ```rust
@@ -425,4 +446,16 @@ mod tests {
             ROUND.store(i, Relaxed);
         }
     }
+
+    #[orengine::test::test_shared]
+    #[should_panic(expected = "child panic")]
+    fn test_shared_scope_propagates_panic() {
+        shared_scope(|scope| async {
+            scope.spawn(async {
+                yield_now().await;
+                panic!("child panic");
+            });
+        })
+        .await;
+    }
 }
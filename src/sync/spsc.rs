@@ -0,0 +1,396 @@
+//! This module contains a single-producer single-consumer ring buffer for `local tasks`:
+//! [`ring_buffer`], [`Producer`] and [`Consumer`].
+//!
+//! Both ends work on the same executor, so the ring buffer needs neither locks nor atomics.
+//! It is the fastest way to pass values between two tasks of the same executor.
+use crate::get_task_from_context;
+use crate::runtime::{local_executor, Task};
+use crate::sync::{RecvResult, SendResult, TryRecvResult, TrySendResult};
+use std::cell::{Cell, UnsafeCell};
+use std::future::Future;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+
+/// This is the internal data structure for the ring buffer.
+struct Inner<T> {
+    buffer: Box<[UnsafeCell<MaybeUninit<T>>]>,
+    /// Index of the next value to receive. It is never wrapped.
+    head: Cell<usize>,
+    /// Index of the next slot to send to. It is never wrapped.
+    tail: Cell<usize>,
+    waiting_producer: Cell<Option<Task>>,
+    waiting_consumer: Cell<Option<Task>>,
+    is_closed: Cell<bool>,
+}
+
+impl<T> Inner<T> {
+    /// Returns the number of values in the ring buffer.
+    #[inline]
+    fn len(&self) -> usize {
+        self.tail.get() - self.head.get()
+    }
+
+    /// Returns the slot for the given index.
+    #[inline]
+    fn slot(&self, index: usize) -> *mut MaybeUninit<T> {
+        self.buffer[index % self.buffer.len()].get()
+    }
+
+    /// Executes the task if it is not `None`.
+    #[inline]
+    fn wake(task: &Cell<Option<Task>>) {
+        if let Some(task) = task.take() {
+            local_executor().exec_task(task);
+        }
+    }
+
+    /// Pushes the value if the ring buffer is not full and wakes the waiting consumer.
+    #[inline]
+    fn try_push(&self, value: T) -> TrySendResult<T> {
+        if self.is_closed.get() {
+            return TrySendResult::Closed(value);
+        }
+
+        if self.len() == self.buffer.len() {
+            return TrySendResult::Full(value);
+        }
+
+        let tail = self.tail.get();
+        unsafe { (*self.slot(tail)).write(value) };
+        self.tail.set(tail + 1);
+        Self::wake(&self.waiting_consumer);
+
+        TrySendResult::Ok
+    }
+
+    /// Pops the value if the ring buffer is not empty and wakes the waiting producer.
+    #[inline]
+    fn try_pop(&self) -> TryRecvResult<T> {
+        if self.len() == 0 {
+            if self.is_closed.get() {
+                return TryRecvResult::Closed;
+            }
+
+            return TryRecvResult::Empty;
+        }
+
+        let head = self.head.get();
+        let value = unsafe { (*self.slot(head)).assume_init_read() };
+        self.head.set(head + 1);
+        Self::wake(&self.waiting_producer);
+
+        TryRecvResult::Ok(value)
+    }
+
+    /// Closes the ring buffer and wakes the task waiting on the other end.
+    fn close(&self) {
+        self.is_closed.set(true);
+        Self::wake(&self.waiting_producer);
+        Self::wake(&self.waiting_consumer);
+    }
+}
+
+impl<T> Drop for Inner<T> {
+    fn drop(&mut self) {
+        for index in self.head.get()..self.tail.get() {
+            unsafe { (*self.slot(index)).assume_init_drop() };
+        }
+    }
+}
+
+/// `WaitSend` is a future returned by [`Producer::send`].
+pub struct WaitSend<'producer, T> {
+    inner: &'producer Inner<T>,
+    value: Option<T>,
+    is_waiting: bool,
+}
+
+impl<T> Future for WaitSend<'_, T> {
+    type Output = SendResult<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = unsafe { self.get_unchecked_mut() };
+        this.is_waiting = false;
+        let value = unsafe { this.value.take().unwrap_unchecked() };
+
+        match this.inner.try_push(value) {
+            TrySendResult::Ok => Poll::Ready(SendResult::Ok),
+            TrySendResult::Closed(value) => Poll::Ready(SendResult::Closed(value)),
+            TrySendResult::Full(value) | TrySendResult::Locked(value) => {
+                this.value = Some(value);
+                this.is_waiting = true;
+                let task = unsafe { get_task_from_context!(cx) };
+                this.inner.waiting_producer.set(Some(task));
+
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<T> Drop for WaitSend<'_, T> {
+    fn drop(&mut self) {
+        if self.is_waiting {
+            self.inner.waiting_producer.set(None);
+        }
+    }
+}
+
+/// `WaitRecv` is a future returned by [`Consumer::recv`].
+pub struct WaitRecv<'consumer, T> {
+    inner: &'consumer Inner<T>,
+    is_waiting: bool,
+}
+
+impl<T> Future for WaitRecv<'_, T> {
+    type Output = RecvResult<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = unsafe { self.get_unchecked_mut() };
+        this.is_waiting = false;
+
+        match this.inner.try_pop() {
+            TryRecvResult::Ok(value) => Poll::Ready(RecvResult::Ok(value)),
+            TryRecvResult::Closed => Poll::Ready(RecvResult::Closed),
+            TryRecvResult::Empty | TryRecvResult::Locked => {
+                this.is_waiting = true;
+                let task = unsafe { get_task_from_context!(cx) };
+                this.inner.waiting_consumer.set(Some(task));
+
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<T> Drop for WaitRecv<'_, T> {
+    fn drop(&mut self) {
+        if self.is_waiting {
+            self.inner.waiting_consumer.set(None);
+        }
+    }
+}
+
+/// `Producer` is the sending end of the [`ring_buffer`].
+///
+/// When it is dropped, the ring buffer is closed. The [`Consumer`] still receives
+/// the values that were sent before.
+pub struct Producer<T> {
+    inner: Rc<Inner<T>>,
+    // impl !Send
+    no_send_marker: PhantomData<*const ()>,
+}
+
+impl<T> Producer<T> {
+    /// Sends the value, waiting while the ring buffer is full.
+    ///
+    /// It returns [`SendResult::Closed`] if the [`Consumer`] has been dropped.
+    #[inline]
+    pub fn send(&self, value: T) -> WaitSend<'_, T> {
+        WaitSend {
+            inner: &self.inner,
+            value: Some(value),
+            is_waiting: false,
+        }
+    }
+
+    /// Sends the value if the ring buffer is not full.
+    ///
+    /// It never returns [`TrySendResult::Locked`].
+    #[inline]
+    pub fn try_send(&self, value: T) -> TrySendResult<T> {
+        self.inner.try_push(value)
+    }
+
+    /// Returns the number of values in the ring buffer.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns whether the ring buffer is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.len() == 0
+    }
+
+    /// Returns the capacity of the ring buffer.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.inner.buffer.len()
+    }
+}
+
+impl<T> Drop for Producer<T> {
+    fn drop(&mut self) {
+        self.inner.close();
+    }
+}
+
+/// `Consumer` is the receiving end of the [`ring_buffer`].
+///
+/// When it is dropped, the ring buffer is closed and the [`Producer`] can't send values anymore.
+pub struct Consumer<T> {
+    inner: Rc<Inner<T>>,
+    // impl !Send
+    no_send_marker: PhantomData<*const ()>,
+}
+
+impl<T> Consumer<T> {
+    /// Receives a value, waiting while the ring buffer is empty.
+    ///
+    /// It returns [`RecvResult::Closed`] if the ring buffer is empty and the [`Producer`]
+    /// has been dropped.
+    #[inline]
+    pub fn recv(&self) -> WaitRecv<'_, T> {
+        WaitRecv {
+            inner: &self.inner,
+            is_waiting: false,
+        }
+    }
+
+    /// Receives a value if the ring buffer is not empty.
+    ///
+    /// It never returns [`TryRecvResult::Locked`].
+    #[inline]
+    pub fn try_recv(&self) -> TryRecvResult<T> {
+        self.inner.try_pop()
+    }
+
+    /// Returns the number of values in the ring buffer.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns whether the ring buffer is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.len() == 0
+    }
+
+    /// Returns the capacity of the ring buffer.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.inner.buffer.len()
+    }
+}
+
+impl<T> Drop for Consumer<T> {
+    fn drop(&mut self) {
+        self.inner.close();
+    }
+}
+
+/// Creates a new single-producer single-consumer ring buffer with the given capacity.
+///
+/// Both ends must be used on the same executor.
+///
+/// # Panics
+///
+/// If `capacity` is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::sync::spsc::ring_buffer;
+/// use orengine::sync::{local_scope, RecvResult};
+///
+/// # async fn foo() {
+/// let (producer, consumer) = ring_buffer(64);
+///
+/// local_scope(|scope| async {
+///     scope.spawn(async move {
+///         for i in 0..1000 {
+///             producer.send(i).await.unwrap();
+///         }
+///     });
+///
+///     let mut sum = 0;
+///     while let RecvResult::Ok(value) = consumer.recv().await {
+///         sum += value;
+///     }
+///
+///     assert_eq!(sum, 499_500);
+/// }).await;
+/// # }
+/// ```
+pub fn ring_buffer<T>(capacity: usize) -> (Producer<T>, Consumer<T>) {
+    assert_ne!(
+        capacity, 0,
+        "capacity of the ring buffer must be greater than 0"
+    );
+
+    let inner = Rc::new(Inner {
+        buffer: (0..capacity)
+            .map(|_| UnsafeCell::new(MaybeUninit::uninit()))
+            .collect(),
+        head: Cell::new(0),
+        tail: Cell::new(0),
+        waiting_producer: Cell::new(None),
+        waiting_consumer: Cell::new(None),
+        is_closed: Cell::new(false),
+    });
+
+    (
+        Producer {
+            inner: inner.clone(),
+            no_send_marker: PhantomData,
+        },
+        Consumer {
+            inner,
+            no_send_marker: PhantomData,
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::sync::local_scope;
+
+    #[orengine::test::test_local]
+    fn test_spsc_ring_buffer() {
+        const N: usize = 1000;
+
+        let (producer, consumer) = ring_buffer(4);
+        assert_eq!(producer.capacity(), 4);
+
+        local_scope(|scope| async {
+            scope.spawn(async move {
+                for i in 0..N {
+                    producer.send(i).await.unwrap();
+                }
+            });
+
+            for i in 0..N {
+                assert_eq!(consumer.recv().await.unwrap(), i);
+            }
+
+            assert!(matches!(consumer.recv().await, RecvResult::Closed));
+        })
+        .await;
+    }
+
+    #[orengine::test::test_local]
+    fn test_spsc_ring_buffer_try() {
+        let (producer, consumer) = ring_buffer(2);
+        assert!(matches!(consumer.try_recv(), TryRecvResult::Empty));
+
+        assert!(matches!(producer.try_send(1), TrySendResult::Ok));
+        assert!(matches!(producer.try_send(2), TrySendResult::Ok));
+        assert!(matches!(producer.try_send(3), TrySendResult::Full(3)));
+        assert_eq!(consumer.len(), 2);
+
+        assert!(matches!(consumer.try_recv(), TryRecvResult::Ok(1)));
+        assert!(matches!(producer.try_send(3), TrySendResult::Ok));
+
+        drop(consumer);
+        assert!(matches!(producer.try_send(4), TrySendResult::Closed(4)));
+        assert!(matches!(producer.send(4).await, SendResult::Closed(4)));
+    }
+}
@@ -0,0 +1,358 @@
+//! This module contains the watch channel: [`channel`], [`WatchSender`] and [`WatchReceiver`].
+//!
+//! The watch channel stores only the latest value and delivers it to all receivers.
+use crate::runtime::call::Call;
+use crate::runtime::{local_executor, Task};
+use crate::utils::SpinLock;
+use crate::{get_task_from_context, panic_if_local_in_future};
+use std::fmt;
+use std::future::Future;
+use std::mem;
+use std::ops::Deref;
+use std::panic::{RefUnwindSafe, UnwindSafe};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+/// `WatchClosed` is returned by [`WatchReceiver::changed`]
+/// if the [`WatchSender`] has been dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WatchClosed;
+
+impl fmt::Display for WatchClosed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Watch sender has been dropped.")
+    }
+}
+
+impl std::error::Error for WatchClosed {}
+
+/// This is the internal data structure for the watch channel.
+struct Inner<T> {
+    value: SpinLock<Arc<T>>,
+    version: AtomicUsize,
+    is_closed: AtomicBool,
+    waiters: SpinLock<Vec<Task>>,
+}
+
+impl<T> Inner<T> {
+    /// Marks the current value as seen if it has been changed.
+    ///
+    /// Returns whether the value has been changed.
+    #[inline]
+    fn update_version_if_changed(&self, seen_version: &mut usize) -> bool {
+        let version = self.version.load(Ordering::Acquire);
+        if version == *seen_version {
+            return false;
+        }
+
+        *seen_version = version;
+
+        true
+    }
+
+    /// Returns a snapshot of the stored value.
+    #[inline]
+    fn snapshot(&self) -> Ref<T> {
+        Ref {
+            value: self.value.lock().clone(),
+        }
+    }
+
+    /// Wakes all tasks that wait for a change.
+    fn wake_all(&self) {
+        let waiters = mem::take(&mut *self.waiters.lock());
+        if waiters.is_empty() {
+            return;
+        }
+
+        let executor = local_executor();
+        for task in waiters {
+            executor.spawn_shared_task(task);
+        }
+    }
+}
+
+unsafe impl<T: Send + Sync> Sync for Inner<T> {}
+#[allow(
+    clippy::non_send_fields_in_send_ty,
+    reason = "We guarantee that `Inner<T>` is `Send`"
+)]
+unsafe impl<T: Send + Sync> Send for Inner<T> {}
+
+/// A snapshot of the value of the watch channel. It is returned by
+/// [`WatchReceiver::borrow`] and [`WatchSender::borrow`].
+///
+/// It doesn't block [`WatchSender::send`], so it can be held across `.await`.
+/// It keeps the value it was taken with, even if a new one has been sent after that.
+pub struct Ref<T> {
+    value: Arc<T>,
+}
+
+impl<T> Deref for Ref<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+/// A [`Future`] that resolves when the value of the watch channel changes.
+///
+/// It is returned by [`WatchReceiver::changed`].
+pub struct WaitChanged<'receiver, T> {
+    receiver: &'receiver mut WatchReceiver<T>,
+    was_called: bool,
+}
+
+impl<T> Future for WaitChanged<'_, T> {
+    type Output = Result<(), WatchClosed>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = unsafe { self.get_unchecked_mut() };
+        panic_if_local_in_future!(cx, "WatchReceiver");
+
+        let receiver = &mut *this.receiver;
+        let inner = &*receiver.inner;
+        if !this.was_called {
+            this.was_called = true;
+
+            let mut waiters = inner.waiters.lock();
+            // The sender changes the version before locking `waiters`,
+            // so we need to check the version with the lock.
+            if inner.update_version_if_changed(&mut receiver.seen_version) {
+                return Poll::Ready(Ok(()));
+            }
+
+            if inner.is_closed.load(Ordering::Acquire) {
+                return Poll::Ready(Err(WatchClosed));
+            }
+
+            unsafe {
+                waiters.push(get_task_from_context!(cx));
+                local_executor().invoke_call(Call::ReleaseAtomicBool(waiters.leak_to_atomic()));
+            }
+
+            return Poll::Pending;
+        }
+
+        if inner.update_version_if_changed(&mut receiver.seen_version) {
+            return Poll::Ready(Ok(()));
+        }
+
+        Poll::Ready(Err(WatchClosed))
+    }
+}
+
+unsafe impl<T: Send + Sync> Send for WaitChanged<'_, T> {}
+impl<T: RefUnwindSafe> UnwindSafe for WaitChanged<'_, T> {}
+impl<T: RefUnwindSafe> RefUnwindSafe for WaitChanged<'_, T> {}
+
+/// The sending half of the watch channel. It is created by [`channel`].
+///
+/// Read [`channel`] for more details.
+pub struct WatchSender<T> {
+    inner: Arc<Inner<T>>,
+}
+
+impl<T> WatchSender<T> {
+    /// Replaces the stored value and wakes all receivers that wait for a change.
+    pub fn send(&self, value: T) {
+        let value = Arc::new(value);
+        let previous_value = mem::replace(&mut *self.inner.value.lock(), value);
+        // The previous value can be dropped only after the lock is released,
+        // because its `Drop` can be slow.
+        drop(previous_value);
+        self.inner.version.fetch_add(1, Ordering::Release);
+
+        self.inner.wake_all();
+    }
+
+    /// Returns a snapshot of the stored value.
+    pub fn borrow(&self) -> Ref<T> {
+        self.inner.snapshot()
+    }
+}
+
+impl<T> Drop for WatchSender<T> {
+    fn drop(&mut self) {
+        self.inner.is_closed.store(true, Ordering::Release);
+        self.inner.wake_all();
+    }
+}
+
+/// The receiving half of the watch channel. It is created by [`channel`] and can be cloned.
+///
+/// Read [`channel`] for more details.
+pub struct WatchReceiver<T> {
+    inner: Arc<Inner<T>>,
+    seen_version: usize,
+}
+
+impl<T> WatchReceiver<T> {
+    /// Returns a snapshot of the stored value without waiting.
+    ///
+    /// It doesn't mark the value as seen.
+    pub fn borrow(&self) -> Ref<T> {
+        self.inner.snapshot()
+    }
+
+    /// Returns whether the value has been changed since the last
+    /// [`changed`](Self::changed) call.
+    pub fn has_changed(&self) -> bool {
+        self.inner.version.load(Ordering::Acquire) != self.seen_version
+    }
+
+    /// Returns a future that resolves when the value changes
+    /// (or immediately, if it has been changed since the last call) and marks it as seen.
+    ///
+    /// Intermediate values are not delivered, use [`borrow`](Self::borrow)
+    /// to get the latest value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WatchClosed`] if the [`WatchSender`] has been dropped and the value
+    /// has not been changed.
+    ///
+    /// # Panics
+    ///
+    /// If it is polled by a `local` task with `debug_assertions`.
+    pub fn changed(&mut self) -> WaitChanged<'_, T> {
+        WaitChanged {
+            receiver: self,
+            was_called: false,
+        }
+    }
+}
+
+impl<T> Clone for WatchReceiver<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            seen_version: self.seen_version,
+        }
+    }
+}
+
+/// Creates a watch channel with the `initial` value and returns its
+/// [`WatchSender`] and [`WatchReceiver`].
+///
+/// The watch channel stores only the latest value. It allows distributing configuration updates,
+/// rate limits and feature flags to many reader tasks without buffering every intermediate value.
+///
+/// # The watch channel and `shared` tasks
+///
+/// The watch channel can be shared between threads, so
+/// [`WatchReceiver::changed`] can be awaited only by `shared` tasks.
+///
+/// Read [`Executor`](crate::Executor) for more details.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::sync::watch;
+///
+/// # async fn foo() {
+/// let (sender, mut receiver) = watch::channel(100);
+///
+/// // in a reader task
+/// while receiver.changed().await.is_ok() {
+///     let rate_limit = *receiver.borrow();
+///     println!("new rate limit: {rate_limit}");
+/// }
+///
+/// // in a writer task
+/// sender.send(200);
+/// # }
+/// ```
+pub fn channel<T: Clone>(initial: T) -> (WatchSender<T>, WatchReceiver<T>) {
+    let inner = Arc::new(Inner {
+        value: SpinLock::new(Arc::new(initial)),
+        version: AtomicUsize::new(0),
+        is_closed: AtomicBool::new(false),
+        waiters: SpinLock::new(Vec::new()),
+    });
+
+    (
+        WatchSender {
+            inner: inner.clone(),
+        },
+        WatchReceiver {
+            inner,
+            seen_version: 0,
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::sync::{AsyncWaitGroup, WaitGroup};
+    use crate::test::sched_future_to_another_thread;
+    use crate::yield_now;
+
+    const PAR: usize = 10;
+
+    #[orengine::test::test_shared]
+    fn test_watch_borrow_and_changed() {
+        let (sender, mut receiver) = channel(1);
+        assert_eq!(*receiver.borrow(), 1);
+        assert!(!receiver.has_changed());
+
+        sender.send(2);
+        sender.send(3);
+        assert!(receiver.has_changed());
+        assert_eq!(receiver.changed().await, Ok(()));
+        assert_eq!(*receiver.borrow(), 3);
+        assert!(!receiver.has_changed());
+
+        drop(sender);
+        assert_eq!(receiver.changed().await, Err(WatchClosed));
+        assert_eq!(*receiver.borrow(), 3);
+    }
+
+    #[orengine::test::test_shared]
+    fn test_watch_many_receivers() {
+        let (sender, receiver) = channel(0);
+        let wg = Arc::new(WaitGroup::new());
+
+        for _ in 0..PAR {
+            let mut receiver = receiver.clone();
+            let wg = wg.clone();
+            wg.inc();
+
+            sched_future_to_another_thread(async move {
+                receiver.changed().await.expect("sender was dropped");
+                assert_eq!(*receiver.borrow(), 42);
+                wg.done();
+            });
+        }
+
+        yield_now().await;
+
+        sender.send(42);
+        wg.wait().await;
+    }
+
+    #[orengine::test::test_shared]
+    fn test_watch_ref_across_await() {
+        let (sender, mut receiver) = channel(1);
+        let wg = Arc::new(WaitGroup::new());
+        let snapshot = receiver.borrow();
+
+        wg.inc();
+        let wg_clone = wg.clone();
+        sched_future_to_another_thread(async move {
+            sender.send(2);
+            wg_clone.done();
+        });
+
+        wg.wait().await;
+        assert_eq!(*snapshot, 1);
+        assert_eq!(receiver.changed().await, Ok(()));
+        assert_eq!(*receiver.borrow(), 2);
+        assert_eq!(*snapshot, 1);
+    }
+}
@@ -63,6 +63,69 @@ impl SyncTaskList {
         self.inner.lock().push(task);
     }
 
+    /// Pushes a task of any locality at the end of the list.
+    ///
+    /// # Safety
+    ///
+    /// The list must be drained only by the [`Executor`](crate::Executor) that owns the task,
+    /// because the task can be `local`.
+    #[inline]
+    pub(crate) unsafe fn push_task_of_owner(&self, task: Task) {
+        self.inner.lock().push(task);
+    }
+
+    /// Pushes all provided tasks at the end of the list under a single lock.
+    ///
+    /// # Safety
+    ///
+    /// - Provided tasks must be `shared`.
+    ///
+    /// # Panics
+    ///
+    /// If any of provided tasks is `local` with `debug_assertions`,
+    /// else it is an undefined behavior.
+    pub unsafe fn push_all(&self, tasks: impl IntoIterator<Item = Task>) {
+        let mut guard = self.inner.lock();
+
+        for task in tasks {
+            #[cfg(debug_assertions)]
+            {
+                assert!(!task.is_local());
+            }
+
+            guard.push(task);
+        }
+    }
+
+    /// Moves at most `n` tasks from `src` to the end of the list and returns
+    /// the number of moved tasks.
+    ///
+    /// Both lists are locked at the same time, so the tasks are transferred atomically.
+    /// The tasks are taken in the same order as [`pop`](Self::pop) would return them
+    /// and keep their relative order.
+    #[inline]
+    pub fn push_n_from_other(&self, src: &Self, n: usize) -> usize {
+        if ptr::eq(self, src) {
+            return 0;
+        }
+
+        // Locks are always acquired in the same order to avoid deadlocks
+        // when two lists transfer tasks to each other.
+        let (mut dst_guard, mut src_guard) = if ptr::from_ref(self) < ptr::from_ref(src) {
+            let dst_guard = self.inner.lock();
+            (dst_guard, src.inner.lock())
+        } else {
+            let src_guard = src.inner.lock();
+            (self.inner.lock(), src_guard)
+        };
+
+        let from = src_guard.len().saturating_sub(n);
+        let number_of_moved_tasks = src_guard.len() - from;
+        dst_guard.extend(src_guard.drain(from..));
+
+        number_of_moved_tasks
+    }
+
     /// Pops the first task from the list.
     #[inline]
     pub fn pop(&self) -> Option<Task> {
@@ -96,3 +159,38 @@ impl Default for SyncTaskList {
 
 unsafe impl Send for SyncTaskList {}
 unsafe impl Sync for SyncTaskList {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::local_executor;
+    use crate::runtime::Locality;
+
+    #[orengine::test::test_local]
+    fn test_push_all_and_push_n_from_other() {
+        let src = SyncTaskList::new();
+        let dst = SyncTaskList::new();
+        let tasks = (0..5).map(|_| unsafe { Task::from_future(async {}, Locality::shared()) });
+
+        unsafe { src.push_all(tasks) };
+        assert_eq!(src.len(), 5);
+
+        assert_eq!(dst.push_n_from_other(&src, 3), 3);
+        assert_eq!(src.len(), 2);
+        assert_eq!(dst.len(), 3);
+
+        assert_eq!(dst.push_n_from_other(&src, 10), 2);
+        assert!(src.is_empty());
+        assert_eq!(dst.push_n_from_other(&dst, 1), 0);
+
+        let mut deque = VecDeque::new();
+        dst.pop_all_in_deque(&mut deque);
+        assert_eq!(deque.len(), 5);
+        assert!(dst.is_empty());
+
+        for task in deque {
+            local_executor().exec_task(task);
+        }
+    }
+}
@@ -1,19 +1,27 @@
 //! This module contains utilities for parallel testing via
 //! [`sched_future_to_another_thread`] or [`sched_future`](ExecutorPool::sched_future).
+//!
+//! It also contains [`executor_pool_map`] and [`pool_for_each`] for data-parallel processing.
 use crate::bug_message::BUG_MESSAGE;
 use crate::runtime::Config;
-use crate::sync::{AsyncChannel, AsyncReceiver, AsyncSender, Channel, RecvResult, SendResult};
-use crate::{local_executor, Executor};
+use crate::sync::{
+    AsyncChannel, AsyncReceiver, AsyncSender, Channel, RecvResult, SendResult, TryRecvResult,
+};
+use crate::{local_executor, sleep, BlockOnError, Executor};
 use crossbeam::queue::SegQueue;
+use std::fmt::{Display, Formatter};
 use std::future::Future;
-use std::panic::UnwindSafe;
+use std::panic::{AssertUnwindSafe, UnwindSafe};
 use std::pin::Pin;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 use std::task::Poll;
+use std::time::{Duration, Instant};
 use std::{panic, ptr, thread};
 
 struct Result {
     future_result: thread::Result<()>,
+    was_cancelled: bool,
     sender: Arc<Channel<Job>>,
 }
 
@@ -22,30 +30,72 @@ unsafe impl Send for Result {}
 /// Short type alias for `Arc<Channel<(thread::Result<()>, Arc<Channel<Job>>)>>`
 type ResultSender = Arc<Channel<Result>>;
 
+/// `CancellationToken` is shared between a [`Job`] and its [`ExecutorPoolJoinHandle`]
+/// to cancel the job.
+#[derive(Clone, Default)]
+struct CancellationToken {
+    is_cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Requests the cancellation.
+    fn cancel(&self) {
+        self.is_cancelled.store(true, Ordering::Release);
+    }
+
+    /// Returns whether the cancellation is requested.
+    fn is_cancelled(&self) -> bool {
+        self.is_cancelled.load(Ordering::Acquire)
+    }
+}
+
 /// `Job` is a wrapper for a [`Future`] via polling it and sending the result
 /// (caught panic) to the `result_sender`.
 ///
 /// It also contains a `sender` to acquired [`Executor`] that will be released after
 /// the task is done.
+///
+/// If the `cancellation_token` is cancelled, the `Job` drops the future the next time
+/// it is polled instead of polling the future.
 struct Job {
     future: Box<dyn Future<Output = ()> + UnwindSafe>,
     sender: Option<Arc<Channel<Job>>>,
     result_sender: ResultSender,
+    cancellation_token: CancellationToken,
 }
 
 impl Job {
     /// Creates a new `Job` instance. Read [`Job`] for more information.
-    pub(crate) fn new<Fut: Future<Output = ()> + UnwindSafe + 'static>(
+    fn new<Fut: Future<Output = ()> + UnwindSafe + 'static>(
         future: Fut,
         channel: Arc<Channel<Self>>,
         result_channel: ResultSender,
+        cancellation_token: CancellationToken,
     ) -> Self {
         Self {
             future: Box::new(future),
             sender: Some(channel),
             result_sender: result_channel,
+            cancellation_token,
         }
     }
+
+    /// Sends the result of the job to the `result_sender`.
+    fn send_result(&mut self, future_result: thread::Result<()>, was_cancelled: bool) {
+        let sender = self.sender.take().unwrap();
+        let result_sender = self.result_sender.clone();
+
+        local_executor().exec_shared_future(async move {
+            let send_res = result_sender
+                .send(Result {
+                    future_result,
+                    was_cancelled,
+                    sender,
+                })
+                .await;
+            assert!(matches!(send_res, SendResult::Ok), "{BUG_MESSAGE}");
+        });
+    }
 }
 
 impl Future for Job {
@@ -54,6 +104,14 @@ impl Future for Job {
     fn poll(mut self: Pin<&mut Self>, mut cx: &mut std::task::Context<'_>) -> Poll<Self::Output> {
         let this = &mut *self;
 
+        if this.cancellation_token.is_cancelled() {
+            // The future is polled only after it is woken, so it doesn't wait for anything now.
+            this.future = Box::new(std::future::ready(()));
+            this.send_result(Ok(()), true);
+
+            return Poll::Ready(());
+        }
+
         let mut unwind_safe_cx = panic::AssertUnwindSafe(&mut cx);
         let mut unwind_safe_future =
             { panic::AssertUnwindSafe(ptr::from_mut(this.future.as_mut())) };
@@ -62,38 +120,18 @@ impl Future for Job {
             pinned_future.poll(*unwind_safe_cx)
         });
 
-        if let Ok(poll_res) = handle {
-            if poll_res.is_ready() {
-                let sender = this.sender.take().unwrap();
-                local_executor().exec_shared_future(async move {
-                    let send_res = this
-                        .result_sender
-                        .send(Result {
-                            future_result: Ok(()),
-                            sender,
-                        })
-                        .await;
-                    assert!(matches!(send_res, SendResult::Ok), "{BUG_MESSAGE}");
-                });
-
-                return Poll::Ready(());
+        match handle {
+            Ok(Poll::Ready(())) => {
+                this.send_result(Ok(()), false);
+
+                Poll::Ready(())
             }
+            Ok(Poll::Pending) => Poll::Pending,
+            Err(err) => {
+                this.send_result(Err(err), false);
 
-            Poll::Pending
-        } else {
-            let sender = this.sender.take().unwrap();
-            local_executor().exec_shared_future(async move {
-                let send_res = this
-                    .result_sender
-                    .send(Result {
-                        future_result: Err(Box::new(handle.unwrap_err())),
-                        sender,
-                    })
-                    .await;
-                assert!(matches!(send_res, SendResult::Ok), "{BUG_MESSAGE}");
-            });
-
-            Poll::Ready(())
+                Poll::Ready(())
+            }
         }
     }
 }
@@ -104,31 +142,71 @@ impl Future for Job {
 )]
 unsafe impl Send for Job {}
 
+/// `JoinError` is returned by [`ExecutorPoolJoinHandle::join_timeout`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinError {
+    /// The task has not completed in time and has been cancelled.
+    Cancelled,
+}
+
+impl Display for JoinError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Cancelled => f.write_str("the task has been cancelled"),
+        }
+    }
+}
+
+impl std::error::Error for JoinError {}
+
 /// `ExecutorPoolJoinHandle` is used to wait for the task sent to the [`ExecutorPool`]
 /// to complete. It can be gotten by [`ExecutorPool::sched_future()`].
 ///
 /// If you don't need to wait,
 /// use [`sched_future_to_another_thread`].
 ///
-/// # Panic
+/// # Cancellation
+///
+/// The task can be cancelled with [`cancel`](ExecutorPoolJoinHandle::cancel)
+/// or [`join_timeout`](ExecutorPoolJoinHandle::join_timeout). Cancellation is cooperative:
+/// the task is dropped the next time it is woken, because it can't be dropped while it waits
+/// (for example, for an in-flight io operation). So, a task that is never woken
+/// is never cancelled.
 ///
-/// If not [`joined`](ExecutorPoolJoinHandle::join) before drop.
+/// # Drop
+///
+/// If the handle is dropped without joining, the task is detached: it keeps running,
+/// its executor returns to the [`pool`](ExecutorPool) after it completes,
+/// and its panic is ignored.
 pub struct ExecutorPoolJoinHandle {
     was_joined: bool,
     channel: ResultSender,
+    cancellation_token: CancellationToken,
     pool: &'static ExecutorPool,
 }
 
 impl ExecutorPoolJoinHandle {
     /// Creates a new `ExecutorPoolJoinHandle` instance.
-    fn new(channel: ResultSender, pool: &'static ExecutorPool) -> Self {
+    fn new(
+        channel: ResultSender,
+        cancellation_token: CancellationToken,
+        pool: &'static ExecutorPool,
+    ) -> Self {
         Self {
             was_joined: false,
             channel,
+            cancellation_token,
             pool,
         }
     }
 
+    /// Releases the executor of the task and returns the result of the task.
+    fn release(&self, res: Result) -> (thread::Result<()>, bool) {
+        self.pool.senders_to_executors.push(res.sender);
+
+        (res.future_result, res.was_cancelled)
+    }
+
     /// Waits for the task sent to the [`ExecutorPool`] to complete.
     ///
     /// # Panics
@@ -137,23 +215,89 @@ impl ExecutorPoolJoinHandle {
     pub async fn join(mut self) {
         self.was_joined = true;
         let res = self.channel.recv().await.unwrap();
-        self.pool.senders_to_executors.push(res.sender);
 
-        if let Err(err) = res.future_result {
+        if let (Err(err), _) = self.release(res) {
+            panic::resume_unwind(err);
+        }
+    }
+
+    /// Cancels the task sent to the [`ExecutorPool`] and waits for the executor
+    /// to acknowledge the cancellation.
+    ///
+    /// If the task completes before it observes the cancellation,
+    /// it works like [`join`](ExecutorPoolJoinHandle::join).
+    /// Read about cancellation in [`ExecutorPoolJoinHandle`].
+    ///
+    /// # Panics
+    ///
+    /// If test fn was panicked before it was cancelled.
+    pub async fn cancel(mut self) {
+        self.was_joined = true;
+        self.cancellation_token.cancel();
+        let res = self.channel.recv().await.unwrap();
+
+        if let (Err(err), _) = self.release(res) {
             panic::resume_unwind(err);
         }
     }
+
+    /// Waits for the task sent to the [`ExecutorPool`] to complete for at most `dur`.
+    ///
+    /// If the task doesn't complete in time, the cancellation is requested,
+    /// the task is detached as if the handle was dropped
+    /// and [`JoinError::Cancelled`] is returned immediately. The task is dropped
+    /// the next time it is woken, read about cancellation in [`ExecutorPoolJoinHandle`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JoinError::Cancelled`] if the task has been cancelled.
+    ///
+    /// # Panics
+    ///
+    /// If test fn was panicked. It is used to `should_panic`.
+    pub async fn join_timeout(mut self, dur: Duration) -> std::result::Result<(), JoinError> {
+        const POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+        let deadline = Instant::now() + dur;
+        let res = loop {
+            if let TryRecvResult::Ok(res) = self.channel.try_recv() {
+                break res;
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                self.cancellation_token.cancel();
+
+                // `self` is dropped without joining, so the task is detached.
+                return Err(JoinError::Cancelled);
+            }
+
+            sleep(POLL_INTERVAL.min(deadline - now)).await;
+        };
+        self.was_joined = true;
+
+        if let (Err(err), _) = self.release(res) {
+            panic::resume_unwind(err);
+        }
+
+        Ok(())
+    }
 }
 
 unsafe impl Send for ExecutorPoolJoinHandle {}
 
 impl Drop for ExecutorPoolJoinHandle {
     fn drop(&mut self) {
-        assert!(
-            self.was_joined,
-            "ExecutorPoolJoinHandle::join() must be called! \
-        If you don't want to wait result immediately, put it somewhere and join it later."
-        );
+        if self.was_joined {
+            return;
+        }
+
+        let channel = self.channel.clone();
+        let pool = self.pool;
+        local_executor().exec_shared_future(async move {
+            let res = channel.recv().await.unwrap();
+            pool.senders_to_executors.push(res.sender);
+        });
     }
 }
 
@@ -195,6 +339,7 @@ impl ExecutorPool {
                     job.await;
                 }
             })
+            .map_err(BlockOnError::resume_unwind)
             .expect(BUG_MESSAGE);
         });
 
@@ -251,17 +396,23 @@ impl ExecutorPool {
         Fut: Future<Output = ()> + Send + 'static + UnwindSafe,
     {
         let result_channel = Arc::new(Channel::bounded(0));
+        let cancellation_token = CancellationToken::default();
         let sender = EXECUTOR_POOL
             .senders_to_executors
             .pop()
             .unwrap_or_else(Self::new_executor);
 
         let send_res = sender
-            .send(Job::new(future, sender.clone(), result_channel.clone()))
+            .send(Job::new(
+                future,
+                sender.clone(),
+                result_channel.clone(),
+                cancellation_token.clone(),
+            ))
             .await;
         assert!(matches!(send_res, SendResult::Ok), "{BUG_MESSAGE}");
 
-        ExecutorPoolJoinHandle::new(result_channel, &EXECUTOR_POOL)
+        ExecutorPoolJoinHandle::new(result_channel, cancellation_token, &EXECUTOR_POOL)
     }
 }
 
@@ -321,3 +472,201 @@ where
         handle.join().await;
     });
 }
+
+/// Applies `f` to each item of `items` concurrently on the executors of the
+/// [`pool`](ExecutorPool) and returns the results in the order of `items`.
+///
+/// Each item is processed by a separate free executor of the [`pool`](ExecutorPool)
+/// (a new one is created if there is no free executor), so it is intended for
+/// batches of heavy items rather than for millions of tiny ones.
+///
+/// # Panics
+///
+/// If `f` or the future returned by it panics. The panic is resumed
+/// after all items are processed.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::test::{executor_pool_map, run_test_and_block_on_shared};
+/// use orengine::yield_now;
+///
+/// run_test_and_block_on_shared(async {
+///     let squares = executor_pool_map(1..=4, |n: u64| async move {
+///         yield_now().await;
+///
+///         n * n
+///     })
+///     .await;
+///
+///     assert_eq!(squares, vec![1, 4, 9, 16]);
+/// });
+/// ```
+pub async fn executor_pool_map<I, T, F, Fut>(items: I, f: F) -> Vec<T>
+where
+    I: IntoIterator<Item = T> + Send,
+    T: Send + 'static,
+    F: Fn(T) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = T> + Send + 'static,
+{
+    let f = Arc::new(f);
+    let mut jobs = Vec::new();
+
+    for item in items {
+        let slot = Arc::new(Mutex::new(None));
+        let slot_clone = slot.clone();
+        let f = f.clone();
+        let handle = ExecutorPool::sched_future(AssertUnwindSafe(async move {
+            let output = f(item).await;
+            *slot_clone.lock().unwrap() = Some(output);
+        }))
+        .await;
+
+        jobs.push((handle, slot));
+    }
+
+    let mut outputs = Vec::with_capacity(jobs.len());
+    for (handle, slot) in jobs {
+        handle.join().await;
+        outputs.push(slot.lock().unwrap().take().expect(BUG_MESSAGE));
+    }
+
+    outputs
+}
+
+/// Applies `f` to each item of `items` concurrently on the executors of the
+/// [`pool`](ExecutorPool) and waits for all of them to complete.
+///
+/// Read [`executor_pool_map`] for more information.
+///
+/// # Panics
+///
+/// If `f` or the future returned by it panics. The panic is resumed
+/// after all items are processed.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::test::{pool_for_each, run_test_and_block_on_shared};
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::sync::Arc;
+///
+/// run_test_and_block_on_shared(async {
+///     let sum = Arc::new(AtomicUsize::new(0));
+///     let sum_clone = sum.clone();
+///
+///     pool_for_each(1..=4, move |n| {
+///         let sum = sum_clone.clone();
+///         async move {
+///             sum.fetch_add(n, Ordering::SeqCst);
+///         }
+///     })
+///     .await;
+///
+///     assert_eq!(sum.load(Ordering::SeqCst), 10);
+/// });
+/// ```
+pub async fn pool_for_each<I, T, F, Fut>(items: I, f: F)
+where
+    I: IntoIterator<Item = T> + Send,
+    T: Send + 'static,
+    F: Fn(T) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let f = Arc::new(f);
+    let mut handles = Vec::new();
+
+    for item in items {
+        let f = f.clone();
+        handles
+            .push(ExecutorPool::sched_future(AssertUnwindSafe(async move { f(item).await })).await);
+    }
+
+    for handle in handles {
+        handle.join().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::utils::droppable_element::DroppableElement;
+    use crate::utils::SpinLock;
+    use crate::yield_now;
+    use std::collections::HashSet;
+
+    #[orengine::test::test_shared]
+    fn test_executor_pool_map() {
+        let outputs = executor_pool_map(0..8u64, |n| async move {
+            yield_now().await;
+
+            n * 10
+        })
+        .await;
+        assert_eq!(outputs, (0..8).map(|n| n * 10).collect::<Vec<_>>());
+
+        let thread_ids = Arc::new(Mutex::new(HashSet::new()));
+        let thread_ids_clone = thread_ids.clone();
+        pool_for_each(0..4, move |_| {
+            let thread_ids = thread_ids_clone.clone();
+            async move {
+                thread_ids.lock().unwrap().insert(thread::current().id());
+            }
+        })
+        .await;
+        assert!(!thread_ids.lock().unwrap().is_empty());
+    }
+
+    #[orengine::test::test_shared]
+    fn test_executor_pool_join_handle_cancellation() {
+        let handle = ExecutorPool::sched_future(async {
+            sleep(Duration::from_millis(1)).await;
+        })
+        .await;
+        assert_eq!(handle.join_timeout(Duration::from_secs(10)).await, Ok(()));
+
+        let handle = ExecutorPool::sched_future(async {
+            loop {
+                sleep(Duration::from_millis(1)).await;
+            }
+        })
+        .await;
+        assert_eq!(
+            handle.join_timeout(Duration::from_millis(10)).await,
+            Err(JoinError::Cancelled)
+        );
+
+        let drop_in = Arc::new(SpinLock::new(Vec::new()));
+        let element = DroppableElement::new(42, drop_in.clone());
+        let handle = ExecutorPool::sched_future(AssertUnwindSafe(async move {
+            let _element = element;
+            loop {
+                yield_now().await;
+            }
+        }))
+        .await;
+        handle.cancel().await;
+        assert_eq!(*drop_in.lock(), vec![42]);
+
+        // The task sleeps longer than the timeout and is not woken by the cancellation.
+        let handle = ExecutorPool::sched_future(async {
+            sleep(Duration::from_secs(100)).await;
+        })
+        .await;
+        let start = Instant::now();
+        assert_eq!(
+            handle.join_timeout(Duration::from_millis(10)).await,
+            Err(JoinError::Cancelled)
+        );
+        assert!(start.elapsed() < Duration::from_secs(10));
+
+        // A dropped handle detaches the task.
+        drop(
+            ExecutorPool::sched_future(async {
+                sleep(Duration::from_millis(1)).await;
+            })
+            .await,
+        );
+    }
+}
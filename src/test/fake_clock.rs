@@ -0,0 +1,128 @@
+//! This module contains [`FakeClock`].
+use crate::runtime::Clock;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+/// `FakeClock` is a [`Clock`] that stands still until it is advanced via
+/// [`advance`](FakeClock::advance).
+///
+/// It allows testing timeout-dependent code deterministically and without waiting:
+/// [`sleeping tasks`](crate::sleep()) whose deadline has passed after
+/// [`advance`](FakeClock::advance) will be woken up in the next round of the
+/// [`Executor`](crate::Executor).
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::runtime::Config;
+/// use orengine::test::FakeClock;
+/// use orengine::{sleep, yield_now, Executor};
+/// use std::time::Duration;
+///
+/// static CLOCK: FakeClock = FakeClock::new();
+///
+/// let ex = Executor::init_with_config(Config::default().set_clock(&CLOCK));
+/// ex.run_and_block_on_local(async {
+///     orengine::local_executor().spawn_local(async {
+///         sleep(Duration::from_secs(3600)).await;
+///     });
+///     yield_now().await;
+///
+///     CLOCK.advance(Duration::from_secs(3601));
+///     yield_now().await; // the sleeping task is woken up
+/// }).unwrap();
+/// ```
+pub struct FakeClock {
+    start: OnceLock<Instant>,
+    elapsed_nanos: AtomicU64,
+}
+
+impl FakeClock {
+    /// Creates a new `FakeClock`. Its time starts at the first call of [`now`](Clock::now).
+    pub const fn new() -> Self {
+        Self {
+            start: OnceLock::new(),
+            elapsed_nanos: AtomicU64::new(0),
+        }
+    }
+
+    /// Fast-forwards the time by the provided `duration`.
+    #[allow(
+        clippy::cast_possible_truncation,
+        reason = "u64 nanoseconds are enough for more than 500 years"
+    )]
+    pub fn advance(&self, duration: Duration) {
+        self.elapsed_nanos
+            .fetch_add(duration.as_nanos() as u64, Ordering::AcqRel);
+    }
+
+    /// Returns how much time has passed since the start of the clock.
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.elapsed_nanos.load(Ordering::Acquire))
+    }
+}
+
+impl Default for FakeClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for FakeClock {
+    #[inline]
+    fn now(&self) -> Instant {
+        *self.start.get_or_init(Instant::now) + self.elapsed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::Config;
+    use crate::{local_executor, sleep, yield_now, Executor, Local};
+
+    #[test]
+    fn test_fake_clock() {
+        static CLOCK: FakeClock = FakeClock::new();
+
+        let start = CLOCK.now();
+        assert_eq!(CLOCK.now(), start);
+
+        CLOCK.advance(Duration::from_secs(10));
+        assert_eq!(CLOCK.now(), start + Duration::from_secs(10));
+        assert_eq!(CLOCK.elapsed(), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_sleep_with_fake_clock() {
+        static CLOCK: FakeClock = FakeClock::new();
+
+        let real_start = Instant::now();
+        let ex = Executor::init_with_config(Config::default().set_clock(&CLOCK));
+
+        ex.run_and_block_on_local(async {
+            let was_woken = Local::new(false);
+            let was_woken_clone = was_woken.clone();
+
+            local_executor().spawn_local(async move {
+                sleep(Duration::from_secs(3600)).await;
+                *was_woken_clone.borrow_mut() = true;
+            });
+
+            yield_now().await;
+            assert!(!*was_woken.borrow());
+
+            CLOCK.advance(Duration::from_secs(1800));
+            yield_now().await;
+            assert!(!*was_woken.borrow());
+
+            CLOCK.advance(Duration::from_secs(1801));
+            yield_now().await;
+            assert!(*was_woken.borrow());
+        })
+        .expect("run_and_block_on_local failed");
+
+        assert!(real_start.elapsed() < Duration::from_secs(10));
+    }
+}
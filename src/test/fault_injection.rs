@@ -0,0 +1,889 @@
+//! This module contains [`FaultIoWorker`] and functions to configure it for the current thread.
+//!
+//! It allows testing how connection-handling code behaves under network faults:
+//! you can make `recv` fail with a given [`ErrorKind`] or delay `send` with a normally
+//! distributed latency.
+//!
+//! # Example
+//!
+//! ```rust
+//! use orengine::test::{clear_injections, FaultIoWorker};
+//! use std::io::ErrorKind;
+//! use std::time::Duration;
+//!
+//! # async fn foo() {
+//! let worker = FaultIoWorker::local();
+//! // Every second `recv` fails with `ECONNRESET`.
+//! worker.inject_error_on_recv(0.5, ErrorKind::ConnectionReset);
+//! worker.inject_latency_on_send(Duration::from_millis(20), Duration::from_millis(5));
+//!
+//! // run code that must reconnect
+//!
+//! clear_injections();
+//! # }
+//! ```
+use crate::io::config::IoWorkerConfig;
+use crate::io::io_request_data::IoRequestDataPtr;
+use crate::io::sys;
+use crate::io::sys::{
+    os_sockaddr, MessageRecvHeader, OsMessageHeader, OsOpenOptions, OsPathPtr, RawFile, RawSocket,
+};
+use crate::io::worker::{get_local_worker_ref, IoWorker};
+use crate::local_executor;
+use std::collections::BTreeMap;
+use std::io::{Error, ErrorKind};
+use std::net::Shutdown;
+use std::time::{Duration, Instant};
+
+/// An io operation which is delayed by [`FaultIoWorker`].
+type DelayedRequest<W> = Box<dyn FnOnce(&mut W)>;
+
+/// `FaultIoWorker` wraps a real io worker and injects faults into the operations
+/// dispatched through it.
+///
+/// All injections are checked in each dispatch method before delegating to the inner worker.
+///
+/// - [`inject_error_on_recv`](Self::inject_error_on_recv) makes `recv` and `recv_from`
+///   operations complete with the provided error without reaching the kernel;
+///
+/// - [`inject_latency_on_send`](Self::inject_latency_on_send) postpones `send`
+///   and `send_to` operations for a normally distributed duration.
+///
+/// When the `fault_injection` feature is enabled (and in the tests of this crate)
+/// each executor uses `FaultIoWorker` as its io worker. Get the worker of the current thread
+/// with [`FaultIoWorker::local`] or use the shortcuts [`inject_error_on_recv`],
+/// [`inject_latency_on_send`] and [`clear_injections`].
+pub struct FaultIoWorker<W> {
+    inner: W,
+    recv_error: Option<(f32, ErrorKind)>,
+    send_latency: Option<(Duration, Duration)>,
+    delayed_requests: BTreeMap<Instant, DelayedRequest<W>>,
+}
+
+impl<W> FaultIoWorker<W> {
+    /// Makes `recv` and `recv_from` operations fail with the provided `error`
+    /// with the provided `probability` (from `0.0` to `1.0`).
+    ///
+    /// # Panics
+    ///
+    /// If `probability` is not in `0.0..=1.0`.
+    pub fn inject_error_on_recv(&mut self, probability: f32, error: ErrorKind) {
+        assert!(
+            (0.0..=1.0).contains(&probability),
+            "probability must be in 0.0..=1.0, but got {probability}"
+        );
+
+        self.recv_error = Some((probability, error));
+    }
+
+    /// Delays `send` and `send_to` operations. The latency is normally distributed
+    /// with the provided `mean` and `stddev`, negative samples are treated as zero.
+    pub fn inject_latency_on_send(&mut self, mean: Duration, stddev: Duration) {
+        self.send_latency = Some((mean, stddev));
+    }
+
+    /// Removes all injections. Already delayed operations will be dispatched as planned.
+    pub fn clear_injections(&mut self) {
+        self.recv_error = None;
+        self.send_latency = None;
+    }
+
+    /// Returns an error that must be returned instead of executing `recv` if it is needed.
+    #[inline]
+    fn recv_error(&self) -> Option<ErrorKind> {
+        match self.recv_error {
+            Some((probability, error)) if fastrand::f32() < probability => Some(error),
+            _ => None,
+        }
+    }
+
+    /// Returns a latency for the next `send` if it is needed.
+    #[inline]
+    fn send_latency(&self) -> Option<Duration> {
+        let (mean, stddev) = self.send_latency?;
+        // Box-Muller transform
+        let u1 = fastrand::f64().max(f64::MIN_POSITIVE);
+        let u2 = fastrand::f64();
+        let z = (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos();
+
+        Some(Duration::from_secs_f64(
+            stddev.as_secs_f64().mul_add(z, mean.as_secs_f64()).max(0.0),
+        ))
+    }
+
+    /// Completes the request with the provided error.
+    fn fail_request(error: ErrorKind, request_ptr: IoRequestDataPtr) {
+        let request = request_ptr.get_mut();
+        request.set_ret(Err(Error::from(error)));
+
+        let task = unsafe { request.task() };
+        if task.is_local() {
+            local_executor().spawn_local_task(task);
+        } else {
+            local_executor().spawn_shared_task(task);
+        }
+    }
+
+    /// Executes `request` after the injected latency or immediately if no latency is injected.
+    fn dispatch_send(&mut self, request: impl FnOnce(&mut W) + 'static) {
+        match self.send_latency() {
+            Some(latency) => {
+                let mut at = Instant::now() + latency;
+                while self.delayed_requests.contains_key(&at) {
+                    at += Duration::from_nanos(1);
+                }
+
+                self.delayed_requests.insert(at, Box::new(request));
+            }
+            None => request(&mut self.inner),
+        }
+    }
+
+    /// Dispatches all delayed requests whose time has come.
+    fn dispatch_delayed_requests(&mut self, now: Instant) {
+        while let Some(entry) = self.delayed_requests.first_entry() {
+            if *entry.key() > now {
+                break;
+            }
+
+            entry.remove()(&mut self.inner);
+        }
+    }
+}
+
+impl FaultIoWorker<sys::WorkerSys> {
+    /// Returns the `FaultIoWorker` of the current thread.
+    ///
+    /// # Panics
+    ///
+    /// If the current thread has no io worker.
+    pub fn local() -> &'static mut Self {
+        get_local_worker_ref()
+            .as_mut()
+            .expect("The current thread has no io worker. Look at the config of the Executor.")
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl FaultIoWorker<sys::WorkerSys> {
+    /// Register __fixed__ buffers.
+    pub(crate) fn register_buffers(&mut self, buffers: &[libc::iovec]) {
+        self.inner.register_buffers(buffers);
+    }
+
+    /// Deregister __fixed__ buffers.
+    pub(crate) fn deregister_buffers(&mut self) {
+        self.inner.deregister_buffers();
+    }
+}
+
+impl<W: IoWorker> IoWorker for FaultIoWorker<W> {
+    fn new(config: IoWorkerConfig) -> Self {
+        Self {
+            inner: W::new(config),
+            recv_error: None,
+            send_latency: None,
+            delayed_requests: BTreeMap::new(),
+        }
+    }
+
+    #[inline]
+    fn deregister_time_bounded_io_task(&mut self, deadline: &Instant) {
+        self.inner.deregister_time_bounded_io_task(deadline);
+    }
+
+    #[inline]
+    fn has_work(&self) -> bool {
+        self.inner.has_work() || !self.delayed_requests.is_empty()
+    }
+
+    #[inline]
+    fn number_of_active_tasks(&self) -> usize {
+        self.inner.number_of_active_tasks() + self.delayed_requests.len()
+    }
+
+    fn must_poll(&mut self, timeout_option: Option<Duration>) {
+        let now = Instant::now();
+        self.dispatch_delayed_requests(now);
+
+        let timeout_option = match (timeout_option, self.delayed_requests.keys().next()) {
+            (Some(timeout), Some(nearest)) => Some(timeout.min(*nearest - now)),
+            // The delayed requests must be dispatched in time, so it can't block indefinitely.
+            (None, Some(nearest)) => Some(*nearest - now),
+            (timeout_option, None) => timeout_option,
+        };
+
+        self.inner.must_poll(timeout_option);
+    }
+
+    #[inline]
+    fn socket(
+        &mut self,
+        domain: socket2::Domain,
+        sock_type: socket2::Type,
+        protocol: socket2::Protocol,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        self.inner.socket(domain, sock_type, protocol, request_ptr);
+    }
+
+    #[inline]
+    fn accept(
+        &mut self,
+        raw_socket: RawSocket,
+        addr_ptr: *mut os_sockaddr,
+        addr_len: *mut sys::socklen_t,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        self.inner
+            .accept(raw_socket, addr_ptr, addr_len, request_ptr);
+    }
+
+    #[inline]
+    fn accept_with_deadline(
+        &mut self,
+        raw_socket: RawSocket,
+        addr_ptr: *mut os_sockaddr,
+        addr_len: *mut sys::socklen_t,
+        request_ptr: IoRequestDataPtr,
+        deadline: &mut Instant,
+    ) {
+        self.inner
+            .accept_with_deadline(raw_socket, addr_ptr, addr_len, request_ptr, deadline);
+    }
+
+    #[inline]
+    fn connect(
+        &mut self,
+        raw_socket: RawSocket,
+        addr_ptr: *const os_sockaddr,
+        addr_len: sys::socklen_t,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        self.inner
+            .connect(raw_socket, addr_ptr, addr_len, request_ptr);
+    }
+
+    #[inline]
+    fn connect_with_deadline(
+        &mut self,
+        raw_socket: RawSocket,
+        addr_ptr: *const os_sockaddr,
+        addr_len: sys::socklen_t,
+        request_ptr: IoRequestDataPtr,
+        deadline: &mut Instant,
+    ) {
+        self.inner
+            .connect_with_deadline(raw_socket, addr_ptr, addr_len, request_ptr, deadline);
+    }
+
+    #[inline]
+    fn poll_socket_read(&mut self, raw_socket: RawSocket, request_ptr: IoRequestDataPtr) {
+        self.inner.poll_socket_read(raw_socket, request_ptr);
+    }
+
+    #[inline]
+    fn poll_socket_read_with_deadline(
+        &mut self,
+        raw_socket: RawSocket,
+        request_ptr: IoRequestDataPtr,
+        deadline: &mut Instant,
+    ) {
+        self.inner
+            .poll_socket_read_with_deadline(raw_socket, request_ptr, deadline);
+    }
+
+    #[inline]
+    fn poll_socket_write(&mut self, raw_socket: RawSocket, request_ptr: IoRequestDataPtr) {
+        self.inner.poll_socket_write(raw_socket, request_ptr);
+    }
+
+    #[inline]
+    fn poll_socket_write_with_deadline(
+        &mut self,
+        raw_socket: RawSocket,
+        request_ptr: IoRequestDataPtr,
+        deadline: &mut Instant,
+    ) {
+        self.inner
+            .poll_socket_write_with_deadline(raw_socket, request_ptr, deadline);
+    }
+
+    // region recv
+
+    #[inline]
+    fn recv(
+        &mut self,
+        raw_socket: RawSocket,
+        ptr: *mut u8,
+        len: u32,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        if let Some(error) = self.recv_error() {
+            return Self::fail_request(error, request_ptr);
+        }
+
+        self.inner.recv(raw_socket, ptr, len, request_ptr);
+    }
+
+    #[inline]
+    fn recv_fixed(
+        &mut self,
+        raw_socket: RawSocket,
+        ptr: *mut u8,
+        len: u32,
+        buf_index: u16,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        if let Some(error) = self.recv_error() {
+            return Self::fail_request(error, request_ptr);
+        }
+
+        self.inner
+            .recv_fixed(raw_socket, ptr, len, buf_index, request_ptr);
+    }
+
+    #[inline]
+    fn recv_with_deadline(
+        &mut self,
+        raw_socket: RawSocket,
+        ptr: *mut u8,
+        len: u32,
+        request_ptr: IoRequestDataPtr,
+        deadline: &mut Instant,
+    ) {
+        if let Some(error) = self.recv_error() {
+            return Self::fail_request(error, request_ptr);
+        }
+
+        self.inner
+            .recv_with_deadline(raw_socket, ptr, len, request_ptr, deadline);
+    }
+
+    #[inline]
+    fn recv_fixed_with_deadline(
+        &mut self,
+        raw_socket: RawSocket,
+        ptr: *mut u8,
+        len: u32,
+        buf_index: u16,
+        request_ptr: IoRequestDataPtr,
+        deadline: &mut Instant,
+    ) {
+        if let Some(error) = self.recv_error() {
+            return Self::fail_request(error, request_ptr);
+        }
+
+        self.inner
+            .recv_fixed_with_deadline(raw_socket, ptr, len, buf_index, request_ptr, deadline);
+    }
+
+    #[inline]
+    fn recv_from(
+        &mut self,
+        raw_socket: RawSocket,
+        msg_header: &mut MessageRecvHeader,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        if let Some(error) = self.recv_error() {
+            return Self::fail_request(error, request_ptr);
+        }
+
+        self.inner.recv_from(raw_socket, msg_header, request_ptr);
+    }
+
+    #[inline]
+    fn recv_from_with_deadline(
+        &mut self,
+        raw_socket: RawSocket,
+        msg_header: &mut MessageRecvHeader,
+        request_ptr: IoRequestDataPtr,
+        deadline: &mut Instant,
+    ) {
+        if let Some(error) = self.recv_error() {
+            return Self::fail_request(error, request_ptr);
+        }
+
+        self.inner
+            .recv_from_with_deadline(raw_socket, msg_header, request_ptr, deadline);
+    }
+
+    // endregion
+
+    // region send
+
+    #[inline]
+    fn send(
+        &mut self,
+        raw_socket: RawSocket,
+        ptr: *const u8,
+        len: u32,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        self.dispatch_send(move |inner| inner.send(raw_socket, ptr, len, request_ptr));
+    }
+
+    #[inline]
+    fn send_fixed(
+        &mut self,
+        raw_socket: RawSocket,
+        ptr: *const u8,
+        len: u32,
+        buf_index: u16,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        self.dispatch_send(move |inner| {
+            inner.send_fixed(raw_socket, ptr, len, buf_index, request_ptr);
+        });
+    }
+
+    #[inline]
+    fn send_with_deadline(
+        &mut self,
+        raw_socket: RawSocket,
+        ptr: *const u8,
+        len: u32,
+        request_ptr: IoRequestDataPtr,
+        deadline: &mut Instant,
+    ) {
+        // The deadline lives in the pinned future which waits for this request.
+        let deadline_ptr: *mut Instant = deadline;
+        self.dispatch_send(move |inner| {
+            inner.send_with_deadline(raw_socket, ptr, len, request_ptr, unsafe {
+                &mut *deadline_ptr
+            });
+        });
+    }
+
+    #[inline]
+    fn send_fixed_with_deadline(
+        &mut self,
+        raw_socket: RawSocket,
+        ptr: *const u8,
+        len: u32,
+        buf_index: u16,
+        request_ptr: IoRequestDataPtr,
+        deadline: &mut Instant,
+    ) {
+        // The deadline lives in the pinned future which waits for this request.
+        let deadline_ptr: *mut Instant = deadline;
+        self.dispatch_send(move |inner| {
+            inner.send_fixed_with_deadline(raw_socket, ptr, len, buf_index, request_ptr, unsafe {
+                &mut *deadline_ptr
+            });
+        });
+    }
+
+    #[inline]
+    fn send_to(
+        &mut self,
+        raw_socket: RawSocket,
+        msg_header: *const OsMessageHeader,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        self.dispatch_send(move |inner| inner.send_to(raw_socket, msg_header, request_ptr));
+    }
+
+    #[inline]
+    fn send_to_with_deadline(
+        &mut self,
+        raw_socket: RawSocket,
+        msg_header: *const OsMessageHeader,
+        request_ptr: IoRequestDataPtr,
+        deadline: &mut Instant,
+    ) {
+        // The deadline lives in the pinned future which waits for this request.
+        let deadline_ptr: *mut Instant = deadline;
+        self.dispatch_send(move |inner| {
+            inner.send_to_with_deadline(raw_socket, msg_header, request_ptr, unsafe {
+                &mut *deadline_ptr
+            });
+        });
+    }
+
+    // endregion
+
+    // region peek
+
+    #[inline]
+    fn peek(
+        &mut self,
+        raw_socket: RawSocket,
+        ptr: *mut u8,
+        len: u32,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        self.inner.peek(raw_socket, ptr, len, request_ptr);
+    }
+
+    #[inline]
+    fn peek_fixed(
+        &mut self,
+        raw_socket: RawSocket,
+        ptr: *mut u8,
+        len: u32,
+        buf_index: u16,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        self.inner
+            .peek_fixed(raw_socket, ptr, len, buf_index, request_ptr);
+    }
+
+    #[inline]
+    fn peek_with_deadline(
+        &mut self,
+        raw_socket: RawSocket,
+        ptr: *mut u8,
+        len: u32,
+        request_ptr: IoRequestDataPtr,
+        deadline: &mut Instant,
+    ) {
+        self.inner
+            .peek_with_deadline(raw_socket, ptr, len, request_ptr, deadline);
+    }
+
+    #[inline]
+    fn peek_fixed_with_deadline(
+        &mut self,
+        raw_socket: RawSocket,
+        ptr: *mut u8,
+        len: u32,
+        buf_index: u16,
+        request_ptr: IoRequestDataPtr,
+        deadline: &mut Instant,
+    ) {
+        self.inner
+            .peek_fixed_with_deadline(raw_socket, ptr, len, buf_index, request_ptr, deadline);
+    }
+
+    #[inline]
+    fn peek_from(
+        &mut self,
+        raw_socket: RawSocket,
+        msg: &mut MessageRecvHeader,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        self.inner.peek_from(raw_socket, msg, request_ptr);
+    }
+
+    #[inline]
+    fn peek_from_with_deadline(
+        &mut self,
+        raw_socket: RawSocket,
+        msg: &mut MessageRecvHeader,
+        request_ptr: IoRequestDataPtr,
+        deadline: &mut Instant,
+    ) {
+        self.inner
+            .peek_from_with_deadline(raw_socket, msg, request_ptr, deadline);
+    }
+
+    // endregion
+
+    #[inline]
+    fn shutdown(&mut self, raw_socket: RawSocket, how: Shutdown, request_ptr: IoRequestDataPtr) {
+        self.inner.shutdown(raw_socket, how, request_ptr);
+    }
+
+    #[inline]
+    fn open(
+        &mut self,
+        path: OsPathPtr,
+        open_how: *const OsOpenOptions,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        self.inner.open(path, open_how, request_ptr);
+    }
+
+    #[inline]
+    fn fallocate(
+        &mut self,
+        raw_file: RawFile,
+        offset: u64,
+        len: u64,
+        flags: i32,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        self.inner
+            .fallocate(raw_file, offset, len, flags, request_ptr);
+    }
+
+    #[inline]
+    fn fadvise(
+        &mut self,
+        raw_file: RawFile,
+        offset: u64,
+        len: u64,
+        advice: i32,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        self.inner
+            .fadvise(raw_file, offset, len, advice, request_ptr);
+    }
+
+    #[inline]
+    fn sync_all(&mut self, raw_file: RawFile, request_ptr: IoRequestDataPtr) {
+        self.inner.sync_all(raw_file, request_ptr);
+    }
+
+    #[inline]
+    fn sync_data(&mut self, raw_file: RawFile, request_ptr: IoRequestDataPtr) {
+        self.inner.sync_data(raw_file, request_ptr);
+    }
+
+    #[inline]
+    fn truncate(&mut self, raw_file: RawFile, len: u64, request_ptr: IoRequestDataPtr) {
+        self.inner.truncate(raw_file, len, request_ptr);
+    }
+
+    // region read
+
+    #[inline]
+    fn read(&mut self, raw_file: RawFile, ptr: *mut u8, len: u32, request_ptr: IoRequestDataPtr) {
+        self.inner.read(raw_file, ptr, len, request_ptr);
+    }
+
+    #[inline]
+    fn read_fixed(
+        &mut self,
+        raw_file: RawFile,
+        ptr: *mut u8,
+        len: u32,
+        buf_index: u16,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        self.inner
+            .read_fixed(raw_file, ptr, len, buf_index, request_ptr);
+    }
+
+    #[inline]
+    fn pread(
+        &mut self,
+        raw_file: RawFile,
+        ptr: *mut u8,
+        len: u32,
+        offset: usize,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        self.inner.pread(raw_file, ptr, len, offset, request_ptr);
+    }
+
+    #[inline]
+    fn pread_fixed(
+        &mut self,
+        raw_file: RawFile,
+        ptr: *mut u8,
+        len: u32,
+        buf_index: u16,
+        offset: usize,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        self.inner
+            .pread_fixed(raw_file, ptr, len, buf_index, offset, request_ptr);
+    }
+
+    // endregion
+
+    // region write
+
+    #[inline]
+    fn write(
+        &mut self,
+        raw_file: RawFile,
+        ptr: *const u8,
+        len: u32,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        self.inner.write(raw_file, ptr, len, request_ptr);
+    }
+
+    #[inline]
+    fn write_fixed(
+        &mut self,
+        raw_file: RawFile,
+        ptr: *const u8,
+        len: u32,
+        buf_index: u16,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        self.inner
+            .write_fixed(raw_file, ptr, len, buf_index, request_ptr);
+    }
+
+    #[inline]
+    fn pwrite(
+        &mut self,
+        raw_file: RawFile,
+        ptr: *const u8,
+        len: u32,
+        offset: usize,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        self.inner.pwrite(raw_file, ptr, len, offset, request_ptr);
+    }
+
+    #[inline]
+    fn pwrite_fixed(
+        &mut self,
+        raw_file: RawFile,
+        ptr: *const u8,
+        len: u32,
+        buf_index: u16,
+        offset: usize,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        self.inner
+            .pwrite_fixed(raw_file, ptr, len, buf_index, offset, request_ptr);
+    }
+
+    // endregion
+
+    #[inline]
+    fn close_file(&mut self, raw_file: RawFile, request_ptr: IoRequestDataPtr) {
+        self.inner.close_file(raw_file, request_ptr);
+    }
+
+    #[inline]
+    fn close_socket(&mut self, raw_socket: RawSocket, request_ptr: IoRequestDataPtr) {
+        self.inner.close_socket(raw_socket, request_ptr);
+    }
+
+    #[inline]
+    fn rename(&mut self, old_path: OsPathPtr, new_path: OsPathPtr, request_ptr: IoRequestDataPtr) {
+        self.inner.rename(old_path, new_path, request_ptr);
+    }
+
+    #[inline]
+    fn create_dir(&mut self, path: OsPathPtr, mode: u32, request_ptr: IoRequestDataPtr) {
+        self.inner.create_dir(path, mode, request_ptr);
+    }
+
+    #[inline]
+    fn remove_file(&mut self, path: OsPathPtr, request_ptr: IoRequestDataPtr) {
+        self.inner.remove_file(path, request_ptr);
+    }
+
+    #[inline]
+    fn remove_dir(&mut self, path: OsPathPtr, request_ptr: IoRequestDataPtr) {
+        self.inner.remove_dir(path, request_ptr);
+    }
+
+    #[inline]
+    fn symlink(&mut self, target: OsPathPtr, link_path: OsPathPtr, request_ptr: IoRequestDataPtr) {
+        self.inner.symlink(target, link_path, request_ptr);
+    }
+
+    #[inline]
+    #[cfg(unix)]
+    fn linkat(
+        &mut self,
+        old_dir: RawFile,
+        old_path: OsPathPtr,
+        new_dir: RawFile,
+        new_path: OsPathPtr,
+        flags: i32,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        self.inner
+            .linkat(old_dir, old_path, new_dir, new_path, flags, request_ptr);
+    }
+
+    #[inline]
+    #[cfg(target_os = "linux")]
+    fn timeout(
+        &mut self,
+        timespec: *const io_uring::types::Timespec,
+        request_ptr: IoRequestDataPtr,
+    ) {
+        self.inner.timeout(timespec, request_ptr);
+    }
+}
+
+/// Makes `recv` and `recv_from` operations of the current thread fail with the provided `error`
+/// with the provided `probability` (from `0.0` to `1.0`).
+///
+/// Read [`FaultIoWorker::inject_error_on_recv`] for more details.
+///
+/// # Panics
+///
+/// If the current thread has no io worker or `probability` is not in `0.0..=1.0`.
+pub fn inject_error_on_recv(probability: f32, error: ErrorKind) {
+    FaultIoWorker::local().inject_error_on_recv(probability, error);
+}
+
+/// Delays `send` and `send_to` operations of the current thread.
+///
+/// Read [`FaultIoWorker::inject_latency_on_send`] for more details.
+///
+/// # Panics
+///
+/// If the current thread has no io worker.
+pub fn inject_latency_on_send(mean: Duration, stddev: Duration) {
+    FaultIoWorker::local().inject_latency_on_send(mean, stddev);
+}
+
+/// Removes all injections of the current thread.
+///
+/// # Panics
+///
+/// If the current thread has no io worker.
+pub fn clear_injections() {
+    FaultIoWorker::local().clear_injections();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+    use crate::io::{AsyncAccept, AsyncBind, AsyncConnectStream, AsyncRecv, AsyncSend};
+    use crate::net::{TcpListener, TcpStream};
+
+    #[orengine::test::test_local]
+    fn test_inject_error_on_recv() {
+        const ADDR: &str = "127.0.0.1:6094";
+
+        let mut listener = TcpListener::bind(ADDR).await.expect("bind failed");
+        let mut client = TcpStream::connect(ADDR).await.expect("connect failed");
+        let mut server = listener.accept().await.expect("accept failed").0;
+
+        client.send_all_bytes(b"ping").await.expect("send failed");
+
+        inject_error_on_recv(1.0, ErrorKind::ConnectionReset);
+        let mut buf = [0u8; 4];
+        let err = server
+            .recv_bytes(&mut buf)
+            .await
+            .expect_err("recv must fail");
+        assert_eq!(err.kind(), ErrorKind::ConnectionReset);
+
+        clear_injections();
+        server
+            .recv_bytes_exact(&mut buf)
+            .await
+            .expect("recv failed");
+        assert_eq!(&buf, b"ping");
+    }
+
+    #[orengine::test::test_local]
+    fn test_inject_latency_on_send() {
+        const ADDR: &str = "127.0.0.1:6095";
+        const LATENCY: Duration = Duration::from_millis(50);
+
+        let mut listener = TcpListener::bind(ADDR).await.expect("bind failed");
+        let mut client = TcpStream::connect(ADDR).await.expect("connect failed");
+        let mut server = listener.accept().await.expect("accept failed").0;
+
+        FaultIoWorker::local().inject_latency_on_send(LATENCY, Duration::ZERO);
+        let start = Instant::now();
+        client.send_all_bytes(b"ping").await.expect("send failed");
+        assert!(start.elapsed() >= LATENCY);
+        clear_injections();
+
+        let mut buf = [0u8; 4];
+        server
+            .recv_bytes_exact(&mut buf)
+            .await
+            .expect("recv failed");
+        assert_eq!(&buf, b"ping");
+    }
+}
@@ -6,6 +6,12 @@
 //! - [`executor_pool`] that contains utilities for parallel testing via
 //!   [`sched_future_to_another_thread`] or [`sched_future`](ExecutorPool::sched_future);
 //!
+//! - [`fake_clock`] that provides [`FakeClock`] to test timeout-dependent code
+//!   without waiting;
+//!
+//! - `fault_injection` (with the `fault_injection` feature) that provides `FaultIoWorker`
+//!   to simulate network errors and latency;
+//!
 //! - [`runner`] that provides a way to run tests with reusing
 //!   the same [`Executor`](crate::Executor) via [`run_test_and_block_on_local`]
 //!   and [`run_test_and_block_on_shared`].
@@ -21,8 +27,14 @@
 //! or [`sched_future`](ExecutorPool::sched_future).
 
 pub mod executor_pool;
+pub mod fake_clock;
+#[cfg(any(test, feature = "fault_injection"))]
+pub mod fault_injection;
 pub mod runner;
 
 pub use executor_pool::*;
+pub use fake_clock::FakeClock;
+#[cfg(any(test, feature = "fault_injection"))]
+pub use fault_injection::*;
 pub use orengine_macros::{test_local, test_shared};
 pub use runner::*;
@@ -28,7 +28,7 @@
 use crate::bug_message::BUG_MESSAGE;
 use crate::runtime::executor::get_local_executor_ref;
 use crate::runtime::Config;
-use crate::{local_executor, yield_now, Executor};
+use crate::{local_executor, yield_now, BlockOnError, Executor};
 use std::future::Future;
 
 /// Prints the first test message. It contains an information about build configuration.
@@ -132,6 +132,7 @@ where
     let executor = get_local_executor();
     executor
         .run_and_block_on_local(upgrade_future(future))
+        .map_err(BlockOnError::resume_unwind)
         .expect(BUG_MESSAGE);
 }
 
@@ -199,5 +200,6 @@ where
     let executor = get_local_executor();
     executor
         .run_and_block_on_shared(upgrade_future(future))
+        .map_err(BlockOnError::resume_unwind)
         .expect(BUG_MESSAGE);
 }
@@ -0,0 +1,212 @@
+//! This module contains [`system_load`] that samples the load of the system
+//! and of the current [`Executor`](crate::Executor).
+use crate::local_executor;
+use crate::BUG_MESSAGE;
+use std::cell::Cell;
+
+/// `SystemLoad` is a sample of the load of the system and of the current
+/// [`Executor`](crate::Executor) returned by [`system_load`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SystemLoad {
+    /// The part of the time in `[0.0, 1.0]` all CPUs were busy since the previous call
+    /// of [`system_load`] on the current thread (since boot for the first call).
+    ///
+    /// On platforms without `/proc/stat` it is estimated as `load_avg_1m`
+    /// divided by the number of CPUs.
+    pub cpu_usage: f64,
+    /// The system load average for the last minute. It is `0.0` on platforms without
+    /// `getloadavg`.
+    pub load_avg_1m: f64,
+    /// The number of tasks in the queues of the current [`Executor`](crate::Executor)
+    /// that are ready to be executed.
+    pub task_queue_depth: usize,
+    /// The number of I/O operations of the current [`Executor`](crate::Executor)
+    /// that are submitted but not completed yet.
+    pub io_queue_depth: usize,
+}
+
+/// Cumulative CPU times read from `/proc/stat`.
+#[derive(Clone, Copy)]
+struct CpuTimes {
+    busy: u64,
+    total: u64,
+}
+
+thread_local! {
+    /// The previous sample of [`CpuTimes`] of the current thread.
+    static PREVIOUS_CPU_TIMES: Cell<Option<CpuTimes>> = const { Cell::new(None) };
+}
+
+/// Reads cumulative CPU times of all CPUs from `/proc/stat`.
+#[cfg(target_os = "linux")]
+fn read_cpu_times() -> Option<CpuTimes> {
+    let stat = std::fs::read_to_string("/proc/stat").ok()?;
+    let times = stat
+        .lines()
+        .next()?
+        .strip_prefix("cpu ")?
+        .split_whitespace()
+        .take(8) // user nice system idle iowait irq softirq steal
+        .map(str::parse::<u64>)
+        .collect::<Result<Vec<_>, _>>()
+        .ok()?;
+    if times.len() < 5 {
+        return None;
+    }
+
+    let total = times.iter().sum();
+    let idle = times[3] + times[4];
+
+    Some(CpuTimes {
+        busy: total - idle,
+        total,
+    })
+}
+
+/// Returns `None`, because `/proc/stat` is available only on Linux.
+#[cfg(not(target_os = "linux"))]
+const fn read_cpu_times() -> Option<CpuTimes> {
+    None
+}
+
+/// Returns the system load average for the last minute.
+#[cfg(unix)]
+fn read_load_avg_1m() -> f64 {
+    let mut load_avg = [0.0f64; 1];
+    if unsafe { libc::getloadavg(load_avg.as_mut_ptr(), 1) } == 1 {
+        load_avg[0]
+    } else {
+        0.0
+    }
+}
+
+/// Returns `0.0`, because `getloadavg` is not available.
+#[cfg(not(unix))]
+const fn read_load_avg_1m() -> f64 {
+    0.0
+}
+
+/// Computes the CPU usage between two samples.
+#[allow(
+    clippy::cast_precision_loss,
+    reason = "CPU times are far less than 2^52 ticks"
+)]
+fn cpu_usage_between(previous: Option<CpuTimes>, current: CpuTimes) -> f64 {
+    let previous = previous.unwrap_or(CpuTimes { busy: 0, total: 0 });
+    let total = current.total.saturating_sub(previous.total);
+    if total == 0 {
+        return 0.0;
+    }
+
+    current.busy.saturating_sub(previous.busy) as f64 / total as f64
+}
+
+/// Estimates the CPU usage from the load average.
+#[allow(
+    clippy::cast_precision_loss,
+    reason = "the number of CPUs is far less than 2^52"
+)]
+fn cpu_usage_from_load_avg(load_avg_1m: f64) -> f64 {
+    let number_of_cpus = std::thread::available_parallelism().map_or(1, usize::from);
+
+    (load_avg_1m / number_of_cpus as f64).min(1.0)
+}
+
+/// Samples the load of the system and of the current [`Executor`](crate::Executor).
+///
+/// System statistics are read in the thread pool of the current
+/// [`Executor`](crate::Executor), so the executor is not blocked by the file system.
+///
+/// It allows adaptive systems to scale up under load and to scale down when idle,
+/// for example, by calling [`Executor::resize_thread_pool`](crate::Executor::resize_thread_pool)
+/// from a background task. Sample it periodically: `cpu_usage` is computed
+/// since the previous call on the same thread.
+///
+/// # Panics
+///
+/// If the thread pool of the current [`Executor`](crate::Executor) is disabled
+/// with `debug_assertions`.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::utils::system_load;
+/// use orengine::{local_executor, sleep};
+/// use std::time::Duration;
+///
+/// # async fn foo() {
+/// loop {
+///     let load = system_load().await;
+///     if load.cpu_usage < 0.5 && load.task_queue_depth > 1024 {
+///         local_executor().resize_thread_pool(8);
+///     } else if load.cpu_usage > 0.9 {
+///         local_executor().resize_thread_pool(1);
+///     }
+///
+///     sleep(Duration::from_secs(1)).await;
+/// }
+/// # }
+/// ```
+#[allow(
+    clippy::future_not_send,
+    reason = "It is not `Send` because of the result cell, it is fine"
+)]
+pub async fn system_load() -> SystemLoad {
+    let result = Cell::new(None);
+
+    crate::asyncify!(|| {
+        result.set(Some((read_cpu_times(), read_load_avg_1m())));
+    })
+    .await;
+
+    let (cpu_times, load_avg_1m) = result.take().expect(BUG_MESSAGE);
+    let cpu_usage = cpu_times.map_or_else(
+        || cpu_usage_from_load_avg(load_avg_1m),
+        |cpu_times| {
+            let previous = PREVIOUS_CPU_TIMES.with(|previous| previous.replace(Some(cpu_times)));
+
+            cpu_usage_between(previous, cpu_times)
+        },
+    );
+
+    let executor = local_executor();
+
+    SystemLoad {
+        cpu_usage,
+        load_avg_1m,
+        task_queue_depth: executor.number_of_spawned_tasks(),
+        io_queue_depth: executor.number_of_active_io_operations(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as orengine;
+
+    #[test]
+    fn test_cpu_usage_between() {
+        let previous = CpuTimes {
+            busy: 100,
+            total: 400,
+        };
+        let current = CpuTimes {
+            busy: 150,
+            total: 600,
+        };
+
+        assert!((cpu_usage_between(Some(previous), current) - 0.25).abs() < f64::EPSILON);
+        assert!(cpu_usage_between(Some(current), current).abs() < f64::EPSILON);
+    }
+
+    #[orengine::test::test_local]
+    fn test_system_load() {
+        let load = system_load().await;
+        assert!((0.0..=1.0).contains(&load.cpu_usage));
+        assert!(load.load_avg_1m >= 0.0);
+        assert_eq!(load.io_queue_depth, 0);
+
+        let load = system_load().await;
+        assert!((0.0..=1.0).contains(&load.cpu_usage));
+    }
+}
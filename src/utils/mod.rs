@@ -3,19 +3,22 @@ pub mod core;
 #[cfg(test)]
 pub(crate) mod droppable_element;
 pub(crate) mod each_addr;
+pub mod load;
 pub(crate) mod never_wait_lock;
 pub mod ptr;
+pub mod rate_meter;
 pub(crate) mod sealed;
 pub mod spin_lock;
 #[macro_use]
 pub mod task_structures_pool;
 pub(crate) mod progressive_timeout;
-pub(crate) mod vec_map;
 
 pub(crate) use assert_hint::assert_hint;
 pub use core::*;
+pub use load::{system_load, SystemLoad};
 pub(crate) use progressive_timeout::*;
 pub use ptr::*;
+pub use rate_meter::RateMeter;
 pub(crate) use sealed::Sealed;
 pub use spin_lock::*;
 pub use task_structures_pool::{
@@ -0,0 +1,171 @@
+//! This module contains [`RateMeter`].
+use crate::local_executor;
+use std::time::{Duration, Instant};
+
+/// `RateMeter` measures the throughput of a stream in bytes per second and operations per second.
+///
+/// Call [`record`](Self::record) on each completion of an io operation.
+/// The rates are exponential moving averages: a sample that is `window` old has
+/// `1 / e` of the weight of a fresh one. So, a shorter `window` reacts faster,
+/// and a longer one is smoother.
+///
+/// The time is read from [`start_round_time`](crate::Executor::start_round_time),
+/// so the configured [`Clock`](crate::runtime::Clock) is used. Completions recorded
+/// in the same round are accumulated until the next round.
+///
+/// It is used by [`RatedSend`](crate::io::RatedSend) and [`RatedRecv`](crate::io::RatedRecv)
+/// and can be used for adaptive flow control, network billing and connection quality metrics.
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::utils::RateMeter;
+/// use std::time::Duration;
+///
+/// # async fn foo() {
+/// let mut meter = RateMeter::new(Duration::from_secs(5));
+///
+/// meter.record(1024);
+///
+/// println!(
+///     "{:.0} B/s, {:.0} ops/s",
+///     meter.rate_bytes_per_sec(),
+///     meter.rate_ops_per_sec()
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RateMeter {
+    window: Duration,
+    last_update: Instant,
+    pending_bytes: usize,
+    pending_ops: usize,
+    bytes_per_sec: f64,
+    ops_per_sec: f64,
+}
+
+impl RateMeter {
+    /// Creates a new `RateMeter` with the given averaging `window`.
+    ///
+    /// # Panics
+    ///
+    /// If `window` is zero.
+    pub fn new(window: Duration) -> Self {
+        assert!(!window.is_zero(), "window must be greater than 0");
+
+        Self {
+            window,
+            last_update: local_executor().start_round_time(),
+            pending_bytes: 0,
+            pending_ops: 0,
+            bytes_per_sec: 0.0,
+            ops_per_sec: 0.0,
+        }
+    }
+
+    /// Returns the averaging window.
+    pub const fn window(&self) -> Duration {
+        self.window
+    }
+
+    /// Records a completed operation that transferred `bytes`.
+    pub fn record(&mut self, bytes: usize) {
+        self.pending_bytes += bytes;
+        self.pending_ops += 1;
+
+        let now = local_executor().start_round_time();
+        if now > self.last_update {
+            (self.bytes_per_sec, self.ops_per_sec) = self.rates_at(now);
+            self.pending_bytes = 0;
+            self.pending_ops = 0;
+            self.last_update = now;
+        }
+    }
+
+    /// Returns the average number of bytes per second.
+    pub fn rate_bytes_per_sec(&self) -> f64 {
+        self.rates_at(local_executor().start_round_time()).0
+    }
+
+    /// Returns the average number of operations per second.
+    pub fn rate_ops_per_sec(&self) -> f64 {
+        self.rates_at(local_executor().start_round_time()).1
+    }
+
+    /// Returns the rates updated with the operations recorded since the last update.
+    ///
+    /// If no operations have been recorded, the rates decay.
+    #[allow(
+        clippy::cast_precision_loss,
+        reason = "The precision of the rates is not important"
+    )]
+    fn rates_at(&self, now: Instant) -> (f64, f64) {
+        let elapsed = now
+            .saturating_duration_since(self.last_update)
+            .as_secs_f64();
+        if elapsed == 0.0 {
+            return (self.bytes_per_sec, self.ops_per_sec);
+        }
+
+        let alpha = 1.0 - (-elapsed / self.window.as_secs_f64()).exp();
+        let update = |rate: f64, pending: usize| rate + alpha * (pending as f64 / elapsed - rate);
+
+        (
+            update(self.bytes_per_sec, self.pending_bytes),
+            update(self.ops_per_sec, self.pending_ops),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::Config;
+    use crate::test::FakeClock;
+    use crate::{yield_now, Executor};
+
+    #[test]
+    fn test_rate_meter() {
+        static CLOCK: FakeClock = FakeClock::new();
+
+        let ex = Executor::init_with_config(Config::default().set_clock(&CLOCK));
+        let res = ex.run_and_block_on_local(async {
+            let mut meter = RateMeter::new(Duration::from_secs(1));
+            assert_eq!(meter.window(), Duration::from_secs(1));
+            assert!(meter.rate_bytes_per_sec().abs() < f64::EPSILON);
+
+            for _ in 0..40 {
+                CLOCK.advance(Duration::from_millis(100));
+                yield_now().await;
+
+                // 2 operations and 1000 bytes per 100 millis.
+                meter.record(400);
+                meter.record(600);
+            }
+            assert!((meter.rate_bytes_per_sec() - 10_000.0).abs() < 300.0);
+            assert!((meter.rate_ops_per_sec() - 20.0).abs() < 0.6);
+
+            for _ in 0..40 {
+                CLOCK.advance(Duration::from_millis(100));
+                yield_now().await;
+
+                meter.record(100);
+            }
+            assert!((meter.rate_bytes_per_sec() - 1_000.0).abs() < 300.0);
+            assert!((meter.rate_ops_per_sec() - 10.0).abs() < 0.6);
+
+            CLOCK.advance(Duration::from_secs(10));
+            yield_now().await;
+            assert!(meter.rate_bytes_per_sec() < 1.0);
+            assert!(meter.rate_ops_per_sec() < 0.01);
+        });
+
+        assert!(res.is_ok(), "{res:?}");
+    }
+
+    #[test]
+    #[should_panic(expected = "window must be greater than 0")]
+    fn test_rate_meter_zero_window() {
+        let _ = RateMeter::new(Duration::ZERO);
+    }
+}
@@ -35,9 +35,93 @@ impl Future for Yield {
     }
 }
 
+/// `LocalYield` implements the [`Future`] for yielding the current task
+/// with [`YieldCurrentLocalTask`](crate::runtime::call::Call::YieldCurrentLocalTask).
+///
+/// Read [`local_yield_now`] for more details.
+pub struct LocalYield {
+    was_yielded: bool,
+}
+
+impl Future for LocalYield {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        if this.was_yielded {
+            return Poll::Ready(());
+        }
+
+        this.was_yielded = true;
+        let task = unsafe { get_task_from_context!(cx) };
+        if task.is_local() {
+            unsafe { local_executor().yield_current_local_task() };
+        } else {
+            local_executor().add_task_at_the_start_of_lifo_shared_queue(task);
+        }
+
+        Poll::Pending
+    }
+}
+
+/// `local_yield_now` transfers control to the executor and places the current `local` task
+/// at the back of the queue of local tasks (`FIFO`).
+///
+/// Every other local task that is ready runs once, and the current task is resumed
+/// __in the same round__. Unlike [`yield_now`], it does __not__ allow I/O completions
+/// or wakeups of sleeping tasks to interpose, so it is suitable for bounded cooperative
+/// spinning, when the awaited condition is changed by other ready local tasks.
+///
+/// Don't spin on a condition that depends on I/O or timers with `local_yield_now`:
+/// the background work of the executor is done only when the round ends,
+/// so such a loop only wastes CPU. Use [`yield_now`] for it.
+///
+/// A `shared` task is yielded like with [`yield_now`].
+///
+/// # Example
+///
+/// ```rust
+/// use orengine::yield_now::local_yield_now;
+/// use orengine::Local;
+///
+/// async fn wait_for_other_local_tasks(number_of_ready_tasks: Local<usize>, expected: usize) {
+///     for _ in 0..100 {
+///         if *number_of_ready_tasks.borrow() == expected {
+///             return;
+///         }
+///         local_yield_now().await;
+///     }
+/// }
+/// ```
+pub fn local_yield_now() -> LocalYield {
+    LocalYield { was_yielded: false }
+}
+
 /// `yield_now` transfers control to the executor and adds the current task
 /// to the beginning of the LIFO queue.
 ///
+/// # Local and shared tasks
+///
+/// The executor executes only the tasks that were in the queue at the start of the round,
+/// so the current task is resumed in the next round:
+///
+/// - a `local` task is added to the queue of local tasks. It allows every other local task
+///   that is ready to run once before the current task is resumed;
+///
+/// - a `shared` task is added to the queue of shared tasks. It is the first task to be given
+///   to another executor if this executor [`shares work`](crate::Executor).
+///
+/// In both cases the background work of the executor (polling I/O completions, waking
+/// sleeping tasks, taking work from other executors) is done before the current task
+/// is resumed.
+///
+/// # The difference from [`local_yield_now`]
+///
+/// [`local_yield_now`] resumes a `local` task in the same round, so I/O completions
+/// and wakeups of sleeping tasks can't interpose. Use `yield_now` when the awaited condition
+/// depends on I/O, timers or other executors, and [`local_yield_now`] when it depends only on
+/// other ready local tasks.
+///
 /// # Example
 ///
 /// ```rust
@@ -73,4 +157,49 @@ mod tests {
         yield_now().await;
         assert!(*i_clone.borrow());
     }
+
+    #[orengine::test::test_local]
+    fn test_yield_now_runs_other_local_tasks_once() {
+        let number_of_runs = Local::new(0);
+        for _ in 0..3 {
+            let number_of_runs = number_of_runs.clone();
+            local_executor().spawn_local(async move {
+                loop {
+                    *number_of_runs.borrow_mut() += 1;
+                    yield_now().await;
+                }
+            });
+        }
+
+        yield_now().await;
+        assert_eq!(*number_of_runs.borrow(), 3);
+
+        yield_now().await;
+        assert_eq!(*number_of_runs.borrow(), 6);
+    }
+
+    #[orengine::test::test_local]
+    fn test_local_yield_now() {
+        let number_of_runs = Local::new(0);
+        for _ in 0..3 {
+            let number_of_runs = number_of_runs.clone();
+            local_executor().spawn_local(async move {
+                loop {
+                    *number_of_runs.borrow_mut() += 1;
+                    local_yield_now().await;
+                }
+            });
+        }
+
+        let rounds = local_executor().metrics().rounds_total();
+        for i in 1..=10 {
+            local_yield_now().await;
+            assert_eq!(*number_of_runs.borrow(), i * 3);
+        }
+        // The background work has not interposed.
+        assert_eq!(local_executor().metrics().rounds_total(), rounds);
+
+        yield_now().await;
+        assert!(local_executor().metrics().rounds_total() > rounds);
+    }
 }